@@ -430,6 +430,25 @@ pub fn validate_utf8_view(views: &[View], buffers: &[Buffer<u8>]) -> PolarsResul
     validate_view(views, buffers, validate_utf8)
 }
 
+/// Validate the data of an inlined view.
+#[inline]
+fn validate_utf8_inline(view: &View) -> PolarsResult<()> {
+    let bytes = view.to_le_bytes();
+    // SAFETY: inlined views have a length of at most 12.
+    let b = unsafe { bytes.get_unchecked_release(4..4 + view.length as usize) };
+    if b.is_ascii() {
+        return Ok(());
+    }
+    validate_utf8(b)
+}
+
+/// Whether `i` is the end of `b` or the start of a UTF-8 encoded character in `b`.
+#[inline]
+fn is_char_boundary(b: &[u8], i: usize) -> bool {
+    // Continuation bytes are of the form 0b10xxxxxx.
+    b.get(i).map_or(i == b.len(), |&byte| (byte as i8) >= -0x40)
+}
+
 /// # Safety
 /// The views and buffers must uphold the invariants of BinaryView otherwise we will go OOB.
 pub(super) unsafe fn validate_utf8_only(
@@ -440,11 +459,7 @@ pub(super) unsafe fn validate_utf8_only(
     // If we have no buffers, we don't have to branch.
     if all_buffers.is_empty() {
         for view in views {
-            let len = view.length;
-            validate_utf8(
-                view.to_le_bytes()
-                    .get_unchecked_release(4..4 + len as usize),
-            )?;
+            validate_utf8_inline(view)?;
         }
         return Ok(());
     }
@@ -452,31 +467,41 @@ pub(super) unsafe fn validate_utf8_only(
     // Fast path if all buffers are ascii
     if buffers_to_check.iter().all(|buf| buf.is_ascii()) {
         for view in views {
-            let len = view.length;
-            if len <= 12 {
-                validate_utf8(
-                    view.to_le_bytes()
-                        .get_unchecked_release(4..4 + len as usize),
-                )?;
+            if view.length <= View::MAX_INLINE_SIZE {
+                validate_utf8_inline(view)?;
             }
         }
     } else {
+        // Validate every buffer at once. A slice of a valid buffer is valid if it starts and ends
+        // at character boundaries. Buffers that are not valid as a whole (e.g. because they
+        // contain bytes that no view refers to) fall back to validating every slice.
+        let first_to_check = all_buffers.len() - buffers_to_check.len();
+        let buffer_is_valid = buffers_to_check
+            .iter()
+            .map(|buf| simdutf8::basic::from_utf8(buf).is_ok())
+            .collect::<Vec<_>>();
+
         for view in views {
             let len = view.length;
-            if len <= 12 {
-                validate_utf8(
-                    view.to_le_bytes()
-                        .get_unchecked_release(4..4 + len as usize),
-                )?;
+            if len <= View::MAX_INLINE_SIZE {
+                validate_utf8_inline(view)?;
             } else {
-                let buffer_idx = view.buffer_idx;
-                let offset = view.offset;
-                let data = all_buffers.get_unchecked_release(buffer_idx as usize);
+                let buffer_idx = view.buffer_idx as usize;
+                let data = all_buffers.get_unchecked_release(buffer_idx).as_slice();
 
-                let start = offset as usize;
+                let start = view.offset as usize;
                 let end = start + len as usize;
-                let b = &data.as_slice().get_unchecked_release(start..end);
-                validate_utf8(b)?;
+                let is_valid = buffer_idx
+                    .checked_sub(first_to_check)
+                    .is_some_and(|i| *buffer_is_valid.get_unchecked_release(i));
+                if is_valid {
+                    polars_ensure!(
+                        is_char_boundary(data, start) && is_char_boundary(data, end),
+                        ComputeError: "invalid utf8"
+                    );
+                } else {
+                    validate_utf8(data.get_unchecked_release(start..end))?;
+                }
             };
         }
     }
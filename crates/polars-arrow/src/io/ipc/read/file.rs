@@ -5,13 +5,14 @@ use arrow_format::ipc::planus::ReadAsRoot;
 use arrow_format::ipc::FooterRef;
 use polars_error::{polars_bail, polars_err, PolarsResult};
 use polars_utils::aliases::{InitHashMaps, PlHashMap};
+use polars_utils::pl_str::PlSmallStr;
 
 use super::super::{ARROW_MAGIC_V1, ARROW_MAGIC_V2, CONTINUATION_MARKER};
 use super::common::*;
 use super::schema::fb_to_schema;
 use super::{Dictionaries, OutOfSpecKind};
 use crate::array::Array;
-use crate::datatypes::ArrowSchemaRef;
+use crate::datatypes::{ArrowSchemaRef, Metadata};
 use crate::io::ipc::IpcSchema;
 use crate::record_batch::RecordBatchT;
 
@@ -34,6 +35,9 @@ pub struct FileMetadata {
 
     /// The total size of the file in bytes
     pub size: u64,
+
+    /// Custom metadata written to the footer of the file
+    pub custom_metadata: Option<Arc<Metadata>>,
 }
 
 /// Read the row count by summing the length of the of the record batches
@@ -211,12 +215,27 @@ pub fn deserialize_footer(footer_data: &[u8], size: u64) -> PolarsResult<FileMet
         })
         .transpose()?;
 
+    let custom_metadata = footer
+        .custom_metadata()?
+        .map(|list| {
+            let mut metadata = Metadata::new();
+            for kv in list {
+                let kv = kv?;
+                if let (Some(k), Some(v)) = (kv.key()?, kv.value()?) {
+                    metadata.insert(PlSmallStr::from_str(k), PlSmallStr::from_str(v));
+                }
+            }
+            PolarsResult::Ok(Arc::new(metadata))
+        })
+        .transpose()?;
+
     Ok(FileMetadata {
         schema: Arc::new(schema),
         ipc_schema,
         blocks,
         dictionaries,
         size,
+        custom_metadata,
     })
 }
 
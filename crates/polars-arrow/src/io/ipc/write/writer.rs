@@ -1,4 +1,5 @@
 use std::io::Write;
+use std::sync::Arc;
 
 use arrow_format::ipc::planus::Builder;
 use polars_error::{polars_bail, PolarsResult};
@@ -40,6 +41,8 @@ pub struct FileWriter<W: Write> {
     pub(crate) dictionary_tracker: DictionaryTracker,
     /// Buffer/scratch that is reused between writes
     pub(crate) encoded_message: EncodedData,
+    /// Custom metadata that is written to the footer
+    pub(crate) custom_footer_metadata: Option<Arc<Metadata>>,
 }
 
 impl<W: Write> FileWriter<W> {
@@ -83,6 +86,7 @@ impl<W: Write> FileWriter<W> {
                 cannot_replace: true,
             },
             encoded_message: Default::default(),
+            custom_footer_metadata: None,
         }
     }
 
@@ -91,6 +95,11 @@ impl<W: Write> FileWriter<W> {
         self.writer
     }
 
+    /// Set custom metadata that is written to the footer of the file when it is finished.
+    pub fn set_custom_footer_metadata(&mut self, custom_metadata: Arc<Metadata>) {
+        self.custom_footer_metadata = Some(custom_metadata);
+    }
+
     /// Get the inner memory scratches so they can be reused in a new writer.
     /// This can be utilized to save memory allocations for performance reasons.
     pub fn get_scratches(&mut self) -> EncodedData {
@@ -194,7 +203,15 @@ impl<W: Write> FileWriter<W> {
             schema: Some(Box::new(schema)),
             dictionaries: Some(std::mem::take(&mut self.dictionary_blocks)),
             record_batches: Some(std::mem::take(&mut self.record_blocks)),
-            custom_metadata: None,
+            custom_metadata: self.custom_footer_metadata.as_ref().map(|metadata| {
+                metadata
+                    .iter()
+                    .map(|(key, value)| arrow_format::ipc::KeyValue {
+                        key: Some(key.to_string()),
+                        value: Some(value.to_string()),
+                    })
+                    .collect()
+            }),
         };
         let mut builder = Builder::new();
         let footer_data = builder.finish(&root, None);
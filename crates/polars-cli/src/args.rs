@@ -0,0 +1,224 @@
+use polars::prelude::*;
+use polars::sql::sql_expr;
+
+use crate::output::OutputFormat;
+
+pub const USAGE: &str = "\
+Run SQL or expression pipelines over Parquet, CSV, IPC and NDJSON files.
+
+Usage: polars [OPTIONS] <SOURCE>...
+
+Every source is a local path, a glob or a cloud url. In SQL, a source is the table named after
+its file stem, and `self` is all the sources concatenated. Without SQL, the pipeline steps run
+in the given order over all the sources concatenated. Expressions use SQL syntax.
+
+Query:
+  -c, --sql <QUERY>           Run a SQL query
+      --filter <EXPR>         Keep the rows where the expression is true
+      --select <EXPRS>        Select comma separated expressions
+      --with-columns <EXPRS>  Add or replace comma separated expressions
+      --group-by <EXPRS>      Group by comma separated expressions, followed by --agg
+      --agg <EXPRS>           Aggregate the groups of the preceding --group-by
+      --sort <EXPRS>          Sort by comma separated expressions, each optionally followed by
+                              ASC or DESC
+      --unique                Keep the unique rows
+      --head <N>              Keep the first N rows
+
+Output:
+  -f, --format <FORMAT>       table, csv, json, ndjson, parquet or ipc [default: from the output
+                              extension, or table]
+  -o, --output <PATH>         Write the result to a file instead of the standard output
+      --explain               Print the optimized query plan instead of running the query
+      --streaming             Run the query on the streaming engine, and stream the result
+                              into the output file, in csv, ndjson, parquet or ipc
+  -h, --help                  Print this help
+";
+
+/// A step of an expression pipeline.
+pub enum Step {
+    Filter(Expr),
+    Select(Vec<Expr>),
+    WithColumns(Vec<Expr>),
+    GroupBy(Vec<Expr>, Vec<Expr>),
+    Sort(Vec<Expr>, Vec<bool>),
+    Unique,
+    Head(IdxSize),
+}
+
+#[derive(Default)]
+pub struct Args {
+    pub sources: Vec<String>,
+    pub sql: Option<String>,
+    pub steps: Vec<Step>,
+    pub format: Option<OutputFormat>,
+    pub output: Option<String>,
+    pub explain: bool,
+    pub streaming: bool,
+    pub help: bool,
+}
+
+impl Args {
+    /// Parse the arguments that follow the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> PolarsResult<Self> {
+        let mut out = Args::default();
+        // The keys of a `--group-by` that still waits for its `--agg`.
+        let mut group_by = None;
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| polars_err!(InvalidOperation: "missing value for `{}`", arg))
+            };
+            if group_by.is_some() && arg != "--agg" {
+                polars_bail!(InvalidOperation: "`--group-by` must be followed by `--agg`");
+            }
+            match arg.as_str() {
+                "-h" | "--help" => out.help = true,
+                "-c" | "--sql" => out.sql = Some(value()?),
+                "--filter" => out.steps.push(Step::Filter(sql_expr(value()?)?)),
+                "--select" => out.steps.push(Step::Select(parse_exprs(&value()?)?)),
+                "--with-columns" => out.steps.push(Step::WithColumns(parse_exprs(&value()?)?)),
+                "--group-by" => group_by = Some(parse_exprs(&value()?)?),
+                "--agg" => {
+                    let keys = group_by.take().ok_or_else(
+                        || polars_err!(InvalidOperation: "`--agg` must follow `--group-by`"),
+                    )?;
+                    out.steps.push(Step::GroupBy(keys, parse_exprs(&value()?)?));
+                },
+                "--sort" => {
+                    let (by, descending): (Vec<_>, Vec<_>) = split_exprs(&value()?)
+                        .into_iter()
+                        .map(|s| {
+                            let s = s.trim();
+                            let (s, descending) = match s.rsplit_once(char::is_whitespace) {
+                                Some((s, order)) if order.eq_ignore_ascii_case("desc") => (s, true),
+                                Some((s, order)) if order.eq_ignore_ascii_case("asc") => (s, false),
+                                _ => (s, false),
+                            };
+                            Ok((sql_expr(s)?, descending))
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?
+                        .into_iter()
+                        .unzip();
+                    out.steps.push(Step::Sort(by, descending));
+                },
+                "--unique" => out.steps.push(Step::Unique),
+                "--head" => {
+                    let n = value()?;
+                    let n = n.parse().map_err(
+                        |_| polars_err!(InvalidOperation: "invalid number of rows `{}`", n),
+                    )?;
+                    out.steps.push(Step::Head(n));
+                },
+                "-f" | "--format" => out.format = Some(value()?.parse()?),
+                "-o" | "--output" => out.output = Some(value()?),
+                "--explain" => out.explain = true,
+                "--streaming" => out.streaming = true,
+                _ if arg.starts_with('-') && arg.len() > 1 => {
+                    polars_bail!(InvalidOperation: "unknown option `{}`", arg)
+                },
+                _ => out.sources.push(arg),
+            }
+        }
+
+        polars_ensure!(
+            group_by.is_none(),
+            InvalidOperation: "`--group-by` must be followed by `--agg`"
+        );
+        if !out.help {
+            polars_ensure!(!out.sources.is_empty(), InvalidOperation: "no sources given");
+            polars_ensure!(
+                out.sql.is_none() || out.steps.is_empty(),
+                InvalidOperation: "`--sql` cannot be combined with pipeline steps"
+            );
+        }
+        Ok(out)
+    }
+}
+
+/// Parse comma separated expressions.
+fn parse_exprs(s: &str) -> PolarsResult<Vec<Expr>> {
+    split_exprs(s).into_iter().map(sql_expr).collect()
+}
+
+/// Split `s` at the commas that are not in parentheses, brackets or quotes.
+fn split_exprs(s: &str) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut depth = 0usize;
+    let mut quote = None;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {},
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(' | '[') => depth += 1,
+            (None, ')' | ']') => depth = depth.saturating_sub(1),
+            (None, ',') if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            },
+            _ => {},
+        }
+    }
+    parts.push(&s[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_split_exprs() {
+        assert_eq!(
+            split_exprs("a, coalesce(b, 0) AS b, 'x,y', [1, 2]"),
+            ["a", " coalesce(b, 0) AS b", " 'x,y'", " [1, 2]"]
+        );
+    }
+
+    fn parse(args: &[&str]) -> PolarsResult<Args> {
+        Args::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() -> PolarsResult<()> {
+        let args = parse(&[
+            "data.parquet",
+            "--filter",
+            "a > 1",
+            "--group-by",
+            "b",
+            "--agg",
+            "sum(a) AS a, concat_ws(',', c) AS c",
+            "--head",
+            "5",
+            "-f",
+            "csv",
+        ])?;
+        assert_eq!(args.sources, ["data.parquet"]);
+        assert!(matches!(args.format, Some(OutputFormat::Csv)));
+        assert_eq!(args.steps.len(), 3);
+        let Step::GroupBy(keys, aggs) = &args.steps[1] else {
+            panic!("expected a group by");
+        };
+        assert_eq!(keys.len(), 1);
+        assert_eq!(aggs.len(), 2);
+        assert!(matches!(args.steps[2], Step::Head(5)));
+
+        let args = parse(&["data.csv", "--sort", "a DESC, length(b)"])?;
+        let Step::Sort(by, descending) = &args.steps[0] else {
+            panic!("expected a sort");
+        };
+        assert_eq!(by.len(), 2);
+        assert_eq!(descending, &[true, false]);
+
+        assert!(parse(&["data.csv", "--group-by", "a"]).is_err());
+        assert!(parse(&["data.csv", "--agg", "sum(a)"]).is_err());
+        assert!(parse(&["data.csv", "-c", "SELECT 1", "--unique"]).is_err());
+        assert!(parse(&["data.csv", "--unknown"]).is_err());
+        assert!(parse(&["--help"])?.help);
+        Ok(())
+    }
+}
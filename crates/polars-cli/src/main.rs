@@ -0,0 +1,65 @@
+//! # Polars CLI
+//!
+//! Run SQL or expression pipelines over Parquet, CSV, IPC and NDJSON files from the command line,
+//! e.g.
+//!
+//! ```text
+//! polars sales.parquet -c "SELECT region, sum(amount) FROM sales GROUP BY region"
+//! polars "logs/*.ndjson" --filter "level = 'error'" --select "ts, msg" -f csv
+//! polars big.csv --group-by key --agg "count(*) AS n" --streaming -o counts.parquet
+//! ```
+mod args;
+mod output;
+mod sources;
+
+use polars::prelude::*;
+
+use crate::args::{Args, Step, USAGE};
+use crate::output::OutputFormat;
+
+fn apply(lf: LazyFrame, step: Step) -> LazyFrame {
+    match step {
+        Step::Filter(predicate) => lf.filter(predicate),
+        Step::Select(exprs) => lf.select(exprs),
+        Step::WithColumns(exprs) => lf.with_columns(exprs),
+        Step::GroupBy(keys, aggs) => lf.group_by_stable(keys).agg(aggs),
+        Step::Sort(by, descending) => lf.sort_by_exprs(
+            by,
+            SortMultipleOptions::default().with_order_descending_multi(descending),
+        ),
+        Step::Unique => lf.unique_stable(None, UniqueKeepStrategy::First),
+        Step::Head(n) => lf.limit(n),
+    }
+}
+
+fn run(args: Args) -> PolarsResult<()> {
+    let lf = match &args.sql {
+        Some(query) => sources::sql_context(&args.sources)?.execute(query)?,
+        None => args
+            .steps
+            .into_iter()
+            .fold(sources::scan_all(&args.sources)?, apply),
+    };
+
+    if args.explain {
+        println!("{}", lf.with_streaming(args.streaming).explain(true)?);
+        return Ok(());
+    }
+    let format = OutputFormat::resolve(args.format, args.output.as_deref())?;
+    output::write(lf, format, args.output.as_deref(), args.streaming)
+}
+
+fn main() {
+    let result = Args::parse(std::env::args().skip(1)).and_then(|args| {
+        if args.help {
+            print!("{USAGE}");
+            Ok(())
+        } else {
+            run(args)
+        }
+    });
+    if let Err(err) = result {
+        eprintln!("error: {err}\n\nRun `polars --help` for the usage.");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,119 @@
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::str::FromStr;
+
+use polars::prelude::*;
+
+/// The format that the result is written in.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    Table,
+    Csv,
+    Json,
+    NdJson,
+    Parquet,
+    Ipc,
+}
+
+impl FromStr for OutputFormat {
+    type Err = PolarsError;
+
+    fn from_str(s: &str) -> PolarsResult<Self> {
+        Ok(match s.to_lowercase().as_str() {
+            "table" => Self::Table,
+            "csv" => Self::Csv,
+            "json" => Self::Json,
+            "ndjson" | "jsonl" => Self::NdJson,
+            "parquet" => Self::Parquet,
+            "ipc" | "arrow" | "feather" => Self::Ipc,
+            _ => polars_bail!(InvalidOperation: "unknown output format `{}`", s),
+        })
+    }
+}
+
+impl OutputFormat {
+    /// The format of `format`, or else the format of the extension of `output`, or else a table.
+    pub fn resolve(format: Option<Self>, output: Option<&str>) -> PolarsResult<Self> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+        match output.and_then(|path| path.rsplit_once('.')) {
+            Some((_, ext)) => ext.parse(),
+            None => Ok(Self::Table),
+        }
+    }
+}
+
+/// Run `lf` and write the result in `format` to `output`, or to the standard output. With
+/// `streaming`, the result is streamed into the output file, which requires an output file in a
+/// format that can be written in batches.
+pub fn write(
+    lf: LazyFrame,
+    format: OutputFormat,
+    output: Option<&str>,
+    streaming: bool,
+) -> PolarsResult<()> {
+    if streaming {
+        let path = output.ok_or_else(|| {
+            polars_err!(InvalidOperation: "--streaming requires an output file, the standard output cannot be streamed into")
+        })?;
+        let lf = lf.with_streaming(true);
+        return match format {
+            OutputFormat::Csv => lf.sink_csv(path, Default::default()),
+            OutputFormat::NdJson => lf.sink_json(path, Default::default()),
+            OutputFormat::Parquet => lf.sink_parquet(path, Default::default()),
+            OutputFormat::Ipc => lf.sink_ipc(path, Default::default()),
+            OutputFormat::Table | OutputFormat::Json => polars_bail!(
+                InvalidOperation: "--streaming cannot stream {:?} output, choose csv, ndjson, parquet or ipc",
+                format
+            ),
+        };
+    }
+
+    let mut df = lf.collect()?;
+    match output {
+        Some(path) => {
+            polars_ensure!(
+                format != OutputFormat::Table,
+                InvalidOperation: "cannot write a table to a file, choose another format"
+            );
+            write_df(&mut df, format, BufWriter::new(File::create(path)?))
+        },
+        None => write_df(&mut df, format, std::io::stdout().lock()),
+    }
+}
+
+fn write_df<W: Write>(df: &mut DataFrame, format: OutputFormat, mut writer: W) -> PolarsResult<()> {
+    match format {
+        OutputFormat::Table => writeln!(writer, "{df}")?,
+        OutputFormat::Csv => CsvWriter::new(&mut writer).finish(df)?,
+        OutputFormat::Json => JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::Json)
+            .finish(df)?,
+        OutputFormat::NdJson => JsonWriter::new(&mut writer)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(df)?,
+        OutputFormat::Parquet => {
+            ParquetWriter::new(&mut writer).finish(df)?;
+        },
+        OutputFormat::Ipc => IpcWriter::new(&mut writer).finish(df)?,
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use polars::df;
+
+    use super::*;
+
+    #[test]
+    fn test_write_streaming_requires_sinkable_output() -> PolarsResult<()> {
+        let lf = df!("a" => [1, 2])?.lazy();
+        assert!(write(lf.clone(), OutputFormat::Csv, None, true).is_err());
+        assert!(write(lf.clone(), OutputFormat::Table, Some("out.txt"), true).is_err());
+        assert!(write(lf, OutputFormat::Json, Some("out.json"), true).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,111 @@
+use polars::prelude::*;
+use polars::sql::SQLContext;
+
+/// The file formats that can be scanned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum SourceFormat {
+    Parquet,
+    Csv,
+    Ipc,
+    NdJson,
+}
+
+impl SourceFormat {
+    /// Infer the format of `source` from its extension, ignoring a compression extension.
+    fn from_path(source: &str) -> PolarsResult<Self> {
+        let lower = source.to_lowercase();
+        let lower = [".gz", ".zst", ".zlib"]
+            .iter()
+            .find_map(|ext| lower.strip_suffix(ext))
+            .unwrap_or(&lower);
+        let ext = lower
+            .rsplit_once('.')
+            .map(|(_, ext)| ext)
+            .unwrap_or_default();
+        Ok(match ext {
+            "parquet" | "pq" => Self::Parquet,
+            "csv" | "tsv" | "txt" => Self::Csv,
+            "ipc" | "arrow" | "feather" => Self::Ipc,
+            "ndjson" | "jsonl" => Self::NdJson,
+            _ => polars_bail!(
+                InvalidOperation: "cannot infer the format of `{}` from its extension", source
+            ),
+        })
+    }
+}
+
+/// Scan `source`, a local path, a glob or a cloud url.
+pub fn scan(source: &str) -> PolarsResult<LazyFrame> {
+    match SourceFormat::from_path(source)? {
+        SourceFormat::Parquet => LazyFrame::scan_parquet(source, Default::default()),
+        SourceFormat::Csv => {
+            let separator = if source.to_lowercase().contains(".tsv") {
+                b'\t'
+            } else {
+                b','
+            };
+            LazyCsvReader::new(source)
+                .with_separator(separator)
+                .finish()
+        },
+        SourceFormat::Ipc => LazyFrame::scan_ipc(source, Default::default()),
+        SourceFormat::NdJson => LazyJsonLineReader::new(source).finish(),
+    }
+}
+
+/// The SQL table name of `source`: its file stem with the characters that are not valid in an
+/// identifier replaced by underscores.
+pub fn table_name(source: &str) -> String {
+    let file_name = source.rsplit(['/', '\\']).next().unwrap_or(source);
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    let mut name = stem
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>();
+    if !name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// Scan all `sources`, concatenated vertically.
+pub fn scan_all(sources: &[String]) -> PolarsResult<LazyFrame> {
+    let lfs = sources
+        .iter()
+        .map(|source| scan(source))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if lfs.len() == 1 {
+        Ok(lfs.into_iter().next().unwrap())
+    } else {
+        concat(lfs, Default::default())
+    }
+}
+
+/// A SQL context with every source registered under its [`table_name`], and all the sources as
+/// `self`.
+pub fn sql_context(sources: &[String]) -> PolarsResult<SQLContext> {
+    let mut ctx = SQLContext::new();
+    for source in sources {
+        ctx.register(&table_name(source), scan(source)?);
+    }
+    ctx.register("self", scan_all(sources)?);
+    Ok(ctx)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_source_names() -> PolarsResult<()> {
+        assert_eq!(
+            SourceFormat::from_path("s3://bucket/data.PARQUET")?,
+            SourceFormat::Parquet
+        );
+        assert_eq!(SourceFormat::from_path("dir/*.csv.gz")?, SourceFormat::Csv);
+        assert!(SourceFormat::from_path("data").is_err());
+        assert_eq!(table_name("dir/sales-2024.csv.gz"), "sales_2024");
+        assert_eq!(table_name("2024.parquet"), "_2024");
+        Ok(())
+    }
+}
@@ -0,0 +1,133 @@
+use arrow::array::{Array, BinaryViewArray, View};
+use arrow::bitmap::Bitmap;
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+use polars_utils::cpuid::is_avx512_enabled;
+
+use super::boolean::filter_boolean_kernel;
+use super::primitive::filter_values;
+
+pub fn filter_binview(array: &BinaryViewArray, mask: &Bitmap) -> BinaryViewArray {
+    let views = filter_views(array.views(), mask);
+    let validity = array.validity().map(|v| filter_boolean_kernel(v, mask));
+
+    // The views still point into the same buffers, so no buffer indices have to be fixed up.
+    unsafe {
+        BinaryViewArray::new_unchecked_unknown_md(
+            array.dtype().clone(),
+            views.into(),
+            array.data_buffers().clone(),
+            validity,
+            Some(array.total_buffer_len()),
+        )
+    }
+}
+
+/// Filter the 16-byte views in bulk.
+fn filter_views(views: &[View], mask: &Bitmap) -> Vec<View> {
+    #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+    if is_avx512_enabled() {
+        if let Some(out) = filter_views_avx512(views, mask) {
+            return out;
+        }
+    }
+
+    filter_values(views, mask)
+}
+
+/// Spreads the lower 32 bits of `x` such that bit `i` ends up in bits `2i` and `2i + 1`.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+#[inline]
+fn spread_bits(x: u64) -> u64 {
+    let mut x = x & 0xFFFF_FFFF;
+    x = (x | (x << 16)) & 0x0000_FFFF_0000_FFFF;
+    x = (x | (x << 8)) & 0x00FF_00FF_00FF_00FF;
+    x = (x | (x << 4)) & 0x0F0F_0F0F_0F0F_0F0F;
+    x = (x | (x << 2)) & 0x3333_3333_3333_3333;
+    x = (x | (x << 1)) & 0x5555_5555_5555_5555;
+    x | (x << 1)
+}
+
+/// Creates a mask that selects both u64 halves of every selected view.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+fn widen_mask(mask: &Bitmap) -> Vec<u8> {
+    let mut out = Vec::with_capacity(2 * mask.len().div_ceil(64) * 8 + 16);
+    let mut push_word = |w: u64| {
+        out.extend_from_slice(&spread_bits(w).to_le_bytes());
+        out.extend_from_slice(&spread_bits(w >> 32).to_le_bytes());
+    };
+
+    let mut iter = mask.fast_iter_u64();
+    iter.by_ref().for_each(&mut push_word);
+    let (rem, rem_len) = iter.remainder();
+    push_word(rem[0]);
+    if rem_len > 64 {
+        push_word(rem[1]);
+    }
+    out
+}
+
+/// Filters the views as pairs of u64 with the AVX512 compress kernel.
+///
+/// Returns `None` if the views or the output allocation are not suitably aligned.
+#[cfg(all(target_arch = "x86_64", feature = "simd"))]
+fn filter_views_avx512(views: &[View], mask: &Bitmap) -> Option<Vec<View>> {
+    use super::avx512::filter_u64_avx512f;
+    use super::scalar::scalar_filter;
+
+    assert_eq!(views.len(), mask.len());
+    let lanes: &[u64] = bytemuck::try_cast_slice(views).ok()?;
+
+    let mask_bits_set = mask.set_bits();
+    // The kernel may overshoot by 8 u64 lanes, the scalar tail by 1 lane.
+    let mut out: Vec<View> = Vec::with_capacity(mask_bits_set + 5);
+    if out.as_ptr() as usize % std::mem::align_of::<u64>() != 0 {
+        return None;
+    }
+    let wide_mask = widen_mask(mask);
+
+    unsafe {
+        // SAFETY: is_avx512_enabled was checked, the output is aligned and padded.
+        let out_ptr = out.as_mut_ptr() as *mut u64;
+        let (lanes, wide_mask, out_ptr) = filter_u64_avx512f(lanes, &wide_mask, out_ptr);
+        scalar_filter(lanes, wide_mask, out_ptr);
+        out.set_len(mask_bits_set);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(all(target_arch = "x86_64", feature = "simd"))]
+    fn test_spread_bits() {
+        assert_eq!(spread_bits(0b1), 0b11);
+        assert_eq!(spread_bits(0b101), 0b110011);
+        assert_eq!(spread_bits(u32::MAX as u64), u64::MAX);
+        // Only the lower 32 bits are spread.
+        assert_eq!(spread_bits(1 << 32), 0);
+    }
+
+    #[test]
+    fn test_filter_binview() {
+        let values = (0..200)
+            .map(|i| (i % 7 != 0).then(|| format!("a somewhat longer value {i}")))
+            .collect::<Vec<_>>();
+        let arr = BinaryViewArray::from_slice(&values);
+        let mask = Bitmap::from_iter((0..200).map(|i| i % 3 == 0));
+
+        for offset in [0, 3, 64] {
+            let arr = arr.clone().sliced(offset, 200 - offset);
+            let mask = mask.clone().sliced(offset, 200 - offset);
+            let out = filter_binview(&arr, &mask);
+
+            let expected = arr
+                .iter()
+                .zip(mask.iter())
+                .filter_map(|(v, m)| m.then_some(v))
+                .collect::<Vec<_>>();
+            assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! Contains operators to filter arrays such as [`filter`].
+mod binview;
 mod boolean;
 mod primitive;
 mod scalar;
@@ -49,19 +50,7 @@ pub fn filter_with_bitmap(array: &dyn Array, mask: &Bitmap) -> Box<dyn Array> {
         },
         BinaryView => {
             let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
-            let views = array.views();
-            let validity = array.validity();
-            let (views, validity) = primitive::filter_values_and_validity(views, validity, mask);
-            unsafe {
-                BinaryViewArray::new_unchecked_unknown_md(
-                    array.dtype().clone(),
-                    views.into(),
-                    array.data_buffers().clone(),
-                    validity,
-                    Some(array.total_buffer_len()),
-                )
-            }
-            .boxed()
+            binview::filter_binview(array, mask).boxed()
         },
         // Should go via BinaryView
         Utf8View => {
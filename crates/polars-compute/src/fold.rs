@@ -0,0 +1,26 @@
+use arrow::bitmap::bitmask::BitMask;
+use arrow::types::NativeType;
+
+/// Fold the values of `values` that are set in `mask` into `acc`, 32 values at a time.
+///
+/// Words of the mask that are entirely set are folded without looking at the mask and words
+/// that are entirely unset are skipped. In the other words, the masked out values are replaced
+/// by `identity` instead of branching per value, so folding `identity` must not change the
+/// accumulator.
+pub(crate) fn fold_masked<T, A, F>(values: &[T], mask: BitMask<'_>, identity: T, acc: A, f: F) -> A
+where
+    T: NativeType,
+    F: Fn(A, T) -> A,
+{
+    debug_assert_eq!(values.len(), mask.len());
+    values
+        .chunks(32)
+        .enumerate()
+        .fold(acc, |acc, (i, chunk)| match mask.get_u32(i * 32) {
+            0 => acc,
+            u32::MAX => chunk.iter().copied().fold(acc, &f),
+            m => chunk.iter().enumerate().fold(acc, |acc, (j, &v)| {
+                f(acc, if (m >> j) & 1 != 0 { v } else { identity })
+            }),
+        })
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, BinaryViewArray, View};
+use arrow::bitmap::Bitmap;
+use arrow::buffer::Buffer;
+use arrow::datatypes::IdxArr;
+use polars_utils::IdxSize;
+
+/// Where the views of a single target array end up in the output.
+struct TargetLayout {
+    /// Cumulative lengths, `cumlens[i]` is the first global index of target `i`.
+    cumlens: Vec<IdxSize>,
+    /// Buffer index offset to add to the non-inlined views of target `i`.
+    buffer_offsets: Vec<u32>,
+    buffers: Arc<[Buffer<u8>]>,
+    total_buffer_len: usize,
+}
+
+impl TargetLayout {
+    fn new(targets: &[&BinaryViewArray]) -> Self {
+        let mut cumlens = Vec::with_capacity(targets.len());
+        let mut len = 0;
+        for arr in targets {
+            cumlens.push(len);
+            len += arr.len() as IdxSize;
+        }
+
+        let first = targets[0].data_buffers();
+        if targets
+            .iter()
+            .all(|arr| Arc::ptr_eq(arr.data_buffers(), first))
+        {
+            // Fast path: all targets share their buffers (e.g. slices of the same array),
+            // the views can be copied as is.
+            return Self {
+                cumlens,
+                buffer_offsets: vec![0; targets.len()],
+                buffers: first.clone(),
+                total_buffer_len: targets[0].total_buffer_len(),
+            };
+        }
+
+        // Concatenate the buffers, deduplicating buffer sets that are shared between targets.
+        let mut seen: Vec<(*const Buffer<u8>, u32)> = Vec::with_capacity(targets.len());
+        let mut buffer_offsets = Vec::with_capacity(targets.len());
+        let mut buffers = Vec::new();
+        let mut total_buffer_len = 0;
+        for arr in targets {
+            let key = arr.data_buffers().as_ptr();
+            let offset = match seen.iter().find(|(k, _)| *k == key) {
+                Some((_, offset)) => *offset,
+                None => {
+                    let offset = buffers.len() as u32;
+                    buffers.extend(arr.data_buffers().iter().cloned());
+                    total_buffer_len += arr.total_buffer_len();
+                    seen.push((key, offset));
+                    offset
+                },
+            };
+            buffer_offsets.push(offset);
+        }
+
+        Self {
+            cumlens,
+            buffer_offsets,
+            buffers: buffers.into(),
+            total_buffer_len,
+        }
+    }
+
+    #[inline]
+    fn resolve(&self, idx: IdxSize) -> (usize, usize) {
+        let chunk_idx = self.cumlens.partition_point(|&l| l <= idx) - 1;
+        (chunk_idx, (idx - self.cumlens[chunk_idx]) as usize)
+    }
+}
+
+/// Adds `offset` to the buffer index of `view` if it isn't inlined.
+#[inline(always)]
+fn fix_up_view(mut view: View, offset: u32) -> View {
+    let not_inlined = (view.length > View::MAX_INLINE_SIZE) as u32;
+    view.buffer_idx += offset & not_inlined.wrapping_neg();
+    view
+}
+
+/// Gathers rows from the (logical) concatenation of `targets` without rechunking them.
+///
+/// Views are copied in bulk, views that point into the buffers of a target get their
+/// buffer index fixed up to point into the concatenated buffers. The validity is built
+/// 64 rows at a time. Null indices produce null values.
+///
+/// # Safety
+/// All non-null indices must be in-bounds of the concatenation of `targets`.
+pub unsafe fn take_binview_unchecked(
+    targets: &[&BinaryViewArray],
+    indices: &IdxArr,
+) -> BinaryViewArray {
+    assert!(!targets.is_empty());
+    let dtype = targets[0].dtype().clone();
+    let total_len: usize = targets.iter().map(|arr| arr.len()).sum();
+    if total_len == 0 {
+        // All indices must be null.
+        return BinaryViewArray::new_null(dtype, indices.len());
+    }
+
+    let layout = TargetLayout::new(targets);
+    let idx = indices.values().as_slice();
+    let idx_validity = indices.validity().filter(|v| v.unset_bits() > 0);
+    let targets_have_nulls = targets.iter().any(|arr| arr.null_count() > 0);
+
+    let views = if targets.len() == 1 {
+        let src = targets[0].views().as_slice();
+        gather_views(idx, idx_validity, |i| *src.get_unchecked(i as usize))
+    } else {
+        let srcs = targets
+            .iter()
+            .map(|arr| arr.views().as_slice())
+            .collect::<Vec<_>>();
+        gather_views(idx, idx_validity, |i| {
+            let (chunk_idx, arr_idx) = layout.resolve(i);
+            let view = *srcs.get_unchecked(chunk_idx).get_unchecked(arr_idx);
+            fix_up_view(view, *layout.buffer_offsets.get_unchecked(chunk_idx))
+        })
+    };
+
+    let validity = if targets_have_nulls {
+        Some(if targets.len() == 1 {
+            let validity = targets[0].validity();
+            gather_validity(idx, idx_validity, |i| {
+                validity.map_or(true, |v| v.get_bit_unchecked(i as usize))
+            })
+        } else {
+            gather_validity(idx, idx_validity, |i| {
+                let (chunk_idx, arr_idx) = layout.resolve(i);
+                targets
+                    .get_unchecked(chunk_idx)
+                    .validity()
+                    .map_or(true, |v| v.get_bit_unchecked(arr_idx))
+            })
+        })
+    } else {
+        idx_validity.cloned()
+    };
+
+    BinaryViewArray::new_unchecked_unknown_md(
+        dtype,
+        views.into(),
+        layout.buffers,
+        validity,
+        Some(layout.total_buffer_len),
+    )
+    .maybe_gc()
+}
+
+/// # Safety
+/// `get_view` must be safe to call with every non-null index.
+unsafe fn gather_views<F: Fn(IdxSize) -> View>(
+    idx: &[IdxSize],
+    idx_validity: Option<&Bitmap>,
+    get_view: F,
+) -> Vec<View> {
+    match idx_validity {
+        None => idx.iter().map(|i| get_view(*i)).collect(),
+        Some(validity) => {
+            // Null indices may contain any value, substitute them with a valid index
+            // and write an empty view instead.
+            let mut out = Vec::with_capacity(idx.len());
+            for_each_word(idx, validity, |chunk, mut word| {
+                for i in chunk {
+                    let valid = word & 1 == 1;
+                    let view = get_view(if valid { *i } else { 0 });
+                    out.push(if valid { view } else { View::default() });
+                    word >>= 1;
+                }
+            });
+            out
+        },
+    }
+}
+
+/// # Safety
+/// `get_bit` must be safe to call with every non-null index.
+unsafe fn gather_validity<F: Fn(IdxSize) -> bool>(
+    idx: &[IdxSize],
+    idx_validity: Option<&Bitmap>,
+    get_bit: F,
+) -> Bitmap {
+    let mut bytes = Vec::with_capacity(idx.len().div_ceil(64) * 8);
+    let mut push_chunk = |chunk: &[IdxSize], idx_word: u64| {
+        let mut word = 0u64;
+        for (bit, i) in chunk.iter().enumerate() {
+            let valid = (idx_word >> bit) & 1 == 1;
+            word |= ((valid && get_bit(if valid { *i } else { 0 })) as u64) << bit;
+        }
+        bytes.extend_from_slice(&word.to_le_bytes());
+    };
+    match idx_validity {
+        None => idx.chunks(64).for_each(|chunk| push_chunk(chunk, u64::MAX)),
+        Some(validity) => for_each_word(idx, validity, push_chunk),
+    }
+    bytes.truncate(idx.len().div_ceil(8));
+    Bitmap::from_u8_vec(bytes, idx.len())
+}
+
+/// Calls `f` for every chunk of 64 indices together with the corresponding validity word.
+fn for_each_word<F: FnMut(&[IdxSize], u64)>(idx: &[IdxSize], validity: &Bitmap, mut f: F) {
+    assert_eq!(idx.len(), validity.len());
+    let mut chunks = idx.chunks(64);
+    let mut words = validity.fast_iter_u64();
+    for word in words.by_ref() {
+        f(chunks.next().unwrap(), word);
+    }
+    let (rem, _) = words.remainder();
+    for word in rem {
+        if let Some(chunk) = chunks.next() {
+            f(chunk, word);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::PrimitiveArray;
+
+    use super::*;
+
+    fn check(targets: &[&BinaryViewArray], indices: &IdxArr) {
+        let all = targets
+            .iter()
+            .flat_map(|arr| arr.iter())
+            .collect::<Vec<_>>();
+        let expected = indices
+            .iter()
+            .map(|i| i.and_then(|i| all[*i as usize]))
+            .collect::<Vec<_>>();
+
+        let out = unsafe { take_binview_unchecked(targets, indices) };
+        assert_eq!(out.iter().collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn test_take_binview_chunked() {
+        let a = BinaryViewArray::from_slice([
+            Some("short"),
+            None,
+            Some("a longer string that is not inlined"),
+        ]);
+        let b = BinaryViewArray::from_slice_values([
+            "another string that lives in a buffer",
+            "tiny",
+            "and one more value that needs a buffer",
+        ]);
+        let indices = (0..150)
+            .map(|i| (i % 11 != 0).then_some(((i * 7) % 6) as IdxSize))
+            .collect::<PrimitiveArray<IdxSize>>();
+
+        check(&[&a], &PrimitiveArray::from_slice([2 as IdxSize, 0, 1, 2]));
+        check(&[&a, &b], &indices);
+        check(&[&b, &a], &indices);
+        // Shared buffers don't need a fix-up.
+        check(
+            &[&b.clone().sliced(0, 1), &b.clone().sliced(1, 2), &a],
+            &indices,
+        );
+    }
+}
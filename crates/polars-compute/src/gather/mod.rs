@@ -0,0 +1,2 @@
+//! Contains kernels to gather values from arrays by index.
+pub mod binview;
@@ -12,9 +12,12 @@ pub mod arity;
 pub mod comparisons;
 pub mod filter;
 pub mod float_sum;
+mod fold;
+pub mod gather;
 pub mod if_then_else;
 pub mod min_max;
 pub mod size;
+pub mod sum;
 pub mod unique;
 
 // Trait to enable the scalar blanket implementation.
@@ -1,10 +1,12 @@
 use arrow::array::{
     Array, BinaryArray, BinaryViewArray, BooleanArray, PrimitiveArray, Utf8Array, Utf8ViewArray,
 };
+use arrow::bitmap::bitmask::BitMask;
 use arrow::types::{NativeType, Offset};
 use polars_utils::min_max::MinMax;
 
 use super::MinMaxKernel;
+use crate::fold::fold_masked;
 
 fn min_max_ignore_nan<T: NativeType>((cur_min, cur_max): (T, T), (min, max): (T, T)) -> (T, T) {
     (
@@ -25,10 +27,14 @@ where
     T: NativeType,
     F: Fn(T, T) -> T,
 {
-    if v.null_count() == 0 {
-        v.values_iter().copied().reduce(f)
-    } else {
-        v.non_null_values_iter().reduce(f)
+    match v.validity().filter(|_| v.null_count() > 0) {
+        None => v.values_iter().copied().reduce(f),
+        Some(validity) => {
+            // Any valid value is an identity of the idempotent min and max.
+            let identity = v.non_null_values_iter().next()?;
+            let mask = BitMask::from_bitmap(validity);
+            Some(fold_masked(v.values(), mask, identity, identity, f))
+        },
     }
 }
 
@@ -37,10 +43,19 @@ where
     T: NativeType,
     F: Fn((T, T), (T, T)) -> (T, T),
 {
-    if v.null_count() == 0 {
-        v.values_iter().copied().map(|v| (v, v)).reduce(f)
-    } else {
-        v.non_null_values_iter().map(|v| (v, v)).reduce(f)
+    match v.validity().filter(|_| v.null_count() > 0) {
+        None => v.values_iter().copied().map(|v| (v, v)).reduce(f),
+        Some(validity) => {
+            let identity = v.non_null_values_iter().next()?;
+            let mask = BitMask::from_bitmap(validity);
+            Some(fold_masked(
+                v.values(),
+                mask,
+                identity,
+                (identity, identity),
+                |acc, v| f(acc, (v, v)),
+            ))
+        },
     }
 }
 
@@ -261,3 +276,34 @@ impl<O: Offset> MinMaxKernel for Utf8Array<O> {
         self.max_ignore_nan_kernel()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use arrow::bitmap::Bitmap;
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_min_max_masked() {
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for len in [0, 1, 31, 32, 33, 100, 1000] {
+            for null_density in [0.0, 0.1, 0.5, 0.9, 1.0] {
+                let values: Vec<i64> = (0..len).map(|_| rng.gen_range(-1000..1000)).collect();
+                let validity: Bitmap = (0..len).map(|_| !rng.gen_bool(null_density)).collect();
+                // Slice so that the mask does not start at a byte boundary.
+                let offset = len.min(3);
+                let arr = PrimitiveArray::new(i64::PRIMITIVE.into(), values.into(), Some(validity))
+                    .sliced(offset, len - offset);
+
+                let valid = || arr.iter().flatten().copied();
+                assert_eq!(reduce_vals(&arr, MinMax::min_ignore_nan), valid().min());
+                assert_eq!(reduce_vals(&arr, MinMax::max_ignore_nan), valid().max());
+                assert_eq!(
+                    reduce_tuple_vals(&arr, min_max_ignore_nan),
+                    valid().min().zip(valid().max())
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use arrow::array::{Array, PrimitiveArray};
+use arrow::bitmap::bitmask::BitMask;
+use arrow::types::NativeType;
+use num_traits::Zero;
+
+use crate::fold::fold_masked;
+
+/// Addition that wraps around on overflow for integers, and the regular addition for floats.
+pub trait WrappingAdd: Copy {
+    fn wrapping_add(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_add {
+    ($($T:ty),*) => {
+        $(impl WrappingAdd for $T {
+            #[inline]
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$T>::wrapping_add(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_wrapping_add!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+impl WrappingAdd for f32 {
+    #[inline]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+impl WrappingAdd for f64 {
+    #[inline]
+    fn wrapping_add(self, rhs: Self) -> Self {
+        self + rhs
+    }
+}
+
+/// Sum the valid values of a primitive array, wrapping around on integer overflow.
+///
+/// This is a scalar fold. The validity is processed a word at a time: words without valid values
+/// are skipped and words without nulls are summed without looking at the validity.
+pub fn sum_arr<T>(arr: &PrimitiveArray<T>) -> T
+where
+    T: NativeType + WrappingAdd + Zero,
+{
+    let add = |acc: T, v: T| acc.wrapping_add(v);
+    match arr.validity().filter(|_| arr.null_count() > 0) {
+        None => arr.values().iter().copied().fold(T::zero(), add),
+        Some(validity) => {
+            let mask = BitMask::from_bitmap(validity);
+            fold_masked(arr.values(), mask, T::zero(), T::zero(), add)
+        },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::bitmap::Bitmap;
+    use rand::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_sum_masked() {
+        let mut rng = StdRng::seed_from_u64(0xdeadbeef);
+        for len in [0, 1, 31, 32, 33, 100, 1000] {
+            for null_density in [0.0, 0.1, 0.5, 0.9, 1.0] {
+                let values: Vec<i64> = (0..len).map(|_| rng.gen_range(-1000..1000)).collect();
+                let validity: Bitmap = (0..len).map(|_| !rng.gen_bool(null_density)).collect();
+                // Slice so that the mask does not start at a byte boundary.
+                let offset = len.min(3);
+                let arr = PrimitiveArray::new(i64::PRIMITIVE.into(), values.into(), Some(validity))
+                    .sliced(offset, len - offset);
+
+                assert_eq!(sum_arr(&arr), arr.iter().flatten().sum::<i64>());
+            }
+        }
+
+        let arr = PrimitiveArray::from_slice([u8::MAX, 2]);
+        assert_eq!(sum_arr(&arr), 1);
+    }
+}
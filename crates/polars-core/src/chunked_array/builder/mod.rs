@@ -2,6 +2,7 @@ mod boolean;
 #[cfg(feature = "dtype-array")]
 pub mod fixed_size_list;
 pub mod list;
+mod nested;
 mod null;
 mod primitive;
 mod string;
@@ -14,6 +15,7 @@ pub use boolean::*;
 #[cfg(feature = "dtype-array")]
 pub(crate) use fixed_size_list::*;
 pub use list::*;
+pub use nested::*;
 pub use null::*;
 pub use primitive::*;
 pub use string::*;
@@ -0,0 +1,470 @@
+use arrow::bitmap::MutableBitmap;
+use arrow::offset::Offsets;
+
+use super::*;
+
+/// Builds the values of a nested builder; nested data types get their own builder so that
+/// rows can be pushed at every level.
+enum ValuesBuilder {
+    #[cfg(feature = "dtype-struct")]
+    Struct(StructBuilder),
+    List(ListBuilder),
+    Values(DataType, Vec<AnyValue<'static>>),
+}
+
+impl ValuesBuilder {
+    fn new(name: PlSmallStr, dtype: &DataType, capacity: usize) -> PolarsResult<Self> {
+        Ok(match dtype {
+            #[cfg(feature = "dtype-struct")]
+            DataType::Struct(fields) => Self::Struct(StructBuilder::new(name, fields, capacity)?),
+            DataType::List(inner) => {
+                Self::List(ListBuilder::new(name, inner.as_ref().clone(), capacity, 0)?)
+            },
+            dtype => {
+                // The values are only converted when finishing, so reject the data types that
+                // they can never be converted to before any value is pushed.
+                polars_ensure!(
+                    dtype.is_known() && !dtype.is_object(),
+                    InvalidOperation: "cannot build nested values of dtype {}", dtype
+                );
+                Self::Values(dtype.clone(), Vec::with_capacity(capacity))
+            },
+        })
+    }
+
+    /// Check that `value` can be pushed and converted when finishing, so that a failing row
+    /// doesn't leave the builders of some fields with more values than others.
+    fn check(&self, value: &AnyValue<'_>) -> PolarsResult<()> {
+        match (self, value) {
+            (_, AnyValue::Null) => Ok(()),
+            (Self::Values(dtype, _), value) => {
+                if value.dtype() != *dtype {
+                    Series::from_any_values_and_dtype(
+                        PlSmallStr::EMPTY,
+                        std::slice::from_ref(value),
+                        dtype,
+                        true,
+                    )?;
+                }
+                Ok(())
+            },
+            #[cfg(feature = "dtype-struct")]
+            (Self::Struct(builder), AnyValue::StructOwned(payload)) => {
+                builder.check_row(&payload.0)
+            },
+            #[cfg(feature = "dtype-struct")]
+            (Self::Struct(_), AnyValue::Struct(..)) => self.check(&value.clone().into_static()),
+            (Self::List(builder), AnyValue::List(s)) => builder.check_series(s),
+            (builder, value) => polars_bail!(
+                SchemaMismatch: "cannot push value {} of dtype {} to a builder of dtype {}",
+                value, value.dtype(), builder.dtype()
+            ),
+        }
+    }
+
+    /// Push a value that passed [`ValuesBuilder::check`].
+    fn push(&mut self, value: AnyValue<'_>) {
+        match (self, value.into_static()) {
+            #[cfg(feature = "dtype-struct")]
+            (Self::Struct(builder), AnyValue::Null) => builder.push_null(),
+            #[cfg(feature = "dtype-struct")]
+            (Self::Struct(builder), AnyValue::StructOwned(payload)) => {
+                builder.push_row_unchecked(&payload.0)
+            },
+            (Self::List(builder), AnyValue::Null) => builder.push_null(),
+            (Self::List(builder), AnyValue::List(s)) => builder.push_row_unchecked(s.iter()),
+            (Self::Values(_, values), value) => values.push(value),
+            _ => unreachable!(),
+        }
+    }
+
+    fn dtype(&self) -> DataType {
+        match self {
+            #[cfg(feature = "dtype-struct")]
+            Self::Struct(builder) => builder.dtype(),
+            Self::List(builder) => DataType::List(Box::new(builder.inner_dtype.clone())),
+            Self::Values(dtype, _) => dtype.clone(),
+        }
+    }
+
+    fn build(&self, name: PlSmallStr) -> PolarsResult<Series> {
+        match self {
+            #[cfg(feature = "dtype-struct")]
+            Self::Struct(builder) => Ok(builder.build()?.into_series()),
+            Self::List(builder) => Ok(builder.build()?.into_series()),
+            Self::Values(dtype, values) => {
+                Series::from_any_values_and_dtype(name, values, dtype, true)
+            },
+        }
+    }
+
+    fn reset(&mut self) {
+        match self {
+            #[cfg(feature = "dtype-struct")]
+            Self::Struct(builder) => builder.reset(),
+            Self::List(builder) => builder.reset(),
+            Self::Values(_, values) => values.clear(),
+        }
+    }
+}
+
+/// Row oriented builder for [`ListChunked`] columns of any inner data type, including
+/// lists of structs and lists of lists.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::chunked_array::builder::ListBuilder;
+/// # fn example() -> PolarsResult<()> {
+/// let mut builder = ListBuilder::new("a".into(), DataType::Int32, 2, 3)?;
+/// builder.push_row([AnyValue::Int32(1), AnyValue::Int32(2)])?;
+/// builder.push_null();
+/// let ca = builder.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct ListBuilder {
+    name: PlSmallStr,
+    inner_dtype: DataType,
+    values: Box<ValuesBuilder>,
+    offsets: Vec<i64>,
+    validity: MutableBitmap,
+}
+
+impl ListBuilder {
+    /// Create a builder with room for `capacity` lists and `values_capacity` values over all
+    /// lists.
+    ///
+    /// Fails if the inner data type contains a data type that values can't be built for, such
+    /// as [`DataType::Unknown`].
+    pub fn new(
+        name: PlSmallStr,
+        inner_dtype: DataType,
+        capacity: usize,
+        values_capacity: usize,
+    ) -> PolarsResult<Self> {
+        let mut offsets = Vec::with_capacity(capacity + 1);
+        offsets.push(0);
+        Ok(Self {
+            values: Box::new(ValuesBuilder::new(
+                PlSmallStr::EMPTY,
+                &inner_dtype,
+                values_capacity,
+            )?),
+            name,
+            inner_dtype,
+            offsets,
+            validity: MutableBitmap::with_capacity(capacity),
+        })
+    }
+
+    fn commit_list(&mut self, len: usize, valid: bool) {
+        let last = *self.offsets.last().unwrap();
+        self.offsets.push(last + len as i64);
+        self.validity.push(valid);
+    }
+
+    fn check_series(&self, s: &Series) -> PolarsResult<()> {
+        polars_ensure!(
+            s.dtype() == &self.inner_dtype || s.dtype() == &DataType::Null,
+            SchemaMismatch: "cannot push series of dtype {} to a list builder of dtype {}",
+            s.dtype(), self.inner_dtype
+        );
+        Ok(())
+    }
+
+    fn push_row_unchecked<'a, I: IntoIterator<Item = AnyValue<'a>>>(&mut self, values: I) {
+        let mut len = 0;
+        for value in values {
+            self.values.push(value);
+            len += 1;
+        }
+        self.commit_list(len, true);
+    }
+
+    /// Push a list with the given values. Values of struct and list data types are given as
+    /// [`AnyValue::StructOwned`] and [`AnyValue::List`].
+    ///
+    /// Values are converted to the inner data type when finishing the builder.
+    pub fn push_row<'a, I: IntoIterator<Item = AnyValue<'a>>>(
+        &mut self,
+        values: I,
+    ) -> PolarsResult<()> {
+        let values = values.into_iter().collect::<Vec<_>>();
+        for value in &values {
+            self.values.check(value)?;
+        }
+        self.push_row_unchecked(values);
+        Ok(())
+    }
+
+    /// Push a list with the values of `s`.
+    pub fn push_series(&mut self, s: &Series) -> PolarsResult<()> {
+        self.check_series(s)?;
+        self.push_row_unchecked(s.iter());
+        Ok(())
+    }
+
+    /// Push a list of structs, with one row of field values per struct.
+    ///
+    /// Fails if the inner data type of the builder is not a struct.
+    #[cfg(feature = "dtype-struct")]
+    pub fn push_struct_rows<'a, I: IntoIterator<Item = &'a [AnyValue<'a>]>>(
+        &mut self,
+        rows: I,
+    ) -> PolarsResult<()> {
+        let ValuesBuilder::Struct(builder) = self.values.as_mut() else {
+            polars_bail!(
+                SchemaMismatch: "cannot push struct rows to a list builder of dtype {}", self.inner_dtype
+            )
+        };
+        let rows = rows.into_iter().collect::<Vec<_>>();
+        for row in &rows {
+            builder.check_row(row)?;
+        }
+        for row in &rows {
+            builder.push_row_unchecked(row);
+        }
+        self.commit_list(rows.len(), true);
+        Ok(())
+    }
+
+    /// Push a null list.
+    pub fn push_null(&mut self) {
+        self.commit_list(0, false);
+    }
+
+    /// Build the [`ListChunked`]. The builder is reset and can be reused. On error the builder
+    /// is left unchanged.
+    pub fn finish(&mut self) -> PolarsResult<ListChunked> {
+        let out = self.build()?;
+        self.reset();
+        Ok(out)
+    }
+
+    fn reset(&mut self) {
+        self.values.reset();
+        self.offsets.clear();
+        self.offsets.push(0);
+        self.validity = MutableBitmap::new();
+    }
+
+    fn build(&self) -> PolarsResult<ListChunked> {
+        let values = self.values.build(PlSmallStr::EMPTY)?.rechunk();
+        let values_arr = match values.chunks().first() {
+            Some(arr) => arr.clone(),
+            None => new_empty_array(values.dtype().to_physical().to_arrow(CompatLevel::newest())),
+        };
+        let dtype = LargeListArray::default_datatype(
+            values.dtype().to_physical().to_arrow(CompatLevel::newest()),
+        );
+
+        // SAFETY: the offsets are monotonically increasing and start at zero.
+        let offsets = unsafe { Offsets::new_unchecked(self.offsets.clone()) };
+        let validity: Bitmap = self.validity.clone().into();
+        let validity = (validity.unset_bits() > 0).then_some(validity);
+
+        let arr = LargeListArray::new(dtype, offsets.into(), values_arr, validity);
+        let mut out = ListChunked::with_chunk(self.name.clone(), arr);
+        out.set_inner_dtype(self.inner_dtype.clone());
+        Ok(out)
+    }
+}
+
+/// Row oriented builder for [`StructChunked`] columns, whose fields may be of any data type,
+/// including lists and structs.
+///
+/// # Example
+///
+/// ```rust
+/// # use polars_core::prelude::*;
+/// # use polars_core::chunked_array::builder::StructBuilder;
+/// # fn example() -> PolarsResult<()> {
+/// let fields = [
+///     Field::new("x".into(), DataType::Int32),
+///     Field::new("tags".into(), DataType::List(Box::new(DataType::String))),
+/// ];
+/// let mut builder = StructBuilder::new("a".into(), &fields, 2)?;
+/// let tags = Series::new("".into(), ["foo", "bar"]);
+/// builder.push_row(&[AnyValue::Int32(1), AnyValue::List(tags)])?;
+/// builder.push_null();
+/// let ca = builder.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "dtype-struct")]
+pub struct StructBuilder {
+    name: PlSmallStr,
+    fields: Vec<(PlSmallStr, ValuesBuilder)>,
+    validity: MutableBitmap,
+}
+
+#[cfg(feature = "dtype-struct")]
+impl StructBuilder {
+    /// Create a builder with room for `capacity` rows.
+    ///
+    /// Fails if a field data type contains a data type that values can't be built for, such
+    /// as [`DataType::Unknown`].
+    pub fn new(name: PlSmallStr, fields: &[Field], capacity: usize) -> PolarsResult<Self> {
+        let fields = fields
+            .iter()
+            .map(|f| {
+                let builder = ValuesBuilder::new(f.name().clone(), f.dtype(), capacity)?;
+                Ok((f.name().clone(), builder))
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self {
+            name,
+            fields,
+            validity: MutableBitmap::with_capacity(capacity),
+        })
+    }
+
+    fn dtype(&self) -> DataType {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, builder)| Field::new(name.clone(), builder.dtype()))
+            .collect();
+        DataType::Struct(fields)
+    }
+
+    fn check_row(&self, values: &[AnyValue]) -> PolarsResult<()> {
+        polars_ensure!(
+            values.len() == self.fields.len(),
+            ShapeMismatch: "expected {} values for the struct row, got {}",
+            self.fields.len(), values.len()
+        );
+        for ((_, builder), value) in self.fields.iter().zip(values) {
+            builder.check(value)?;
+        }
+        Ok(())
+    }
+
+    fn push_row_unchecked(&mut self, values: &[AnyValue]) {
+        for ((_, builder), value) in self.fields.iter_mut().zip(values) {
+            builder.push(value.clone());
+        }
+        self.validity.push(true);
+    }
+
+    /// Push a struct with one value per field, in the order of the fields. Values of struct
+    /// and list data types are given as [`AnyValue::StructOwned`] and [`AnyValue::List`].
+    ///
+    /// Values are converted to the field data types when finishing the builder.
+    pub fn push_row(&mut self, values: &[AnyValue]) -> PolarsResult<()> {
+        self.check_row(values)?;
+        self.push_row_unchecked(values);
+        Ok(())
+    }
+
+    /// Push a null struct.
+    pub fn push_null(&mut self) {
+        for (_, builder) in self.fields.iter_mut() {
+            builder.push(AnyValue::Null);
+        }
+        self.validity.push(false);
+    }
+
+    /// Build the [`StructChunked`]. The builder is reset and can be reused. On error the
+    /// builder is left unchanged.
+    pub fn finish(&mut self) -> PolarsResult<StructChunked> {
+        let out = self.build()?;
+        self.reset();
+        Ok(out)
+    }
+
+    fn reset(&mut self) {
+        for (_, builder) in self.fields.iter_mut() {
+            builder.reset();
+        }
+        self.validity = MutableBitmap::new();
+    }
+
+    fn build(&self) -> PolarsResult<StructChunked> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, builder)| builder.build(name.clone()))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut out = StructChunked::from_series(self.name.clone(), fields.iter())?;
+
+        let validity: Bitmap = self.validity.clone().into();
+        if validity.unset_bits() > 0 {
+            out = out.rechunk();
+            out.set_outer_validity(Some(validity));
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(all(test, feature = "dtype-struct"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nested_builders() -> PolarsResult<()> {
+        let point = DataType::Struct(vec![
+            Field::new("x".into(), DataType::Int32),
+            Field::new("y".into(), DataType::Float64),
+        ]);
+
+        // list of structs
+        let mut builder = ListBuilder::new("points".into(), point.clone(), 3, 3)?;
+        builder.push_struct_rows([
+            [AnyValue::Int32(1), AnyValue::Float64(0.5)].as_slice(),
+            [AnyValue::Null, AnyValue::Float64(1.5)].as_slice(),
+        ])?;
+        builder.push_null();
+        builder.push_struct_rows([])?;
+        let ca = builder.finish()?;
+        assert_eq!(ca.dtype(), &DataType::List(Box::new(point.clone())));
+        assert_eq!(ca.len(), 3);
+        assert_eq!(ca.null_count(), 1);
+        let first = ca.get_as_series(0).unwrap();
+        assert_eq!(first.struct_()?.field_by_name("x")?.null_count(), 1);
+        assert_eq!(ca.get_as_series(2).unwrap().len(), 0);
+
+        // struct of lists
+        let fields = [
+            Field::new("id".into(), DataType::String),
+            Field::new("values".into(), DataType::List(Box::new(DataType::Int64))),
+        ];
+        let mut builder = StructBuilder::new("s".into(), &fields, 2)?;
+        builder.push_row(&[
+            AnyValue::String("a"),
+            AnyValue::List(Series::new("".into(), [1i64, 2])),
+        ])?;
+        builder.push_null();
+        assert!(builder.push_row(&[AnyValue::String("b")]).is_err());
+        assert!(builder
+            .push_row(&[AnyValue::String("b"), AnyValue::Int64(1)])
+            .is_err());
+        let ca = builder.finish()?;
+        assert_eq!(ca.len(), 2);
+        assert_eq!(ca.null_count(), 1);
+        let values = ca.field_by_name("values")?;
+        assert_eq!(values.dtype(), fields[1].dtype());
+        assert_eq!(values.list()?.get_as_series(0).unwrap().len(), 2);
+
+        // the builder is reset after finishing
+        assert_eq!(builder.finish()?.len(), 0);
+
+        // leaf values are validated when they are pushed
+        let mut builder = ListBuilder::new("".into(), DataType::Int32, 1, 1)?;
+        builder.push_row([AnyValue::Int32(1)])?;
+        assert!(builder
+            .push_row([AnyValue::Int32(2), AnyValue::String("x")])
+            .is_err());
+        let ca = builder.finish()?;
+        assert_eq!(ca.len(), 1);
+        assert_eq!(ca.get_as_series(0).unwrap().len(), 1);
+
+        // leaf data types are validated up front
+        let unknown = DataType::List(Box::new(DataType::Unknown(Default::default())));
+        assert!(ListBuilder::new("".into(), unknown.clone(), 0, 0).is_err());
+        assert!(StructBuilder::new("".into(), &[Field::new("a".into(), unknown)], 0).is_err());
+        Ok(())
+    }
+}
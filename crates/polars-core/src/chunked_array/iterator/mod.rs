@@ -17,6 +17,11 @@ where
                 .trust_my_length(self.len())
         }
     }
+
+    /// Collect the values into a [`Vec`], with `None` for the null values.
+    pub fn to_vec_options(&self) -> Vec<Option<T::Physical<'_>>> {
+        self.iter().collect()
+    }
 }
 
 /// A [`PolarsIterator`] is an iterator over a [`ChunkedArray`] which contains polars types. A [`PolarsIterator`]
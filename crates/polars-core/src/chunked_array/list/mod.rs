@@ -1,6 +1,7 @@
 //! Special list utility methods
 pub(super) mod iterator;
 
+use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
 use crate::prelude::*;
 
 impl ListChunked {
@@ -18,6 +19,16 @@ impl ListChunked {
         field.coerce(DataType::List(Box::new(dtype)));
     }
 
+    /// Row-encode the lists such that they compare lexicographically by their elements.
+    pub fn get_row_encoded(&self, options: SortOptions) -> PolarsResult<BinaryOffsetChunked> {
+        _get_rows_encoded_ca(
+            self.name().clone(),
+            &[self.clone().into_column()],
+            &[options.descending],
+            &[options.nulls_last],
+        )
+    }
+
     pub fn set_fast_explode(&mut self) {
         self.set_fast_explode_list(true)
     }
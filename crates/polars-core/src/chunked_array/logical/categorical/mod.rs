@@ -174,6 +174,106 @@ impl CategoricalChunked {
         }
     }
 
+    /// Drop the categories that don't occur in this array from its [`RevMapping`].
+    ///
+    /// Local categoricals are re-encoded, which makes them incompatible with the categoricals
+    /// they were derived from. Global categoricals keep their indexes into the string cache and
+    /// stay compatible. Enums are returned as is, as their categories are part of the dtype.
+    pub fn compact(&self) -> Self {
+        if self.is_enum() {
+            return self.clone();
+        }
+        let rev_map = self.get_rev_map();
+        let categories = rev_map.get_categories();
+
+        // The positions in `categories` that are used.
+        let mut used = vec![false; categories.len()];
+        match &**rev_map {
+            RevMapping::Local(_, _) => {
+                for v in self.physical().iter().flatten() {
+                    used[v as usize] = true;
+                }
+            },
+            RevMapping::Global(map, _, _) => {
+                for v in self.physical().iter().flatten() {
+                    used[*map.get(&v).unwrap() as usize] = true;
+                }
+            },
+        }
+        if used.iter().all(|&used| used) {
+            return self.clone();
+        }
+
+        // The position of every used category in the compacted categories.
+        let mut new_idx = vec![0u32; categories.len()];
+        for (n_used, (new_idx, _)) in new_idx
+            .iter_mut()
+            .zip(&used)
+            .filter(|(_, &used)| used)
+            .enumerate()
+        {
+            *new_idx = n_used as u32;
+        }
+        // Copy the used categories so that the buffers of the old ones can be freed.
+        let compacted: Utf8ViewArray = MutableBinaryViewArray::<str>::from_values_iter(
+            categories
+                .values_iter()
+                .zip(&used)
+                .filter_map(|(s, &used)| used.then_some(s)),
+        )
+        .into();
+
+        let mut out = match &**rev_map {
+            RevMapping::Local(_, _) => {
+                let physical = self
+                    .physical()
+                    .apply(|opt_v| opt_v.map(|v| new_idx[v as usize]));
+                // SAFETY: the new indexes are in bounds of the compacted categories.
+                unsafe {
+                    CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        physical,
+                        Arc::new(RevMapping::build_local(compacted)),
+                        false,
+                        self.get_ordering(),
+                    )
+                }
+            },
+            RevMapping::Global(map, _, id) => {
+                let map = map
+                    .iter()
+                    .filter(|(_, &local)| used[local as usize])
+                    .map(|(&global, &local)| (global, new_idx[local as usize]))
+                    .collect();
+                let mut out = self.clone();
+                // SAFETY: all global indexes that are used are kept.
+                unsafe {
+                    out.set_rev_map(Arc::new(RevMapping::Global(map, compacted, *id)), false)
+                };
+                out
+            },
+        };
+        // All categories are used now.
+        out.set_fast_unique(true);
+        out
+    }
+
+    /// [`compact`](Self::compact) a global categorical after a selective filter.
+    ///
+    /// This only happens if the [`RevMapping`] holds many more categories than this array has
+    /// values. Local categoricals are left alone, as compacting them would make them
+    /// incompatible with the arrays they were derived from.
+    pub(crate) fn compact_if_sparse(self) -> Self {
+        const MIN_CATEGORIES: usize = 1 << 10;
+
+        let rev_map = self.get_rev_map();
+        if rev_map.is_global() && rev_map.len() >= MIN_CATEGORIES && self.len() * 4 < rev_map.len()
+        {
+            self.compact()
+        } else {
+            self
+        }
+    }
+
     pub(crate) fn get_flags(&self) -> MetadataFlags {
         self.physical().get_flags()
     }
@@ -511,6 +611,58 @@ mod test {
         assert_eq!(out.n_unique().unwrap(), 2);
     }
 
+    #[test]
+    fn test_compact() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        let values = [Some("a"), Some("b"), None, Some("c"), Some("d"), Some("b")];
+
+        for global in [false, true] {
+            disable_string_cache();
+            if global {
+                enable_string_cache();
+            }
+            let s = Series::new(PlSmallStr::from_static("a"), values)
+                .cast(&DataType::Categorical(None, Default::default()))?;
+            let out = s.slice(1, 4);
+            let ca = out.categorical()?.compact();
+            assert_eq!(ca.get_rev_map().len(), 3);
+            assert_eq!(ca.get_rev_map().is_global(), global);
+            assert!(ca.physical().null_count() == 1);
+            assert_eq!(ca.iter_str().collect::<Vec<_>>(), &values[1..5]);
+            assert_eq!(ca.n_unique()?, 4);
+            if global {
+                // The global indexes are not changed.
+                assert!(ca
+                    .physical()
+                    .equal_missing(out.categorical()?.physical())
+                    .all());
+                assert!(ca.get_rev_map().same_src(s.categorical()?.get_rev_map()));
+            }
+        }
+        disable_string_cache();
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_after_filter() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        disable_string_cache();
+        enable_string_cache();
+
+        let values = (0..2000).map(|i| format!("cat_{i}")).collect::<Vec<_>>();
+        let s = Series::new(PlSmallStr::from_static("a"), &values)
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        let mask =
+            BooleanChunked::from_iter_values(PlSmallStr::EMPTY, (0..2000).map(|i| i % 100 == 0));
+        let out = s.filter(&mask)?;
+        let ca = out.categorical()?;
+        assert_eq!(ca.get_rev_map().len(), 20);
+        assert!(ca._can_fast_unique());
+        assert_eq!(out.cast(&DataType::String)?.str()?.get(1), Some("cat_100"));
+        disable_string_cache();
+        Ok(())
+    }
+
     #[test]
     fn test_categorical_flow() -> PolarsResult<()> {
         let _lock = SINGLE_LOCK.lock();
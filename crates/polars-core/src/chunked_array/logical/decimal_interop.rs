@@ -0,0 +1,279 @@
+//! Conversions between [`DecimalChunked`] and the decimal types of
+//! [rust_decimal](https://docs.rs/rust_decimal/) and [bigdecimal](https://docs.rs/bigdecimal/).
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::num_bigint::BigInt;
+#[cfg(feature = "bigdecimal")]
+use bigdecimal::{BigDecimal, ToPrimitive, Zero};
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+use crate::prelude::*;
+
+/// The largest scale supported by [`rust_decimal::Decimal`].
+#[cfg(feature = "rust_decimal")]
+const RUST_DECIMAL_MAX_SCALE: usize = 28;
+
+/// The largest precision of a Decimal.
+const MAX_PRECISION: usize = 38;
+/// The largest absolute value of a Decimal with [`MAX_PRECISION`].
+const MAX_ABS_VALUE: i128 = 10_i128.pow(MAX_PRECISION as u32) - 1;
+
+fn check_scale(scale: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        scale <= MAX_PRECISION,
+        ComputeError: "Decimal scale {} exceeds the maximum precision {}", scale, MAX_PRECISION
+    );
+    Ok(())
+}
+
+/// Rescale `value` from scale `from` to the larger scale `to`.
+fn upscale(value: i128, from: usize, to: usize) -> PolarsResult<i128> {
+    polars_ensure!(
+        from <= to,
+        ComputeError: "decimal value with scale {} does not fit into a Decimal with scale {}", from, to
+    );
+    10_i128
+        .checked_pow((to - from) as u32)
+        .and_then(|factor| value.checked_mul(factor))
+        .filter(|v| v.unsigned_abs() <= MAX_ABS_VALUE as u128)
+        .ok_or_else(
+            || polars_err!(ComputeError: "decimal value does not fit into a Decimal with scale {}", to),
+        )
+}
+
+#[cfg(feature = "rust_decimal")]
+impl DecimalChunked {
+    /// Construct a new [`DecimalChunked`] from an iterator over [`rust_decimal::Decimal`].
+    ///
+    /// If `scale` is `None` the largest scale of the values is used.
+    pub fn from_rust_decimal<I: IntoIterator<Item = Decimal>>(
+        name: PlSmallStr,
+        v: I,
+        scale: Option<usize>,
+    ) -> PolarsResult<Self> {
+        Self::from_rust_decimal_options(name, v.into_iter().map(Some), scale)
+    }
+
+    /// Construct a new [`DecimalChunked`] from an iterator over optional
+    /// [`rust_decimal::Decimal`].
+    ///
+    /// If `scale` is `None` the largest scale of the values is used.
+    pub fn from_rust_decimal_options<I: IntoIterator<Item = Option<Decimal>>>(
+        name: PlSmallStr,
+        v: I,
+        scale: Option<usize>,
+    ) -> PolarsResult<Self> {
+        let v = v.into_iter().collect::<Vec<_>>();
+        let scale = scale.unwrap_or_else(|| {
+            v.iter()
+                .flatten()
+                .map(|d| d.scale() as usize)
+                .max()
+                .unwrap_or(0)
+        });
+        check_scale(scale)?;
+        let vals = v
+            .into_iter()
+            .map(|opt| {
+                opt.map(|d| upscale(d.mantissa(), d.scale() as usize, scale))
+                    .transpose()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Int128Chunked::from_iter_options(name, vals.into_iter())
+            .into_decimal_unchecked(None, scale))
+    }
+
+    /// Iterate over the values as [`rust_decimal::Decimal`].
+    ///
+    /// Fails if the scale or any of the values is out of the range of [`rust_decimal::Decimal`].
+    pub fn as_rust_decimal_iter(
+        &self,
+    ) -> PolarsResult<impl TrustedLen<Item = Option<Decimal>> + '_> {
+        let scale = self.scale();
+        polars_ensure!(
+            scale <= RUST_DECIMAL_MAX_SCALE,
+            ComputeError: "cannot convert Decimal with scale {} to rust_decimal, the maximum scale is {}",
+            scale, RUST_DECIMAL_MAX_SCALE
+        );
+        if let Some((min, max)) = self.0.min_max() {
+            let max_mantissa = Decimal::MAX.mantissa();
+            polars_ensure!(
+                -max_mantissa <= min && max <= max_mantissa,
+                ComputeError: "Decimal values are out of the range of rust_decimal"
+            );
+        }
+        Ok(self
+            .0
+            .iter()
+            .map(move |opt_v| opt_v.map(|v| Decimal::from_i128_with_scale(v, scale as u32))))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl From<Decimal> for AnyValue<'static> {
+    fn from(v: Decimal) -> Self {
+        AnyValue::Decimal(v.mantissa(), v.scale() as usize)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+fn bigdecimal_to_i128(v: &BigDecimal, scale: usize) -> PolarsResult<i128> {
+    let (digits, exponent) = v.as_bigint_and_exponent();
+    let (digits, from) = if exponent < 0 {
+        // Any non-zero value with more trailing zeros exceeds the maximum precision, so don't
+        // materialize it.
+        let shift = exponent.unsigned_abs();
+        polars_ensure!(
+            shift <= MAX_PRECISION as u64 || digits.is_zero(),
+            ComputeError: "BigDecimal value {} does not fit into a Decimal", v
+        );
+        (
+            digits * BigInt::from(10).pow(shift.min(MAX_PRECISION as u64) as u32),
+            0,
+        )
+    } else {
+        (digits, exponent as usize)
+    };
+    let digits = digits.to_i128().ok_or_else(
+        || polars_err!(ComputeError: "BigDecimal value {} does not fit into a Decimal", v),
+    )?;
+    upscale(digits, from, scale)
+}
+
+#[cfg(feature = "bigdecimal")]
+impl DecimalChunked {
+    /// Construct a new [`DecimalChunked`] from an iterator over [`bigdecimal::BigDecimal`].
+    ///
+    /// If `scale` is `None` the largest scale of the values is used.
+    pub fn from_bigdecimal<I: IntoIterator<Item = BigDecimal>>(
+        name: PlSmallStr,
+        v: I,
+        scale: Option<usize>,
+    ) -> PolarsResult<Self> {
+        Self::from_bigdecimal_options(name, v.into_iter().map(Some), scale)
+    }
+
+    /// Construct a new [`DecimalChunked`] from an iterator over optional
+    /// [`bigdecimal::BigDecimal`].
+    ///
+    /// If `scale` is `None` the largest scale of the values is used.
+    pub fn from_bigdecimal_options<I: IntoIterator<Item = Option<BigDecimal>>>(
+        name: PlSmallStr,
+        v: I,
+        scale: Option<usize>,
+    ) -> PolarsResult<Self> {
+        let v = v.into_iter().collect::<Vec<_>>();
+        let scale = scale.unwrap_or_else(|| {
+            v.iter()
+                .flatten()
+                .map(|d| d.fractional_digit_count().max(0) as usize)
+                .max()
+                .unwrap_or(0)
+        });
+        check_scale(scale)?;
+        let vals = v
+            .iter()
+            .map(|opt| {
+                opt.as_ref()
+                    .map(|d| bigdecimal_to_i128(d, scale))
+                    .transpose()
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        Ok(Int128Chunked::from_iter_options(name, vals.into_iter())
+            .into_decimal_unchecked(None, scale))
+    }
+
+    /// Iterate over the values as [`bigdecimal::BigDecimal`].
+    pub fn as_bigdecimal_iter(&self) -> impl TrustedLen<Item = Option<BigDecimal>> + '_ {
+        let scale = self.scale() as i64;
+        self.0
+            .iter()
+            .map(move |opt_v| opt_v.map(|v| BigDecimal::new(BigInt::from(v), scale)))
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl TryFrom<&BigDecimal> for AnyValue<'static> {
+    type Error = PolarsError;
+
+    fn try_from(v: &BigDecimal) -> PolarsResult<Self> {
+        let scale = v.fractional_digit_count().max(0) as usize;
+        check_scale(scale)?;
+        Ok(AnyValue::Decimal(bigdecimal_to_i128(v, scale)?, scale))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "rust_decimal")]
+    fn test_rust_decimal() -> PolarsResult<()> {
+        let values = [
+            Some(Decimal::new(-12345, 2)),
+            None,
+            Some(Decimal::new(7, 0)),
+        ];
+        let ca = DecimalChunked::from_rust_decimal_options(PlSmallStr::EMPTY, values, None)?;
+        assert_eq!(ca.scale(), 2);
+        assert_eq!(
+            ca.0.iter().collect::<Vec<_>>(),
+            [Some(-12345), None, Some(700)]
+        );
+        assert_eq!(ca.as_rust_decimal_iter()?.collect::<Vec<_>>(), values);
+
+        assert!(DecimalChunked::from_rust_decimal(
+            PlSmallStr::EMPTY,
+            [values[0].unwrap()],
+            Some(1)
+        )
+        .is_err());
+        assert_eq!(
+            AnyValue::from(Decimal::new(15, 1)),
+            AnyValue::Decimal(15, 1)
+        );
+
+        let ca = Int128Chunked::new(PlSmallStr::EMPTY, &[1]).into_decimal_unchecked(None, 30);
+        assert!(ca.as_rust_decimal_iter().is_err());
+
+        // Upscaling the largest mantissa to the largest scale overflows the precision.
+        let values = [Decimal::MAX, Decimal::new(1, 28)];
+        assert!(DecimalChunked::from_rust_decimal(PlSmallStr::EMPTY, values, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "bigdecimal")]
+    fn test_bigdecimal() -> PolarsResult<()> {
+        let values = ["-123.45", "7", "1e3"].map(|s| s.parse::<BigDecimal>().unwrap());
+        let ca = DecimalChunked::from_bigdecimal(PlSmallStr::EMPTY, values.clone(), None)?;
+        assert_eq!(ca.scale(), 2);
+        assert_eq!(
+            ca.0.iter().collect::<Vec<_>>(),
+            [Some(-12345), Some(700), Some(100000)]
+        );
+        assert_eq!(
+            ca.as_bigdecimal_iter().collect::<Vec<_>>(),
+            values.clone().map(Some)
+        );
+
+        for too_large in [
+            "1e40",
+            "1e1000000000000",
+            "1e-50",
+            "100000000000000000000000000000000000000",
+        ] {
+            let too_large = too_large.parse::<BigDecimal>().unwrap();
+            assert!(DecimalChunked::from_bigdecimal(PlSmallStr::EMPTY, [too_large], None).is_err());
+        }
+        let zero = "0e1000000000000".parse::<BigDecimal>().unwrap();
+        let ca = DecimalChunked::from_bigdecimal(PlSmallStr::EMPTY, [zero], None)?;
+        assert_eq!(ca.0.get(0), Some(0));
+        assert_eq!(
+            AnyValue::try_from(&values[0])?,
+            AnyValue::Decimal(-12345, 2)
+        );
+        Ok(())
+    }
+}
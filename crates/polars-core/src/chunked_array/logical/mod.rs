@@ -10,6 +10,8 @@ pub use datetime::*;
 mod decimal;
 #[cfg(feature = "dtype-decimal")]
 pub use decimal::*;
+#[cfg(any(feature = "rust_decimal", feature = "bigdecimal"))]
+mod decimal_interop;
 #[cfg(feature = "dtype-duration")]
 mod duration;
 #[cfg(feature = "dtype-duration")]
@@ -1,4 +1,5 @@
 //! The typed heart of every Series column.
+use std::borrow::Cow;
 use std::iter::Map;
 use std::sync::{Arc, RwLockReadGuard, RwLockWriteGuard};
 
@@ -860,6 +861,23 @@ where
         Ok(self.downcast_iter().next().map(|arr| arr.values()).unwrap())
     }
 
+    /// Returns the values of the array as a slice.
+    ///
+    /// This is zero-copy if the array consists of a single chunk, otherwise the chunks are
+    /// copied into a new buffer. Fails if the array contains null values.
+    pub fn as_slice_nonnull(&self) -> PolarsResult<Cow<'_, [T::Native]>> {
+        polars_ensure!(
+            self.null_count() == 0,
+            ComputeError: "cannot get the values of '{}' as a slice: it contains {} null values",
+            self.name(), self.null_count()
+        );
+        Ok(match self.chunks.len() {
+            0 => Cow::Borrowed(&[]),
+            1 => Cow::Borrowed(self.downcast_iter().next().unwrap().values()),
+            _ => Cow::Owned(self.into_no_null_iter().collect()),
+        })
+    }
+
     /// Returns the values of the array as a contiguous mutable slice.
     pub(crate) fn cont_slice_mut(&mut self) -> Option<&mut [T::Native]> {
         if self.chunks.len() == 1 && self.chunks[0].null_count() == 0 {
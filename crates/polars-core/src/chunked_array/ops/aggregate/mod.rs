@@ -69,7 +69,7 @@ where
             }
         }
     } else {
-        compute::aggregate::sum_primitive(array).unwrap_or(T::zero())
+        polars_compute::sum::sum_arr(array)
     }
 }
 
@@ -1,6 +1,8 @@
 //! Implementations of the ChunkApply Trait.
 use std::borrow::Cow;
 
+use arrow::bitmap::MutableBitmap;
+
 use crate::chunked_array::arity::{unary_elementwise, unary_elementwise_values};
 use crate::chunked_array::cast::CastOptions;
 use crate::prelude::*;
@@ -114,6 +116,124 @@ where
             .collect::<Vec<_>>();
         ChunkedArray::try_from_chunk_iter(self.name().clone(), chunks)
     }
+
+    /// Applies a function to the non-null elements and their index, collecting the
+    /// results into a [`Series`] with the given `dtype`.
+    ///
+    /// The function returns the output value and whether that value is valid, so that
+    /// nulls can be produced without wrapping every value in an [`Option`]. Null elements
+    /// stay null and are not passed to the function.
+    ///
+    /// Fails if the physical type of `dtype` is not `U`, or if `dtype` is categorical.
+    pub fn apply_with_dtype<'a, U, F>(&'a self, dtype: DataType, mut op: F) -> PolarsResult<Series>
+    where
+        U: PolarsNumericType,
+        F: FnMut(T::Physical<'a>, usize) -> (U::Native, bool),
+    {
+        polars_ensure!(
+            dtype.to_physical() == U::get_dtype() && !dtype.is_categorical() && !dtype.is_enum(),
+            SchemaMismatch: "cannot apply with output dtype {}, the output values are of dtype {}",
+            dtype, U::get_dtype()
+        );
+        let arrow_dtype = U::get_dtype().to_arrow(CompatLevel::newest());
+        let mut offset = 0;
+        let iter = self.downcast_iter().map(|arr| {
+            let mut values = Vec::with_capacity(arr.len());
+            let mut validity = MutableBitmap::with_capacity(arr.len());
+            match arr.validity() {
+                None => {
+                    for (i, v) in arr.values_iter().enumerate() {
+                        let (out, valid) = op(v, offset + i);
+                        values.push(out);
+                        validity.push(valid);
+                    }
+                },
+                Some(mask) => {
+                    for (i, (v, is_valid)) in arr.values_iter().zip(mask.iter()).enumerate() {
+                        let (out, valid) = if is_valid {
+                            op(v, offset + i)
+                        } else {
+                            (U::Native::default(), false)
+                        };
+                        values.push(out);
+                        validity.push(valid);
+                    }
+                },
+            }
+            offset += arr.len();
+            PrimitiveArray::new(arrow_dtype.clone(), values.into(), validity.into()).boxed()
+        });
+        let chunks = iter.collect::<Vec<_>>();
+
+        // SAFETY: the physical type of `dtype` is the type of the chunks.
+        Ok(unsafe { Series::from_chunks_and_dtype_unchecked(self.name().clone(), chunks, &dtype) })
+    }
+
+    /// Applies a function to the non-null elements and their index, writing the output
+    /// into an amortized [`String`] buffer.
+    ///
+    /// The buffer is cleared before every call. If the function returns `false` the output
+    /// is null. Null elements stay null and are not passed to the function.
+    pub fn apply_into_string_amortized_with_validity<'a, F>(&'a self, mut f: F) -> StringChunked
+    where
+        F: FnMut(T::Physical<'a>, usize, &mut String) -> bool,
+    {
+        let mut buf = String::new();
+        let mut offset = 0;
+        let chunks = self
+            .downcast_iter()
+            .map(|arr| {
+                let mut mutarr = MutablePlString::with_capacity(arr.len());
+                arr.iter().enumerate().for_each(|(i, opt)| match opt {
+                    None => mutarr.push_null(),
+                    Some(v) => {
+                        buf.clear();
+                        if f(v, offset + i, &mut buf) {
+                            mutarr.push_value(&buf)
+                        } else {
+                            mutarr.push_null()
+                        }
+                    },
+                });
+                offset += arr.len();
+                mutarr.freeze()
+            })
+            .collect::<Vec<_>>();
+        ChunkedArray::from_chunk_iter(self.name().clone(), chunks)
+    }
+
+    /// Applies a function to the non-null elements and their index, writing the values of
+    /// the output list into an amortized [`Vec`] buffer.
+    ///
+    /// The buffer is cleared before every call. If the function returns `false` the output
+    /// is null. Null elements stay null and are not passed to the function.
+    pub fn apply_into_list_amortized<'a, U, F>(&'a self, mut f: F) -> ListChunked
+    where
+        U: PolarsNumericType,
+        F: FnMut(T::Physical<'a>, usize, &mut Vec<U::Native>) -> bool,
+    {
+        let mut buf = Vec::new();
+        let mut builder = ListPrimitiveChunkedBuilder::<U>::new(
+            self.name().clone(),
+            self.len(),
+            self.len(),
+            U::get_dtype(),
+        );
+        for (i, opt) in self.iter().enumerate() {
+            match opt {
+                None => builder.append_null(),
+                Some(v) => {
+                    buf.clear();
+                    if f(v, i, &mut buf) {
+                        builder.append_slice(&buf)
+                    } else {
+                        builder.append_null()
+                    }
+                },
+            }
+        }
+        builder.finish()
+    }
 }
 
 fn apply_in_place_impl<S, F>(name: PlSmallStr, chunks: Vec<ArrayRef>, f: F) -> ChunkedArray<S>
@@ -586,3 +706,48 @@ impl StringChunked {
         out
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+
+    #[test]
+    fn test_apply_with_validity() -> PolarsResult<()> {
+        let mut ca = Int32Chunked::new(PlSmallStr::from_static("a"), &[Some(1), None, Some(3)]);
+        ca.append(&Int32Chunked::new(PlSmallStr::from_static("a"), &[4]))
+            .unwrap();
+
+        let out = ca.apply_with_dtype::<Float64Type, _>(DataType::Float64, |v, i| {
+            (v as f64 / 2.0, i != 3)
+        })?;
+        assert_eq!(Vec::from(out.f64()?), &[Some(0.5), None, Some(1.5), None]);
+
+        #[cfg(feature = "dtype-date")]
+        {
+            let out = ca.apply_with_dtype::<Int32Type, _>(DataType::Date, |v, _| (v, true))?;
+            assert_eq!(out.dtype(), &DataType::Date);
+            assert_eq!(out.get(3)?, AnyValue::Date(4));
+        }
+        assert!(ca
+            .apply_with_dtype::<Int32Type, _>(DataType::Float64, |v, _| (v, true))
+            .is_err());
+
+        let out = ca.apply_into_string_amortized_with_validity(|v, i, buf| {
+            buf.push_str(&format!("{i}:{v}"));
+            v != 3
+        });
+        assert_eq!(Vec::from(&out), &[Some("0:1"), None, None, Some("3:4")]);
+
+        let out = ca.apply_into_list_amortized::<Int64Type, _>(|v, _, buf| {
+            buf.extend((0..v as i64).rev());
+            v > 1
+        });
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Int64)));
+        assert_eq!(out.null_count(), 2);
+        assert_eq!(
+            Vec::from(out.get_as_series(3).unwrap().i64().unwrap()),
+            &[Some(3), Some(2), Some(1), Some(0)]
+        );
+        Ok(())
+    }
+}
@@ -1,6 +1,8 @@
 use arrow::bitmap::bitmask::BitMask;
 use arrow::bitmap::Bitmap;
+#[cfg(feature = "dtype-struct")]
 use arrow::compute::take::take_unchecked;
+use polars_compute::gather::binview::take_binview_unchecked;
 use polars_error::polars_ensure;
 use polars_utils::index::check_bounds;
 
@@ -235,16 +237,14 @@ where
 impl ChunkTakeUnchecked<IdxCa> for BinaryChunked {
     /// Gather values from ChunkedArray by index.
     unsafe fn take_unchecked(&self, indices: &IdxCa) -> Self {
-        let rechunked = self.rechunk();
-        let indices = indices.rechunk();
-        let indices_arr = indices.downcast_iter().next().unwrap();
-        let chunks = rechunked
-            .chunks()
-            .iter()
-            .map(|arr| take_unchecked(arr.as_ref(), indices_arr))
-            .collect::<Vec<_>>();
+        // Gather directly from all chunks, this fixes up the buffer indices of the views
+        // instead of rechunking (and thus copying) the views first.
+        let targets: Vec<_> = self.downcast_iter().collect();
+        let chunks = indices
+            .downcast_iter()
+            .map(|idx_arr| take_binview_unchecked(&targets, idx_arr));
 
-        let mut out = ChunkedArray::from_chunks(self.name().clone(), chunks);
+        let mut out = ChunkedArray::from_chunk_iter_like(self, chunks);
 
         let sorted_flag =
             _update_gather_sorted_flag(self.is_sorted_flag(), indices.is_sorted_flag());
@@ -261,6 +261,31 @@ pub fn _get_rows_encoded_ca_unordered(
         .map(|rows| BinaryOffsetChunked::with_chunk(name, rows.into_array()))
 }
 
+/// Reduce a column to its smallest or largest non-null value, ordered by the row encoding.
+///
+/// This gives nested values such as lists and structs lexicographic min/max semantics.
+pub(crate) fn _min_max_reduce_row_encoded(s: &Series, max: bool) -> PolarsResult<Scalar> {
+    let s = s.rechunk();
+    let mut av = AnyValue::Null;
+    if !s.is_empty() {
+        let rows = _get_rows_encoded_arr(&[s.clone().into_column()], &[false], &[false])?;
+        let arr = &s.chunks()[0];
+        let valid = rows
+            .values_iter()
+            .enumerate()
+            .filter(|(idx, _)| arr.is_valid(*idx));
+        let idx = if max {
+            valid.max_by_key(|(_, row)| *row)
+        } else {
+            valid.min_by_key(|(_, row)| *row)
+        };
+        if let Some((idx, _)) = idx {
+            av = s.get(idx)?.into_static();
+        }
+    }
+    Ok(Scalar::new(s.dtype().clone(), av))
+}
+
 pub(crate) fn argsort_multiple_row_fmt(
     by: &[Column],
     mut descending: Vec<bool>,
@@ -621,8 +621,13 @@ impl ChunkSort<StructType> for StructChunked {
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        let bin = self.get_row_encoded(options).unwrap();
-        bin.arg_sort(Default::default())
+        match rank_nested_list_values(self).and_then(|ca| ca.get_row_encoded(options)) {
+            Ok(bin) => bin.arg_sort(Default::default()),
+            // Only lists of values without an order, e.g. objects, cannot be encoded. This cannot
+            // return an error, so they keep their order as if all values were equal;
+            // `Series::sort_with` reports the error.
+            Err(_) => IdxCa::from_vec(self.name().clone(), (0..self.len() as IdxSize).collect()),
+        }
     }
 }
 
@@ -708,6 +713,49 @@ impl ChunkSort<BooleanType> for BooleanChunked {
     }
 }
 
+/// Replace the nested values of a list column by their dense rank among all values.
+///
+/// The row encoding cannot encode lists of nested values directly. The ranks order and compare
+/// equal exactly as the row encodings of the values, so the lists keep their lexical order. The
+/// ranks depend on the other values of the column, so only the rows of a single ranked column
+/// can be compared with each other.
+pub(crate) fn rank_nested_list_values(ca: &ListChunked) -> PolarsResult<ListChunked> {
+    if !ca.inner_dtype().is_nested() {
+        return Ok(ca.clone());
+    }
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = ca.get_inner();
+    let rows = _get_rows_encoded_ca(
+        PlSmallStr::EMPTY,
+        &[values.into_column()],
+        &[false],
+        &[false],
+    )?;
+    let rows_arr = rows.downcast_iter().next().unwrap();
+
+    let mut ranks = vec![0 as IdxSize; rows_arr.len()];
+    let mut rank: IdxSize = 0;
+    let mut prev: Option<&[u8]> = None;
+    for i in rows.arg_sort(SortOptions::default()).into_no_null_iter() {
+        let row = rows_arr.value(i as usize);
+        if prev.is_some_and(|prev| prev != row) {
+            rank += 1;
+        }
+        ranks[i as usize] = rank;
+        prev = Some(row);
+    }
+
+    let dtype = LargeListArray::default_datatype(IDX_DTYPE.to_arrow(CompatLevel::newest()));
+    let out = LargeListArray::new(
+        dtype,
+        arr.offsets().clone(),
+        Box::new(IdxArr::from_vec(ranks)),
+        arr.validity().cloned(),
+    );
+    Ok(ListChunked::with_chunk(ca.name().clone(), out))
+}
+
 pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series> {
     use DataType::*;
     let out = match s.dtype() {
@@ -1004,4 +1052,64 @@ mod test {
         let expected = &[Some("c"), Some("b"), Some("a")];
         assert_eq!(Vec::from(&out), expected);
     }
+
+    #[test]
+    fn test_sort_list_lexically() -> PolarsResult<()> {
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [
+                Series::new(PlSmallStr::EMPTY, &[1, 2]),
+                Series::new(PlSmallStr::EMPTY, &[1]),
+                Series::new(PlSmallStr::EMPTY, &[0, 5]),
+                Series::new(PlSmallStr::EMPTY, &[1, 3]),
+            ],
+        );
+        let idx = s.arg_sort(SortOptions::default());
+        assert_eq!(idx.cont_slice().unwrap(), &[2, 1, 0, 3]);
+        let idx = s.arg_sort(SortOptions::default().with_order_descending(true));
+        assert_eq!(idx.cont_slice().unwrap(), &[3, 0, 1, 2]);
+
+        let min = s.min_reduce()?.into_series(PlSmallStr::EMPTY);
+        assert!(min.equals(&s.slice(2, 1)));
+        let max = s.max_reduce()?.into_series(PlSmallStr::EMPTY);
+        assert!(max.equals(&s.slice(3, 1)));
+
+        let other = s.slice(1, 1);
+        let out = s.lt(&other)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(false), Some(false), Some(true), Some(false)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sort_nested_list_lexically() -> PolarsResult<()> {
+        let list = |values: &[&[i32]]| {
+            Series::new(
+                PlSmallStr::EMPTY,
+                values
+                    .iter()
+                    .map(|v| Series::new(PlSmallStr::EMPTY, *v))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [
+                list(&[&[1, 2], &[3]]),
+                list(&[&[1]]),
+                list(&[&[1, 2], &[0]]),
+                list(&[&[1]]),
+            ],
+        );
+        let sorted = s.sort_with(SortOptions::default())?;
+        let expected = s.take(&IdxCa::from_vec(PlSmallStr::EMPTY, vec![1, 3, 2, 0]))?;
+        assert!(sorted.equals(&expected));
+        assert_eq!(s.n_unique()?, 3);
+
+        // The ranks of separately encoded columns cannot be compared.
+        assert!(s.lt(&s.slice(2, 1)).is_err());
+        Ok(())
+    }
 }
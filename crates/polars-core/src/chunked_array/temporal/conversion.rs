@@ -32,6 +32,44 @@ impl From<&AnyValue<'_>> for NaiveTime {
     }
 }
 
+#[cfg(feature = "dtype-date")]
+impl From<NaiveDate> for AnyValue<'static> {
+    fn from(v: NaiveDate) -> Self {
+        AnyValue::Date(naive_datetime_to_date(v.and_time(NaiveTime::MIN)))
+    }
+}
+
+/// Stored in nanoseconds, or in microseconds if the value is out of the range of nanosecond
+/// timestamps.
+#[cfg(feature = "dtype-datetime")]
+impl From<NaiveDateTime> for AnyValue<'static> {
+    fn from(v: NaiveDateTime) -> Self {
+        match v.and_utc().timestamp_nanos_opt() {
+            Some(ns) => AnyValue::Datetime(ns, TimeUnit::Nanoseconds, None),
+            None => AnyValue::Datetime(datetime_to_timestamp_us(v), TimeUnit::Microseconds, None),
+        }
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl From<NaiveTime> for AnyValue<'static> {
+    fn from(v: NaiveTime) -> Self {
+        AnyValue::Time(super::time_to_time64ns(&v))
+    }
+}
+
+/// Stored in the finest time unit that can represent the value.
+#[cfg(feature = "dtype-duration")]
+impl From<Duration> for AnyValue<'static> {
+    fn from(v: Duration) -> Self {
+        match (v.num_nanoseconds(), v.num_microseconds()) {
+            (Some(ns), _) => AnyValue::Duration(ns, TimeUnit::Nanoseconds),
+            (None, Some(us)) => AnyValue::Duration(us, TimeUnit::Microseconds),
+            (None, None) => AnyValue::Duration(v.num_milliseconds(), TimeUnit::Milliseconds),
+        }
+    }
+}
+
 // Used by lazy for literal conversion
 pub fn datetime_to_timestamp_ns(v: NaiveDateTime) -> i64 {
     v.and_utc().timestamp_nanos_opt().unwrap()
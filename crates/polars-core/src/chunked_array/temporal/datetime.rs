@@ -4,7 +4,7 @@ use arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
 };
 #[cfg(feature = "timezones")]
-use chrono::TimeZone as TimeZoneTrait;
+use chrono::{DateTime, TimeZone as TimeZoneTrait};
 
 use super::*;
 use crate::prelude::DataType::Datetime;
@@ -51,7 +51,7 @@ impl DatetimeChunked {
         let mut ca: StringChunked = match self.time_zone() {
             #[cfg(feature = "timezones")]
             Some(time_zone) => {
-                let parsed_time_zone = time_zone.parse::<Tz>().expect("already validated");
+                let parsed_time_zone = parse_time_zone(time_zone)?;
                 let datefmt_f = |ndt| parsed_time_zone.from_utc_datetime(&ndt).format(format);
                 self.try_apply_into_string_amortized(|val, buf| {
                     let ndt = conversion_f(val);
@@ -113,6 +113,61 @@ impl DatetimeChunked {
         Int64Chunked::from_iter_options(name, vals).into_datetime(tu, None)
     }
 
+    /// Construct a new [`DatetimeChunked`] from an iterator over time zone aware [`DateTime`]s.
+    ///
+    /// The values may have any offset, they are stored as instants and displayed in
+    /// `time_zone`. If `time_zone` is `None` the UTC wall time of the values is stored.
+    #[cfg(feature = "timezones")]
+    pub fn from_datetime<Z: TimeZoneTrait, I: IntoIterator<Item = DateTime<Z>>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+        time_zone: Option<TimeZone>,
+    ) -> PolarsResult<Self> {
+        Self::from_datetime_options(name, v.into_iter().map(Some), tu, time_zone)
+    }
+
+    /// Construct a new [`DatetimeChunked`] from an iterator over optional time zone aware
+    /// [`DateTime`]s. See [`DatetimeChunked::from_datetime`]. Values that don't fit in `tu`
+    /// become null.
+    #[cfg(feature = "timezones")]
+    pub fn from_datetime_options<Z: TimeZoneTrait, I: IntoIterator<Item = Option<DateTime<Z>>>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+        time_zone: Option<TimeZone>,
+    ) -> PolarsResult<Self> {
+        if let Some(time_zone) = &time_zone {
+            validate_time_zone(time_zone)?;
+        }
+        let vals = v.into_iter().map(|opt_dt| {
+            let ndt = opt_dt?.naive_utc();
+            match tu {
+                TimeUnit::Nanoseconds => ndt.and_utc().timestamp_nanos_opt(),
+                TimeUnit::Microseconds => Some(datetime_to_timestamp_us(ndt)),
+                TimeUnit::Milliseconds => Some(datetime_to_timestamp_ms(ndt)),
+            }
+        });
+        Ok(Int64Chunked::from_iter_options(name, vals).into_datetime(tu, time_zone))
+    }
+
+    /// Iterate over the values as [`DateTime`]s in the time zone of this array. Values
+    /// without a time zone are interpreted as UTC.
+    #[cfg(feature = "timezones")]
+    pub fn as_datetime_tz_iter(
+        &self,
+    ) -> PolarsResult<impl TrustedLen<Item = Option<DateTime<Tz>>> + '_> {
+        let tz = match self.time_zone() {
+            Some(tz) => parse_time_zone(tz)?,
+            None => chrono_tz::UTC,
+        };
+        let iter = self
+            .as_datetime_iter()
+            .map(move |opt_ndt| opt_ndt.map(|ndt| tz.from_utc_datetime(&ndt)));
+        // SAFETY: we know the iterators len
+        Ok(unsafe { iter.trust_my_length(self.len()) })
+    }
+
     /// Change the underlying [`TimeUnit`]. And update the data accordingly.
     #[must_use]
     pub fn cast_time_unit(&self, tu: TimeUnit) -> Self {
@@ -218,4 +273,81 @@ mod test {
             dt.cont_slice().unwrap()
         );
     }
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn from_datetime_tz() -> PolarsResult<()> {
+        use chrono::{DateTime, TimeZone, Utc};
+
+        let ndt =
+            NaiveDateTime::parse_from_str("2024-03-31 01:30:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let utc: DateTime<Utc> = Utc.from_utc_datetime(&ndt);
+        let ca = DatetimeChunked::new(PlSmallStr::EMPTY, [Some(utc), None]);
+        assert_eq!(ca.time_zone().as_deref(), Some("UTC"));
+        assert_eq!(ca.as_datetime_iter().next().unwrap(), Some(ndt));
+
+        let ams = chrono_tz::Europe::Amsterdam;
+        let ca = DatetimeChunked::from_datetime(
+            PlSmallStr::EMPTY,
+            [utc.with_timezone(&ams)],
+            TimeUnit::Microseconds,
+            Some("Europe/Amsterdam".into()),
+        )?;
+        let out = ca.as_datetime_tz_iter()?.collect::<Vec<_>>();
+        assert_eq!(out, [Some(utc.with_timezone(&ams))]);
+        assert_eq!(
+            out[0].unwrap().naive_local().to_string(),
+            "2024-03-31 03:30:00"
+        );
+
+        assert!(DatetimeChunked::from_datetime(
+            PlSmallStr::EMPTY,
+            [utc],
+            TimeUnit::Microseconds,
+            Some("foo".into())
+        )
+        .is_err());
+
+        // Out of the range of nanosecond timestamps.
+        let late = Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap();
+        let ca = DatetimeChunked::from_datetime(
+            PlSmallStr::EMPTY,
+            [late, utc],
+            TimeUnit::Nanoseconds,
+            None,
+        )?;
+        assert_eq!(ca.null_count(), 1);
+        let ca = DatetimeChunked::new(PlSmallStr::EMPTY, [late]);
+        assert_eq!(
+            ca.as_datetime_tz_iter()?.next().unwrap(),
+            Some(late.with_timezone(&chrono_tz::UTC))
+        );
+        assert!(matches!(
+            AnyValue::from(late.naive_utc()),
+            AnyValue::Datetime(_, TimeUnit::Microseconds, None)
+        ));
+        assert!(matches!(
+            AnyValue::from(chrono::Duration::days(365 * 1_000_000)),
+            AnyValue::Duration(_, TimeUnit::Milliseconds)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn parse_fixed_offset_time_zone() -> PolarsResult<()> {
+        use crate::chunked_array::temporal::parse_time_zone;
+
+        assert_eq!(parse_time_zone("+01:00")?, chrono_tz::Etc::GMTMinus1);
+        assert_eq!(parse_time_zone("-0500")?, chrono_tz::Etc::GMTPlus5);
+        assert_eq!(
+            parse_time_zone("Europe/Amsterdam")?,
+            chrono_tz::Europe::Amsterdam
+        );
+        assert!(parse_time_zone("+01:30").is_err());
+
+        let ca = Int64Chunked::new(PlSmallStr::EMPTY, &[0i64])
+            .into_datetime(TimeUnit::Milliseconds, Some("+01:00".into()));
+        assert_eq!(ca.time_zone().as_deref(), Some("+01:00"));
+        Ok(())
+    }
 }
@@ -3,6 +3,20 @@ use crate::prelude::DataType::Duration;
 use crate::prelude::*;
 
 impl DurationChunked {
+    pub fn as_duration_iter(&self) -> impl TrustedLen<Item = Option<ChronoDuration>> + '_ {
+        let func = match self.time_unit() {
+            TimeUnit::Nanoseconds => ChronoDuration::nanoseconds,
+            TimeUnit::Microseconds => ChronoDuration::microseconds,
+            TimeUnit::Milliseconds => ChronoDuration::milliseconds,
+        };
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(move |iter| iter.into_iter().map(move |opt_v| opt_v.copied().map(func)))
+                .trust_my_length(self.len())
+        }
+    }
+
     pub fn time_unit(&self) -> TimeUnit {
         match self.2.as_ref().unwrap() {
             DataType::Duration(tu) => *tu,
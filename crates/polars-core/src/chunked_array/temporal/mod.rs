@@ -8,6 +8,8 @@ mod datetime;
 mod duration;
 #[cfg(feature = "dtype-time")]
 mod time;
+#[cfg(feature = "time")]
+mod time_crate;
 #[cfg(feature = "dtype-date")]
 use chrono::NaiveDate;
 use chrono::NaiveDateTime;
@@ -26,7 +28,7 @@ pub use time::time_to_time64ns;
 
 pub use self::conversion::*;
 #[cfg(feature = "timezones")]
-use crate::prelude::{polars_bail, PolarsResult};
+use crate::prelude::{polars_bail, polars_err, PolarsResult};
 
 #[cfg(feature = "timezones")]
 static FIXED_OFFSET_PATTERN: &str = r#"(?x)
@@ -42,21 +44,20 @@ static FIXED_OFFSET_RE: Lazy<Regex> = Lazy::new(|| Regex::new(FIXED_OFFSET_PATTE
 
 #[cfg(feature = "timezones")]
 pub fn validate_time_zone(tz: &str) -> PolarsResult<()> {
-    match tz.parse::<Tz>() {
-        Ok(_) => Ok(()),
-        Err(_) => {
-            polars_bail!(ComputeError: "unable to parse time zone: '{}'. Please check the Time Zone Database for a list of available time zones", tz)
-        },
-    }
+    parse_time_zone(tz).map(|_| ())
 }
 
+/// Parse a time zone from the time zone database.
+///
+/// Fixed offsets of whole hours (e.g. `+01:00`) are accepted everywhere a named time zone is and
+/// resolve to the corresponding `Etc/GMT` zone, see [`parse_fixed_offset`].
 #[cfg(feature = "timezones")]
 pub fn parse_time_zone(tz: &str) -> PolarsResult<Tz> {
     match tz.parse::<Tz>() {
         Ok(tz) => Ok(tz),
-        Err(_) => {
-            polars_bail!(ComputeError: "unable to parse time zone: '{}'. Please check the Time Zone Database for a list of available time zones", tz)
-        },
+        Err(_) => parse_fixed_offset(tz)?.parse::<Tz>().map_err(|_| {
+            polars_err!(ComputeError: "unable to parse time zone: '{}'. Please check the Time Zone Database for a list of available time zones", tz)
+        }),
     }
 }
 
@@ -0,0 +1,518 @@
+//! Conversions between the types of the [time](https://docs.rs/time/) crate and Polars.
+//!
+//! The ranges of the types differ, values that the target type can't represent become null.
+#[cfg(feature = "dtype-date")]
+use time::Date;
+#[cfg(feature = "dtype-duration")]
+use time::Duration as TimeDuration;
+#[cfg(feature = "dtype-datetime")]
+use time::OffsetDateTime;
+#[cfg(feature = "dtype-datetime")]
+use time::PrimitiveDateTime;
+#[cfg(feature = "dtype-time")]
+use time::Time;
+
+use crate::prelude::*;
+
+/// The julian day of 1970-01-01.
+#[cfg(feature = "dtype-date")]
+const UNIX_EPOCH_JULIAN_DAY: i32 = 2_440_588;
+
+#[cfg(feature = "dtype-date")]
+fn date_to_date32(v: Date) -> i32 {
+    v.to_julian_day() - UNIX_EPOCH_JULIAN_DAY
+}
+
+#[cfg(feature = "dtype-date")]
+fn date32_to_date(v: i32) -> Option<Date> {
+    Date::from_julian_day(v.checked_add(UNIX_EPOCH_JULIAN_DAY)?).ok()
+}
+
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn nanoseconds_in(tu: TimeUnit) -> i128 {
+    match tu {
+        TimeUnit::Nanoseconds => 1,
+        TimeUnit::Microseconds => 1_000,
+        TimeUnit::Milliseconds => 1_000_000,
+    }
+}
+
+/// The finest time unit in which `nanoseconds` fit, and their value in that unit.
+#[cfg(any(feature = "dtype-datetime", feature = "dtype-duration"))]
+fn to_finest_time_unit(nanoseconds: i128) -> Option<(i64, TimeUnit)> {
+    [
+        TimeUnit::Nanoseconds,
+        TimeUnit::Microseconds,
+        TimeUnit::Milliseconds,
+    ]
+    .into_iter()
+    .find_map(|tu| {
+        let v = i64::try_from(nanoseconds.div_euclid(nanoseconds_in(tu))).ok()?;
+        Some((v, tu))
+    })
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn offset_datetime_to_timestamp(v: OffsetDateTime, tu: TimeUnit) -> Option<i64> {
+    i64::try_from(v.unix_timestamp_nanos().div_euclid(nanoseconds_in(tu))).ok()
+}
+
+#[cfg(feature = "dtype-datetime")]
+fn timestamp_to_offset_datetime(v: i64, tu: TimeUnit) -> Option<OffsetDateTime> {
+    OffsetDateTime::from_unix_timestamp_nanos(v as i128 * nanoseconds_in(tu)).ok()
+}
+
+#[cfg(feature = "dtype-time")]
+fn time_to_time64ns(v: Time) -> i64 {
+    let (hour, minute, second, nanosecond) = v.as_hms_nano();
+    (hour as i64 * 3_600 + minute as i64 * 60 + second as i64) * 1_000_000_000 + nanosecond as i64
+}
+
+#[cfg(feature = "dtype-time")]
+fn time64ns_to_time(v: i64) -> Time {
+    Time::MIDNIGHT + time::Duration::nanoseconds(v)
+}
+
+#[cfg(feature = "dtype-duration")]
+fn duration_to_i64(v: TimeDuration, tu: TimeUnit) -> Option<i64> {
+    i64::try_from(v.whole_nanoseconds() / nanoseconds_in(tu)).ok()
+}
+
+#[cfg(feature = "dtype-date")]
+impl DateChunked {
+    /// Construct a new [`DateChunked`] from an iterator over [`time::Date`].
+    pub fn from_time_date<I: IntoIterator<Item = Date>>(name: PlSmallStr, v: I) -> Self {
+        let vals = v.into_iter().map(date_to_date32).collect::<Vec<_>>();
+        Int32Chunked::from_vec(name, vals).into()
+    }
+
+    /// Construct a new [`DateChunked`] from an iterator over optional [`time::Date`].
+    pub fn from_time_date_options<I: IntoIterator<Item = Option<Date>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        let vals = v.into_iter().map(|opt| opt.map(date_to_date32));
+        Int32Chunked::from_iter_options(name, vals).into()
+    }
+
+    pub fn as_time_date_iter(&self) -> impl TrustedLen<Item = Option<Date>> + '_ {
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(|iter| {
+                    iter.into_iter()
+                        .map(|opt_v| opt_v.copied().and_then(date32_to_date))
+                })
+                .trust_my_length(self.len())
+        }
+    }
+}
+
+#[cfg(feature = "dtype-datetime")]
+impl DatetimeChunked {
+    /// Construct a new [`DatetimeChunked`] from an iterator over [`time::PrimitiveDateTime`].
+    /// Values that don't fit in `tu` become null.
+    pub fn from_primitive_datetime<I: IntoIterator<Item = PrimitiveDateTime>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        Self::from_primitive_datetime_options(name, v.into_iter().map(Some), tu)
+    }
+
+    /// Construct a new [`DatetimeChunked`] from an iterator over optional
+    /// [`time::PrimitiveDateTime`]. Values that don't fit in `tu` become null.
+    pub fn from_primitive_datetime_options<I: IntoIterator<Item = Option<PrimitiveDateTime>>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        let vals = v
+            .into_iter()
+            .map(|opt| opt.and_then(|dt| offset_datetime_to_timestamp(dt.assume_utc(), tu)));
+        Int64Chunked::from_iter_options(name, vals).into_datetime(tu, None)
+    }
+
+    /// Construct a new [`DatetimeChunked`] in UTC from an iterator over
+    /// [`time::OffsetDateTime`]. The values may have any offset, values that don't fit in `tu`
+    /// become null.
+    pub fn from_offset_datetime<I: IntoIterator<Item = OffsetDateTime>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        Self::from_offset_datetime_options(name, v.into_iter().map(Some), tu)
+    }
+
+    /// Construct a new [`DatetimeChunked`] in UTC from an iterator over optional
+    /// [`time::OffsetDateTime`]. The values may have any offset, values that don't fit in `tu`
+    /// become null.
+    pub fn from_offset_datetime_options<I: IntoIterator<Item = Option<OffsetDateTime>>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        let vals = v
+            .into_iter()
+            .map(|opt| opt.and_then(|dt| offset_datetime_to_timestamp(dt, tu)));
+        Int64Chunked::from_iter_options(name, vals)
+            .into_datetime(tu, Some(PlSmallStr::from_static("UTC")))
+    }
+
+    /// Iterate over the values as [`time::PrimitiveDateTime`]s, ignoring the time zone. Values
+    /// outside of the range of [`time::PrimitiveDateTime`] are `None`.
+    pub fn as_primitive_datetime_iter(
+        &self,
+    ) -> impl TrustedLen<Item = Option<PrimitiveDateTime>> + '_ {
+        let tu = self.time_unit();
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(move |iter| {
+                    iter.into_iter().map(move |opt_v| {
+                        opt_v.and_then(|v| {
+                            let dt = timestamp_to_offset_datetime(*v, tu)?;
+                            Some(PrimitiveDateTime::new(dt.date(), dt.time()))
+                        })
+                    })
+                })
+                .trust_my_length(self.len())
+        }
+    }
+
+    /// Iterate over the values as [`time::OffsetDateTime`]s in UTC. Values without a time
+    /// zone are interpreted as UTC, values outside of the range of [`time::OffsetDateTime`] are
+    /// `None`.
+    pub fn as_offset_datetime_iter(&self) -> impl TrustedLen<Item = Option<OffsetDateTime>> + '_ {
+        let tu = self.time_unit();
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(move |iter| {
+                    iter.into_iter()
+                        .map(move |opt_v| opt_v.and_then(|v| timestamp_to_offset_datetime(*v, tu)))
+                })
+                .trust_my_length(self.len())
+        }
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl TimeChunked {
+    /// Construct a new [`TimeChunked`] from an iterator over [`time::Time`].
+    pub fn from_time_time<I: IntoIterator<Item = Time>>(name: PlSmallStr, v: I) -> Self {
+        let vals = v.into_iter().map(time_to_time64ns).collect::<Vec<_>>();
+        Int64Chunked::from_vec(name, vals).into_time()
+    }
+
+    /// Construct a new [`TimeChunked`] from an iterator over optional [`time::Time`].
+    pub fn from_time_time_options<I: IntoIterator<Item = Option<Time>>>(
+        name: PlSmallStr,
+        v: I,
+    ) -> Self {
+        let vals = v.into_iter().map(|opt| opt.map(time_to_time64ns));
+        Int64Chunked::from_iter_options(name, vals).into_time()
+    }
+
+    pub fn as_time_time_iter(&self) -> impl TrustedLen<Item = Option<Time>> + '_ {
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(|iter| {
+                    iter.into_iter()
+                        .map(|opt_v| opt_v.copied().map(time64ns_to_time))
+                })
+                .trust_my_length(self.len())
+        }
+    }
+}
+
+#[cfg(feature = "dtype-duration")]
+impl DurationChunked {
+    /// Construct a new [`DurationChunked`] from an iterator over [`time::Duration`]. Values
+    /// that don't fit in `tu` become null.
+    pub fn from_time_duration<I: IntoIterator<Item = TimeDuration>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        Self::from_time_duration_options(name, v.into_iter().map(Some), tu)
+    }
+
+    /// Construct a new [`DurationChunked`] from an iterator over optional [`time::Duration`].
+    /// Values that don't fit in `tu` become null.
+    pub fn from_time_duration_options<I: IntoIterator<Item = Option<TimeDuration>>>(
+        name: PlSmallStr,
+        v: I,
+        tu: TimeUnit,
+    ) -> Self {
+        let vals = v
+            .into_iter()
+            .map(|opt| opt.and_then(|v| duration_to_i64(v, tu)));
+        Int64Chunked::from_iter_options(name, vals).into_duration(tu)
+    }
+
+    pub fn as_time_duration_iter(&self) -> impl TrustedLen<Item = Option<TimeDuration>> + '_ {
+        let func = match self.time_unit() {
+            TimeUnit::Nanoseconds => TimeDuration::nanoseconds,
+            TimeUnit::Microseconds => TimeDuration::microseconds,
+            TimeUnit::Milliseconds => TimeDuration::milliseconds,
+        };
+        // SAFETY: we know the iterators len
+        unsafe {
+            self.downcast_iter()
+                .flat_map(move |iter| iter.into_iter().map(move |opt_v| opt_v.copied().map(func)))
+                .trust_my_length(self.len())
+        }
+    }
+}
+
+#[cfg(feature = "dtype-date")]
+impl From<Date> for AnyValue<'static> {
+    fn from(v: Date) -> Self {
+        AnyValue::Date(date_to_date32(v))
+    }
+}
+
+/// Stored in the finest time unit that can represent the value.
+#[cfg(feature = "dtype-datetime")]
+impl From<PrimitiveDateTime> for AnyValue<'static> {
+    fn from(v: PrimitiveDateTime) -> Self {
+        match to_finest_time_unit(v.assume_utc().unix_timestamp_nanos()) {
+            Some((v, tu)) => AnyValue::Datetime(v, tu, None),
+            None => AnyValue::Null,
+        }
+    }
+}
+
+/// Stored in UTC, in the finest time unit that can represent the value.
+#[cfg(feature = "dtype-datetime")]
+impl From<OffsetDateTime> for AnyValue<'static> {
+    fn from(v: OffsetDateTime) -> Self {
+        let tz = Arc::new(PlSmallStr::from_static("UTC"));
+        match to_finest_time_unit(v.unix_timestamp_nanos()) {
+            Some((v, tu)) => AnyValue::DatetimeOwned(v, tu, Some(tz)),
+            None => AnyValue::Null,
+        }
+    }
+}
+
+#[cfg(feature = "dtype-time")]
+impl From<Time> for AnyValue<'static> {
+    fn from(v: Time) -> Self {
+        AnyValue::Time(time_to_time64ns(v))
+    }
+}
+
+/// Stored in the finest time unit that can represent the value.
+#[cfg(feature = "dtype-duration")]
+impl From<TimeDuration> for AnyValue<'static> {
+    fn from(v: TimeDuration) -> Self {
+        match to_finest_time_unit(v.whole_nanoseconds()) {
+            Some((v, tu)) => AnyValue::Duration(v, tu),
+            None => AnyValue::Null,
+        }
+    }
+}
+
+macro_rules! impl_named_from_time {
+    ($feature:literal, $ca:ty, $t:ty, $from:ident, $from_options:ident $(, $tu:expr)?) => {
+        #[cfg(feature = $feature)]
+        impl<T: AsRef<[$t]>> NamedFrom<T, [$t]> for $ca {
+            fn new(name: PlSmallStr, v: T) -> Self {
+                <$ca>::$from(name, v.as_ref().iter().copied() $(, $tu)?)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: AsRef<[$t]>> NamedFrom<T, [$t]> for Series {
+            fn new(name: PlSmallStr, v: T) -> Self {
+                <$ca>::$from(name, v.as_ref().iter().copied() $(, $tu)?).into_series()
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: AsRef<[Option<$t>]>> NamedFrom<T, [Option<$t>]> for $ca {
+            fn new(name: PlSmallStr, v: T) -> Self {
+                <$ca>::$from_options(name, v.as_ref().iter().copied() $(, $tu)?)
+            }
+        }
+
+        #[cfg(feature = $feature)]
+        impl<T: AsRef<[Option<$t>]>> NamedFrom<T, [Option<$t>]> for Series {
+            fn new(name: PlSmallStr, v: T) -> Self {
+                <$ca>::$from_options(name, v.as_ref().iter().copied() $(, $tu)?).into_series()
+            }
+        }
+    };
+}
+
+impl_named_from_time!(
+    "dtype-date",
+    DateChunked,
+    Date,
+    from_time_date,
+    from_time_date_options
+);
+impl_named_from_time!(
+    "dtype-datetime",
+    DatetimeChunked,
+    PrimitiveDateTime,
+    from_primitive_datetime,
+    from_primitive_datetime_options,
+    TimeUnit::Milliseconds
+);
+impl_named_from_time!(
+    "dtype-datetime",
+    DatetimeChunked,
+    OffsetDateTime,
+    from_offset_datetime,
+    from_offset_datetime_options,
+    TimeUnit::Milliseconds
+);
+impl_named_from_time!(
+    "dtype-time",
+    TimeChunked,
+    Time,
+    from_time_time,
+    from_time_time_options
+);
+impl_named_from_time!(
+    "dtype-duration",
+    DurationChunked,
+    TimeDuration,
+    from_time_duration,
+    from_time_duration_options,
+    TimeUnit::Nanoseconds
+);
+
+#[cfg(test)]
+mod test {
+    use time::{Month, UtcOffset};
+
+    use super::*;
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-time",
+        feature = "dtype-duration"
+    ))]
+    fn test_time_crate_roundtrip() {
+        let date = |y, m, d| Date::from_calendar_date(y, m, d).unwrap();
+
+        let dates = [
+            Some(date(1969, Month::December, 31)),
+            None,
+            Some(date(2024, Month::February, 29)),
+        ];
+        let ca = DateChunked::new(PlSmallStr::EMPTY, dates);
+        assert_eq!(ca.physical().get(0), Some(-1));
+        assert_eq!(ca.as_time_date_iter().collect::<Vec<_>>(), dates);
+
+        let datetimes = [
+            PrimitiveDateTime::new(
+                date(1969, Month::December, 31),
+                Time::from_hms_milli(23, 59, 59, 500).unwrap(),
+            ),
+            PrimitiveDateTime::new(
+                date(2024, Month::February, 29),
+                Time::from_hms(12, 30, 0).unwrap(),
+            ),
+        ];
+        let ca = DatetimeChunked::new(PlSmallStr::EMPTY, datetimes);
+        assert_eq!(ca.physical().get(0), Some(-500));
+        assert_eq!(
+            ca.as_primitive_datetime_iter().collect::<Vec<_>>(),
+            datetimes.map(Some)
+        );
+
+        let offset = UtcOffset::from_hms(1, 0, 0).unwrap();
+        let ca = DatetimeChunked::new(
+            PlSmallStr::EMPTY,
+            [
+                datetimes[1].assume_offset(offset),
+                datetimes[1].assume_utc(),
+            ],
+        );
+        assert_eq!(ca.time_zone().as_deref(), Some("UTC"));
+        assert_eq!(
+            ca.physical().get(0).unwrap() + 3_600_000,
+            ca.physical().get(1).unwrap()
+        );
+
+        let times = [
+            Time::MIDNIGHT,
+            Time::from_hms_nano(23, 59, 59, 999_999_999).unwrap(),
+        ];
+        let ca = TimeChunked::new(PlSmallStr::EMPTY, times);
+        assert_eq!(ca.as_time_time_iter().collect::<Vec<_>>(), times.map(Some));
+
+        let durations = [TimeDuration::milliseconds(-1_500), TimeDuration::days(2)];
+        let ca = DurationChunked::new(PlSmallStr::EMPTY, durations);
+        assert_eq!(ca.physical().get(0), Some(-1_500_000_000));
+        assert_eq!(
+            ca.as_time_duration_iter().collect::<Vec<_>>(),
+            durations.map(Some)
+        );
+
+        assert_eq!(
+            AnyValue::from(date(1970, Month::January, 2)),
+            AnyValue::Date(1)
+        );
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "dtype-date",
+        feature = "dtype-datetime",
+        feature = "dtype-duration"
+    ))]
+    fn test_time_crate_out_of_range() {
+        let ca = Int32Chunked::new(PlSmallStr::EMPTY, [i32::MAX, 0]).into_date();
+        assert_eq!(
+            ca.as_time_date_iter().collect::<Vec<_>>(),
+            [
+                None,
+                Some(Date::from_calendar_date(1970, Month::January, 1).unwrap())
+            ]
+        );
+
+        // Beyond the range of nanosecond timestamps, but within the range of the time crate.
+        let late = PrimitiveDateTime::new(
+            Date::from_calendar_date(9999, Month::December, 31).unwrap(),
+            Time::MIDNIGHT,
+        );
+        let ca = DatetimeChunked::from_primitive_datetime(
+            PlSmallStr::EMPTY,
+            [late],
+            TimeUnit::Nanoseconds,
+        );
+        assert_eq!(ca.physical().get(0), None);
+        assert!(matches!(
+            AnyValue::from(late),
+            AnyValue::Datetime(_, TimeUnit::Microseconds, None)
+        ));
+        let ca = DatetimeChunked::new(PlSmallStr::EMPTY, [late]);
+        assert_eq!(
+            ca.as_primitive_datetime_iter().collect::<Vec<_>>(),
+            [Some(late)]
+        );
+
+        let ca = Int64Chunked::new(PlSmallStr::EMPTY, [i64::MAX])
+            .into_datetime(TimeUnit::Milliseconds, None);
+        assert_eq!(ca.as_offset_datetime_iter().collect::<Vec<_>>(), [None]);
+
+        let ca = DurationChunked::from_time_duration(
+            PlSmallStr::EMPTY,
+            [TimeDuration::MAX],
+            TimeUnit::Nanoseconds,
+        );
+        assert_eq!(ca.physical().get(0), None);
+        assert!(matches!(
+            AnyValue::from(TimeDuration::days(200_000)),
+            AnyValue::Duration(_, TimeUnit::Microseconds)
+        ));
+    }
+}
@@ -37,6 +37,7 @@ pub use into_scalar::*;
 use num_traits::{Bounded, FromPrimitive, Num, NumCast, One, Zero};
 use polars_compute::arithmetic::HasPrimitiveArithmeticKernel;
 use polars_compute::float_sum::FloatSum;
+use polars_compute::sum::WrappingAdd;
 use polars_utils::abs_diff::AbsDiff;
 use polars_utils::float::IsFloat;
 use polars_utils::min_max::MinMax;
@@ -356,6 +357,7 @@ pub trait NumericNative:
     + FloatSum<f64>
     + MinMax
     + IsNull
+    + WrappingAdd
 {
     type PolarsType: PolarsNumericType;
     type TrueDivPolarsType: PolarsNumericType;
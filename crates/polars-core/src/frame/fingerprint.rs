@@ -0,0 +1,323 @@
+use arrow::array::{FixedSizeListArray, LargeListArray};
+use xxhash_rust::xxh3::{xxh3_128_with_seed, Xxh3};
+
+use super::*;
+
+/// The version of the value encoding that [`DataFrame::hash_frame`] hashes. It is part of every
+/// digest, and must be bumped whenever the encoding in [`value_fingerprints`] changes.
+const FINGERPRINT_VERSION: u8 = 1;
+
+/// The number of rows that are fingerprinted at once.
+const FINGERPRINT_BATCH_SIZE: usize = 1 << 16;
+
+/// `dtype` with its categoricals replaced by strings, whose physical representation does not
+/// depend on the string cache.
+fn categoricals_to_string(dtype: &DataType) -> DataType {
+    match dtype {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => DataType::String,
+        DataType::List(inner) => DataType::List(Box::new(categoricals_to_string(inner))),
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, width) => {
+            DataType::Array(Box::new(categoricals_to_string(inner)), *width)
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => DataType::Struct(
+            fields
+                .iter()
+                .map(|f| Field::new(f.name.clone(), categoricals_to_string(&f.dtype)))
+                .collect(),
+        ),
+        dt => dt.clone(),
+    }
+}
+
+/// Hash every value of `values`, a null is encoded as a `0` byte and a valid value as a `1` byte
+/// followed by the bytes that `write` appends.
+fn hash_values<T>(
+    values: impl Iterator<Item = Option<T>>,
+    seed: u64,
+    mut write: impl FnMut(T, &mut Vec<u8>),
+) -> Vec<u128> {
+    let mut buf = Vec::new();
+    values
+        .map(|opt_v| {
+            buf.clear();
+            match opt_v {
+                None => buf.push(0),
+                Some(v) => {
+                    buf.push(1);
+                    write(v, &mut buf);
+                },
+            }
+            xxh3_128_with_seed(&buf, seed)
+        })
+        .collect()
+}
+
+/// Hash the nested values of a list or array, valid values are encoded as their length followed
+/// by the hashes of their elements.
+fn hash_nested_values(
+    ranges: impl Iterator<Item = Option<(usize, usize)>>,
+    inner: &[u128],
+    seed: u64,
+) -> Vec<u128> {
+    hash_values(ranges, seed, |(start, end), buf| {
+        buf.extend_from_slice(&((end - start) as u64).to_le_bytes());
+        for h in &inner[start..end] {
+            buf.extend_from_slice(&h.to_le_bytes());
+        }
+    })
+}
+
+/// The 128-bit hash of every value of `s`, computed from an explicit encoding that does not
+/// depend on the platform, the chunking or the in-memory layout. `s` may not contain
+/// categoricals.
+fn value_fingerprints(s: &Series, seed: u64) -> PolarsResult<Vec<u128>> {
+    macro_rules! hash_le_bytes {
+        ($ca:expr) => {
+            hash_values($ca.iter(), seed, |v, buf| {
+                buf.extend_from_slice(&v.to_le_bytes())
+            })
+        };
+    }
+
+    let s = s.to_physical_repr();
+    let out = match s.dtype() {
+        DataType::Null => vec![xxh3_128_with_seed(&[0], seed); s.len()],
+        DataType::Boolean => {
+            hash_values(s.bool().unwrap().iter(), seed, |v, buf| buf.push(v as u8))
+        },
+        DataType::UInt8 => hash_le_bytes!(s.u8().unwrap()),
+        DataType::UInt16 => hash_le_bytes!(s.u16().unwrap()),
+        DataType::UInt32 => hash_le_bytes!(s.u32().unwrap()),
+        DataType::UInt64 => hash_le_bytes!(s.u64().unwrap()),
+        DataType::Int8 => hash_le_bytes!(s.i8().unwrap()),
+        DataType::Int16 => hash_le_bytes!(s.i16().unwrap()),
+        DataType::Int32 => hash_le_bytes!(s.i32().unwrap()),
+        DataType::Int64 => hash_le_bytes!(s.i64().unwrap()),
+        #[cfg(feature = "dtype-i128")]
+        DataType::Int128 => hash_le_bytes!(s.i128().unwrap()),
+        // Hash the bits of canonical floats, such that all NaNs and both zeros are equal.
+        DataType::Float32 => hash_values(s.f32().unwrap().to_canonical().iter(), seed, |v, buf| {
+            buf.extend_from_slice(&v.to_bits().to_le_bytes())
+        }),
+        DataType::Float64 => hash_values(s.f64().unwrap().to_canonical().iter(), seed, |v, buf| {
+            buf.extend_from_slice(&v.to_bits().to_le_bytes())
+        }),
+        DataType::String => hash_values(s.str().unwrap().iter(), seed, |v, buf| {
+            buf.extend_from_slice(&(v.len() as u64).to_le_bytes());
+            buf.extend_from_slice(v.as_bytes());
+        }),
+        DataType::Binary => hash_values(s.binary().unwrap().iter(), seed, |v, buf| {
+            buf.extend_from_slice(&(v.len() as u64).to_le_bytes());
+            buf.extend_from_slice(v);
+        }),
+        DataType::List(inner_dtype) => {
+            let ca = s.list().unwrap().rechunk();
+            let arr: &LargeListArray = ca.downcast_iter().next().unwrap();
+            let inner = value_fingerprints(&inner_series(arr.values(), inner_dtype), seed)?;
+            hash_nested_values(
+                (0..arr.len()).map(|i| arr.is_valid(i).then(|| arr.offsets().start_end(i))),
+                &inner,
+                seed,
+            )
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner_dtype, width) => {
+            let ca = s.array().unwrap().rechunk();
+            let arr: &FixedSizeListArray = ca.downcast_iter().next().unwrap();
+            let inner = value_fingerprints(&inner_series(arr.values(), inner_dtype), seed)?;
+            hash_nested_values(
+                (0..arr.len()).map(|i| arr.is_valid(i).then_some((i * width, (i + 1) * width))),
+                &inner,
+                seed,
+            )
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => {
+            let ca = s.struct_().unwrap();
+            let fields = ca
+                .fields_as_series()
+                .iter()
+                .map(|field| value_fingerprints(field, seed))
+                .collect::<PolarsResult<Vec<_>>>()?;
+            let valid = s.is_not_null();
+            hash_values(
+                valid
+                    .into_no_null_iter()
+                    .enumerate()
+                    .map(|(i, valid)| valid.then_some(i)),
+                seed,
+                |i, buf| {
+                    for field in &fields {
+                        buf.extend_from_slice(&field[i].to_le_bytes());
+                    }
+                },
+            )
+        },
+        dt => polars_bail!(opq = hash_frame, dt),
+    };
+    Ok(out)
+}
+
+/// The values of a rechunked list or array as a [`Series`] of `dtype`.
+fn inner_series(values: &ArrayRef, dtype: &DataType) -> Series {
+    // SAFETY: the values of a physical nested array have the physical inner dtype.
+    unsafe {
+        Series::from_chunks_and_dtype_unchecked(PlSmallStr::EMPTY, vec![values.clone()], dtype)
+    }
+}
+
+impl DataFrame {
+    /// The 128-bit hash of every row, computed from the value hashes of its columns.
+    fn row_fingerprints(&self, seed: u64) -> PolarsResult<Vec<u128>> {
+        if self.width() == 0 {
+            return Ok(vec![xxh3_128_with_seed(&[], seed); self.height()]);
+        }
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| {
+                polars_ensure!(
+                    !c.dtype().contains_objects(),
+                    InvalidOperation: "cannot hash column `{}` of dtype {}", c.name(), c.dtype()
+                );
+                let s = c.as_materialized_series();
+                if s.dtype().contains_categoricals() {
+                    s.cast(&categoricals_to_string(s.dtype()))
+                } else {
+                    Ok(s.clone())
+                }
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let n_batches = self.height().div_ceil(FINGERPRINT_BATCH_SIZE);
+        let hashes = POOL.install(|| {
+            (0..n_batches)
+                .into_par_iter()
+                .map(|batch| {
+                    let offset = batch * FINGERPRINT_BATCH_SIZE;
+                    let columns = columns
+                        .iter()
+                        .map(|s| {
+                            value_fingerprints(
+                                &s.slice(offset as i64, FINGERPRINT_BATCH_SIZE),
+                                seed,
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    let mut buf = Vec::with_capacity(columns.len() * 16);
+                    Ok((0..columns[0].len())
+                        .map(|i| {
+                            buf.clear();
+                            for column in &columns {
+                                buf.extend_from_slice(&column[i].to_le_bytes());
+                            }
+                            xxh3_128_with_seed(&buf, seed)
+                        })
+                        .collect::<Vec<_>>())
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+        Ok(hashes.into_iter().flatten().collect())
+    }
+
+    /// Compute a stable 128-bit digest of the schema and the values of this [`DataFrame`].
+    ///
+    /// The values are hashed through an explicit, versioned encoding, so the digest does not
+    /// depend on the chunking, the platform or the string cache and can be stored to detect
+    /// changes in a dataset later. It does depend on the order of the rows, use
+    /// [`DataFrame::hash_frame_unordered`] to ignore it. Categoricals are hashed as their strings
+    /// and object columns are rejected.
+    pub fn hash_frame(&self, seed: u64) -> PolarsResult<u128> {
+        let mut hasher = Xxh3::with_seed(seed);
+        hasher.update(&[FINGERPRINT_VERSION]);
+        hasher.update(&self.schema().fingerprint().to_le_bytes());
+        hasher.update(&(self.height() as u64).to_le_bytes());
+        for h in self.row_fingerprints(seed)? {
+            hasher.update(&h.to_le_bytes());
+        }
+        Ok(hasher.digest128())
+    }
+
+    /// Compute a stable 128-bit digest like [`DataFrame::hash_frame`], but independent of the
+    /// order of the rows.
+    ///
+    /// The row hashes are combined with a wrapping sum, so duplicated rows still change the digest.
+    pub fn hash_frame_unordered(&self, seed: u64) -> PolarsResult<u128> {
+        let sum = self
+            .row_fingerprints(seed)?
+            .into_iter()
+            .fold(0u128, u128::wrapping_add);
+        let mut hasher = Xxh3::with_seed(seed);
+        hasher.update(&[FINGERPRINT_VERSION]);
+        hasher.update(&self.schema().fingerprint().to_le_bytes());
+        hasher.update(&(self.height() as u64).to_le_bytes());
+        hasher.update(&sum.to_le_bytes());
+        Ok(hasher.digest128())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hash_frame() -> PolarsResult<()> {
+        let df = df![
+            "a" => [Some(1), None, Some(3)],
+            "b" => ["x", "y", "z"],
+        ]?;
+        let reversed = df.reverse();
+        let mut chunked = df.slice(0, 1);
+        chunked.vstack_mut(&df.slice(1, 2))?;
+
+        let h = df.hash_frame(0)?;
+        assert_eq!(h, chunked.hash_frame(0)?);
+        assert_ne!(h, reversed.hash_frame(0)?);
+        assert_ne!(h, df.hash_frame(1)?);
+        assert_eq!(
+            df.hash_frame_unordered(0)?,
+            reversed.hash_frame_unordered(0)?
+        );
+
+        let mut renamed = df.clone();
+        renamed.rename("b", "c".into())?;
+        assert_ne!(h, renamed.hash_frame(0)?);
+        let mut cast = df.clone();
+        cast.apply("a", |c| c.cast(&DataType::Int64).unwrap())?;
+        assert_ne!(df.schema().fingerprint(), cast.schema().fingerprint());
+        let head = df.head(Some(2));
+        assert_ne!(df.hash_frame_unordered(0)?, head.hash_frame_unordered(0)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_frame_nested_lists() -> PolarsResult<()> {
+        let list = |values: &[&[i32]]| {
+            Series::new(
+                PlSmallStr::EMPTY,
+                values
+                    .iter()
+                    .map(|v| Series::new(PlSmallStr::EMPTY, *v))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        let s = Series::new(
+            PlSmallStr::from_static("a"),
+            [
+                list(&[&[1, 2], &[3]]),
+                list(&[&[1]]),
+                list(&[&[1], &[2, 3]]),
+            ],
+        );
+        let df = DataFrame::new(vec![s.into()])?;
+        let mut chunked = df.slice(0, 2);
+        chunked.vstack_mut(&df.slice(2, 1))?;
+
+        assert_eq!(df.hash_frame(0)?, chunked.hash_frame(0)?);
+        assert_ne!(df.hash_frame(0)?, df.slice(1, 2).hash_frame(0)?);
+        Ok(())
+    }
+}
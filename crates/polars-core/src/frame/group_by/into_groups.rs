@@ -5,6 +5,7 @@ use super::*;
 use crate::chunked_array::cast::CastOptions;
 use crate::config::verbose;
 use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca_unordered;
+use crate::prelude::sort::rank_nested_list_values;
 use crate::series::BitRepr;
 use crate::utils::flatten::flatten_par;
 
@@ -260,6 +261,32 @@ impl IntoGroupsProxy for BooleanChunked {
     }
 }
 
+/// Create the groups of sorted keys with a single scan, starting a group wherever the key
+/// differs from the previous one.
+fn groups_from_sorted_runs<K: PartialEq>(mut keys: impl Iterator<Item = K>) -> GroupsSlice {
+    if verbose() {
+        eprintln!("group_by keys are sorted; running sorted key fast path");
+    }
+    let mut groups = GroupsSlice::default();
+    let Some(mut current) = keys.next() else {
+        return groups;
+    };
+    let mut start = 0 as IdxSize;
+    let mut len = 1 as IdxSize;
+    for key in keys {
+        if key == current {
+            len += 1;
+        } else {
+            groups.push([start, len]);
+            start += len;
+            len = 1;
+            current = key;
+        }
+    }
+    groups.push([start, len]);
+    groups
+}
+
 impl IntoGroupsProxy for StringChunked {
     #[allow(clippy::needless_lifetimes)]
     fn group_tuples<'a>(&'a self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
@@ -274,6 +301,14 @@ impl IntoGroupsProxy for BinaryChunked {
         mut multithreaded: bool,
         sorted: bool,
     ) -> PolarsResult<GroupsProxy> {
+        // sorted path
+        if self.is_sorted_ascending_flag() || self.is_sorted_descending_flag() {
+            return Ok(GroupsProxy::Slice {
+                groups: groups_from_sorted_runs(self.iter()),
+                rolling: false,
+            });
+        }
+
         multithreaded &= POOL.current_num_threads() > 1;
         let bh = self.to_bytes_hashes(multithreaded, Default::default());
 
@@ -320,11 +355,12 @@ impl IntoGroupsProxy for ListChunked {
         sorted: bool,
     ) -> PolarsResult<GroupsProxy> {
         multithreaded &= POOL.current_num_threads() > 1;
-        let by = &[self.clone().into_series()];
+        // Rank nested values over the whole column, the partitions are encoded separately.
+        let by = &[rank_nested_list_values(self)?.into_series()];
         let ca = if multithreaded {
-            encode_rows_vertical_par_unordered(by).unwrap()
+            encode_rows_vertical_par_unordered(by)?
         } else {
-            _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, by).unwrap()
+            _get_rows_encoded_ca_unordered(PlSmallStr::EMPTY, by)?
         };
 
         ca.group_tuples(multithreaded, sorted)
@@ -12,13 +12,15 @@ use crate::chunked_array::ops::unique::is_unique_helper;
 use crate::prelude::*;
 #[cfg(feature = "row_hash")]
 use crate::utils::split_df;
-use crate::utils::{slice_offsets, try_get_supertype, NoNull};
+use crate::utils::{slice_offsets, try_get_supertype, CastDiagnostics, NoNull};
 
 #[cfg(feature = "dataframe_arithmetic")]
 mod arithmetic;
 mod chunks;
 pub mod column;
 pub mod explode;
+#[cfg(feature = "row_hash")]
+mod fingerprint;
 mod from;
 #[cfg(feature = "algorithm_group_by")]
 pub mod group_by;
@@ -1077,6 +1079,50 @@ impl DataFrame {
         Ok(self.columns.remove(idx))
     }
 
+    /// Cast the given columns, reporting the values that could not be converted instead of
+    /// raising.
+    ///
+    /// Like [`Series::cast_with_diagnostics`] this returns the non-strict cast output, together
+    /// with a single diagnostics frame that lists the failed and lossy conversions of all
+    /// columns. This allows auditing dirty data while still producing output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use polars_core::prelude::*;
+    /// let df = df!("a" => ["1", "x", "3"])?;
+    /// let dtypes = PlHashMap::from_iter([("a", DataType::Int32)]);
+    /// let (out, diagnostics) = df.cast_with_diagnostics(&dtypes)?;
+    ///
+    /// assert_eq!(out.column("a")?.null_count(), 1);
+    /// assert_eq!(diagnostics.height(), 1);
+    /// # Ok::<(), PolarsError>(())
+    /// ```
+    pub fn cast_with_diagnostics(
+        &self,
+        dtypes: &PlHashMap<&str, DataType>,
+    ) -> PolarsResult<(DataFrame, DataFrame)> {
+        for name in dtypes.keys() {
+            self.check_name_to_idx(name)?;
+        }
+        let mut diagnostics = CastDiagnostics::default();
+        let columns = self
+            .columns
+            .iter()
+            .map(|c| match dtypes.get(c.name().as_str()) {
+                None => Ok(c.clone()),
+                Some(dtype) => {
+                    let input = c.as_materialized_series();
+                    let out = input.cast_with_options(dtype, CastOptions::NonStrict)?;
+                    diagnostics.push(input, &out)?;
+                    Ok(out.into_column())
+                },
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let df = unsafe { DataFrame::new_no_checks(columns) };
+        Ok((df, diagnostics.finish()))
+    }
+
     /// Return a new [`DataFrame`] where all null values are dropped.
     ///
     /// # Example
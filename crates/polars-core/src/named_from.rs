@@ -8,6 +8,8 @@ use chrono::NaiveDate;
 use chrono::NaiveDateTime;
 #[cfg(feature = "dtype-time")]
 use chrono::NaiveTime;
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+use chrono::{DateTime, Utc};
 
 use crate::chunked_array::builder::{get_list_builder, AnonymousListBuilder};
 use crate::prelude::*;
@@ -354,6 +356,45 @@ impl<T: AsRef<[Option<NaiveDateTime>]>> NamedFrom<T, [Option<NaiveDateTime>]> fo
     }
 }
 
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+impl<T: AsRef<[DateTime<Utc>]>> NamedFrom<T, [DateTime<Utc>]> for DatetimeChunked {
+    fn new(name: PlSmallStr, v: T) -> Self {
+        let vals = v
+            .as_ref()
+            .iter()
+            .map(|dt| datetime_to_timestamp_ms(dt.naive_utc()))
+            .collect::<Vec<_>>();
+        Int64Chunked::from_vec(name, vals)
+            .into_datetime(TimeUnit::Milliseconds, Some(PlSmallStr::from_static("UTC")))
+    }
+}
+
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+impl<T: AsRef<[DateTime<Utc>]>> NamedFrom<T, [DateTime<Utc>]> for Series {
+    fn new(name: PlSmallStr, v: T) -> Self {
+        DatetimeChunked::new(name, v).into_series()
+    }
+}
+
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+impl<T: AsRef<[Option<DateTime<Utc>>]>> NamedFrom<T, [Option<DateTime<Utc>>]> for DatetimeChunked {
+    fn new(name: PlSmallStr, v: T) -> Self {
+        let vals = v
+            .as_ref()
+            .iter()
+            .map(|opt_dt| opt_dt.map(|dt| datetime_to_timestamp_ms(dt.naive_utc())));
+        Int64Chunked::from_iter_options(name, vals)
+            .into_datetime(TimeUnit::Milliseconds, Some(PlSmallStr::from_static("UTC")))
+    }
+}
+
+#[cfg(all(feature = "dtype-datetime", feature = "timezones"))]
+impl<T: AsRef<[Option<DateTime<Utc>>]>> NamedFrom<T, [Option<DateTime<Utc>>]> for Series {
+    fn new(name: PlSmallStr, v: T) -> Self {
+        DatetimeChunked::new(name, v).into_series()
+    }
+}
+
 #[cfg(feature = "dtype-duration")]
 impl<T: AsRef<[ChronoDuration]>> NamedFrom<T, [ChronoDuration]> for DurationChunked {
     fn new(name: PlSmallStr, v: T) -> Self {
@@ -6,7 +6,7 @@ use rand::prelude::*;
 static POLARS_GLOBAL_RNG_STATE: Lazy<Mutex<SmallRng>> =
     Lazy::new(|| Mutex::new(SmallRng::from_entropy()));
 
-pub(crate) fn get_global_random_u64() -> u64 {
+pub fn get_global_random_u64() -> u64 {
     POLARS_GLOBAL_RNG_STATE.lock().unwrap().next_u64()
 }
 
@@ -20,6 +20,46 @@ pub trait SchemaExt {
     fn iter_fields(&self) -> impl ExactSizeIterator<Item = Field> + '_;
 
     fn to_supertype(&mut self, other: &Schema) -> PolarsResult<bool>;
+
+    fn fingerprint(&self) -> u128;
+}
+
+/// Write a canonical description of `dtype` to `out`, names are prefixed by their length so that
+/// they cannot run into each other.
+fn write_dtype_fingerprint(dtype: &DataType, out: &mut String) {
+    use std::fmt::Write;
+    match dtype {
+        DataType::List(inner) => {
+            out.push_str("list[");
+            write_dtype_fingerprint(inner, out);
+            out.push(']');
+        },
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(inner, width) => {
+            write!(out, "array{width}[").unwrap();
+            write_dtype_fingerprint(inner, out);
+            out.push(']');
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => {
+            out.push_str("struct[");
+            for field in fields {
+                write!(out, "{}:{}:", field.name.len(), field.name).unwrap();
+                write_dtype_fingerprint(&field.dtype, out);
+                out.push(',');
+            }
+            out.push(']');
+        },
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(Some(rev_map), _) => {
+            out.push_str("enum[");
+            for category in rev_map.get_categories().values_iter() {
+                write!(out, "{}:{},", category.len(), category).unwrap();
+            }
+            out.push(']');
+        },
+        dt => write!(out, "{dt}").unwrap(),
+    }
 }
 
 impl SchemaExt for Schema {
@@ -88,6 +128,21 @@ impl SchemaExt for Schema {
         }
         Ok(changed)
     }
+
+    /// Compute a stable 128-bit digest of the names and dtypes of this schema, in order.
+    ///
+    /// Unlike the [`Hash`] implementation, the digest does not depend on the platform or the
+    /// process, so that it can be stored to detect schema changes later.
+    fn fingerprint(&self) -> u128 {
+        use std::fmt::Write;
+        let mut out = String::new();
+        for (name, dtype) in self.iter() {
+            write!(out, "{}:{}:", name.len(), name).unwrap();
+            write_dtype_fingerprint(dtype, &mut out);
+            out.push(';');
+        }
+        xxhash_rust::xxh3::xxh3_128(out.as_bytes())
+    }
 }
 
 pub trait SchemaNamesAndDtypes {
@@ -1,3 +1,4 @@
+use super::list_borrowed::{is_nested_numeric, nested_numeric_arithmetic};
 use super::*;
 use crate::utils::align_chunks_binary;
 
@@ -520,6 +521,9 @@ impl Add for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.add(b))
             },
+            (l, r) if is_nested_numeric(l, r) => {
+                nested_numeric_arithmetic(self, rhs, |a, b| a.add(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.add_to(rhs.as_ref())
@@ -538,6 +542,9 @@ impl Sub for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.sub(b))
             },
+            (l, r) if is_nested_numeric(l, r) => {
+                nested_numeric_arithmetic(self, rhs, |a, b| a.sub(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.subtract(rhs.as_ref())
@@ -572,6 +579,9 @@ impl Mul for &Series {
                 let out = rhs.multiply(self)?;
                 Ok(out.with_name(self.name().clone()))
             },
+            (l, r) if is_nested_numeric(l, r) => {
+                nested_numeric_arithmetic(self, rhs, |a, b| a.mul(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.multiply(rhs.as_ref())
@@ -606,6 +616,7 @@ impl Div for &Series {
             | (_ , Date)
             | (_ , Datetime(_, _))
             => polars_bail!(opq = div, self.dtype(), rhs.dtype()),
+            (l, r) if is_nested_numeric(l, r) => nested_numeric_arithmetic(self, rhs, |a, b| a.div(b)),
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.divide(rhs.as_ref())
@@ -629,6 +640,9 @@ impl Rem for &Series {
             (DataType::Struct(_), DataType::Struct(_)) => {
                 _struct_arithmetic(self, rhs, |a, b| a.rem(b))
             },
+            (l, r) if is_nested_numeric(l, r) => {
+                nested_numeric_arithmetic(self, rhs, |a, b| a.rem(b))
+            },
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
                 lhs.remainder(rhs.as_ref())
@@ -978,6 +992,89 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_nested_numeric_arithmetic() -> PolarsResult<()> {
+        let list = [Some(vec![1i64, 2]), None, Some(vec![]), Some(vec![4])]
+            .into_iter()
+            .map(|v| v.map(|v| Series::new("".into(), v)))
+            .collect::<ListChunked>()
+            .into_series();
+        let values = |s: &Series| -> Vec<Option<Vec<Option<i64>>>> {
+            s.list()
+                .unwrap()
+                .into_iter()
+                .map(|v| v.map(|v| Vec::from(v.i64().unwrap())))
+                .collect()
+        };
+
+        // List with a scalar.
+        let out = (&list + &Series::new("".into(), [10i64]))?;
+        assert_eq!(
+            values(&out),
+            [
+                Some(vec![Some(11), Some(12)]),
+                None,
+                Some(vec![]),
+                Some(vec![Some(14)])
+            ]
+        );
+        assert_eq!(out.name().as_str(), "");
+
+        // A numeric series is broadcast per row, also on a sliced list.
+        let per_row = Series::new("b".into(), [Some(10i64), Some(20), Some(30), None]);
+        let out = (&per_row - &list)?;
+        assert_eq!(
+            values(&out),
+            [
+                Some(vec![Some(9), Some(8)]),
+                None,
+                Some(vec![]),
+                Some(vec![None])
+            ]
+        );
+        assert_eq!(out.name().as_str(), "b");
+        let out = (&list.slice(2, 2) * &per_row.slice(0, 2))?;
+        assert_eq!(values(&out), [Some(vec![]), Some(vec![Some(80)])]);
+
+        // Supertypes are taken on the leaf values.
+        let out = (&list * &Series::new("".into(), [0.5f64]))?;
+        assert_eq!(out.dtype(), &DataType::List(Box::new(DataType::Float64)));
+
+        // A single list is broadcast over a list.
+        let single = Series::new("".into(), [Series::new("".into(), [1i64, 1])]);
+        let pairs = Series::new(
+            "".into(),
+            [
+                Series::new("".into(), [1i64, 2]),
+                Series::new("".into(), [3i64, 4]),
+            ],
+        );
+        let out = (&single + &pairs)?;
+        assert_eq!(
+            values(&out),
+            [Some(vec![Some(2), Some(3)]), Some(vec![Some(4), Some(5)])]
+        );
+
+        // Lengths that cannot be broadcast are an error.
+        let err = (&list + &Series::new("".into(), [1i64, 2])).unwrap_err();
+        assert!(matches!(err, PolarsError::ShapeMismatch(_)));
+        assert!((&Series::new_empty("".into(), &DataType::Int64) * &pairs).is_err());
+
+        #[cfg(feature = "dtype-array")]
+        {
+            let dtype = DataType::Array(Box::new(DataType::Int64), 2);
+            let arrays = pairs.cast(&dtype)?;
+            let out = (&arrays * &Series::new("".into(), [2i64, 3]))?;
+            assert_eq!(out.dtype(), &dtype);
+            let out = out.cast(&DataType::List(Box::new(DataType::Int64)))?;
+            assert_eq!(
+                values(&out),
+                [Some(vec![Some(2), Some(4)]), Some(vec![Some(9), Some(12)])]
+            );
+        }
+        Ok(())
+    }
+
     #[test]
     #[cfg(feature = "checked_arithmetic")]
     fn test_checked_div() {
@@ -53,6 +53,129 @@ fn lists_same_shapes(left: &ArrayRef, right: &ArrayRef) -> bool {
     }
 }
 
+fn is_list_or_array(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(_) => true,
+        #[cfg(feature = "dtype-array")]
+        DataType::Array(_, _) => true,
+        _ => false,
+    }
+}
+
+/// Whether one of `lhs` and `rhs` is a list or array and the other one is numeric.
+pub(super) fn is_nested_numeric(lhs: &DataType, rhs: &DataType) -> bool {
+    (is_list_or_array(lhs) && rhs.is_numeric()) || (lhs.is_numeric() && is_list_or_array(rhs))
+}
+
+/// For every row of the list or array `arr`, the start of its values in the leaf values array,
+/// followed by the end of the values of the last row.
+fn leaf_bounds(arr: &dyn Array) -> Vec<usize> {
+    let mut bounds = (0..=arr.len()).collect::<Vec<_>>();
+    let mut arr = arr;
+    loop {
+        if let Some(list) = arr.as_any().downcast_ref::<LargeListArray>() {
+            let offsets = list.offsets().as_slice();
+            bounds.iter_mut().for_each(|b| *b = offsets[*b] as usize);
+            arr = list.values().as_ref();
+            continue;
+        }
+        #[cfg(feature = "dtype-array")]
+        if let Some(array) = arr.as_any().downcast_ref::<FixedSizeListArray>() {
+            let size = array.size();
+            bounds.iter_mut().for_each(|b| *b *= size);
+            arr = array.values().as_ref();
+            continue;
+        }
+        return bounds;
+    }
+}
+
+/// Replace the leaf values of the list or array `shape` by `leaf`, which has the same length but
+/// may have a different type.
+fn with_leaf_values(shape: &dyn Array, leaf: ArrayRef) -> ArrayRef {
+    if let Some(list) = shape.as_any().downcast_ref::<LargeListArray>() {
+        let values = with_leaf_values(list.values().as_ref(), leaf);
+        return Box::new(LargeListArray::new(
+            LargeListArray::default_datatype(values.dtype().clone()),
+            list.offsets().clone(),
+            values,
+            list.validity().cloned(),
+        ));
+    }
+    #[cfg(feature = "dtype-array")]
+    if let Some(array) = shape.as_any().downcast_ref::<FixedSizeListArray>() {
+        let values = with_leaf_values(array.values().as_ref(), leaf);
+        return Box::new(FixedSizeListArray::new(
+            FixedSizeListArray::default_datatype(values.dtype().clone(), array.size()),
+            values,
+            array.validity().cloned(),
+        ));
+    }
+    leaf
+}
+
+/// Arithmetic between a list or array and a numeric series, in either order.
+///
+/// A numeric scalar applies to all inner values and a numeric series of the same length applies
+/// to the inner values of its row. `op` runs once on the flat leaf values and the result keeps
+/// the nesting of the list or array.
+pub(super) fn nested_numeric_arithmetic(
+    lhs: &Series,
+    rhs: &Series,
+    op: impl Fn(&Series, &Series) -> PolarsResult<Series>,
+) -> PolarsResult<Series> {
+    let nested_is_lhs = is_list_or_array(lhs.dtype());
+    let (nested, numeric) = if nested_is_lhs {
+        (lhs, rhs)
+    } else {
+        (rhs, lhs)
+    };
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "cannot apply arithmetic between series of different length ({} != {})",
+        lhs.len(),
+        rhs.len()
+    );
+    let nested = if nested.len() == 1 && numeric.len() != 1 {
+        nested.new_from_index(0, numeric.len())
+    } else {
+        nested.rechunk()
+    };
+    let arr = nested.chunks()[0].as_ref();
+    let leaf = nested.get_leaf_array();
+
+    let numeric = if numeric.len() == 1 {
+        // Numeric scalars are broadcast by the kernel itself.
+        numeric.clone()
+    } else if numeric.is_empty() {
+        // The leaf values of an empty slice are not referenced by any row.
+        Series::full_null(numeric.name().clone(), leaf.len(), numeric.dtype())
+    } else {
+        let bounds = leaf_bounds(arr);
+        let mut idx = vec![0 as IdxSize; leaf.len()];
+        for (row, w) in bounds.windows(2).enumerate() {
+            idx[w[0]..w[1]].fill(row as IdxSize);
+        }
+        // SAFETY: the rows are in bounds as `numeric` has the length of `nested`.
+        unsafe { numeric.take_slice_unchecked(&idx) }
+    };
+
+    let out = if nested_is_lhs {
+        op(&leaf, &numeric)?
+    } else {
+        op(&numeric, &leaf)?
+    };
+    let out = out.rechunk();
+    let values = with_leaf_values(arr, out.chunks()[0].clone());
+    let dtype = nested.dtype().cast_leaf(out.dtype().clone());
+    // SAFETY: the physical type of the rebuilt nesting matches `dtype`.
+    Ok(
+        unsafe {
+            Series::from_chunks_and_dtype_unchecked(lhs.name().clone(), vec![values], &dtype)
+        },
+    )
+}
+
 impl ListChunked {
     /// Helper function for NumOpsDispatchInner implementation for ListChunked.
     ///
@@ -63,6 +186,14 @@ impl ListChunked {
         op: &dyn Fn(&Series, &Series) -> PolarsResult<Series>,
         has_nulls: Option<bool>,
     ) -> PolarsResult<Series> {
+        // Broadcast a single list over the other side.
+        match (self.len(), rhs.len()) {
+            (1, n) if n != 1 => return self.new_from_index(0, n).arithm_helper(rhs, op, has_nulls),
+            (n, 1) if n != 1 => {
+                return self.arithm_helper(&rhs.new_from_index(0, n), op, has_nulls)
+            },
+            _ => {},
+        }
         polars_ensure!(
             self.len() == rhs.len(),
             InvalidOperation: "can only do arithmetic operations on Series of the same size; got {} and {}",
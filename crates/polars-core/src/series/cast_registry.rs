@@ -0,0 +1,98 @@
+//! A global registry of custom cast functions.
+//!
+//! Downstream crates can register a function that casts between a pair of dtypes the
+//! built-in cast machinery does not support (e.g. from or to their own object types).
+//! [`Series::cast_with_options`] consults the registry before it returns a cast error,
+//! so registered casts also work inside expressions.
+use std::sync::{Arc, RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::chunked_array::cast::CastOptions;
+use crate::prelude::*;
+
+/// Casts a [`Series`] to the given [`DataType`].
+pub type CastFunction =
+    Arc<dyn Fn(&Series, &DataType, CastOptions) -> PolarsResult<Series> + Send + Sync>;
+
+struct CastRule {
+    from: DataType,
+    to: DataType,
+    function: CastFunction,
+}
+
+static GLOBAL_CAST_REGISTRY: Lazy<RwLock<Vec<CastRule>>> = Lazy::new(Default::default);
+
+/// Register a function that casts [`Series`] of dtype `from` to dtype `to`.
+///
+/// The function is only called if the built-in cast fails. It receives the
+/// [`CastOptions`] of the cast and is responsible for honoring its strictness.
+/// A function that was registered earlier for the same pair is replaced.
+pub fn register_cast_function(from: DataType, to: DataType, function: CastFunction) {
+    let mut registry = GLOBAL_CAST_REGISTRY.write().unwrap();
+    match registry
+        .iter_mut()
+        .find(|rule| rule.from == from && rule.to == to)
+    {
+        Some(rule) => rule.function = function,
+        None => registry.push(CastRule { from, to, function }),
+    }
+}
+
+/// Remove the function registered for casting `from` to `to`.
+///
+/// Returns whether a function was registered.
+pub fn unregister_cast_function(from: &DataType, to: &DataType) -> bool {
+    let mut registry = GLOBAL_CAST_REGISTRY.write().unwrap();
+    let len = registry.len();
+    registry.retain(|rule| !(&rule.from == from && &rule.to == to));
+    registry.len() != len
+}
+
+/// Get the function registered for casting `from` to `to`.
+pub fn get_cast_function(from: &DataType, to: &DataType) -> Option<CastFunction> {
+    GLOBAL_CAST_REGISTRY
+        .read()
+        .unwrap()
+        .iter()
+        .find(|rule| &rule.from == from && &rule.to == to)
+        .map(|rule| rule.function.clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_registered_cast() -> PolarsResult<()> {
+        let from = DataType::List(Box::new(DataType::Boolean));
+        let to = DataType::Boolean;
+        let s = [Some(vec![true, false]), None, Some(vec![false])]
+            .into_iter()
+            .map(|opt_v| opt_v.map(|v| Series::new(PlSmallStr::EMPTY, v)))
+            .collect::<ListChunked>()
+            .with_name("a".into())
+            .into_series();
+        assert!(s.cast(&to).is_err());
+
+        register_cast_function(
+            from.clone(),
+            to.clone(),
+            Arc::new(|s, _, _| {
+                Ok(s.list()?
+                    .amortized_iter()
+                    .map(|opt_s| opt_s.map(|s| s.as_ref().bool().unwrap().any()))
+                    .collect::<BooleanChunked>()
+                    .with_name(s.name().clone())
+                    .into_series())
+            }),
+        );
+        let out = s.cast(&to)?;
+        assert_eq!(out.name().as_str(), "a");
+        assert_eq!(Vec::from(out.bool()?), &[Some(true), None, Some(false)]);
+
+        assert!(unregister_cast_function(&from, &to));
+        assert!(s.cast(&to).is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,8 @@
 //! Comparison operations on Series.
 
+use std::cmp::Ordering;
+
+use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_arr;
 use crate::prelude::*;
 use crate::series::arithmetic::coerce_lhs_rhs;
 use crate::series::nulls::replace_non_null;
@@ -106,6 +109,79 @@ fn raise_struct(_a: &(), _b: &()) -> PolarsResult<BooleanChunked> {
     unimplemented!()
 }
 
+/// Whether values of this dtype are ordered lexicographically by their row encoding.
+fn is_lexically_ordered(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(_) => true,
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => true,
+        _ => false,
+    }
+}
+
+/// Whether `dtype` holds lists of nested values, which the row encoding cannot order.
+fn has_nested_list(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(inner) => inner.is_nested() || has_nested_list(inner),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields.iter().any(|f| has_nested_list(f.dtype())),
+        _ => false,
+    }
+}
+
+/// Order comparison of nested values.
+///
+/// Lists compare element by element and structs field by field, both by comparing
+/// their row encodings. The output is null where either side is null.
+fn compare_lexically(
+    lhs: &Series,
+    rhs: &Series,
+    op: fn(Ordering) -> bool,
+) -> PolarsResult<BooleanChunked> {
+    polars_ensure!(
+        lhs.len() == rhs.len() || lhs.len() == 1 || rhs.len() == 1,
+        ShapeMismatch: "could not compare between two series of different length ({} != {})",
+        lhs.len(),
+        rhs.len()
+    );
+    let err = || {
+        polars_err!(SchemaMismatch: "could not evaluate comparison between series '{}' of dtype: {} and series '{}' of dtype: {}",
+        lhs.name(), lhs.dtype(), rhs.name(), rhs.dtype())
+    };
+    let (lhs, rhs) = coerce_lhs_rhs(lhs, rhs).map_err(|_| err())?;
+    if lhs.dtype() != rhs.dtype() {
+        return Err(err());
+    }
+    polars_ensure!(
+        !has_nested_list(lhs.dtype()),
+        InvalidOperation: "cannot compare the order of values of dtype `{}`, lists of nested values are not supported",
+        lhs.dtype()
+    );
+
+    let encode = |s: &Series| _get_rows_encoded_arr(&[s.clone().into_column()], &[false], &[false]);
+    let lhs_rows = encode(&lhs)?;
+    let rhs_rows = encode(&rhs)?;
+
+    let lhs = lhs.rechunk();
+    let rhs = rhs.rechunk();
+    let lhs_arr = &lhs.chunks()[0];
+    let rhs_arr = &rhs.chunks()[0];
+    let lhs_broadcast = lhs.len() == 1;
+    let rhs_broadcast = rhs.len() == 1;
+    let len = if lhs_broadcast { rhs.len() } else { lhs.len() };
+
+    let mut out: BooleanChunked = (0..len)
+        .map(|i| {
+            let l = if lhs_broadcast { 0 } else { i };
+            let r = if rhs_broadcast { 0 } else { i };
+            (lhs_arr.is_valid(l) && rhs_arr.is_valid(r))
+                .then(|| op(lhs_rows.value(l).cmp(rhs_rows.value(r))))
+        })
+        .collect_trusted();
+    out.rename(lhs.name().clone());
+    Ok(out)
+}
+
 fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
 
@@ -166,21 +242,33 @@ impl ChunkCompare<&Series> for Series {
 
     /// Create a boolean mask by checking if self > rhs.
     fn gt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        if is_lexically_ordered(self.dtype()) && is_lexically_ordered(rhs.dtype()) {
+            return compare_lexically(self, rhs, Ordering::is_gt);
+        }
         impl_compare!(self, rhs, gt, raise_struct)
     }
 
     /// Create a boolean mask by checking if self >= rhs.
     fn gt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        if is_lexically_ordered(self.dtype()) && is_lexically_ordered(rhs.dtype()) {
+            return compare_lexically(self, rhs, Ordering::is_ge);
+        }
         impl_compare!(self, rhs, gt_eq, raise_struct)
     }
 
     /// Create a boolean mask by checking if self < rhs.
     fn lt(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        if is_lexically_ordered(self.dtype()) && is_lexically_ordered(rhs.dtype()) {
+            return compare_lexically(self, rhs, Ordering::is_lt);
+        }
         impl_compare!(self, rhs, lt, raise_struct)
     }
 
     /// Create a boolean mask by checking if self <= rhs.
     fn lt_eq(&self, rhs: &Series) -> PolarsResult<BooleanChunked> {
+        if is_lexically_ordered(self.dtype()) && is_lexically_ordered(rhs.dtype()) {
+            return compare_lexically(self, rhs, Ordering::is_le);
+        }
         impl_compare!(self, rhs, lt_eq, raise_struct)
     }
 }
@@ -237,9 +237,12 @@ impl Series {
                 let canonical_tz = DataType::canonical_timezone(tz);
                 let tz = match canonical_tz.as_deref() {
                     #[cfg(feature = "timezones")]
-                    Some(tz_str) => match validate_time_zone(tz_str) {
-                        Ok(_) => canonical_tz,
-                        Err(_) => Some(parse_fixed_offset(tz_str)?),
+                    Some(tz_str) => match parse_fixed_offset(tz_str) {
+                        Ok(etc_tz) => Some(etc_tz),
+                        Err(_) => {
+                            validate_time_zone(tz_str)?;
+                            canonical_tz
+                        },
                     },
                     _ => canonical_tz,
                 };
@@ -183,7 +183,7 @@ impl SeriesTrait for SeriesWrap<CategoricalChunked> {
 
     fn filter(&self, filter: &BooleanChunked) -> PolarsResult<Series> {
         self.try_with_state(false, |cats| cats.filter(filter))
-            .map(|ca| ca.into_series())
+            .map(|ca| ca.compact_if_sparse().into_series())
     }
 
     fn take(&self, indices: &IdxCa) -> PolarsResult<Series> {
@@ -2,6 +2,8 @@ use super::*;
 use crate::chunked_array::comparison::*;
 #[cfg(feature = "algorithm_group_by")]
 use crate::frame::group_by::*;
+use crate::prelude::sort::arg_sort_multiple::_min_max_reduce_row_encoded;
+use crate::prelude::sort::rank_nested_list_values;
 use crate::prelude::*;
 
 impl private::PrivateSeries for SeriesWrap<ListChunked> {
@@ -93,6 +95,14 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         );
     }
 
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        _min_max_reduce_row_encoded(&self.0.clone().into_series(), true)
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        _min_max_reduce_row_encoded(&self.0.clone().into_series(), false)
+    }
+
     fn slice(&self, offset: i64, length: usize) -> Series {
         self.0.slice(offset, length).into_series()
     }
@@ -167,9 +177,6 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     fn unique(&self) -> PolarsResult<Series> {
-        if !self.inner_dtype().is_numeric() {
-            polars_bail!(opq = unique, self.dtype());
-        }
         // this can be called in aggregation, so this fast path can be worth a lot
         if self.len() < 2 {
             return Ok(self.0.clone().into_series());
@@ -183,9 +190,6 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     fn n_unique(&self) -> PolarsResult<usize> {
-        if !self.inner_dtype().is_numeric() {
-            polars_bail!(opq = n_unique, self.dtype());
-        }
         // this can be called in aggregation, so this fast path can be worth a lot
         match self.len() {
             0 => Ok(0),
@@ -200,9 +204,6 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
 
     #[cfg(feature = "algorithm_group_by")]
     fn arg_unique(&self) -> PolarsResult<IdxCa> {
-        if !self.inner_dtype().is_numeric() {
-            polars_bail!(opq = arg_unique, self.dtype());
-        }
         // this can be called in aggregation, so this fast path can be worth a lot
         if self.len() == 1 {
             return Ok(IdxCa::new_vec(self.name().clone(), vec![0 as IdxSize]));
@@ -214,6 +215,18 @@ impl SeriesTrait for SeriesWrap<ListChunked> {
         Ok(IdxCa::from_vec(self.name().clone(), first))
     }
 
+    fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
+        let idx = rank_nested_list_values(&self.0)?
+            .get_row_encoded(options)?
+            .arg_sort(Default::default());
+        // SAFETY: the sort indices are in bounds.
+        Ok(unsafe { self.0.take_unchecked(&idx) }.into_series())
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        ChunkSort::arg_sort(&self.0, options)
+    }
+
     fn is_null(&self) -> BooleanChunked {
         self.0.is_null()
     }
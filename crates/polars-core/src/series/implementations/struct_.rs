@@ -4,6 +4,7 @@ use arrow::bitmap::Bitmap;
 
 use super::*;
 use crate::chunked_array::StructChunked;
+use crate::prelude::sort::arg_sort_multiple::_min_max_reduce_row_encoded;
 use crate::prelude::*;
 use crate::series::private::{PrivateSeries, PrivateSeriesNumeric};
 
@@ -247,6 +248,14 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
         &self.0
     }
 
+    fn max_reduce(&self) -> PolarsResult<Scalar> {
+        _min_max_reduce_row_encoded(&self.0.clone().into_series(), true)
+    }
+
+    fn min_reduce(&self) -> PolarsResult<Scalar> {
+        _min_max_reduce_row_encoded(&self.0.clone().into_series(), false)
+    }
+
     fn sort_with(&self, options: SortOptions) -> PolarsResult<Series> {
         Ok(self.0.sort_with(options).into_series())
     }
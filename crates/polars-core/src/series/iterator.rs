@@ -1,3 +1,12 @@
+#[cfg(feature = "dtype-duration")]
+use chrono::Duration as ChronoDuration;
+#[cfg(feature = "dtype-date")]
+use chrono::NaiveDate;
+#[cfg(feature = "dtype-datetime")]
+use chrono::NaiveDateTime;
+#[cfg(feature = "dtype-time")]
+use chrono::NaiveTime;
+
 use crate::prelude::any_value::arr_to_any_value;
 use crate::prelude::*;
 use crate::utils::NoNull;
@@ -71,6 +80,110 @@ impl FromIterator<String> for Series {
     }
 }
 
+impl Series {
+    /// Append the values of `iter`, collected into a [`Series`] the same way as
+    /// [`FromIterator`] does, e.g. `Vec<i32>` values into a `List(Int32)` column.
+    ///
+    /// An empty [`Series`] of the `Null` data type takes the data type of the values. Otherwise
+    /// it fails if the data type of the values is not the data type of this [`Series`].
+    pub fn try_extend<T, I: IntoIterator<Item = T>>(&mut self, iter: I) -> PolarsResult<()>
+    where
+        Series: FromIterator<T>,
+    {
+        let other = iter.into_iter().collect::<Series>();
+        if self.is_empty() && self.dtype() == &DataType::Null {
+            *self = other.with_name(self.name().clone());
+            return Ok(());
+        }
+        polars_ensure!(
+            self.dtype() == other.dtype(),
+            SchemaMismatch: "cannot extend a Series of dtype {} with values of dtype {}",
+            self.dtype(), other.dtype()
+        );
+        self.extend(&other)?;
+        Ok(())
+    }
+}
+
+impl<T> FromIterator<Option<Vec<T>>> for Series
+where
+    T: NumericNative,
+    T::PolarsType: PolarsNumericType<Native = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Option<Vec<T>>>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+        let mut builder = ListPrimitiveChunkedBuilder::<T::PolarsType>::new(
+            PlSmallStr::EMPTY,
+            iter.size_hint().0,
+            iter.size_hint().0 * 5,
+            T::PolarsType::get_dtype(),
+        );
+        for opt_v in iter {
+            builder.append_opt_slice(opt_v.as_deref());
+        }
+        builder.finish().into_series()
+    }
+}
+
+impl<T> FromIterator<Vec<T>> for Series
+where
+    T: NumericNative,
+    T::PolarsType: PolarsNumericType<Native = T>,
+{
+    fn from_iter<I: IntoIterator<Item = Vec<T>>>(iter: I) -> Self {
+        iter.into_iter().map(Some).collect()
+    }
+}
+
+#[cfg(any(
+    feature = "dtype-date",
+    feature = "dtype-datetime",
+    feature = "dtype-time",
+    feature = "dtype-duration"
+))]
+macro_rules! from_iterator_temporal {
+    ($native:ty, |$v:ident| $from_values:expr, |$opt:ident| $from_options:expr) => {
+        impl FromIterator<$native> for Series {
+            fn from_iter<I: IntoIterator<Item = $native>>(iter: I) -> Self {
+                let $v = iter;
+                $from_values.into_series()
+            }
+        }
+
+        impl FromIterator<Option<$native>> for Series {
+            fn from_iter<I: IntoIterator<Item = Option<$native>>>(iter: I) -> Self {
+                let $opt = iter;
+                $from_options.into_series()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "dtype-date")]
+from_iterator_temporal!(
+    NaiveDate,
+    |v| DateChunked::from_naive_date(PlSmallStr::EMPTY, v),
+    |v| DateChunked::from_naive_date_options(PlSmallStr::EMPTY, v)
+);
+#[cfg(feature = "dtype-datetime")]
+from_iterator_temporal!(
+    NaiveDateTime,
+    |v| DatetimeChunked::from_naive_datetime(PlSmallStr::EMPTY, v, TimeUnit::Milliseconds),
+    |v| DatetimeChunked::from_naive_datetime_options(PlSmallStr::EMPTY, v, TimeUnit::Milliseconds)
+);
+#[cfg(feature = "dtype-time")]
+from_iterator_temporal!(
+    NaiveTime,
+    |v| TimeChunked::from_naive_time(PlSmallStr::EMPTY, v),
+    |v| TimeChunked::from_naive_time_options(PlSmallStr::EMPTY, v)
+);
+#[cfg(feature = "dtype-duration")]
+from_iterator_temporal!(
+    ChronoDuration,
+    |v| DurationChunked::from_duration(PlSmallStr::EMPTY, v, TimeUnit::Nanoseconds),
+    |v| DurationChunked::from_duration_options(PlSmallStr::EMPTY, v, TimeUnit::Nanoseconds)
+);
+
 pub type SeriesPhysIter<'a> = Box<dyn ExactSizeIterator<Item = AnyValue<'a>> + 'a>;
 
 impl Series {
@@ -223,4 +336,43 @@ mod test {
         let b = Series::new("".into(), data);
         assert_eq!(a, b);
     }
+
+    #[test]
+    fn test_collect_list() -> PolarsResult<()> {
+        let data = vec![Some(vec![1i32, 2]), None, Some(vec![])];
+        let mut s: Series = data.clone().into_iter().collect();
+        assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Int32)));
+        assert_eq!(s.null_count(), 1);
+
+        s.try_extend(data)?;
+        assert_eq!(s.len(), 6);
+        assert!(s.try_extend([vec![1.0f64]]).is_err());
+        assert_eq!(s.len(), 6);
+
+        // An empty Series keeps its data type.
+        let mut s = Series::new_empty("".into(), &DataType::List(Box::new(DataType::Int64)));
+        assert!(s.try_extend([vec![1i32]]).is_err());
+        s.try_extend([vec![1i64]])?;
+        assert_eq!(s.dtype(), &DataType::List(Box::new(DataType::Int64)));
+
+        let empty: Series = std::iter::empty::<Vec<f64>>().collect();
+        assert_eq!(empty.dtype(), &DataType::List(Box::new(DataType::Float64)));
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "dtype-date")]
+    fn test_collect_date() -> PolarsResult<()> {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let s: Series = [Some(date), None].into_iter().collect();
+        assert_eq!(s.dtype(), &DataType::Date);
+        assert_eq!(s.get(0).unwrap(), AnyValue::Date(19723));
+
+        let mut s = Series::new_empty("".into(), &DataType::Null);
+        s.try_extend([date, date])?;
+        assert_eq!(s.dtype(), &DataType::Date);
+        assert_eq!(s.len(), 2);
+        assert!(s.try_extend([1i32]).is_err());
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use crate::{HEAD_DEFAULT_LENGTH, TAIL_DEFAULT_LENGTH};
 pub mod amortized_iter;
 mod any_value;
 pub mod arithmetic;
+pub mod cast_registry;
 mod comparison;
 mod from;
 pub mod implementations;
@@ -29,7 +30,7 @@ use crate::chunked_array::cast::CastOptions;
 use crate::chunked_array::metadata::{IMMetadata, Metadata, MetadataFlags};
 #[cfg(feature = "zip_with")]
 use crate::series::arithmetic::coerce_lhs_rhs;
-use crate::utils::{handle_casting_failures, materialize_dyn_int, Wrap};
+use crate::utils::{handle_casting_failures, materialize_dyn_int, CastDiagnostics, Wrap};
 use crate::POOL;
 
 /// # Series
@@ -447,7 +448,13 @@ impl Series {
             opt => opt,
         };
 
-        let ret = self.0.cast(dtype, new_options);
+        let ret = match self.0.cast(dtype, new_options) {
+            Err(err) => match cast_registry::get_cast_function(self.dtype(), dtype) {
+                Some(function) => return function(self, dtype, options),
+                None => Err(err),
+            },
+            ret => ret,
+        };
 
         match options {
             CastOptions::NonStrict | CastOptions::Overflowing => ret,
@@ -711,6 +718,18 @@ impl Series {
         self.cast_with_options(dtype, CastOptions::Strict)
     }
 
+    /// Cast that neither raises nor silently nulls out values that can't be converted, but
+    /// reports them.
+    ///
+    /// Returns the (non-strict) cast output together with a diagnostics frame that holds the
+    /// row index and value of every failed or lossy conversion. See [`CastDiagnostics`].
+    pub fn cast_with_diagnostics(&self, dtype: &DataType) -> PolarsResult<(Series, DataFrame)> {
+        let out = self.cast_with_options(dtype, CastOptions::NonStrict)?;
+        let mut diagnostics = CastDiagnostics::default();
+        diagnostics.push(self, &out)?;
+        Ok((out, diagnostics.finish()))
+    }
+
     #[cfg(feature = "dtype-time")]
     pub(crate) fn into_time(self) -> Series {
         match self.dtype() {
@@ -836,6 +855,44 @@ impl Series {
         }
     }
 
+    /// Get the underlying [`ChunkedArray`] of type `T`.
+    ///
+    /// Unlike [`unpack`](SeriesTrait::unpack), the error names the series and both data
+    /// types. Logical types have to be accessed through [`Series::to_physical_repr`].
+    pub fn try_as<T: PolarsDataType + 'static>(&self) -> PolarsResult<&ChunkedArray<T>> {
+        polars_ensure!(
+            &T::get_dtype() == self.dtype(),
+            SchemaMismatch: "cannot access series '{}' of dtype {} as {}",
+            self.name(), self.dtype(), T::get_dtype()
+        );
+        Ok(self.as_ref().as_ref())
+    }
+
+    /// Iterate over the values as `T`, with `None` for the null values.
+    ///
+    /// Fails if the data type of the series is not `T`.
+    pub fn iter_typed<T: PolarsDataType + 'static>(
+        &self,
+    ) -> PolarsResult<impl PolarsIterator<Item = Option<T::Physical<'_>>>> {
+        Ok(self.try_as::<T>()?.iter())
+    }
+
+    /// Collect the values as `T` into a [`Vec`], with `None` for the null values.
+    ///
+    /// Fails if the data type of the series is not `T`.
+    pub fn to_vec_options<T: PolarsDataType + 'static>(
+        &self,
+    ) -> PolarsResult<Vec<Option<T::Physical<'_>>>> {
+        Ok(self.try_as::<T>()?.to_vec_options())
+    }
+
+    /// Returns the values as a slice of `T`, see [`ChunkedArray::as_slice_nonnull`].
+    ///
+    /// Fails if the data type of the series is not `T` or if it contains null values.
+    pub fn as_slice_nonnull<T: PolarsNumericType>(&self) -> PolarsResult<Cow<'_, [T::Native]>> {
+        self.try_as::<T>()?.as_slice_nonnull()
+    }
+
     /// Returns an estimation of the total (heap) allocated size of the `Series` in bytes.
     ///
     /// # Implementation
@@ -968,6 +1025,45 @@ mod test {
         assert!(s2.f32().is_ok());
     }
 
+    #[test]
+    fn cast_with_diagnostics() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(1.0f64), Some(1.5), None, Some(1e20)]);
+        let (out, diagnostics) = s.cast_with_diagnostics(&DataType::Int32)?;
+        assert_eq!(out.null_count(), 2);
+
+        let row_index = diagnostics.column("row_index")?.idx()?;
+        assert_eq!(row_index.into_no_null_iter().collect::<Vec<_>>(), &[3, 1]);
+        let kind = diagnostics.column("kind")?.str()?;
+        assert_eq!(
+            kind.into_no_null_iter().collect::<Vec<_>>(),
+            &["failed", "lossy"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn typed_accessors() -> PolarsResult<()> {
+        let mut s = Series::new("a".into(), &[1i32, 2]);
+        assert!(matches!(
+            s.as_slice_nonnull::<Int32Type>()?,
+            Cow::Borrowed(&[1, 2])
+        ));
+        s.append(&Series::new("a".into(), &[3i32]))?;
+        assert!(matches!(s.as_slice_nonnull::<Int32Type>()?, Cow::Owned(_)));
+        assert_eq!(s.as_slice_nonnull::<Int32Type>()?.as_ref(), &[1, 2, 3]);
+
+        let err = s.as_slice_nonnull::<Int64Type>().unwrap_err();
+        assert!(err.to_string().contains("of dtype i32 as i64"));
+
+        let s = Series::new("a".into(), &[Some("x"), None]);
+        assert_eq!(s.to_vec_options::<StringType>()?, [Some("x"), None]);
+        assert_eq!(s.iter_typed::<StringType>()?.len(), 2);
+
+        let s = Series::new("a".into(), &[Some(1.0f64), None]);
+        assert!(s.as_slice_nonnull::<Float64Type>().is_err());
+        Ok(())
+    }
+
     #[test]
     fn new_series() {
         let _ = Series::new("boolean series".into(), &vec![true, false, true]);
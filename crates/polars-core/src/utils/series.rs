@@ -44,3 +44,78 @@ pub fn handle_casting_failures(input: &Series, output: &Series) -> PolarsResult<
         additional_info,
     )
 }
+
+/// Collects the values that could not be converted losslessly by a cast.
+///
+/// A value failed if it was valid in the input but is null in the output. For numeric casts a
+/// value is lossy if casting it back doesn't give the original value (e.g. truncated floats).
+#[derive(Default)]
+pub struct CastDiagnostics {
+    columns: Vec<PlSmallStr>,
+    row_index: Vec<IdxSize>,
+    values: Vec<String>,
+    kinds: Vec<&'static str>,
+    dtypes: Vec<String>,
+}
+
+impl CastDiagnostics {
+    /// Record the failed and lossy conversions of casting `input` into `output`.
+    pub fn push(&mut self, input: &Series, output: &Series) -> PolarsResult<()> {
+        polars_ensure!(
+            input.len() == output.len(),
+            ShapeMismatch: "cast output has length {}, expected {}", output.len(), input.len()
+        );
+        let failed = !input.is_null() & output.is_null();
+        let lossy = if input.dtype().is_numeric()
+            && output.dtype().is_numeric()
+            && input.dtype() != output.dtype()
+        {
+            let back = output.cast(input.dtype())?;
+            let mut changed = input.not_equal_missing(&back)? & !failed.clone();
+            if input.dtype().is_float() {
+                changed = changed & !(input.is_nan()? & back.is_nan()?);
+            }
+            Some(changed)
+        } else {
+            None
+        };
+
+        let mut push_rows = |mask: &BooleanChunked, kind: &'static str| -> PolarsResult<()> {
+            for (idx, _) in mask
+                .into_iter()
+                .enumerate()
+                .filter(|(_, m)| *m == Some(true))
+            {
+                self.columns.push(input.name().clone());
+                self.row_index.push(idx as IdxSize);
+                self.values.push(input.str_value(idx)?.into_owned());
+                self.kinds.push(kind);
+                self.dtypes.push(output.dtype().to_string());
+            }
+            Ok(())
+        };
+        push_rows(&failed, "failed")?;
+        if let Some(lossy) = lossy {
+            push_rows(&lossy, "lossy")?;
+        }
+        Ok(())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.row_index.is_empty()
+    }
+
+    /// Creates the diagnostics frame with the columns `column`, `row_index`, `value`, `kind`
+    /// and `dtype`.
+    pub fn finish(self) -> DataFrame {
+        let columns = self.columns.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+        let columns = vec![
+            Column::new(PlSmallStr::from_static("column"), columns),
+            Column::new(PlSmallStr::from_static("row_index"), self.row_index),
+            Column::new(PlSmallStr::from_static("value"), self.values),
+            Column::new(PlSmallStr::from_static("kind"), self.kinds),
+            Column::new(PlSmallStr::from_static("dtype"), self.dtypes),
+        ];
+        unsafe { DataFrame::new_no_checks(columns) }
+    }
+}
@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use polars_core::prelude::*;
 use polars_core::POOL;
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 use polars_io::predicates::{BatchStats, StatsEvaluator};
 #[cfg(feature = "is_between")]
 use polars_ops::prelude::ClosedInterval;
@@ -442,7 +442,7 @@ impl PhysicalExpr for ApplyExpr {
     fn to_field(&self, input_schema: &Schema) -> PolarsResult<Field> {
         self.expr.to_field(input_schema, Context::Default)
     }
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         let function = match &self.expr {
             Expr::Function { function, .. } => function,
@@ -539,7 +539,7 @@ fn apply_multiple_elementwise<'a>(
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl StatsEvaluator for ApplyExpr {
     fn should_read(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let read = self.should_read_impl(stats)?;
@@ -555,7 +555,7 @@ impl StatsEvaluator for ApplyExpr {
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 impl ApplyExpr {
     fn should_read_impl(&self, stats: &BatchStats) -> PolarsResult<bool> {
         let (function, input) = match &self.expr {
@@ -66,6 +66,13 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::Multiply => left * right,
         Operator::Divide => left / right,
         Operator::TrueDivide => match left.dtype() {
+            dt if dt.is_numeric() && right.dtype().is_list() => {
+                left.cast(&Float64)? / right.cast(&right.dtype().cast_leaf(Float64))?
+            },
+            #[cfg(feature = "dtype-array")]
+            dt if dt.is_numeric() && right.dtype().is_array() => {
+                left.cast(&Float64)? / right.cast(&right.dtype().cast_leaf(Float64))?
+            },
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => left / right,
             Duration(_) | Date | Datetime(_, _) | Float32 | Float64 => left / right,
@@ -270,13 +277,13 @@ impl PhysicalExpr for BinaryExpr {
         Some(self)
     }
 
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         Some(self)
     }
 }
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "ipc"))]
 mod stats {
     use polars_io::predicates::{BatchStats, StatsEvaluator};
 
@@ -409,6 +416,8 @@ mod stats {
                     (DataType::String, DataType::Categorical(_, _) | DataType::Enum(_, _)) => {},
                     #[cfg(feature = "dtype-categorical")]
                     (DataType::Categorical(_, _) | DataType::Enum(_, _), DataType::String) => {},
+                    // Dynamic literals are not coerced to the type of the column.
+                    (DataType::Unknown(_), _) | (_, DataType::Unknown(_)) => {},
                     (l, r) if l != r => panic!("implementation error: {l:?}, {r:?}"),
                     _ => {},
                 }
@@ -620,10 +620,44 @@ impl PhysicalIoExpr for PhysicalIoHelper {
         Some(expr_to_leaf_column_names(self.expr.as_expression()?))
     }
 
-    #[cfg(feature = "parquet")]
+    #[cfg(any(feature = "parquet", feature = "ipc"))]
     fn as_stats_evaluator(&self) -> Option<&dyn polars_io::predicates::StatsEvaluator> {
         self.expr.as_stats_evaluator()
     }
+
+    fn as_dictionary_predicate(&self) -> Option<polars_io::predicates::DictionaryPredicate> {
+        let (column, values) = match self.expr.as_expression()? {
+            Expr::BinaryExpr {
+                left,
+                op: Operator::Eq,
+                right,
+            } => match (left.as_ref(), right.as_ref()) {
+                (Expr::Column(column), Expr::Literal(LiteralValue::String(value)))
+                | (Expr::Literal(LiteralValue::String(value)), Expr::Column(column)) => (
+                    column.clone(),
+                    Series::new(PlSmallStr::EMPTY, [value.as_str()]),
+                ),
+                _ => return None,
+            },
+            #[cfg(feature = "is_in")]
+            Expr::Function {
+                input,
+                function: FunctionExpr::Boolean(BooleanFunction::IsIn),
+                ..
+            } => match input.as_slice() {
+                [Expr::Column(column), Expr::Literal(LiteralValue::Series(values))]
+                    if values.dtype() == &DataType::String =>
+                {
+                    (column.clone(), Series::clone(values))
+                },
+                _ => return None,
+            },
+            _ => return None,
+        };
+        // Null semantics differ between `==` and `is_in`, only handle non-null values.
+        (values.null_count() == 0)
+            .then_some(polars_io::predicates::DictionaryPredicate { column, values })
+    }
 }
 
 pub fn phys_expr_to_io_expr(expr: Arc<dyn PhysicalExpr>) -> Arc<dyn PhysicalIoExpr> {
@@ -6,9 +6,12 @@ use bitflags::bitflags;
 use once_cell::sync::OnceCell;
 use polars_core::config::verbose;
 use polars_core::prelude::*;
+use polars_io::predicates::BatchStats;
 use polars_ops::prelude::ChunkJoinOptIds;
+use polars_plan::dsl::Expr;
 
-use super::NodeTimer;
+use super::{ExprTimer, NodeTimer};
+use crate::expressions::PhysicalExpr;
 
 pub type JoinTuplesCache = Arc<Mutex<PlHashMap<String, ChunkJoinOptIds>>>;
 pub type GroupsProxyCache = Arc<RwLock<PlHashMap<String, GroupsProxy>>>;
@@ -55,12 +58,20 @@ impl From<u8> for StateFlags {
     }
 }
 
-type CachedValue = Arc<(AtomicI64, OnceCell<DataFrame>)>;
+/// The number of remaining cache hits, the cached frame and the statistics of its chunks. The
+/// statistics are computed by the first consumer that filters the frame.
+type CachedValue = Arc<(AtomicI64, OnceCell<DataFrame>, OnceCell<Vec<BatchStats>>)>;
+
+/// The masks of the filters on cached frames, keyed by the cache id and the structure of the
+/// predicate.
+type FilterMaskCache = Arc<Mutex<PlHashMap<(usize, Expr), Arc<OnceCell<BooleanChunked>>>>>;
 
 /// State/ cache that is maintained during the Execution of the physical plan.
 pub struct ExecutionState {
     // cached by a `.cache` call and kept in memory for the duration of the plan.
     df_cache: Arc<Mutex<PlHashMap<usize, CachedValue>>>,
+    // shared between the branches, so that identical filters on a cached frame are evaluated once.
+    filter_masks: FilterMaskCache,
     pub schema_cache: RwLock<Option<SchemaRef>>,
     /// Used by Window Expression to prevent redundant grouping
     pub group_tuples: GroupsProxyCache,
@@ -71,6 +82,7 @@ pub struct ExecutionState {
     pub flags: AtomicU8,
     pub ext_contexts: Arc<Vec<DataFrame>>,
     node_timer: Option<NodeTimer>,
+    expr_timer: Option<ExprTimer>,
     stop: Arc<AtomicBool>,
 }
 
@@ -82,6 +94,7 @@ impl ExecutionState {
         }
         Self {
             df_cache: Default::default(),
+            filter_masks: Default::default(),
             schema_cache: Default::default(),
             group_tuples: Default::default(),
             join_tuples: Default::default(),
@@ -89,6 +102,7 @@ impl ExecutionState {
             flags: AtomicU8::new(StateFlags::init().as_u8()),
             ext_contexts: Default::default(),
             node_timer: None,
+            expr_timer: None,
             stop: Arc::new(AtomicBool::new(false)),
         }
     }
@@ -105,6 +119,22 @@ impl ExecutionState {
         self.node_timer.unwrap().finish()
     }
 
+    /// Toggle this to measure the execution times of the individual expressions
+    /// evaluated by projection and filter nodes.
+    pub fn time_expressions(&mut self) {
+        self.expr_timer = Some(ExprTimer::new())
+    }
+    pub fn has_expr_timer(&self) -> bool {
+        self.expr_timer.is_some()
+    }
+
+    /// Take the expression timings recorded so far, ranked by total time spent.
+    pub fn finish_expr_timer(&self) -> PolarsResult<DataFrame> {
+        let timer = self.expr_timer.as_ref();
+        polars_ensure!(timer.is_some(), ComputeError: "expression timer not enabled");
+        timer.unwrap().finish()
+    }
+
     // This is wrong when the U64 overflows which will never happen.
     pub fn should_stop(&self) -> PolarsResult<()> {
         polars_ensure!(!self.stop.load(Ordering::Relaxed), ComputeError: "query interrupted");
@@ -129,11 +159,30 @@ impl ExecutionState {
         }
     }
 
+    /// Evaluate `func` and attribute its duration to `expr` within `node`.
+    pub fn record_expr<T, F: FnOnce() -> T>(
+        &self,
+        func: F,
+        node: &'static str,
+        expr: &dyn PhysicalExpr,
+    ) -> T {
+        match &self.expr_timer {
+            None => func(),
+            Some(timer) => {
+                let start = std::time::Instant::now();
+                let out = func();
+                timer.store(node, format!("{}", &expr), start.elapsed());
+                out
+            },
+        }
+    }
+
     /// Partially clones and partially clears state
     /// This should be used when splitting a node, like a join or union
     pub fn split(&self) -> Self {
         Self {
             df_cache: self.df_cache.clone(),
+            filter_masks: self.filter_masks.clone(),
             schema_cache: Default::default(),
             group_tuples: Default::default(),
             join_tuples: Default::default(),
@@ -141,6 +190,7 @@ impl ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            expr_timer: self.expr_timer.clone(),
             stop: self.stop.clone(),
         }
     }
@@ -166,7 +216,13 @@ impl ExecutionState {
         let mut guard = self.df_cache.lock().unwrap();
         guard
             .entry(key)
-            .or_insert_with(|| Arc::new((AtomicI64::new(cache_hits as i64), OnceCell::new())))
+            .or_insert_with(|| {
+                Arc::new((
+                    AtomicI64::new(cache_hits as i64),
+                    OnceCell::new(),
+                    OnceCell::new(),
+                ))
+            })
             .clone()
     }
 
@@ -175,6 +231,17 @@ impl ExecutionState {
         let _ = guard.remove(&key).unwrap();
     }
 
+    /// Get the mask of the filter `predicate` on the frame cached under `cache_id`, which is
+    /// computed by the first filter that requests it.
+    pub fn get_filter_mask(
+        &self,
+        cache_id: usize,
+        predicate: Expr,
+    ) -> Arc<OnceCell<BooleanChunked>> {
+        let mut guard = self.filter_masks.lock().unwrap();
+        guard.entry((cache_id, predicate)).or_default().clone()
+    }
+
     /// Clear the cache used by the Window expressions
     pub fn clear_window_expr_cache(&self) {
         {
@@ -242,6 +309,7 @@ impl Clone for ExecutionState {
     fn clone(&self) -> Self {
         Self {
             df_cache: self.df_cache.clone(),
+            filter_masks: self.filter_masks.clone(),
             schema_cache: self.schema_cache.read().unwrap().clone().into(),
             group_tuples: self.group_tuples.clone(),
             join_tuples: self.join_tuples.clone(),
@@ -249,6 +317,7 @@ impl Clone for ExecutionState {
             flags: AtomicU8::new(self.flags.load(Ordering::Relaxed)),
             ext_contexts: self.ext_contexts.clone(),
             node_timer: self.node_timer.clone(),
+            expr_timer: self.expr_timer.clone(),
             stop: self.stop.clone(),
         }
     }
@@ -0,0 +1,64 @@
+use std::sync::Mutex;
+use std::time::Duration;
+
+use polars_core::prelude::*;
+use polars_core::utils::NoNull;
+
+/// (node, expression) -> (number of evaluations, total time spent)
+type Timings = PlIndexMap<(&'static str, String), (u64, Duration)>;
+
+/// Attributes time to the individual expressions that are evaluated by a node.
+///
+/// Where the [`NodeTimer`](super::NodeTimer) only knows that a `select` took a long time,
+/// this records which of its expressions (including UDFs) was responsible.
+#[derive(Clone)]
+pub(super) struct ExprTimer {
+    data: Arc<Mutex<Timings>>,
+}
+
+impl ExprTimer {
+    pub(super) fn new() -> Self {
+        Self {
+            data: Arc::new(Mutex::new(Default::default())),
+        }
+    }
+
+    pub(super) fn store(&self, node: &'static str, expr: String, elapsed: Duration) {
+        let mut data = self.data.lock().unwrap();
+        let entry = data.entry((node, expr)).or_insert((0, Duration::ZERO));
+        entry.0 += 1;
+        entry.1 += elapsed;
+    }
+
+    /// Creates the report, ranked by total time spent (heaviest expression first).
+    pub(super) fn finish(&self) -> PolarsResult<DataFrame> {
+        let data = std::mem::take(&mut *self.data.lock().unwrap());
+
+        let mut nodes = Vec::with_capacity(data.len());
+        let mut exprs = Vec::with_capacity(data.len());
+        let calls: NoNull<UInt64Chunked> = data.values().map(|v| v.0).collect();
+        let mut calls = calls.into_inner();
+        calls.rename(PlSmallStr::from_static("calls"));
+        let total: NoNull<UInt64Chunked> = data.values().map(|v| v.1.as_micros() as u64).collect();
+        let mut total = total.into_inner();
+        total.rename(PlSmallStr::from_static("total_time"));
+        for (node, expr) in data.into_keys() {
+            nodes.push(node);
+            exprs.push(expr);
+        }
+
+        let columns = vec![
+            Column::new(PlSmallStr::from_static("node"), nodes),
+            Column::new(PlSmallStr::from_static("expression"), exprs),
+            calls.into_column(),
+            total.into_column(),
+        ];
+        let df = unsafe { DataFrame::new_no_checks(columns) };
+        df.sort(
+            vec!["total_time"],
+            SortMultipleOptions::default()
+                .with_order_descending(true)
+                .with_maintain_order(true),
+        )
+    }
+}
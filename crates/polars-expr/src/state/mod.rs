@@ -1,5 +1,7 @@
 mod execution_state;
+mod expr_timer;
 mod node_timer;
 
 pub use execution_state::*;
+use expr_timer::*;
 use node_timer::*;
@@ -0,0 +1,143 @@
+//! Version negotiation and capability discovery between the Polars engine and plugins.
+//!
+//! After loading a plugin the engine checks the version returned by
+//! `_polars_plugin_get_version` against the versions it supports and refuses to load
+//! plugins it cannot call. If the plugin exports
+//! `_polars_plugin_set_host_info(*const HostInfo)`, the engine then calls it with the ABI
+//! version and the capabilities of the engine, so that the plugin can degrade gracefully
+//! on engines that are older than the plugin.
+use std::sync::OnceLock;
+
+use crate::{MAJOR, MINOR};
+
+/// Plugins that are elementwise may be called on batches by the streaming engine.
+pub const CAPABILITY_STREAMING: u64 = 1 << 0;
+/// Plugins may be called once per group in a group-by context.
+pub const CAPABILITY_GROUP_AWARE: u64 = 1 << 1;
+/// Plugin calls receive a [`CallerContext`](crate::version_0::CallerContext).
+pub const CAPABILITY_CALLER_CONTEXT: u64 = 1 << 2;
+/// Plugins may export a batch entry point and state that is kept across calls, see
+/// [`BatchFunction`](crate::version_0::BatchFunction).
+pub const CAPABILITY_BATCH: u64 = 1 << 3;
+
+/// Information about the engine that loaded a plugin.
+///
+/// Fields are only ever appended, `size` holds the size of the struct the engine was
+/// compiled with.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(C)]
+pub struct HostInfo {
+    pub size: usize,
+    pub major: u16,
+    pub minor: u16,
+    pub capabilities: u64,
+}
+
+impl HostInfo {
+    /// The [`HostInfo`] of this engine.
+    pub const fn host() -> Self {
+        Self {
+            size: std::mem::size_of::<Self>(),
+            major: MAJOR,
+            minor: MINOR,
+            capabilities: CAPABILITY_STREAMING
+                | CAPABILITY_GROUP_AWARE
+                | CAPABILITY_CALLER_CONTEXT
+                | CAPABILITY_BATCH,
+        }
+    }
+
+    /// The highest ABI version the engine supports.
+    pub fn abi_version(&self) -> (u16, u16) {
+        (self.major, self.minor)
+    }
+
+    pub fn has_capability(&self, capability: u64) -> bool {
+        self.capabilities & capability == capability
+    }
+
+    pub fn supports_streaming(&self) -> bool {
+        self.has_capability(CAPABILITY_STREAMING)
+    }
+
+    pub fn supports_group_aware_calls(&self) -> bool {
+        self.has_capability(CAPABILITY_GROUP_AWARE)
+    }
+
+    pub fn supports_batch_calls(&self) -> bool {
+        self.has_capability(CAPABILITY_BATCH)
+    }
+
+    /// Read the [`HostInfo`] passed by an engine, which may have been compiled with a
+    /// different (smaller or larger) version of this struct. Fields the engine doesn't
+    /// know about are zeroed.
+    ///
+    /// # Safety
+    /// `ptr` must point to a valid [`HostInfo`] of at least `(*ptr).size` bytes.
+    pub unsafe fn from_ptr(ptr: *const HostInfo) -> Self {
+        let mut out = Self {
+            size: 0,
+            major: 0,
+            minor: 0,
+            capabilities: 0,
+        };
+        let size = std::ptr::read_unaligned(ptr as *const usize).min(std::mem::size_of::<Self>());
+        std::ptr::copy_nonoverlapping(ptr as *const u8, &mut out as *mut Self as *mut u8, size);
+        out
+    }
+}
+
+/// Whether this engine can call plugins that were compiled against ABI version
+/// `major.minor`.
+pub const fn is_supported_version(major: u16, minor: u16) -> bool {
+    major == MAJOR && minor <= MINOR
+}
+
+static HOST_INFO: OnceLock<HostInfo> = OnceLock::new();
+
+/// Store the [`HostInfo`] the engine passes to `_polars_plugin_set_host_info`.
+///
+/// Plugins call this from their implementation of that symbol.
+///
+/// # Safety
+/// See [`HostInfo::from_ptr`].
+pub unsafe fn set_host_info(ptr: *const HostInfo) {
+    let _ = HOST_INFO.set(HostInfo::from_ptr(ptr));
+}
+
+/// The [`HostInfo`] of the engine that loaded this plugin.
+///
+/// Returns `None` if the engine didn't perform the handshake, in which case it predates
+/// it and only the capabilities of ABI version 0.1 may be assumed.
+pub fn host_info() -> Option<HostInfo> {
+    HOST_INFO.get().copied()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_host_info() {
+        let host = HostInfo::host();
+        assert!(host.supports_streaming());
+        assert!(host.supports_group_aware_calls());
+        assert!(host.supports_batch_calls());
+        assert!(is_supported_version(MAJOR, 0));
+        assert!(!is_supported_version(MAJOR, MINOR + 1));
+        assert!(!is_supported_version(MAJOR + 1, 0));
+
+        assert_eq!(unsafe { HostInfo::from_ptr(&host) }, host);
+
+        // An engine that only knows about the version fields.
+        let mut old = host;
+        old.size = std::mem::offset_of!(HostInfo, capabilities);
+        let read = unsafe { HostInfo::from_ptr(&old) };
+        assert_eq!(read.abi_version(), host.abi_version());
+        assert_eq!(read.capabilities, 0);
+
+        assert_eq!(host_info(), None);
+        unsafe { set_host_info(&host) };
+        assert_eq!(host_info(), Some(host));
+    }
+}
@@ -1,3 +1,4 @@
+mod handshake;
 pub mod version_0;
 
 use std::mem::ManuallyDrop;
@@ -5,11 +6,12 @@ use std::mem::ManuallyDrop;
 use arrow::array::ArrayRef;
 use arrow::ffi;
 use arrow::ffi::{ArrowArray, ArrowSchema};
+pub use handshake::*;
 use polars_core::error::PolarsResult;
 use polars_core::prelude::{ArrowField, Series};
 
 pub const MAJOR: u16 = 0;
-pub const MINOR: u16 = 1;
+pub const MINOR: u16 = 2;
 
 pub const fn get_version() -> (u16, u16) {
     (MAJOR, MINOR)
@@ -113,6 +113,92 @@ pub unsafe fn import_series_buffer(e: *mut SeriesExport, len: usize) -> PolarsRe
     Ok(out)
 }
 
+/// The batch entry point `_polars_plugin_batch_{name}` of a plugin function.
+///
+/// Unlike `_polars_plugin_{name}`, which receives chunked [`SeriesExport`]s, this receives one
+/// array per input column through the Arrow C data interface, so that the plugin can process
+/// whole batches, including their validity, without depending on the layout of a `Series`:
+///
+/// - `arrays` and `schemas` hold `n_inputs` single-chunk arrays of the input columns. The
+///   inputs are split along their chunks and the function is called once per batch; inputs of
+///   length one are passed whole with every batch. The arrays are borrowed for the duration of
+///   the call, but the plugin may move them out by leaving released arrays behind, which
+///   [`import_batch`] does.
+/// - `kwargs` and `kwargs_len` hold the serialized keyword arguments.
+/// - `state` is the pointer returned by `_polars_plugin_state_new_{name}`, or null if the plugin
+///   doesn't export it, see [`StateNewFunction`]. The same state may be used by concurrent calls.
+/// - The result must be written to `out_array` and `out_schema`, e.g. by
+///   [`export_batch_output`]. If `out_schema` is left empty, the call is considered failed and
+///   `_polars_plugin_get_last_error_message` is consulted.
+pub type BatchFunction = unsafe extern "C" fn(
+    arrays: *mut ArrowArray,
+    schemas: *const ArrowSchema,
+    n_inputs: usize,
+    kwargs: *const u8,
+    kwargs_len: usize,
+    state: *mut std::os::raw::c_void,
+    out_array: *mut ArrowArray,
+    out_schema: *mut ArrowSchema,
+    context: *const CallerContext,
+);
+
+/// `_polars_plugin_state_new_{name}`, which creates the state of an expression.
+///
+/// The state is passed to every call of the [`BatchFunction`] of the expression. This is called
+/// with the serialized keyword arguments on the first call and returns null on failure.
+pub type StateNewFunction =
+    unsafe extern "C" fn(kwargs: *const u8, kwargs_len: usize) -> *mut std::os::raw::c_void;
+
+/// `_polars_plugin_state_drop_{name}`, which releases the state once the expression is dropped.
+pub type StateDropFunction = unsafe extern "C" fn(state: *mut std::os::raw::c_void);
+
+/// Export chunk `chunk_idx` of `s` as an input of a [`BatchFunction`].
+pub fn export_batch_array(s: &Series, chunk_idx: usize) -> (ArrowArray, ArrowSchema) {
+    let dtype = s.dtype().to_arrow(CompatLevel::newest());
+    let arr = if s.chunks().is_empty() {
+        arrow::array::new_empty_array(dtype.clone())
+    } else {
+        // Make sure we export the logical type.
+        s.to_arrow(chunk_idx, CompatLevel::newest())
+    };
+    let field = ArrowField::new(s.name().clone(), dtype, true);
+    (ffi::export_array_to_c(arr), ffi::export_field_to_c(&field))
+}
+
+/// Move the inputs of a [`BatchFunction`] into `Series`.
+///
+/// # Safety
+/// `arrays` and `schemas` must point to `len` valid arrays and schemas.
+pub unsafe fn import_batch(
+    arrays: *mut ArrowArray,
+    schemas: *const ArrowSchema,
+    len: usize,
+) -> PolarsResult<Vec<Series>> {
+    (0..len)
+        .map(|i| {
+            let array = std::mem::replace(&mut *arrays.add(i), ArrowArray::empty());
+            let schema = &*schemas.add(i);
+            let arr = import_array(array, schema)?;
+            let field = ffi::import_field_from_c(schema)?;
+            Series::try_from((field.name, vec![arr]))
+        })
+        .collect()
+}
+
+/// Write `s` as the output of a [`BatchFunction`].
+///
+/// # Safety
+/// `out_array` and `out_schema` must be valid for writes.
+pub unsafe fn export_batch_output(
+    s: &Series,
+    out_array: *mut ArrowArray,
+    out_schema: *mut ArrowSchema,
+) {
+    let (array, schema) = export_batch_array(&s.rechunk(), 0);
+    std::ptr::write(out_array, array);
+    std::ptr::write(out_schema, schema);
+}
+
 /// Passed to an expression.
 /// This contains information for the implementer of the expression on what it is allowed to do.
 #[derive(Copy, Clone, Debug, Default)]
@@ -159,4 +245,27 @@ mod test {
             assert_eq!(import_series(e).unwrap(), s);
         };
     }
+
+    #[test]
+    fn test_ffi_batch() {
+        let a = Series::new("a".into(), [Some(1), None, Some(3)]);
+        let mut b = Series::new("b".into(), ["x", "y"]);
+        b.append(&Series::new("b".into(), ["z"])).unwrap();
+
+        let (mut arrays, schemas): (Vec<_>, Vec<_>) = [(&a, 0), (&b, 1)]
+            .into_iter()
+            .map(|(s, i)| export_batch_array(s, i))
+            .unzip();
+        let inputs = unsafe { import_batch(arrays.as_mut_ptr(), schemas.as_ptr(), 2) }.unwrap();
+        assert!(inputs[0].equals_missing(&a));
+        assert!(inputs[1].equals_missing(&b.slice(2, 1)));
+
+        let mut out_array = ArrowArray::empty();
+        let mut out_schema = ffi::ArrowSchema::empty();
+        unsafe { export_batch_output(&b, &mut out_array, &mut out_schema) };
+        let field = unsafe { ffi::import_field_from_c(&out_schema) }.unwrap();
+        let arr = unsafe { import_array(out_array, &out_schema) }.unwrap();
+        let out = Series::try_from((field.name, vec![arr])).unwrap();
+        assert!(out.equals_missing(&b));
+    }
 }
@@ -152,6 +152,8 @@ pub struct Utf8Field {
     scratch: Vec<u8>,
     quote_char: u8,
     encoding: CsvEncoding,
+    /// Whether the bytes the fields are parsed from are known to be valid UTF-8.
+    utf8_validated: bool,
 }
 
 impl Utf8Field {
@@ -167,6 +169,7 @@ impl Utf8Field {
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
             encoding,
+            utf8_validated: false,
         }
     }
 }
@@ -215,7 +218,7 @@ impl ParsedBuffer for Utf8Field {
 
         // It is important that this happens after escaping, as invalid escaped string can produce
         // invalid utf8.
-        let parse_result = validate_utf8(escaped_bytes);
+        let parse_result = self.utf8_validated || validate_utf8(escaped_bytes);
 
         match parse_result {
             true => {
@@ -254,6 +257,8 @@ pub struct CategoricalField {
     escape_scratch: Vec<u8>,
     quote_char: u8,
     builder: CategoricalChunkedBuilder,
+    /// Whether the bytes the fields are parsed from are known to be valid UTF-8.
+    utf8_validated: bool,
 }
 
 #[cfg(feature = "dtype-categorical")]
@@ -270,6 +275,7 @@ impl CategoricalField {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
             builder,
+            utf8_validated: false,
         }
     }
 
@@ -287,7 +293,7 @@ impl CategoricalField {
             return Ok(());
         }
 
-        if validate_utf8(bytes) {
+        if self.utf8_validated || validate_utf8(bytes) {
             if needs_escaping {
                 polars_ensure!(bytes.len() > 1, ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", std::str::from_utf8(bytes).map_err(to_compute_err)?);
                 self.escape_scratch.clear();
@@ -485,6 +491,28 @@ where
     }
 }
 
+/// Validate the bytes that `buffers` are parsed from at once, instead of every string field on its
+/// own. Fields are split at the separator, quote and end-of-line bytes, which are character
+/// boundaries if they are ASCII, so every field of valid bytes is valid as well.
+///
+/// Returns whether the string buffers can skip the validation of their fields.
+pub(crate) fn validate_utf8_once(
+    buffers: &[Buffer],
+    bytes: &[u8],
+    separator: u8,
+    quote_char: Option<u8>,
+    eol_char: u8,
+) -> bool {
+    let has_strings = buffers
+        .iter()
+        .any(|buf| matches!(buf, Buffer::Utf8(_) | Buffer::Categorical(_)));
+    has_strings
+        && separator.is_ascii()
+        && quote_char.map_or(true, |c| c.is_ascii())
+        && eol_char.is_ascii()
+        && validate_utf8(bytes)
+}
+
 pub fn init_buffers(
     projection: &[usize],
     capacity: usize,
@@ -591,6 +619,17 @@ pub enum Buffer {
 }
 
 impl Buffer {
+    /// Mark the bytes that string fields are parsed from as valid UTF-8, so that the fields
+    /// themselves are not validated again.
+    pub(crate) fn set_utf8_validated(&mut self) {
+        match self {
+            Buffer::Utf8(v) => v.utf8_validated = true,
+            #[cfg(feature = "dtype-categorical")]
+            Buffer::Categorical(v) => v.utf8_validated = true,
+            _ => {},
+        }
+    }
+
     pub fn into_series(self) -> PolarsResult<Series> {
         let s = match self {
             Buffer::Boolean(v) => v.finish().into_series(),
@@ -93,6 +93,11 @@ pub fn count_rows_from_slice(
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let local_bytes = &bytes[start..stop];
+        if comment_prefix.is_none() {
+            if let Some(count) = count_lines_unquoted(local_bytes, quote_char, eol_char) {
+                return Ok(count);
+            }
+        }
         let row_iterator = SplitLines::new(local_bytes, quote_char.unwrap_or(b'"'), eol_char);
         if comment_prefix.is_some() {
             Ok(row_iterator
@@ -111,6 +116,17 @@ pub fn count_rows_from_slice(
     }
 }
 
+/// Count the lines of `bytes` with the SIMD accelerated `memchr`, if it contains no quote
+/// characters and thus no end of line characters embedded in string fields.
+fn count_lines_unquoted(bytes: &[u8], quote_char: Option<u8>, eol_char: u8) -> Option<usize> {
+    if quote_char.is_some_and(|quote_char| memchr::memchr(quote_char, bytes).is_some()) {
+        return None;
+    }
+    let n_eol = memchr::memchr_iter(eol_char, bytes).count();
+    // The last line does not need to end with an end of line character.
+    Some(n_eol + bytes.last().is_some_and(|&c| c != eol_char) as usize)
+}
+
 /// Skip the utf-8 Byte Order Mark.
 /// credits to csv-core
 pub(super) fn skip_bom(input: &[u8]) -> &[u8] {
@@ -622,7 +638,7 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
 
 #[cfg(test)]
 mod test {
-    use super::SplitLines;
+    use super::{count_lines_unquoted, SplitLines};
 
     #[test]
     fn test_splitlines() {
@@ -638,4 +654,20 @@ mod test {
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_count_lines_unquoted() {
+        for input in ["", "a,b", "a,b\n", "a,b\n\n1,2", "a,b\n1,2\n"] {
+            let expected = SplitLines::new(input.as_bytes(), b'"', b'\n').count();
+            assert_eq!(
+                count_lines_unquoted(input.as_bytes(), Some(b'"'), b'\n'),
+                Some(expected)
+            );
+        }
+        assert_eq!(
+            count_lines_unquoted(b"1,\"a\nb\"\n", Some(b'"'), b'\n'),
+            None
+        );
+        assert_eq!(count_lines_unquoted(b"1,\"a\nb\"\n", None, b'\n'), Some(2));
+    }
 }
@@ -11,7 +11,7 @@ use polars_time::prelude::*;
 use polars_utils::flatten;
 use rayon::prelude::*;
 
-use super::buffer::init_buffers;
+use super::buffer::{init_buffers, validate_utf8_once, Buffer};
 use super::options::{CommentPrefix, CsvEncoding, NullValues, NullValuesCompiled};
 use super::parser::{
     get_line_stats, is_comment_line, next_line_position, next_line_position_naive, parse_lines,
@@ -516,6 +516,7 @@ impl<'a> CoreReader<'a> {
                         let mut read = bytes_offset_thread;
                         let mut dfs = Vec::with_capacity(256);
                         let mut last_read = usize::MAX;
+                        let mut utf8_validated = None;
                         loop {
                             if read >= stop_at_nbytes || read == last_read {
                                 break;
@@ -529,6 +530,19 @@ impl<'a> CoreReader<'a> {
                                 self.encoding,
                                 self.decimal_comma,
                             )?;
+                            if *utf8_validated.get_or_insert_with(|| {
+                                validate_utf8_once(
+                                    &buffers,
+                                    bytes
+                                        .get(bytes_offset_thread..stop_at_nbytes)
+                                        .unwrap_or_default(),
+                                    self.separator,
+                                    self.quote_char,
+                                    self.eol_char,
+                                )
+                            }) {
+                                buffers.iter_mut().for_each(Buffer::set_utf8_validated);
+                            }
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
@@ -735,6 +749,17 @@ fn read_chunk(
         encoding,
         decimal_comma,
     )?;
+    if validate_utf8_once(
+        &buffers,
+        bytes
+            .get(bytes_offset_thread..stop_at_nbytes)
+            .unwrap_or_default(),
+        separator,
+        quote_char,
+        eol_char,
+    ) {
+        buffers.iter_mut().for_each(Buffer::set_utf8_validated);
+    }
 
     let mut last_read = usize::MAX;
     loop {
@@ -18,11 +18,11 @@ use crate::utils::{BOOLEAN_RE, FLOAT_RE, FLOAT_RE_DECIMAL, INTEGER_RE};
 
 #[derive(Clone, Debug, Default)]
 pub struct SchemaInferenceResult {
-    inferred_schema: SchemaRef,
-    rows_read: usize,
-    bytes_read: usize,
-    bytes_total: usize,
-    n_threads: Option<usize>,
+    pub(crate) inferred_schema: SchemaRef,
+    pub(crate) rows_read: usize,
+    pub(crate) bytes_read: usize,
+    pub(crate) bytes_total: usize,
+    pub(crate) n_threads: Option<usize>,
 }
 
 impl SchemaInferenceResult {
@@ -4,6 +4,8 @@ use std::io::Write;
 
 use arrow::array::NullArray;
 use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::*;
 use polars_core::POOL;
 use polars_error::polars_ensure;
@@ -57,7 +59,7 @@ pub(crate) fn write<W: Write>(
                             .datetime_format
                             .as_deref()
                             .unwrap_or("%FT%H:%M:%S.%3f%z"),
-                        tz.parse::<Tz>().ok(),
+                        parse_time_zone(tz).ok(),
                     ),
                     _ => (
                         options
@@ -77,7 +79,7 @@ pub(crate) fn write<W: Write>(
                             .datetime_format
                             .as_deref()
                             .unwrap_or("%FT%H:%M:%S.%6f%z"),
-                        tz.parse::<Tz>().ok(),
+                        parse_time_zone(tz).ok(),
                     ),
                     _ => (
                         options
@@ -97,7 +99,7 @@ pub(crate) fn write<W: Write>(
                             .datetime_format
                             .as_deref()
                             .unwrap_or("%FT%H:%M:%S.%9f%z"),
-                        tz.parse::<Tz>().ok(),
+                        parse_time_zone(tz).ok(),
                     ),
                     _ => (
                         options
@@ -42,6 +42,7 @@ use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics;
 use crate::hive::materialize_hive_partitions;
 use crate::mmap::MmapBytesReader;
 use crate::predicates::PhysicalIoExpr;
@@ -85,6 +86,7 @@ pub struct IpcReader<R: MmapBytesReader> {
     pub(super) memory_map: Option<PathBuf>,
     metadata: Option<read::FileMetadata>,
     schema: Option<ArrowSchemaRef>,
+    predicate: Option<Arc<dyn PhysicalIoExpr>>,
 }
 
 fn check_mmap_err(err: PolarsError) -> PolarsResult<()> {
@@ -153,6 +155,29 @@ impl<R: MmapBytesReader> IpcReader<R> {
         self
     }
 
+    /// Only include the rows that pass the predicate. The predicate is evaluated for every record
+    /// batch while the file is read, record batches whose statistics show that none of their rows
+    /// pass it are skipped.
+    pub fn with_predicate(mut self, predicate: Option<Arc<dyn PhysicalIoExpr>>) -> Self {
+        self.predicate = predicate;
+        self
+    }
+
+    /// Remove the record batches that cannot pass `predicate` from `metadata`.
+    pub(super) fn prune_record_batches(
+        &self,
+        metadata: &mut read::FileMetadata,
+        predicate: Option<&dyn PhysicalIoExpr>,
+    ) -> PolarsResult<()> {
+        match predicate {
+            // Skipping record batches would shift the row index and the row limit.
+            Some(predicate) if self.row_index.is_none() && self.n_rows.is_none() => {
+                statistics::prune_record_batches(metadata, predicate)
+            },
+            _ => Ok(()),
+        }
+    }
+
     /// Set if the file is to be memory_mapped. Only works with uncompressed files.
     /// The file name must be passed to register the memory mapped file.
     pub fn memory_mapped(mut self, path_buf: Option<PathBuf>) -> Self {
@@ -177,7 +202,8 @@ impl<R: MmapBytesReader> IpcReader<R> {
             }
         }
         let rechunk = self.rechunk;
-        let metadata = read::read_file_metadata(&mut self.reader)?;
+        let mut metadata = read::read_file_metadata(&mut self.reader)?;
+        self.prune_record_batches(&mut metadata, predicate.as_deref())?;
 
         // NOTE: For some code paths this already happened. See
         // https://github.com/pola-rs/polars/pull/14984#discussion_r1520125000
@@ -221,6 +247,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             memory_map: None,
             metadata: None,
             schema: None,
+            predicate: None,
         }
     }
 
@@ -256,7 +283,7 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
             }
 
             if self.memory_map.is_some() && self.reader.to_file().is_some() {
-                match self.finish_memmapped(None) {
+                match self.finish_memmapped(self.predicate.clone()) {
                     Ok(df) => {
                         let n = df.height();
                         return Ok((df, n));
@@ -278,11 +305,20 @@ impl<R: MmapBytesReader> SerReader<R> for IpcReader<R> {
                 schema
             };
 
-            let metadata = self.get_metadata()?.clone();
+            let mut metadata = self.get_metadata()?.clone();
+            let predicate = self.predicate.take();
+            self.prune_record_batches(&mut metadata, predicate.as_deref())?;
 
             let ipc_reader =
                 read::FileReader::new(self.reader, metadata, self.projection, self.n_rows);
-            let df = finish_reader(ipc_reader, rechunk, None, None, &schema, self.row_index)?;
+            let df = finish_reader(
+                ipc_reader,
+                rechunk,
+                None,
+                predicate,
+                &schema,
+                self.row_index,
+            )?;
             let n = df.height();
             Ok((df, n))
         })()?;
@@ -19,8 +19,9 @@ impl<R: MmapBytesReader> IpcReader<R> {
         match self.reader.to_file() {
             Some(file) => {
                 let semaphore = MMapSemaphore::new_from_file(file)?;
-                let metadata =
+                let mut metadata =
                     read::read_file_metadata(&mut std::io::Cursor::new(semaphore.as_ref()))?;
+                self.prune_record_batches(&mut metadata, predicate.as_deref())?;
 
                 if let Some(columns) = &self.columns {
                     let schema = &metadata.schema;
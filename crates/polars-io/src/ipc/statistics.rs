@@ -0,0 +1,133 @@
+//! Statistics of the record batches of an IPC file.
+//!
+//! The IPC format has no place for statistics, so the [`IpcWriter`](super::IpcWriter) records the
+//! null count, minimum and maximum of the columns of every record batch in the custom metadata of
+//! the file footer. Readers use them to skip record batches that cannot pass a predicate.
+use arrow::datatypes::Metadata;
+#[cfg(feature = "ipc")]
+use arrow::io::ipc::read::FileMetadata;
+use polars_core::prelude::*;
+
+#[cfg(feature = "ipc")]
+use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
+fn stats_key(batch: usize, column: usize, stat: &str) -> PlSmallStr {
+    format!("polars:stats:{batch}:{column}:{stat}").into()
+}
+
+fn stat_to_string(value: &AnyValue, dtype: &DataType) -> Option<PlSmallStr> {
+    // Reductions may return borrowed, owned or categorical strings.
+    if let Some(v) = value.get_str() {
+        Some(PlSmallStr::from_str(v))
+    } else if dtype.is_float() {
+        Some(value.extract::<f64>()?.to_string().into())
+    } else if dtype.is_unsigned_integer() {
+        Some(value.extract::<u64>()?.to_string().into())
+    } else if dtype.is_signed_integer() {
+        Some(value.extract::<i64>()?.to_string().into())
+    } else {
+        None
+    }
+}
+
+/// Record the statistics of the columns of the record batch `df` with index `batch`.
+pub(super) fn write_batch_statistics(
+    df: &DataFrame,
+    batch: usize,
+    metadata: &mut Metadata,
+) -> PolarsResult<()> {
+    for (i, column) in df.get_columns().iter().enumerate() {
+        let logical = column.dtype();
+        if !(logical.is_numeric() || logical.is_temporal() || logical == &DataType::String) {
+            continue;
+        }
+        let s = column.as_materialized_series().to_physical_repr();
+        let dtype = s.dtype();
+        metadata.insert(
+            stats_key(batch, i, "null_count"),
+            s.null_count().to_string().into(),
+        );
+        // The minimum and maximum ignore NaN, but NaN compares greater than all other values.
+        if dtype.is_float() && s.is_nan()?.any() {
+            continue;
+        }
+        for (stat, value) in [("min", s.min_reduce()?), ("max", s.max_reduce()?)] {
+            if let Some(value) = stat_to_string(value.value(), dtype) {
+                metadata.insert(stats_key(batch, i, stat), value);
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Read the statistics of the record batch with index `batch`.
+#[cfg(feature = "ipc")]
+fn read_batch_statistics(metadata: &Metadata, schema: &SchemaRef, batch: usize) -> BatchStats {
+    let stats = schema
+        .iter()
+        .enumerate()
+        .map(|(i, (name, dtype))| {
+            let read_stat = |stat, stat_dtype: &DataType| {
+                let value = metadata.get(&stats_key(batch, i, stat))?;
+                let s = Series::new(name.clone(), [value.as_str()]);
+                s.strict_cast(&stat_dtype.to_physical())
+                    .and_then(|s| s.cast(stat_dtype))
+                    .ok()
+            };
+            ColumnStats::new(
+                Field::new(name.clone(), dtype.clone()),
+                read_stat("null_count", &IDX_DTYPE),
+                read_stat("min", dtype),
+                read_stat("max", dtype),
+            )
+        })
+        .collect();
+    BatchStats::new(schema.clone(), stats, None)
+}
+
+/// Remove the record batches from `metadata` that the statistics in its footer show cannot pass
+/// `predicate`.
+#[cfg(feature = "ipc")]
+pub(super) fn prune_record_batches(
+    metadata: &mut FileMetadata,
+    predicate: &dyn PhysicalIoExpr,
+) -> PolarsResult<()> {
+    let (Some(evaluator), Some(custom_metadata)) =
+        (predicate.as_stats_evaluator(), &metadata.custom_metadata)
+    else {
+        return Ok(());
+    };
+    let schema = Arc::new(Schema::from_arrow_schema(&metadata.schema));
+
+    let keep = (0..metadata.blocks.len())
+        .map(|batch| {
+            let stats = read_batch_statistics(custom_metadata, &schema, batch);
+            match evaluator.should_read(&stats) {
+                // Not all columns have statistics.
+                Err(PolarsError::ColumnNotFound(_)) => Ok(true),
+                should_read => should_read,
+            }
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let mut keep = keep.into_iter();
+    metadata.blocks.retain(|_| keep.next().unwrap());
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stat_to_string() -> PolarsResult<()> {
+        let s = Series::new("a".into(), ["b", "a", "c"]);
+        let min = s.min_reduce()?;
+        assert_eq!(stat_to_string(min.value(), s.dtype()).as_deref(), Some("a"));
+        let owned = AnyValue::StringOwned("c".into());
+        assert_eq!(
+            stat_to_string(&owned, &DataType::String).as_deref(),
+            Some("c")
+        );
+        Ok(())
+    }
+}
@@ -1,11 +1,13 @@
 use std::io::Write;
 
+use arrow::datatypes::Metadata;
 use arrow::io::ipc::write;
 use arrow::io::ipc::write::WriteOptions;
 use polars_core::prelude::*;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use super::statistics::write_batch_statistics;
 use crate::prelude::*;
 use crate::shared::schema_to_arrow_checked;
 
@@ -48,6 +50,7 @@ pub struct IpcWriter<W> {
     pub(super) compression: Option<IpcCompression>,
     /// Polars' flavor of arrow. This might be temporary.
     pub(super) compat_level: CompatLevel,
+    pub(super) batch_statistics: bool,
 }
 
 impl<W: Write> IpcWriter<W> {
@@ -62,6 +65,14 @@ impl<W: Write> IpcWriter<W> {
         self
     }
 
+    /// Record the null count, minimum and maximum of the columns of every record batch in the
+    /// footer of the file. Readers use them to skip record batches that cannot pass a predicate.
+    /// Defaults to `false`.
+    pub fn with_batch_statistics(mut self, batch_statistics: bool) -> Self {
+        self.batch_statistics = batch_statistics;
+        self
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
         let schema = schema_to_arrow_checked(schema, self.compat_level, "ipc")?;
         let mut writer = write::FileWriter::new(
@@ -77,6 +88,8 @@ impl<W: Write> IpcWriter<W> {
         Ok(BatchedWriter {
             writer,
             compat_level: self.compat_level,
+            statistics: self.batch_statistics.then(Metadata::default),
+            num_batches: 0,
         })
     }
 }
@@ -90,6 +103,7 @@ where
             writer,
             compression: None,
             compat_level: CompatLevel::newest(),
+            batch_statistics: false,
         }
     }
 
@@ -104,19 +118,47 @@ where
             },
         )?;
         df.align_chunks();
-        let iter = df.iter_chunks(self.compat_level, true);
-
-        for batch in iter {
-            ipc_writer.write(&batch, None)?
+        let mut statistics = self.batch_statistics.then(Metadata::default);
+        write_batches(
+            &mut ipc_writer,
+            df,
+            self.compat_level,
+            statistics.as_mut(),
+            &mut 0,
+        )?;
+        if let Some(statistics) = statistics {
+            ipc_writer.set_custom_footer_metadata(Arc::new(statistics));
         }
         ipc_writer.finish()?;
         Ok(())
     }
 }
 
+/// Write the chunks of `df` as record batches and record their statistics in `statistics`.
+fn write_batches<W: Write>(
+    writer: &mut write::FileWriter<W>,
+    df: &DataFrame,
+    compat_level: CompatLevel,
+    mut statistics: Option<&mut Metadata>,
+    num_batches: &mut usize,
+) -> PolarsResult<()> {
+    let mut offset = 0;
+    for batch in df.iter_chunks(compat_level, true) {
+        if let Some(statistics) = statistics.as_deref_mut() {
+            write_batch_statistics(&df.slice(offset, batch.len()), *num_batches, statistics)?;
+        }
+        offset += batch.len() as i64;
+        *num_batches += 1;
+        writer.write(&batch, None)?
+    }
+    Ok(())
+}
+
 pub struct BatchedWriter<W: Write> {
     writer: write::FileWriter<W>,
     compat_level: CompatLevel,
+    statistics: Option<Metadata>,
+    num_batches: usize,
 }
 
 impl<W: Write> BatchedWriter<W> {
@@ -125,15 +167,20 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
-        let iter = df.iter_chunks(self.compat_level, true);
-        for batch in iter {
-            self.writer.write(&batch, None)?
-        }
-        Ok(())
+        write_batches(
+            &mut self.writer,
+            df,
+            self.compat_level,
+            self.statistics.as_mut(),
+            &mut self.num_batches,
+        )
     }
 
     /// Writes the footer of the IPC file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if let Some(statistics) = self.statistics.take() {
+            self.writer.set_custom_footer_metadata(Arc::new(statistics));
+        }
         self.writer.finish()?;
         Ok(())
     }
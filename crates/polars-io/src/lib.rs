@@ -14,6 +14,8 @@ pub mod file_cache;
 pub mod ipc;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "metadata_cache")]
+pub mod metadata_cache;
 pub mod mmap;
 #[cfg(feature = "json")]
 pub mod ndjson;
@@ -0,0 +1,487 @@
+//! A cache of the metadata of scanned files on local disk, so that repeated scans of the same
+//! files, e.g. in notebooks or command line sessions, do not fetch and parse it again.
+//!
+//! The entries are keyed by the uri of the file and checked against its version: the ETag of
+//! cloud objects or the size and modification time of local files. The cache is disabled by
+//! default, set `POLARS_METADATA_CACHE=1` or call [`MetadataCache::set_enabled`] to enable it.
+//!
+//! The cache is best-effort: scans look up and insert entries without failing, a cache that
+//! cannot be read or written is skipped and the reason is logged in verbose mode.
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use arrow::datatypes::ArrowSchemaRef;
+use arrow::io::ipc::read::deserialize_schema;
+use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
+use once_cell::sync::Lazy;
+use polars_core::config::verbose;
+use polars_error::{PolarsError, PolarsResult};
+use polars_utils::pl_str::PlSmallStr;
+use serde::{Deserialize, Serialize};
+
+use crate::cloud::CloudOptions;
+#[cfg(feature = "csv")]
+use crate::csv::read::{schema_inference::SchemaInferenceResult, CsvReadOptions};
+use crate::path_utils::{ensure_directory_init, is_cloud_url, polars_temp_dir_base_path};
+
+const DEFAULT_MAX_SIZE: u64 = 128 * 1024 * 1024;
+/// Evicting lists the whole cache, so it only runs once the entries written since the last
+/// eviction take up this fraction of the maximum size.
+const EVICT_FRACTION: u64 = 16;
+
+/// Numbers the temporary files of the entries that are written by this process.
+static TMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Log a failed cache operation, the cache is best-effort so it never fails a query.
+fn log_err<T>(res: PolarsResult<T>, what: &str, uri: &str) -> Option<T> {
+    res.map_err(|err| {
+        if verbose() {
+            eprintln!("metadata cache: failed to {what} the entry of {uri}: {err}")
+        }
+    })
+    .ok()
+}
+
+pub static METADATA_CACHE: Lazy<MetadataCache> = Lazy::new(|| {
+    let cache = MetadataCache::new(polars_temp_dir_base_path().join("metadata-cache/"));
+    cache.set_enabled(std::env::var("POLARS_METADATA_CACHE").as_deref() == Ok("1"));
+    if let Some(max_size) = std::env::var("POLARS_METADATA_CACHE_MAX_SIZE")
+        .ok()
+        .and_then(|v| v.parse().ok())
+    {
+        cache.max_size.store(max_size, Ordering::Relaxed);
+    }
+    cache
+});
+
+/// The metadata of a Parquet file that the planning of a scan needs.
+#[derive(Clone, Debug)]
+pub struct ParquetScanMetadata {
+    pub schema: ArrowSchemaRef,
+    pub num_rows: usize,
+    /// The name, null count and lower bound of the distinct count of the columns with
+    /// statistics.
+    pub column_counts: Vec<(PlSmallStr, Option<usize>, Option<usize>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+enum Payload {
+    Parquet {
+        num_rows: usize,
+        column_counts: Vec<(String, Option<usize>, Option<usize>)>,
+    },
+    Csv {
+        options_hash: u64,
+        rows_read: usize,
+        bytes_read: usize,
+        bytes_total: usize,
+        n_threads: Option<usize>,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    uri: String,
+    version: String,
+    /// The schema in the IPC format.
+    schema: Vec<u8>,
+    payload: Payload,
+}
+
+pub struct MetadataCache {
+    /// The directory of the cache, which is created on first use.
+    dir: Box<Path>,
+    enabled: AtomicBool,
+    max_size: AtomicU64,
+    /// The bytes written since the last eviction. Starts at the maximum so that the first insert
+    /// evicts what earlier processes left behind.
+    written: AtomicU64,
+    // Serializes the eviction within this process.
+    lock: Mutex<()>,
+}
+
+/// The version of the file at `uri` that the cached metadata must match.
+fn file_version(uri: &str, cloud_options: Option<&CloudOptions>) -> PolarsResult<String> {
+    if is_cloud_url(uri) {
+        use crate::cloud::{build_object_store, object_path_from_str, CloudLocation};
+
+        let CloudLocation { prefix, .. } = CloudLocation::new(uri, false)?;
+        let path = object_path_from_str(&prefix)?;
+        let meta = crate::pl_async::get_runtime().block_on_potential_spawn(async {
+            let (_, store) = build_object_store(uri, cloud_options, false).await?;
+            store.head(&path).await.map_err(PolarsError::from)
+        })?;
+        return Ok(match meta.e_tag {
+            Some(e_tag) => format!("etag:{e_tag}"),
+            None => format!("{}:{}", meta.size, meta.last_modified.timestamp_millis()),
+        });
+    }
+    let meta = std::fs::metadata(uri)?;
+    let modified = meta
+        .modified()?
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis();
+    Ok(format!("{}:{}", meta.len(), modified))
+}
+
+/// The uri of local files is canonicalized, so that a file has one entry.
+fn canonical_uri(uri: &str) -> PolarsResult<String> {
+    if is_cloud_url(uri) {
+        Ok(uri.to_string())
+    } else {
+        Ok(std::fs::canonicalize(uri)?.to_string_lossy().into_owned())
+    }
+}
+
+impl MetadataCache {
+    /// A disabled cache in `dir`, the cache of the process is [`METADATA_CACHE`].
+    pub fn new(dir: PathBuf) -> Self {
+        Self {
+            dir: dir.into_boxed_path(),
+            enabled: AtomicBool::new(false),
+            max_size: AtomicU64::new(DEFAULT_MAX_SIZE),
+            written: AtomicU64::new(u64::MAX),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn cache_dir(&self) -> PolarsResult<&Path> {
+        ensure_directory_init(&self.dir)?;
+        Ok(&self.dir)
+    }
+
+    fn entry_path(&self, uri: &str, kind: &str) -> PolarsResult<PathBuf> {
+        let hash = blake3::hash(format!("{kind}:{uri}").as_bytes());
+        Ok(self
+            .cache_dir()?
+            .join(format!("{}.json", &hash.to_hex()[..32])))
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed)
+    }
+
+    /// The maximum size of the cache on disk in bytes.
+    pub fn max_size(&self) -> u64 {
+        self.max_size.load(Ordering::Relaxed)
+    }
+
+    /// Set the maximum size of the cache on disk in bytes and evict the least recently written
+    /// entries that no longer fit.
+    pub fn set_max_size(&self, max_size: u64) -> PolarsResult<()> {
+        self.max_size.store(max_size, Ordering::Relaxed);
+        self.evict()
+    }
+
+    /// The size of the cache on disk in bytes.
+    pub fn size(&self) -> PolarsResult<u64> {
+        Ok(self.entries()?.iter().map(|(_, size, _)| size).sum())
+    }
+
+    /// Remove all entries from the cache.
+    pub fn clear(&self) -> PolarsResult<()> {
+        let _guard = self.lock.lock().unwrap();
+        for (path, _, _) in self.entries()? {
+            remove_entry(&path)?;
+        }
+        Ok(())
+    }
+
+    /// The path, size and modification time of every entry, skipping the entries that another
+    /// process removes while they are listed.
+    fn entries(&self) -> PolarsResult<Vec<(PathBuf, u64, std::time::SystemTime)>> {
+        let mut entries = vec![];
+        for entry in std::fs::read_dir(self.cache_dir()?)? {
+            let Ok(entry) = entry else {
+                continue;
+            };
+            let Ok(meta) = entry.metadata() else {
+                continue;
+            };
+            if let (true, Ok(modified)) = (meta.is_file(), meta.modified()) {
+                entries.push((entry.path(), meta.len(), modified));
+            }
+        }
+        Ok(entries)
+    }
+
+    fn evict(&self) -> PolarsResult<()> {
+        let _guard = self.lock.lock().unwrap();
+        self.written.store(0, Ordering::Relaxed);
+        let mut entries = self.entries()?;
+        let mut size = entries.iter().map(|(_, size, _)| size).sum::<u64>();
+        let max_size = self.max_size();
+        if size <= max_size {
+            return Ok(());
+        }
+        entries.sort_by_key(|(_, _, modified)| *modified);
+        for (path, entry_size, _) in entries {
+            if size <= max_size {
+                break;
+            }
+            // An entry that cannot be removed is kept, it is retried by the next eviction.
+            if log_err(remove_entry(&path), "evict", &path.to_string_lossy()).is_some() {
+                size -= entry_size;
+            }
+        }
+        Ok(())
+    }
+
+    /// Look up the entry of `uri`, ignoring entries that are stale or cannot be read.
+    fn get(
+        &self,
+        uri: &str,
+        kind: &str,
+        cloud_options: Option<&CloudOptions>,
+    ) -> Option<(ArrowSchemaRef, Payload)> {
+        log_err(self.try_get(uri, kind, cloud_options), "read", uri).flatten()
+    }
+
+    fn try_get(
+        &self,
+        uri: &str,
+        kind: &str,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<Option<(ArrowSchemaRef, Payload)>> {
+        let uri = canonical_uri(uri)?;
+        let Ok(bytes) = std::fs::read(self.entry_path(&uri, kind)?) else {
+            return Ok(None);
+        };
+        let Ok(entry) = serde_json::from_slice::<Entry>(&bytes) else {
+            return Ok(None);
+        };
+        if entry.uri != uri || entry.version != file_version(&uri, cloud_options)? {
+            return Ok(None);
+        }
+        let Ok((schema, _)) = deserialize_schema(&entry.schema) else {
+            return Ok(None);
+        };
+        Ok(Some((schema.into(), entry.payload)))
+    }
+
+    /// Store the entry of `uri`, skipping it if it cannot be written.
+    fn insert(
+        &self,
+        uri: &str,
+        kind: &str,
+        schema: &ArrowSchemaRef,
+        payload: Payload,
+        cloud_options: Option<&CloudOptions>,
+    ) {
+        let res = self.try_insert(uri, kind, schema, payload, cloud_options);
+        log_err(res, "write", uri);
+    }
+
+    fn try_insert(
+        &self,
+        uri: &str,
+        kind: &str,
+        schema: &ArrowSchemaRef,
+        payload: Payload,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<()> {
+        let uri = canonical_uri(uri)?;
+        let version = file_version(&uri, cloud_options)?;
+        let ipc_fields = default_ipc_fields(schema.iter_values());
+        let entry = Entry {
+            uri: uri.clone(),
+            schema: schema_to_bytes(schema, &ipc_fields),
+            version,
+            payload,
+        };
+        let bytes = serde_json::to_vec(&entry).map_err(polars_error::to_compute_err)?;
+        let size = bytes.len() as u64;
+
+        // Write to a temporary file first, so that no process reads a partial entry. The name is
+        // unique to this write, as other threads and processes may write the same entry.
+        let path = self.entry_path(&uri, kind)?;
+        let tmp_path = path.with_extension(format!(
+            "{}.{}.tmp",
+            std::process::id(),
+            TMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        std::fs::write(&tmp_path, bytes)?;
+        if let Err(err) = std::fs::rename(&tmp_path, &path) {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(err.into());
+        }
+
+        let written = self
+            .written
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |w| {
+                Some(w.saturating_add(size))
+            })
+            .unwrap()
+            .saturating_add(size);
+        if written >= self.max_size() / EVICT_FRACTION {
+            self.evict()?;
+        }
+        Ok(())
+    }
+
+    /// Look up the metadata of the Parquet file at `uri`.
+    pub fn get_parquet(
+        &self,
+        uri: &str,
+        cloud_options: Option<&CloudOptions>,
+    ) -> Option<ParquetScanMetadata> {
+        match self.get(uri, "parquet", cloud_options) {
+            Some((
+                schema,
+                Payload::Parquet {
+                    num_rows,
+                    column_counts,
+                },
+            )) => Some(ParquetScanMetadata {
+                schema,
+                num_rows,
+                column_counts: column_counts
+                    .into_iter()
+                    .map(|(name, nulls, distinct)| (PlSmallStr::from_string(name), nulls, distinct))
+                    .collect(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Store the metadata of the Parquet file at `uri`.
+    pub fn insert_parquet(
+        &self,
+        uri: &str,
+        metadata: &ParquetScanMetadata,
+        cloud_options: Option<&CloudOptions>,
+    ) {
+        let payload = Payload::Parquet {
+            num_rows: metadata.num_rows,
+            column_counts: metadata
+                .column_counts
+                .iter()
+                .map(|(name, nulls, distinct)| (name.to_string(), *nulls, *distinct))
+                .collect(),
+        };
+        self.insert(uri, "parquet", &metadata.schema, payload, cloud_options)
+    }
+
+    /// Look up the schema inferred from the CSV file at `uri` with `options`.
+    #[cfg(feature = "csv")]
+    pub fn get_csv_inference(
+        &self,
+        uri: &str,
+        options: &CsvReadOptions,
+        cloud_options: Option<&CloudOptions>,
+    ) -> Option<SchemaInferenceResult> {
+        use polars_core::prelude::{Schema, SchemaExt};
+
+        match self.get(uri, "csv", cloud_options) {
+            Some((
+                schema,
+                Payload::Csv {
+                    options_hash,
+                    rows_read,
+                    bytes_read,
+                    bytes_total,
+                    n_threads,
+                },
+            )) if options_hash == csv_options_hash(options) => Some(SchemaInferenceResult {
+                inferred_schema: std::sync::Arc::new(Schema::from_arrow_schema(&schema)),
+                rows_read,
+                bytes_read,
+                bytes_total,
+                n_threads,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Store the schema inferred from the CSV file at `uri` with `options`.
+    #[cfg(feature = "csv")]
+    pub fn insert_csv_inference(
+        &self,
+        uri: &str,
+        options: &CsvReadOptions,
+        result: &SchemaInferenceResult,
+        cloud_options: Option<&CloudOptions>,
+    ) {
+        use polars_core::prelude::{CompatLevel, SchemaExt};
+
+        let schema = result.inferred_schema.to_arrow(CompatLevel::newest());
+        let payload = Payload::Csv {
+            options_hash: csv_options_hash(options),
+            rows_read: result.rows_read,
+            bytes_read: result.bytes_read,
+            bytes_total: result.bytes_total,
+            n_threads: result.n_threads,
+        };
+        self.insert(uri, "csv", &schema.into(), payload, cloud_options)
+    }
+}
+
+/// The inference depends on the options, so an entry is only used with the same options.
+///
+/// The hash is persisted, so it uses xxh3 rather than the std hasher, whose output may change
+/// between Rust releases.
+#[cfg(feature = "csv")]
+fn csv_options_hash(options: &CsvReadOptions) -> u64 {
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = xxhash_rust::xxh3::Xxh3::new();
+    options.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn remove_entry(path: &Path) -> PolarsResult<()> {
+    match std::fs::remove_file(path) {
+        // Another process removed it first.
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        res => Ok(res?),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+    use std::sync::Arc;
+
+    use arrow::datatypes::{ArrowDataType, ArrowSchema, Field};
+
+    use super::*;
+
+    #[test]
+    fn test_metadata_cache() -> PolarsResult<()> {
+        // An isolated cache, as the shrinking below evicts every entry of the cache.
+        let dir = tempfile::tempdir()?;
+        let cache = MetadataCache::new(dir.path().join("metadata-cache/"));
+        let mut file = tempfile::NamedTempFile::new()?;
+        file.write_all(b"data")?;
+        let uri = file.path().to_str().unwrap();
+
+        let schema = ArrowSchema::from_iter([Field::new("a".into(), ArrowDataType::Int64, true)]);
+        let metadata = ParquetScanMetadata {
+            schema: Arc::new(schema),
+            num_rows: 3,
+            column_counts: vec![("a".into(), Some(1), None)],
+        };
+        cache.insert_parquet(uri, &metadata, None);
+        let cached = cache.get_parquet(uri, None).unwrap();
+        assert_eq!(cached.schema, metadata.schema);
+        assert_eq!(cached.num_rows, 3);
+        assert_eq!(cached.column_counts, metadata.column_counts);
+        assert!(cache.size()? > 0);
+
+        // A changed file invalidates its entry.
+        file.write_all(b"more data")?;
+        assert!(cache.get_parquet(uri, None).is_none());
+
+        cache.insert_parquet(uri, &metadata, None);
+        cache.set_max_size(0)?;
+        assert!(cache.get_parquet(uri, None).is_none());
+        assert_eq!(cache.size()?, 0);
+        Ok(())
+    }
+}
@@ -299,31 +299,102 @@ impl<'a> CoreJsonReader<'a> {
         };
         let file_chunks = get_file_chunks_json(bytes, n_threads);
 
+        // Parse the columns of the predicate first and only build the full rows of the lines that
+        // pass it.
+        let predicate_schema = self.predicate.as_ref().and_then(|predicate| {
+            let schema = predicate
+                .live_variables()?
+                .into_iter()
+                .map(|name| {
+                    let dtype = self.schema.get(&name)?.clone();
+                    Some(Field::new(name, dtype))
+                })
+                .collect::<Option<Schema>>()?;
+            (!schema.is_empty() && schema.len() < self.schema.len()).then_some(schema)
+        });
+        let to_df = |buffers: PlIndexMap<BufferKey, Buffer>| {
+            DataFrame::new(
+                buffers
+                    .into_values()
+                    .map(|buf| buf.into_series().into_column())
+                    .collect::<_>(),
+            )
+        };
+
         let row_index = self.row_index.as_ref().map(|ri| ri as &RowIndex);
         let (mut dfs, prepredicate_heights) = POOL.install(|| {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
-                    let mut local_df = DataFrame::new(
-                        buffers
-                            .into_values()
-                            .map(|buf| buf.into_series().into_column())
-                            .collect::<_>(),
-                    )?;
-
-                    let prepredicate_height = local_df.height() as IdxSize;
+                    let bytes = &bytes[start_pos..stop_at_nbytes];
+                    let (mut local_df, mask) = match &predicate_schema {
+                        Some(predicate_schema) => {
+                            let lines = json_lines(bytes).collect::<Vec<_>>();
+                            let mut buffers =
+                                init_buffers(predicate_schema, lines.len(), self.ignore_errors)?;
+                            parse_line_iter(lines.iter().copied(), &mut buffers)?;
+
+                            let predicate = self.predicate.as_ref().unwrap();
+                            let mask = predicate.evaluate_io(&to_df(buffers)?)?;
+                            let mask = if mask.len() == 1 {
+                                mask.new_from_index(0, lines.len())
+                            } else {
+                                mask
+                            };
+                            let mask = mask.bool()?.clone();
+
+                            let mut buffers = init_buffers(
+                                &self.schema,
+                                mask.sum().unwrap_or(0) as usize,
+                                self.ignore_errors,
+                            )?;
+                            let passing = lines
+                                .iter()
+                                .zip(mask.iter())
+                                .filter_map(|(line, keep)| keep.unwrap_or(false).then_some(*line));
+                            parse_line_iter(passing, &mut buffers)?;
+                            (to_df(buffers)?, Some(mask))
+                        },
+                        None => {
+                            let mut buffers =
+                                init_buffers(&self.schema, capacity, self.ignore_errors)?;
+                            parse_lines(bytes, &mut buffers)?;
+                            (to_df(buffers)?, None)
+                        },
+                    };
+
+                    let prepredicate_height =
+                        mask.as_ref().map_or(local_df.height(), |mask| mask.len()) as IdxSize;
                     if let Some(projection) = self.projection.as_deref() {
                         local_df = local_df.select(projection.iter().cloned())?;
                     }
 
                     if let Some(row_index) = row_index {
-                        local_df = local_df
-                            .with_row_index(row_index.name.clone(), Some(row_index.offset))?;
+                        match &mask {
+                            Some(mask) => {
+                                let index = mask
+                                    .iter()
+                                    .enumerate()
+                                    .filter_map(|(i, keep)| {
+                                        keep.unwrap_or(false)
+                                            .then_some(row_index.offset + i as IdxSize)
+                                    })
+                                    .collect::<Vec<_>>();
+                                local_df.insert_column(
+                                    0,
+                                    IdxCa::from_vec(row_index.name.clone(), index),
+                                )?;
+                            },
+                            None => {
+                                local_df = local_df.with_row_index(
+                                    row_index.name.clone(),
+                                    Some(row_index.offset),
+                                )?;
+                            },
+                        }
                     }
 
-                    if let Some(predicate) = &self.predicate {
+                    if let (Some(predicate), None) = (&self.predicate, &mask) {
                         let s = predicate.evaluate_io(&local_df)?;
                         let mask = s.bool()?;
                         local_df = local_df.filter(mask)?;
@@ -408,10 +479,16 @@ fn json_lines(bytes: &[u8]) -> impl Iterator<Item = &[u8]> {
 }
 
 fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> PolarsResult<()> {
+    parse_line_iter(json_lines(bytes), buffers)
+}
+
+fn parse_line_iter<'a>(
+    lines: impl Iterator<Item = &'a [u8]>,
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+) -> PolarsResult<()> {
     let mut scratch = Scratch::default();
 
-    let iter = json_lines(bytes);
-    for bytes in iter {
+    for bytes in lines {
         parse_impl(bytes, buffers, &mut scratch)?;
     }
     Ok(())
@@ -86,6 +86,10 @@ impl ParquetObjectStore {
         }
         Ok(self.metadata.as_ref().unwrap())
     }
+
+    pub(super) fn set_metadata(&mut self, metadata: FileMetadataRef) {
+        self.metadata = Some(metadata);
+    }
 }
 
 fn read_n<const N: usize>(reader: &mut &[u8]) -> Option<[u8; N]> {
@@ -21,6 +21,7 @@ mod options;
 mod predicates;
 mod read_impl;
 mod reader;
+mod salvage;
 mod to_metadata;
 mod utils;
 
@@ -36,10 +37,12 @@ use polars_error::{ErrString, PolarsError};
 #[cfg(feature = "cloud")]
 pub use reader::ParquetAsyncReader;
 pub use reader::{BatchedParquetReader, ParquetReader};
+pub use salvage::{RowGroupSalvage, SkippedRowGroup, SkippedRowGroups};
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
     pub use super::mmap::to_deserializer;
     pub use super::predicates::read_this_row_group;
     pub use super::read_impl::{calc_prefilter_cost, PrefilterMaskSetting};
+    pub use super::utils::resolve_field_ids;
 }
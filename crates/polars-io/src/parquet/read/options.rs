@@ -7,6 +7,15 @@ pub struct ParquetOptions {
     pub parallel: ParallelStrategy,
     pub low_memory: bool,
     pub use_statistics: bool,
+    /// Match the columns of the files by their Parquet field ids instead of by their names,
+    /// using the names of the first file. This keeps columns that were renamed in later versions
+    /// of a table, e.g. by Iceberg schema evolution, readable from older files.
+    pub use_field_ids: bool,
+    /// Skip the row groups whose data is out of spec, e.g. corrupt or failing to decompress,
+    /// instead of failing the scan, after fetching them from the object store once more. Other
+    /// errors, such as unsupported features, still fail the scan. The skipped row groups are
+    /// recorded in the [`SkippedRowGroups`](super::SkippedRowGroups) of the scan.
+    pub skip_corrupt_row_groups: bool,
 }
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default, Hash)]
@@ -1,6 +1,6 @@
 use polars_core::prelude::*;
 use polars_parquet::read::statistics::{deserialize, Statistics};
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::read::{FileMetadata, RowGroupMetadata};
 
 use crate::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
 
@@ -45,6 +45,50 @@ pub(crate) fn collect_statistics(
     )))
 }
 
+/// Compute the minimum and maximum of a column from the statistics of all row groups.
+///
+/// Returns `None` if a row group that holds non-null values has no statistics for the column.
+pub(super) fn min_max_from_statistics(
+    md: &FileMetadata,
+    field: &ArrowField,
+) -> PolarsResult<Option<(Scalar, Scalar)>> {
+    let mut mins = Vec::with_capacity(md.row_groups.len());
+    let mut maxs = Vec::with_capacity(md.row_groups.len());
+    for rg in &md.row_groups {
+        let iter = rg.columns_under_root_iter(&field.name);
+        if iter.len() == 0 {
+            return Ok(None);
+        }
+        let stats = ColumnStats::from_arrow_stats(deserialize(field, iter)?, field);
+        match (stats.to_min(), stats.to_max()) {
+            (Some(min), Some(max)) => {
+                mins.push(min.clone());
+                maxs.push(max.clone());
+            },
+            // Row groups with only nulls do not contribute to the minimum and maximum.
+            _ if stats.null_count() == Some(rg.num_rows()) => {},
+            _ => return Ok(None),
+        }
+    }
+
+    let dtype = Field::from(field).dtype;
+    let reduce = |values: Vec<Series>, max: bool| -> PolarsResult<Scalar> {
+        let mut values = values.into_iter();
+        let Some(mut s) = values.next() else {
+            return Ok(Scalar::new(dtype.clone(), AnyValue::Null));
+        };
+        for other in values {
+            s.append(&other)?;
+        }
+        if max {
+            s.max_reduce()
+        } else {
+            s.min_reduce()
+        }
+    };
+    Ok(Some((reduce(mins, false)?, reduce(maxs, true)?)))
+}
+
 pub fn read_this_row_group(
     predicate: Option<&dyn PhysicalIoExpr>,
     md: &RowGroupMetadata,
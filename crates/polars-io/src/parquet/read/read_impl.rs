@@ -2,14 +2,15 @@ use std::borrow::Cow;
 use std::collections::VecDeque;
 use std::ops::{Deref, Range};
 
-use arrow::array::BooleanArray;
-use arrow::bitmap::MutableBitmap;
-use arrow::datatypes::ArrowSchemaRef;
+use arrow::array::{BooleanArray, DictionaryArray, Utf8ViewArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::datatypes::{ArrowSchemaRef, IntegerType};
 use polars_core::chunked_array::builder::NullChunkedBuilder;
+use polars_core::config::verbose;
 use polars_core::prelude::*;
 use polars_core::utils::{accumulate_dataframes_vertical, split_df};
 use polars_core::POOL;
-use polars_parquet::parquet::error::ParquetResult;
+use polars_parquet::parquet::error::{is_out_of_spec, ParquetResult};
 use polars_parquet::parquet::statistics::Statistics;
 use polars_parquet::read::{
     self, ColumnChunkMetadata, FileMetadata, Filter, PhysicalType, RowGroupMetadata,
@@ -21,6 +22,7 @@ use rayon::prelude::*;
 use super::async_impl::FetchRowGroupsFromObjectStore;
 use super::mmap::{mmap_columns, ColumnStore};
 use super::predicates::read_this_row_group;
+use super::salvage::RowGroupSalvage;
 use super::to_metadata::ToMetadata;
 use super::utils::materialize_empty_df;
 use super::{mmap, ParallelStrategy};
@@ -28,7 +30,7 @@ use crate::hive::materialize_hive_partitions;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::parquet::metadata::FileMetadataRef;
 use crate::parquet::read::ROW_COUNT_OVERFLOW_ERR;
-use crate::predicates::{apply_predicate, PhysicalIoExpr};
+use crate::predicates::{apply_predicate, DictionaryPredicate, PhysicalIoExpr};
 use crate::utils::get_reader_bytes;
 use crate::utils::slice::split_slice_at_file;
 use crate::RowIndex;
@@ -79,8 +81,12 @@ fn column_idx_to_series(
         .iter()
         .map(|(col_md, _)| col_md.statistics().transpose())
         .collect::<ParquetResult<Vec<Option<Statistics>>>>();
-    let array = mmap::to_deserializer(columns, field.clone(), filter)?;
-    let mut series = Series::try_from((field, array))?;
+    let mut series = if is_categorical_string(field) {
+        categorical_from_dictionary(columns, field, filter)?
+    } else {
+        let array = mmap::to_deserializer(columns, field.clone(), filter)?;
+        Series::try_from((field, array))?
+    };
 
     // We cannot really handle nested metadata at the moment. Just skip it.
     use ArrowDataType as AD;
@@ -135,6 +141,113 @@ fn column_idx_to_series(
     Ok(series)
 }
 
+/// Whether `field` is a string column that is read as `Categorical`, see
+/// [`ParquetReader::with_arrow_schema_projection`](super::ParquetReader::with_arrow_schema_projection).
+fn is_categorical_string(field: &ArrowField) -> bool {
+    cfg!(feature = "dtype-categorical")
+        && matches!(
+            field.dtype(),
+            ArrowDataType::Dictionary(_, values, _) if values.as_ref() == &ArrowDataType::Utf8View
+        )
+        && !field.metadata.contains_key(DTYPE_ENUM_KEY)
+}
+
+/// Decode a string column into a `Categorical` with the dictionary of the column chunk as
+/// categories. Columns that are not dictionary encoded are decoded as strings and cast instead.
+fn categorical_from_dictionary(
+    columns: Vec<(&ColumnChunkMetadata, MemSlice)>,
+    field: &ArrowField,
+    filter: Option<Filter>,
+) -> PolarsResult<Series> {
+    if columns
+        .iter()
+        .all(|(col_md, _)| col_md.is_dictionary_encoded())
+    {
+        // Writers fall back to plain encoding if the dictionary grows too large, so the metadata
+        // does not tell whether all data pages are dictionary encoded.
+        if let Ok(array) = mmap::to_deserializer(columns.clone(), field.clone(), filter.clone()) {
+            return Series::try_from((field, array));
+        }
+    }
+
+    let dtype = DataType::from_arrow(field.dtype(), true);
+    let field = ArrowField::new(
+        field.name.clone(),
+        ArrowDataType::Utf8View,
+        field.is_nullable,
+    );
+    let array = mmap::to_deserializer(columns, field.clone(), filter)?;
+    Series::try_from((&field, array))?.cast(&dtype)
+}
+
+/// Evaluate `predicate` on a dictionary encoded string column by matching every entry of the
+/// dictionary once and then filtering the keys. Returns the matching rows and the mask, or `None`
+/// if the column chunk is not fully dictionary encoded.
+///
+/// Only the strings of the matching rows are materialized.
+fn dictionary_prefilter(
+    column_i: usize,
+    field_md: &[&ColumnChunkMetadata],
+    predicate: &DictionaryPredicate,
+    file_schema: &ArrowSchema,
+    store: &mmap::ColumnStore,
+) -> PolarsResult<Option<(Series, Bitmap)>> {
+    let field = file_schema.get_at_index(column_i).unwrap().1;
+    let columns = mmap_columns(store, field_md);
+    if !columns
+        .iter()
+        .all(|(col_md, _)| col_md.is_dictionary_encoded())
+    {
+        return Ok(None);
+    }
+    let dictionary_field = ArrowField::new(
+        field.name.clone(),
+        ArrowDataType::Dictionary(
+            IntegerType::UInt32,
+            Box::new(ArrowDataType::Utf8View),
+            false,
+        ),
+        field.is_nullable,
+    );
+    // Writers fall back to plain encoding if the dictionary grows too large.
+    let Ok(array) = mmap::to_deserializer(columns, dictionary_field, None) else {
+        return Ok(None);
+    };
+    let array = array
+        .as_any()
+        .downcast_ref::<DictionaryArray<u32>>()
+        .unwrap();
+
+    let values = predicate.values.str()?;
+    let values = values.into_no_null_iter().collect::<PlHashSet<_>>();
+    let dictionary = array
+        .values()
+        .as_any()
+        .downcast_ref::<Utf8ViewArray>()
+        .unwrap();
+    let matches = dictionary
+        .iter()
+        .map(|v| v.is_some_and(|v| values.contains(v)))
+        .collect::<Vec<_>>();
+
+    let mask = array
+        .keys()
+        .iter()
+        .map(|key| key.is_some_and(|key| matches[*key as usize]))
+        .collect::<Bitmap>();
+    let keys = array
+        .keys()
+        .values()
+        .iter()
+        .zip(mask.iter())
+        .filter_map(|(key, is_match)| is_match.then_some(*key as IdxSize))
+        .collect::<Vec<_>>();
+    let dictionary = Series::try_from((field.name.clone(), dictionary.clone().boxed()))?;
+    // SAFETY: the keys of a dictionary array are in bounds of its values.
+    let column = unsafe { dictionary.take_slice_unchecked(&keys) };
+    Ok(Some((column, mask)))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn rg_to_dfs(
     store: &mmap::ColumnStore,
@@ -223,6 +336,92 @@ fn rg_to_dfs(
     }
 }
 
+/// [`rg_to_dfs`], but with a `salvage` the row groups that fail to decode are skipped and
+/// recorded in it instead of failing. The row groups are first decoded together, and only
+/// decoded one by one to find the corrupt ones if that fails.
+#[allow(clippy::too_many_arguments)]
+fn rg_to_dfs_salvaged(
+    store: &mmap::ColumnStore,
+    previous_row_count: &mut IdxSize,
+    row_group_start: usize,
+    row_group_end: usize,
+    slice: (usize, usize),
+    file_metadata: &FileMetadata,
+    schema: &ArrowSchemaRef,
+    predicate: Option<&dyn PhysicalIoExpr>,
+    row_index: Option<RowIndex>,
+    parallel: ParallelStrategy,
+    projection: &[usize],
+    use_statistics: bool,
+    hive_partition_columns: Option<&[Series]>,
+    salvage: Option<&RowGroupSalvage>,
+) -> PolarsResult<Vec<DataFrame>> {
+    let start_row_count = *previous_row_count;
+    let decode = |previous_row_count: &mut IdxSize, row_group_start, row_group_end| {
+        rg_to_dfs(
+            store,
+            previous_row_count,
+            row_group_start,
+            row_group_end,
+            slice,
+            file_metadata,
+            schema,
+            predicate,
+            row_index.clone(),
+            parallel,
+            projection,
+            use_statistics,
+            hive_partition_columns,
+        )
+    };
+
+    let result = decode(previous_row_count, row_group_start, row_group_end);
+    let Some(salvage) = salvage else {
+        return result;
+    };
+    match &result {
+        // Nothing is decoded without projected columns.
+        Err(err) if is_decode_error(err) && !projection.is_empty() => {},
+        _ => return result,
+    }
+
+    *previous_row_count = start_row_count;
+    let mut n_rows_processed: usize = file_metadata.row_groups[..row_group_start]
+        .iter()
+        .map(|md| md.num_rows())
+        .sum();
+    let mut dfs = Vec::with_capacity(row_group_end - row_group_start);
+    for rg_idx in row_group_start..row_group_end {
+        let rg_start = n_rows_processed;
+        let num_rows = file_metadata.row_groups[rg_idx].num_rows();
+        let rg_slice =
+            split_slice_at_file(&mut n_rows_processed, num_rows, slice.0, slice.0 + slice.1);
+
+        let row_count = *previous_row_count;
+        match decode(previous_row_count, rg_idx, rg_idx + 1) {
+            Ok(rg_dfs) => dfs.extend(rg_dfs),
+            Err(err) if is_decode_error(&err) => {
+                salvage.record(rg_idx, rg_start..rg_start + num_rows, err);
+                *previous_row_count = row_count
+                    .checked_add(rg_slice.1 as IdxSize)
+                    .ok_or(ROW_COUNT_OVERFLOW_ERR)?;
+            },
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(dfs)
+}
+
+/// Whether `err` is caused by data that is out of spec, e.g. a corrupt page or a failed
+/// decompression. Unsupported or inactive features, IO errors and memory limits are not.
+fn is_decode_error(err: &PolarsError) -> bool {
+    match err {
+        PolarsError::ComputeError(msg) => is_out_of_spec(err) || msg.starts_with("out-of-spec: "),
+        PolarsError::Context { error, .. } => is_decode_error(error),
+        _ => false,
+    }
+}
+
 /// Load several Parquet row groups as DataFrames while filtering predicate items.
 ///
 /// This strategy works as follows:
@@ -301,6 +500,18 @@ fn rg_to_dfs_prefiltered(
     debug_assert_eq!(live_idx_to_col_idx.len(), num_live_columns);
     debug_assert_eq!(dead_idx_to_col_idx.len(), num_dead_columns);
 
+    // An equality or membership test of a single string column can be evaluated on the
+    // dictionary of the column.
+    let dictionary_predicate = predicate.as_dictionary_predicate().filter(|p| {
+        live_idx_to_col_idx.len() == 1
+            && p.values.dtype() == &DataType::String
+            && schema
+                .get_at_index(live_idx_to_col_idx[0])
+                .is_some_and(|(name, field)| {
+                    name == &p.column && field.dtype == ArrowDataType::Utf8View
+                })
+    });
+
     let mask_setting = PrefilterMaskSetting::init_from_env();
 
     let dfs: Vec<Option<DataFrame>> = POOL.install(move || {
@@ -320,53 +531,103 @@ fn rg_to_dfs_prefiltered(
                     }
                 }
 
-                // Collect the data for the live columns
-                let live_columns = (0..num_live_columns)
-                    .into_par_iter()
-                    .map(|i| {
-                        let col_idx = live_idx_to_col_idx[i];
-
+                let dictionary_prefiltered = match &dictionary_predicate {
+                    Some(dictionary_predicate) => {
+                        let col_idx = live_idx_to_col_idx[0];
                         let name = schema.get_at_index(col_idx).unwrap().0;
-                        let field_md = file_metadata.row_groups[rg_idx]
-                            .columns_under_root_iter(name)
-                            .collect::<Vec<_>>();
-
-                        column_idx_to_series(col_idx, field_md.as_slice(), None, schema, store)
-                            .map(Column::from)
-                    })
-                    .collect::<PolarsResult<Vec<_>>>()?;
-
-                // Apply the predicate to the live columns and save the dataframe and the bitmask
-                let md = &file_metadata.row_groups[rg_idx];
-                let mut df = unsafe { DataFrame::new_no_checks(live_columns) };
+                        let field_md = md.columns_under_root_iter(name).collect::<Vec<_>>();
+                        dictionary_prefilter(
+                            col_idx,
+                            field_md.as_slice(),
+                            dictionary_predicate,
+                            schema,
+                            store,
+                        )?
+                    },
+                    None => None,
+                };
 
-                materialize_hive_partitions(
-                    &mut df,
-                    schema.as_ref(),
-                    hive_partition_columns,
-                    md.num_rows(),
-                );
-                let s = predicate.evaluate_io(&df)?;
-                let mask = s.bool().expect("filter predicates was not of type boolean");
+                let (df, filter_mask) = match dictionary_prefiltered {
+                    Some((column, filter_mask)) => {
+                        let num_rows = column.len();
+                        let mut df =
+                            unsafe { DataFrame::new_no_checks(vec![column.into_column()]) };
+
+                        materialize_hive_partitions(
+                            &mut df,
+                            schema.as_ref(),
+                            hive_partition_columns,
+                            num_rows,
+                        );
+                        if let Some(rc) = &row_index {
+                            let offset = rg_offsets[rg_idx] + rc.offset;
+                            let index = filter_mask
+                                .true_idx_iter()
+                                .map(|i| offset + i as IdxSize)
+                                .collect::<Vec<_>>();
+                            df.insert_column(0, IdxCa::from_vec(rc.name.clone(), index))?;
+                        }
+                        (df, filter_mask)
+                    },
+                    None => {
+                        // Collect the data for the live columns
+                        let live_columns = (0..num_live_columns)
+                            .into_par_iter()
+                            .map(|i| {
+                                let col_idx = live_idx_to_col_idx[i];
+
+                                let name = schema.get_at_index(col_idx).unwrap().0;
+                                let field_md = file_metadata.row_groups[rg_idx]
+                                    .columns_under_root_iter(name)
+                                    .collect::<Vec<_>>();
+
+                                column_idx_to_series(
+                                    col_idx,
+                                    field_md.as_slice(),
+                                    None,
+                                    schema,
+                                    store,
+                                )
+                                .map(Column::from)
+                            })
+                            .collect::<PolarsResult<Vec<_>>>()?;
+
+                        // Apply the predicate to the live columns and save the dataframe and the bitmask
+                        let md = &file_metadata.row_groups[rg_idx];
+                        let mut df = unsafe { DataFrame::new_no_checks(live_columns) };
+
+                        materialize_hive_partitions(
+                            &mut df,
+                            schema.as_ref(),
+                            hive_partition_columns,
+                            md.num_rows(),
+                        );
+                        let s = predicate.evaluate_io(&df)?;
+                        let mask = s.bool().expect("filter predicates was not of type boolean");
 
-                if let Some(rc) = &row_index {
-                    df.with_row_index_mut(rc.name.clone(), Some(rg_offsets[rg_idx] + rc.offset));
-                }
-                df = df.filter(mask)?;
+                        if let Some(rc) = &row_index {
+                            df.with_row_index_mut(
+                                rc.name.clone(),
+                                Some(rg_offsets[rg_idx] + rc.offset),
+                            );
+                        }
+                        df = df.filter(mask)?;
 
-                let mut filter_mask = MutableBitmap::with_capacity(mask.len());
+                        let mut filter_mask = MutableBitmap::with_capacity(mask.len());
 
-                // We need to account for the validity of the items
-                for chunk in mask.downcast_iter() {
-                    match chunk.validity() {
-                        None => filter_mask.extend_from_bitmap(chunk.values()),
-                        Some(validity) => {
-                            filter_mask.extend_from_bitmap(&(validity & chunk.values()))
-                        },
-                    }
-                }
+                        // We need to account for the validity of the items
+                        for chunk in mask.downcast_iter() {
+                            match chunk.validity() {
+                                None => filter_mask.extend_from_bitmap(chunk.values()),
+                                Some(validity) => {
+                                    filter_mask.extend_from_bitmap(&(validity & chunk.values()))
+                                },
+                            }
+                        }
 
-                let filter_mask = filter_mask.freeze();
+                        (df, filter_mask.freeze())
+                    },
+                };
 
                 debug_assert_eq!(md.num_rows(), filter_mask.len());
                 debug_assert_eq!(df.height(), filter_mask.set_bits());
@@ -393,7 +654,7 @@ fn rg_to_dfs_prefiltered(
                         #[cfg(debug_assertions)]
                         {
                             let md = &file_metadata.row_groups[rg_idx];
-                            debug_assert_eq!(md.num_rows(), mask.len());
+                            debug_assert_eq!(md.num_rows(), filter_mask.len());
                         }
                         let field_md = file_metadata.row_groups[rg_idx]
                             .columns_under_root_iter(name)
@@ -417,7 +678,7 @@ fn rg_to_dfs_prefiltered(
                                 store,
                             )?;
 
-                            debug_assert_eq!(array.len(), mask.len());
+                            debug_assert_eq!(array.len(), filter_mask.len());
 
                             let mask_arr = BooleanArray::new(
                                 ArrowDataType::Boolean,
@@ -722,6 +983,7 @@ pub fn read_parquet<R: MmapBytesReader>(
     row_index: Option<RowIndex>,
     use_statistics: bool,
     hive_partition_columns: Option<&[Series]>,
+    salvage: Option<&RowGroupSalvage>,
 ) -> PolarsResult<DataFrame> {
     // Fast path.
     if slice.1 == 0 {
@@ -785,7 +1047,7 @@ pub fn read_parquet<R: MmapBytesReader>(
             .into_mem_slice(),
     );
 
-    let dfs = rg_to_dfs(
+    let dfs = rg_to_dfs_salvaged(
         &store,
         &mut 0,
         0,
@@ -799,6 +1061,7 @@ pub fn read_parquet<R: MmapBytesReader>(
         &materialized_projection,
         use_statistics,
         hive_partition_columns,
+        salvage,
     )?;
 
     if dfs.is_empty() {
@@ -930,6 +1193,8 @@ pub struct BatchedParquetReader {
     use_statistics: bool,
     hive_partition_columns: Option<Arc<[Series]>>,
     include_file_path: Option<StringChunked>,
+    /// Where the skipped row groups are recorded, if corrupt row groups are skipped.
+    salvage: Option<RowGroupSalvage>,
     /// Has returned at least one materialized frame.
     has_returned: bool,
 }
@@ -949,6 +1214,7 @@ impl BatchedParquetReader {
         hive_partition_columns: Option<Vec<Series>>,
         include_file_path: Option<(PlSmallStr, Arc<str>)>,
         mut parallel: ParallelStrategy,
+        salvage: Option<RowGroupSalvage>,
     ) -> PolarsResult<Self> {
         let n_row_groups = metadata.row_groups.len();
         let projection = projection
@@ -988,6 +1254,7 @@ impl BatchedParquetReader {
             hive_partition_columns: hive_partition_columns.map(Arc::from),
             include_file_path: include_file_path
                 .map(|(col, path)| StringChunked::full(col, &path, 1)),
+            salvage,
             has_returned: false,
         })
     }
@@ -1004,6 +1271,97 @@ impl BatchedParquetReader {
         self.row_group_offset + n > self.n_row_groups
     }
 
+    /// Decode the row groups in `row_group_range` of `store`, skipping the corrupt ones if a
+    /// `salvage` is given.
+    async fn decode_row_groups(
+        &mut self,
+        store: ColumnStore,
+        row_group_range: Range<usize>,
+        salvage: Option<RowGroupSalvage>,
+    ) -> PolarsResult<Vec<DataFrame>> {
+        match store {
+            ColumnStore::Local(_) => {
+                let mut rows_read = self.rows_read;
+                let dfs = rg_to_dfs_salvaged(
+                    &store,
+                    &mut rows_read,
+                    row_group_range.start,
+                    row_group_range.end,
+                    self.slice,
+                    &self.metadata,
+                    &self.schema,
+                    self.predicate.as_deref(),
+                    self.row_index.clone(),
+                    self.parallel,
+                    &self.projection,
+                    self.use_statistics,
+                    self.hive_partition_columns.as_deref(),
+                    salvage.as_ref(),
+                )?;
+                self.rows_read = rows_read;
+                Ok(dfs)
+            },
+            #[cfg(feature = "async")]
+            ColumnStore::Fetched(b) => {
+                // This branch we spawn the decoding and decompression of the bytes on a rayon task.
+                // This will ensure we don't block the async thread.
+
+                // Reconstruct as that makes it a 'static.
+                let store = ColumnStore::Fetched(b);
+                let (tx, rx) = tokio::sync::oneshot::channel();
+
+                // Make everything 'static.
+                let mut rows_read = self.rows_read;
+                let row_index = self.row_index.clone();
+                let predicate = self.predicate.clone();
+                let schema = self.schema.clone();
+                let metadata = self.metadata.clone();
+                let parallel = self.parallel;
+                let projection = self.projection.clone();
+                let use_statistics = self.use_statistics;
+                let hive_partition_columns = self.hive_partition_columns.clone();
+                let slice = self.slice;
+
+                let f = move || {
+                    let dfs = rg_to_dfs_salvaged(
+                        &store,
+                        &mut rows_read,
+                        row_group_range.start,
+                        row_group_range.end,
+                        slice,
+                        &metadata,
+                        &schema,
+                        predicate.as_deref(),
+                        row_index,
+                        parallel,
+                        &projection,
+                        use_statistics,
+                        hive_partition_columns.as_deref(),
+                        salvage.as_ref(),
+                    );
+
+                    // Don't unwrap send attempt - async task could be cancelled.
+                    let _ = tx.send((dfs, rows_read));
+                };
+
+                // Spawn the task and wait on it asynchronously.
+                if POOL.current_thread_index().is_some() {
+                    // We are a rayon thread, so we can't use POOL.spawn as it would mean we spawn a task and block until
+                    // another rayon thread executes it - we would deadlock if all rayon threads did this.
+                    // Safety: The tokio runtime flavor is multi-threaded.
+                    tokio::task::block_in_place(f);
+                } else {
+                    POOL.spawn(f);
+                };
+
+                let (dfs, rows_read) = rx.await.unwrap();
+                let dfs = dfs?;
+                self.rows_read = rows_read;
+                Ok(dfs)
+            },
+        }
+    }
+
     pub async fn next_batches(&mut self, n: usize) -> PolarsResult<Option<Vec<DataFrame>>> {
         if self.rows_read as usize == self.slice.0 + self.slice.1 && self.has_returned {
             return if self.chunks_fifo.is_empty() {
@@ -1033,80 +1391,31 @@ impl BatchedParquetReader {
                 .row_group_fetcher
                 .fetch_row_groups(row_group_range.clone())
                 .await?;
-
-            let mut dfs = match store {
-                ColumnStore::Local(_) => rg_to_dfs(
-                    &store,
-                    &mut self.rows_read,
-                    row_group_range.start,
-                    row_group_range.end,
-                    self.slice,
-                    &self.metadata,
-                    &self.schema,
-                    self.predicate.as_deref(),
-                    self.row_index.clone(),
-                    self.parallel,
-                    &self.projection,
-                    self.use_statistics,
-                    self.hive_partition_columns.as_deref(),
-                ),
-                #[cfg(feature = "async")]
-                ColumnStore::Fetched(b) => {
-                    // This branch we spawn the decoding and decompression of the bytes on a rayon task.
-                    // This will ensure we don't block the async thread.
-
-                    // Reconstruct as that makes it a 'static.
-                    let store = ColumnStore::Fetched(b);
-                    let (tx, rx) = tokio::sync::oneshot::channel();
-
-                    // Make everything 'static.
-                    let mut rows_read = self.rows_read;
-                    let row_index = self.row_index.clone();
-                    let predicate = self.predicate.clone();
-                    let schema = self.schema.clone();
-                    let metadata = self.metadata.clone();
-                    let parallel = self.parallel;
-                    let projection = self.projection.clone();
-                    let use_statistics = self.use_statistics;
-                    let hive_partition_columns = self.hive_partition_columns.clone();
-                    let slice = self.slice;
-
-                    let f = move || {
-                        let dfs = rg_to_dfs(
-                            &store,
-                            &mut rows_read,
+            let mut dfs = match (
+                self.decode_row_groups(store, row_group_range.clone(), None)
+                    .await,
+                self.salvage.clone(),
+            ) {
+                (Err(err), Some(salvage)) if is_decode_error(&err) => {
+                    if verbose() {
+                        eprintln!(
+                            "parquet: retrying row groups {}..{} of {}: {err}",
                             row_group_range.start,
                             row_group_range.end,
-                            slice,
-                            &metadata,
-                            &schema,
-                            predicate.as_deref(),
-                            row_index,
-                            parallel,
-                            &projection,
-                            use_statistics,
-                            hive_partition_columns.as_deref(),
+                            salvage.source()
                         );
-
-                        // Don't unwrap send attempt - async task could be cancelled.
-                        let _ = tx.send((dfs, rows_read));
-                    };
-
-                    // Spawn the task and wait on it asynchronously.
-                    if POOL.current_thread_index().is_some() {
-                        // We are a rayon thread, so we can't use POOL.spawn as it would mean we spawn a task and block until
-                        // another rayon thread executes it - we would deadlock if all rayon threads did this.
-                        // Safety: The tokio runtime flavor is multi-threaded.
-                        tokio::task::block_in_place(f);
-                    } else {
-                        POOL.spawn(f);
-                    };
-
-                    let (dfs, rows_read) = rx.await.unwrap();
-                    self.rows_read = rows_read;
-                    dfs
+                    }
+                    // The error may be transient, e.g. a corrupted transfer, so fetch the row
+                    // groups again before skipping the corrupt ones.
+                    let store = self
+                        .row_group_fetcher
+                        .fetch_row_groups(row_group_range.clone())
+                        .await?;
+                    self.decode_row_groups(store, row_group_range, Some(salvage))
+                        .await?
                 },
-            }?;
+                (dfs, _) => dfs?,
+            };
 
             if let Some(ca) = self.include_file_path.as_mut() {
                 let mut max_len = 0;
@@ -11,15 +11,19 @@ use polars_parquet::read;
 use super::async_impl::FetchRowGroupsFromObjectStore;
 #[cfg(feature = "cloud")]
 use super::async_impl::ParquetObjectStore;
+use super::predicates::min_max_from_statistics;
 pub use super::read_impl::BatchedParquetReader;
 use super::read_impl::{compute_row_group_range, read_parquet, FetchRowGroupsFromMmapReader};
+use super::salvage::RowGroupSalvage;
 #[cfg(feature = "cloud")]
 use super::utils::materialize_empty_df;
-use super::utils::projected_arrow_schema_to_projection_indices;
+use super::utils::{
+    projected_arrow_schema_to_projection_indices, resolve_field_ids, with_categorical_strings,
+};
 #[cfg(feature = "cloud")]
 use crate::cloud::CloudOptions;
 use crate::mmap::MmapBytesReader;
-use crate::parquet::metadata::FileMetadataRef;
+use crate::parquet::metadata::{FileMetadata, FileMetadataRef};
 use crate::predicates::PhysicalIoExpr;
 use crate::prelude::*;
 use crate::RowIndex;
@@ -41,6 +45,7 @@ pub struct ParquetReader<R: Read + Seek> {
     hive_partition_columns: Option<Vec<Series>>,
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     use_statistics: bool,
+    salvage: Option<RowGroupSalvage>,
 }
 
 impl<R: MmapBytesReader> ParquetReader<R> {
@@ -90,11 +95,12 @@ impl<R: MmapBytesReader> ParquetReader<R> {
     ) -> PolarsResult<Self> {
         let schema = self.schema()?;
 
-        if let Some(projected_arrow_schema) = projected_arrow_schema {
+        let expected_schema = if let Some(projected_arrow_schema) = projected_arrow_schema {
             self.projection = projected_arrow_schema_to_projection_indices(
                 schema.as_ref(),
                 projected_arrow_schema,
             )?;
+            projected_arrow_schema
         } else {
             if schema.len() > first_schema.len() {
                 polars_bail!(
@@ -105,8 +111,21 @@ impl<R: MmapBytesReader> ParquetReader<R> {
 
             self.projection =
                 projected_arrow_schema_to_projection_indices(schema.as_ref(), first_schema)?;
-        }
+            first_schema
+        };
+        self.schema = Some(with_categorical_strings(schema, expected_schema));
+
+        Ok(self)
+    }
 
+    /// Resolve the columns of the file by their Parquet field ids in `reference`, the metadata of
+    /// another file of the same dataset, instead of by their names.
+    pub fn with_field_ids(mut self, reference: &FileMetadata) -> PolarsResult<Self> {
+        let metadata = self.get_metadata()?.clone();
+        let schema = self.schema()?;
+        let (metadata, schema) = resolve_field_ids(&metadata, &schema, reference);
+        self.metadata = Some(Arc::new(metadata));
+        self.schema = Some(schema);
         Ok(self)
     }
 
@@ -130,12 +149,33 @@ impl<R: MmapBytesReader> ParquetReader<R> {
         self
     }
 
+    /// Skip the row groups that cannot be decoded instead of failing, and record them in
+    /// `salvage`, see [`SkippedRowGroups::for_source`](super::SkippedRowGroups::for_source).
+    /// `None` disables skipping.
+    pub fn skip_corrupt_row_groups(mut self, salvage: Option<RowGroupSalvage>) -> Self {
+        self.salvage = salvage;
+        self
+    }
+
     /// Number of rows in the parquet file.
     pub fn num_rows(&mut self) -> PolarsResult<usize> {
         let metadata = self.get_metadata()?;
         Ok(metadata.num_rows)
     }
 
+    /// The minimum and maximum of the column `name` according to the statistics of the file, or
+    /// `None` if not all row groups have statistics for the column.
+    pub fn min_max_from_statistics(
+        &mut self,
+        name: &str,
+    ) -> PolarsResult<Option<(Scalar, Scalar)>> {
+        let schema = self.schema()?;
+        let Some(field) = schema.get(name) else {
+            return Ok(None);
+        };
+        min_max_from_statistics(self.get_metadata()?, field)
+    }
+
     pub fn with_hive_partition_columns(mut self, columns: Option<Vec<Series>>) -> Self {
         self.hive_partition_columns = columns;
         self
@@ -187,6 +227,7 @@ impl<R: MmapBytesReader + 'static> ParquetReader<R> {
             self.hive_partition_columns,
             self.include_file_path,
             self.parallel,
+            self.salvage,
         )
     }
 }
@@ -209,6 +250,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             use_statistics: true,
             hive_partition_columns: None,
             include_file_path: None,
+            salvage: None,
         }
     }
 
@@ -237,6 +279,7 @@ impl<R: MmapBytesReader> SerReader<R> for ParquetReader<R> {
             self.row_index,
             self.use_statistics,
             self.hive_partition_columns.as_deref(),
+            self.salvage.as_ref(),
         )?;
 
         if self.rechunk {
@@ -275,6 +318,7 @@ pub struct ParquetAsyncReader {
     include_file_path: Option<(PlSmallStr, Arc<str>)>,
     schema: Option<ArrowSchemaRef>,
     parallel: ParallelStrategy,
+    salvage: Option<RowGroupSalvage>,
 }
 
 #[cfg(feature = "cloud")]
@@ -296,6 +340,7 @@ impl ParquetAsyncReader {
             include_file_path: None,
             schema: None,
             parallel: Default::default(),
+            salvage: None,
         })
     }
 
@@ -306,11 +351,12 @@ impl ParquetAsyncReader {
     ) -> PolarsResult<Self> {
         let schema = self.schema().await?;
 
-        if let Some(projected_arrow_schema) = projected_arrow_schema {
+        let expected_schema = if let Some(projected_arrow_schema) = projected_arrow_schema {
             self.projection = projected_arrow_schema_to_projection_indices(
                 schema.as_ref(),
                 projected_arrow_schema,
             )?;
+            projected_arrow_schema
         } else {
             if schema.len() > first_schema.len() {
                 polars_bail!(
@@ -321,8 +367,21 @@ impl ParquetAsyncReader {
 
             self.projection =
                 projected_arrow_schema_to_projection_indices(schema.as_ref(), first_schema)?;
-        }
+            first_schema
+        };
+        self.schema = Some(with_categorical_strings(schema, expected_schema));
+
+        Ok(self)
+    }
 
+    /// Resolve the columns of the file by their Parquet field ids in `reference`, the metadata of
+    /// another file of the same dataset, instead of by their names.
+    pub async fn with_field_ids(mut self, reference: &FileMetadata) -> PolarsResult<Self> {
+        let metadata = self.get_metadata().await?.clone();
+        let schema = self.schema().await?;
+        let (metadata, schema) = resolve_field_ids(&metadata, &schema, reference);
+        self.reader.set_metadata(Arc::new(metadata));
+        self.schema = Some(schema);
         Ok(self)
     }
 
@@ -395,6 +454,15 @@ impl ParquetAsyncReader {
         self
     }
 
+    /// Skip the row groups that cannot be decoded instead of failing, after fetching them once
+    /// more, and record them in `salvage`, see
+    /// [`SkippedRowGroups::for_source`](super::SkippedRowGroups::for_source). `None` disables
+    /// skipping.
+    pub fn skip_corrupt_row_groups(mut self, salvage: Option<RowGroupSalvage>) -> Self {
+        self.salvage = salvage;
+        self
+    }
+
     pub async fn batched(mut self, chunk_size: usize) -> PolarsResult<BatchedParquetReader> {
         let metadata = self.reader.get_metadata().await?.clone();
         let schema = match self.schema {
@@ -429,6 +497,7 @@ impl ParquetAsyncReader {
             self.hive_partition_columns,
             self.include_file_path,
             self.parallel,
+            self.salvage,
         )
     }
 
@@ -0,0 +1,81 @@
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+
+/// A row group that a scan with [`ParquetOptions::skip_corrupt_row_groups`] skipped because it
+/// could not be decoded.
+///
+/// [`ParquetOptions::skip_corrupt_row_groups`]: super::ParquetOptions::skip_corrupt_row_groups
+#[derive(Clone, Debug)]
+pub struct SkippedRowGroup {
+    /// The file that the row group belongs to.
+    pub source: Arc<str>,
+    /// The index of the row group in the file.
+    pub row_group: usize,
+    /// The rows of the file that the row group holds.
+    pub rows: Range<usize>,
+    /// The error that the decoding failed with.
+    pub error: String,
+}
+
+/// Collects the row groups that the readers of a query skipped, in the order they were skipped.
+///
+/// Clones share the same collection, so a clone can be handed to the readers while the original
+/// is kept to [`take`](Self::take) the skipped row groups once the query has run. Two collectors
+/// are only equal if they share their collection.
+#[derive(Clone, Debug, Default)]
+pub struct SkippedRowGroups(Arc<Mutex<Vec<SkippedRowGroup>>>);
+
+impl SkippedRowGroups {
+    /// Take the row groups that were skipped since the last call.
+    pub fn take(&self) -> Vec<SkippedRowGroup> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+
+    /// Record the row groups that are skipped while reading `source` in this collector.
+    pub fn for_source(&self, source: Arc<str>) -> RowGroupSalvage {
+        RowGroupSalvage {
+            source,
+            skipped: self.clone(),
+        }
+    }
+}
+
+impl PartialEq for SkippedRowGroups {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for SkippedRowGroups {}
+
+/// Where a reader that skips corrupt row groups records them, see
+/// [`SkippedRowGroups::for_source`].
+#[derive(Clone, Debug)]
+pub struct RowGroupSalvage {
+    source: Arc<str>,
+    skipped: SkippedRowGroups,
+}
+
+impl RowGroupSalvage {
+    pub(super) fn source(&self) -> &Arc<str> {
+        &self.source
+    }
+
+    pub(super) fn record(&self, row_group: usize, rows: Range<usize>, error: PolarsError) {
+        if verbose() {
+            eprintln!(
+                "parquet: skipping corrupt row group {row_group} (rows {}..{}) of {}: {error}",
+                rows.start, rows.end, self.source
+            );
+        }
+        self.skipped.0.lock().unwrap().push(SkippedRowGroup {
+            source: self.source.clone(),
+            row_group,
+            rows,
+            error: error.to_string(),
+        });
+    }
+}
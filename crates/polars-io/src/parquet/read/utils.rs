@@ -1,7 +1,13 @@
 use std::borrow::Cow;
+use std::sync::Arc;
 
-use polars_core::prelude::{ArrowSchema, DataFrame, DataType, Series, IDX_DTYPE};
+use arrow::datatypes::ArrowSchemaRef;
+use polars_core::prelude::{
+    ArrowDataType, ArrowField, ArrowSchema, DataFrame, DataType, InitHashMaps, PlHashMap,
+    PlHashSet, Series, DTYPE_ENUM_KEY, IDX_DTYPE,
+};
 use polars_error::{polars_bail, PolarsResult};
+use polars_parquet::read::FileMetadata;
 
 use crate::hive::materialize_hive_partitions;
 use crate::utils::apply_projection;
@@ -37,25 +43,118 @@ pub(super) fn projected_arrow_schema_to_projection_indices(
     let mut projection_indices = Vec::with_capacity(projected_arrow_schema.len());
     let mut is_full_ordered_projection = projected_arrow_schema.len() == schema.len();
 
-    for (i, field) in projected_arrow_schema.iter_values().enumerate() {
+    for (i, expected_field) in projected_arrow_schema.iter_values().enumerate() {
         let dtype = {
-            let Some((idx, _, field)) = schema.get_full(&field.name) else {
-                polars_bail!(SchemaMismatch: "did not find column in file: {}", field.name)
+            let Some((idx, _, field)) = schema.get_full(&expected_field.name) else {
+                polars_bail!(SchemaMismatch: "did not find column in file: {}", expected_field.name)
             };
 
             projection_indices.push(idx);
             is_full_ordered_projection &= idx == i;
 
+            if is_categorical_string(&field.dtype, expected_field) {
+                continue;
+            }
+
             DataType::from_arrow(&field.dtype, true)
         };
-        let expected_dtype = DataType::from_arrow(&field.dtype, true);
+        let expected_dtype = DataType::from_arrow(&expected_field.dtype, true);
 
         if dtype.clone() != expected_dtype {
             polars_bail!(SchemaMismatch: "data type mismatch for column {}: found: {}, expected: {}",
-                &field.name, dtype, expected_dtype
+                &expected_field.name, dtype, expected_dtype
             )
         }
     }
 
     Ok((!is_full_ordered_projection).then_some(projection_indices))
 }
+
+/// Whether a string column of the file with `dtype` is expected as a `Categorical`.
+fn is_categorical_string(dtype: &ArrowDataType, expected: &ArrowField) -> bool {
+    cfg!(feature = "dtype-categorical")
+        && matches!(dtype, ArrowDataType::Utf8View)
+        && matches!(
+            &expected.dtype,
+            ArrowDataType::Dictionary(_, values, _) if values.as_ref() == &ArrowDataType::Utf8View
+        )
+        && !expected.metadata.contains_key(DTYPE_ENUM_KEY)
+}
+
+/// Give the string columns of the file schema that are expected as `Categorical` the dictionary
+/// dtype of the expected schema. These columns are then decoded from the dictionary pages
+/// directly instead of materializing all strings first.
+pub(super) fn with_categorical_strings(
+    schema: ArrowSchemaRef,
+    expected_schema: &ArrowSchema,
+) -> ArrowSchemaRef {
+    let mut schema = schema;
+    for expected_field in expected_schema.iter_values() {
+        let Some(field) = schema.get(&expected_field.name) else {
+            continue;
+        };
+        if is_categorical_string(&field.dtype, expected_field) {
+            let field = Arc::make_mut(&mut schema)
+                .try_get_mut(&expected_field.name)
+                .unwrap();
+            field.dtype = expected_field.dtype.clone();
+        }
+    }
+    schema
+}
+
+/// Resolve the top-level columns of a file by their Parquet field ids instead of their names.
+///
+/// Columns are renamed to the name of the column with the same field id in `reference`, so that
+/// files written before a column was renamed are read correctly. Columns without a field id keep
+/// their name, columns with a field id that `reference` does not know are left out.
+pub fn resolve_field_ids(
+    md: &FileMetadata,
+    schema: &ArrowSchema,
+    reference: &FileMetadata,
+) -> (FileMetadata, ArrowSchemaRef) {
+    let reference_names = reference
+        .schema()
+        .fields()
+        .iter()
+        .filter_map(|field| {
+            let info = field.get_field_info();
+            Some((info.id?, &info.name))
+        })
+        .collect::<PlHashMap<_, _>>();
+
+    let mut renames = PlHashMap::with_capacity(schema.len());
+    let (with_id, without_id): (Vec<_>, Vec<_>) = md
+        .schema()
+        .fields()
+        .iter()
+        .map(|field| field.get_field_info())
+        .partition(|info| info.id.is_some());
+    for info in with_id {
+        if let Some(&name) = reference_names.get(&info.id.unwrap()) {
+            renames.insert(info.name.clone(), name.clone());
+        }
+    }
+    // A renamed column takes precedence over a column without field id of the same name.
+    let taken = renames.values().cloned().collect::<PlHashSet<_>>();
+    for info in without_id {
+        if !taken.contains(&info.name) {
+            renames.insert(info.name.clone(), info.name.clone());
+        }
+    }
+
+    let mut md = md.clone();
+    for rg in md.row_groups.iter_mut() {
+        rg.rename_roots(&renames);
+    }
+    let schema = schema
+        .iter_values()
+        .filter_map(|field| {
+            let name = renames.get(&field.name)?;
+            let mut field = field.clone();
+            field.name = name.clone();
+            Some((name.clone(), field))
+        })
+        .collect();
+    (md, Arc::new(schema))
+}
@@ -28,6 +28,33 @@ impl WriteDataFrameToFile for IpcWriterOptions {
     }
 }
 
+/// The relative directory of a hive partition, e.g. `year=2024/month=1`, formatted from the first
+/// row of the `partition_by_col_idx` columns of `df`.
+pub fn get_hive_path_part(df: &DataFrame, partition_by_col_idx: &[usize]) -> String {
+    let cols = df.get_columns();
+
+    partition_by_col_idx
+        .iter()
+        .map(|&i| {
+            let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
+
+            format!(
+                "{}={}",
+                s.name(),
+                percent_encoding::percent_encode(
+                    s.str()
+                        .unwrap()
+                        .get(0)
+                        .unwrap_or("__HIVE_DEFAULT_PARTITION__")
+                        .as_bytes(),
+                    URL_ENCODE_CHAR_SET
+                )
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
 fn write_partitioned_dataset_impl<W>(
     df: &mut DataFrame,
     path: &Path,
@@ -61,30 +88,7 @@ where
             })
             .collect::<PolarsResult<Vec<_>>>()?;
 
-        move |df: &DataFrame| {
-            let cols = df.get_columns();
-
-            partition_by_col_idx
-                .iter()
-                .map(|&i| {
-                    let s = &cols[i].slice(0, 1).cast(&DataType::String).unwrap();
-
-                    format!(
-                        "{}={}",
-                        s.name(),
-                        percent_encoding::percent_encode(
-                            s.str()
-                                .unwrap()
-                                .get(0)
-                                .unwrap_or("__HIVE_DEFAULT_PARTITION__")
-                                .as_bytes(),
-                            URL_ENCODE_CHAR_SET
-                        )
-                    )
-                })
-                .collect::<Vec<_>>()
-                .join("/")
-        }
+        move |df: &DataFrame| get_hive_path_part(df, &partition_by_col_idx)
     };
 
     let base_path = path;
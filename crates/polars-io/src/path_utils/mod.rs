@@ -13,13 +13,17 @@ mod hugging_face;
 
 use crate::cloud::CloudOptions;
 
-pub static POLARS_TEMP_DIR_BASE_PATH: Lazy<Box<Path>> = Lazy::new(|| {
-    let path = std::env::var("POLARS_TEMP_DIR")
+/// The path of the temporary directory of polars, without creating it.
+pub(crate) fn polars_temp_dir_base_path() -> PathBuf {
+    std::env::var("POLARS_TEMP_DIR")
         .map(PathBuf::from)
         .unwrap_or_else(|_| {
             PathBuf::from(std::env::temp_dir().to_string_lossy().as_ref()).join("polars/")
         })
-        .into_boxed_path();
+}
+
+pub static POLARS_TEMP_DIR_BASE_PATH: Lazy<Box<Path>> = Lazy::new(|| {
+    let path = polars_temp_dir_base_path().into_boxed_path();
 
     if let Err(err) = std::fs::create_dir_all(path.as_ref()) {
         if !path.is_dir() {
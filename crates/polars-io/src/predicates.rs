@@ -17,6 +17,23 @@ pub trait PhysicalIoExpr: Send + Sync {
     fn as_stats_evaluator(&self) -> Option<&dyn StatsEvaluator> {
         None
     }
+
+    /// Get the predicate as a [`DictionaryPredicate`] if it only tests a single column for
+    /// equality or membership.
+    fn as_dictionary_predicate(&self) -> Option<DictionaryPredicate> {
+        None
+    }
+}
+
+/// A predicate `col(column) == value` or `col(column).is_in(values)`.
+///
+/// Readers can evaluate such a predicate once for every entry of the dictionary of an encoded
+/// column instead of once for every row.
+#[derive(Debug, Clone)]
+pub struct DictionaryPredicate {
+    pub column: PlSmallStr,
+    /// The values the column is compared with. A row matches if it equals any of them.
+    pub values: Series,
 }
 
 pub trait StatsEvaluator {
@@ -4,8 +4,10 @@
 //!
 
 use polars_core::prelude::*;
+use polars_ops::frame::JoinCoalesce;
 pub use polars_plan::dsl::functions::*;
 use polars_plan::prelude::UnionArgs;
+use polars_utils::format_pl_smallstr;
 use rayon::prelude::*;
 
 use crate::prelude::*;
@@ -97,10 +99,99 @@ where
     polars_core::POOL.install(|| iter.map(|lf| lf.collect()).collect())
 }
 
+/// Compute the changes between two versions of a dataset whose rows are identified by `keys`.
+///
+/// Returns a change-data-capture frame with the `keys` and the other columns of every inserted,
+/// updated or deleted row, and the kind of `change` as `"insert"`, `"update"` or `"delete"`.
+/// Inserted and updated rows have their values in `new`, deleted rows their values in `old`.
+/// Rows are matched in a single full join on the keys, where null keys match each other, and
+/// updates are found by comparing the other columns with null equal to null. Both versions
+/// must have the same columns, none named `change`, and unique keys, which the join validates.
+/// The output is sorted by the keys.
+pub fn compute_delta(
+    mut old: LazyFrame,
+    mut new: LazyFrame,
+    keys: &[&str],
+) -> PolarsResult<LazyFrame> {
+    const OLD_SUFFIX: &str = "__POLARS_DELTA_OLD";
+    const IN_OLD: &str = "__POLARS_DELTA_IN_OLD";
+    const IN_NEW: &str = "__POLARS_DELTA_IN_NEW";
+    const CHANGE: &str = "change";
+
+    let old_schema = old.collect_schema()?;
+    let new_schema = new.collect_schema()?;
+    polars_ensure!(
+        old_schema.len() == new_schema.len()
+            && new_schema.iter_names().all(|name| old_schema.contains(name)),
+        SchemaMismatch: "compute_delta requires both versions to have the same columns"
+    );
+    polars_ensure!(
+        !new_schema.contains(CHANGE),
+        Duplicate: "compute_delta cannot add the `{}` column, it is already a column of the data",
+        CHANGE
+    );
+    for key in keys {
+        polars_ensure!(
+            new_schema.contains(key),
+            ColumnNotFound: "compute_delta key `{}` not found", key
+        );
+    }
+    let values = new_schema
+        .iter_names()
+        .filter(|name| !keys.contains(&name.as_str()))
+        .cloned()
+        .collect::<Vec<_>>();
+    let old_name = |name: &PlSmallStr| format_pl_smallstr!("{name}{OLD_SUFFIX}");
+
+    let key_exprs = keys.iter().map(|k| col(*k)).collect::<Vec<_>>();
+    let old = old.select(
+        key_exprs
+            .iter()
+            .cloned()
+            .chain(values.iter().map(|v| col(v.clone()).alias(old_name(v))))
+            .chain([lit(true).alias(IN_OLD)])
+            .collect::<Vec<_>>(),
+    );
+    let new = new.with_column(lit(true).alias(IN_NEW));
+
+    let updated = values
+        .iter()
+        .map(|v| col(v.clone()).eq_missing(col(old_name(v))).not())
+        .reduce(|a, b| a.or(b))
+        .unwrap_or(lit(false));
+    let change = when(col(IN_NEW).is_null())
+        .then(lit("delete"))
+        .when(col(IN_OLD).is_null())
+        .then(lit("insert"))
+        .when(updated)
+        .then(lit("update"))
+        .otherwise(lit(NULL).cast(DataType::String))
+        .alias(CHANGE);
+    let output = key_exprs
+        .iter()
+        .cloned()
+        .chain(values.iter().map(|v| {
+            when(col(IN_NEW).is_null())
+                .then(col(old_name(v)))
+                .otherwise(col(v.clone()))
+                .alias(v.clone())
+        }))
+        .chain([col(CHANGE)])
+        .collect::<Vec<_>>();
+
+    let mut args = JoinArgs::new(JoinType::Full).with_coalesce(JoinCoalesce::CoalesceColumns);
+    args.join_nulls = true;
+    args.validation = JoinValidation::OneToOne;
+    Ok(new
+        .join(old, key_exprs.clone(), key_exprs, args)
+        .with_column(change)
+        .filter(col(CHANGE).is_not_null())
+        .select(output)
+        .sort(keys.iter().copied(), Default::default()))
+}
+
 #[cfg(test)]
 mod test {
-    // used only if feature="diagonal_concat"
-    #[allow(unused_imports)]
     use super::*;
 
     #[test]
@@ -143,4 +234,33 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_compute_delta() -> PolarsResult<()> {
+        let old = df![
+            "id" => [Some(1), Some(2), Some(3), None],
+            "value" => [Some("a"), Some("b"), None, Some("n")],
+        ]?;
+        let new = df![
+            "id" => [Some(4), Some(3), Some(2), None],
+            "value" => [Some("d"), Some("c"), Some("b"), Some("n")],
+        ]?;
+        let out = compute_delta(old.clone().lazy(), new.clone().lazy(), &["id"])?.collect()?;
+        let expected = df![
+            "id" => [1, 3, 4],
+            "value" => ["a", "c", "d"],
+            "change" => ["delete", "update", "insert"],
+        ]?;
+        assert!(out.equals(&expected));
+
+        // The keys must be unique.
+        let duplicated = concat([new.clone().lazy(), new.lazy()], Default::default())?;
+        assert!(compute_delta(old.clone().lazy(), duplicated, &["id"])?
+            .collect()
+            .is_err());
+        // The `change` column cannot be a column of the data.
+        let old = old.lazy().rename(["value"], ["change"]);
+        assert!(compute_delta(old.clone(), old, &["id"]).is_err());
+        Ok(())
+    }
 }
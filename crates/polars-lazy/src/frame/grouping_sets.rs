@@ -0,0 +1,275 @@
+//! Group by over several grouping sets at once, e.g. to compute subtotals and grand totals.
+
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_plan::prelude::UnionArgs;
+use polars_plan::utils::has_expr;
+
+use crate::prelude::*;
+
+/// The name of the column that identifies the grouping set of every output row.
+pub const GROUPING_ID_NAME: &str = "grouping_id";
+const PARTIAL_PREFIX: &str = "__POLARS_GROUPING_SETS_PARTIAL_";
+/// Every key has a bit in the `u32` grouping id.
+const MAX_KEYS: usize = u32::BITS as usize;
+
+/// The grouping id of a set has a bit set for every key that is not grouped on, with the first
+/// key as the most significant bit, like SQL's `GROUPING_ID`.
+fn grouping_id(n_keys: usize, set: &[usize]) -> u32 {
+    (0..n_keys)
+        .filter(|i| !set.contains(i))
+        .fold(0, |id, i| id | 1 << (n_keys - 1 - i))
+}
+
+/// Whether `expr` has a single output column that is known without a schema.
+fn has_single_output(expr: &Expr) -> bool {
+    !has_expr(expr, |e| match e {
+        Expr::Column(name) => name.starts_with('^') && name.ends_with('$'),
+        Expr::Wildcard
+        | Expr::Columns(_)
+        | Expr::DtypeColumn(_)
+        | Expr::IndexColumn(_)
+        | Expr::Nth(_)
+        | Expr::Exclude(..)
+        | Expr::Selector(_)
+        | Expr::KeepName(_)
+        | Expr::RenameAlias { .. } => true,
+        _ => false,
+    }) && expr_output_name(expr).is_ok()
+}
+
+/// How the partial aggregates of the groups of all keys are combined into the groups of a
+/// grouping set.
+#[derive(Clone, Copy)]
+enum Combine {
+    Sum,
+    Min { propagate_nans: bool },
+    Max { propagate_nans: bool },
+}
+
+impl Combine {
+    /// The combination of an aggregation, if it can be computed from the aggregates of finer
+    /// groups.
+    fn of(agg: &Expr) -> Option<Self> {
+        match agg {
+            Expr::Alias(agg, _) => Self::of(agg),
+            Expr::Len | Expr::Agg(AggExpr::Sum(_) | AggExpr::Count(..)) => Some(Self::Sum),
+            Expr::Agg(AggExpr::Min { propagate_nans, .. }) => Some(Self::Min {
+                propagate_nans: *propagate_nans,
+            }),
+            Expr::Agg(AggExpr::Max { propagate_nans, .. }) => Some(Self::Max {
+                propagate_nans: *propagate_nans,
+            }),
+            _ => None,
+        }
+    }
+
+    fn apply(self, partial: Expr) -> Expr {
+        let input = Arc::new(partial);
+        Expr::Agg(match self {
+            Self::Sum => AggExpr::Sum(input),
+            Self::Min { propagate_nans } => AggExpr::Min {
+                input,
+                propagate_nans,
+            },
+            Self::Max { propagate_nans } => AggExpr::Max {
+                input,
+                propagate_nans,
+            },
+        })
+    }
+}
+
+/// Utility struct for a lazy group by over several grouping sets, see
+/// [`LazyFrame::group_by_grouping_sets`], [`LazyFrame::group_by_rollup`] and
+/// [`LazyFrame::group_by_cube`].
+#[derive(Clone)]
+pub struct LazyGroupingSets {
+    input: LazyFrame,
+    keys: Vec<Expr>,
+    /// Every grouping set as the indices of its keys, ordered by grouping id.
+    sets: Vec<Vec<usize>>,
+}
+
+impl LazyGroupingSets {
+    /// The keys are the distinct keys of the `sets`, in order of appearance.
+    pub(crate) fn new(input: LazyFrame, sets: Vec<Vec<Expr>>) -> Self {
+        let mut keys: Vec<Expr> = vec![];
+        let mut sets = sets
+            .into_iter()
+            .map(|set| {
+                let mut set = set
+                    .into_iter()
+                    .map(|key| match keys.iter().position(|k| *k == key) {
+                        Some(i) => i,
+                        None => {
+                            keys.push(key);
+                            keys.len() - 1
+                        },
+                    })
+                    .collect::<Vec<_>>();
+                set.sort_unstable();
+                set.dedup();
+                set
+            })
+            .collect::<Vec<_>>();
+        // Too many keys are rejected by `agg`, they have no grouping id.
+        if keys.len() <= MAX_KEYS {
+            sets.sort_by_key(|set| grouping_id(keys.len(), set));
+        }
+        Self { input, keys, sets }
+    }
+
+    /// The grouping sets `(k1, .., kn), (k1, .., kn-1), .., (k1), ()`.
+    pub(crate) fn rollup(input: LazyFrame, keys: Vec<Expr>) -> Self {
+        let sets = (0..=keys.len()).rev().map(|n| (0..n).collect()).collect();
+        Self { input, keys, sets }
+    }
+
+    /// Every subset of the keys as a grouping set.
+    pub(crate) fn cube(input: LazyFrame, keys: Vec<Expr>) -> Self {
+        let n_keys = keys.len();
+        // Too many keys are rejected by `agg`, don't enumerate their sets.
+        let n_sets = if n_keys > MAX_KEYS {
+            0
+        } else {
+            1usize << n_keys
+        };
+        let sets = (0..n_sets)
+            .map(|id| {
+                (0..n_keys)
+                    .filter(|i| id & (1 << (n_keys - 1 - i)) == 0)
+                    .collect()
+            })
+            .collect();
+        Self { input, keys, sets }
+    }
+
+    /// Group by every grouping set and aggregate.
+    ///
+    /// The results of the grouping sets are concatenated. The keys that are not grouped on in
+    /// a set are null, and the [`GROUPING_ID_NAME`] column tells the grouping set of a row, so
+    /// that subtotals can be told apart from groups of null keys. Duplicate grouping sets
+    /// produce duplicate rows, as in SQL. The grand total of the empty grouping set evaluates
+    /// the aggregations as in a `select`, so it always has exactly one row.
+    ///
+    /// If every aggregation is a `sum`, `min`, `max`, `count` or `len`, the input is aggregated
+    /// once by all keys and the grouping sets are rolled up from those partial aggregates.
+    /// Otherwise the input is computed once and aggregated by every grouping set.
+    pub fn agg<E: AsRef<[Expr]>>(self, aggs: E) -> PolarsResult<LazyFrame> {
+        let aggs = aggs.as_ref();
+        let n_keys = self.keys.len();
+        polars_ensure!(
+            n_keys <= MAX_KEYS,
+            InvalidOperation: "grouping sets support at most {} keys, got {}", MAX_KEYS, n_keys
+        );
+        let opt_state = self.input.get_opt_state();
+
+        let combines = aggs
+            .iter()
+            .map(|agg| Combine::of(agg).filter(|_| has_single_output(agg)))
+            .collect::<Option<Vec<_>>>()
+            .filter(|_| self.keys.iter().all(has_single_output));
+        let inputs = match combines {
+            Some(combines) => self.rollup_partials(aggs, &combines)?,
+            None => self.aggregate_per_set(aggs),
+        };
+        let lp = DslPlan::Union {
+            inputs,
+            args: UnionArgs {
+                to_supertypes: true,
+                diagonal: true,
+                ..Default::default()
+            },
+        };
+
+        // The first grouping set may not have all keys, so restore the order of the keys.
+        let key_names = self
+            .keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect::<Vec<_>>();
+        Ok(LazyFrame::from_logical_plan(lp, opt_state)
+            .select([cols(key_names.clone()), all().exclude(key_names)]))
+    }
+
+    /// Add the grouping id of `set` to the aggregates of that set. A `select` is used such that
+    /// an aggregate named like the grouping id column is an error.
+    fn with_grouping_id(&self, lf: LazyFrame, set: &[usize]) -> DslPlan {
+        let id = lit(grouping_id(self.keys.len(), set)).alias(GROUPING_ID_NAME);
+        lf.select([all(), id]).logical_plan
+    }
+
+    /// Aggregate the input by all keys and roll the grouping sets up from those partial
+    /// aggregates.
+    fn rollup_partials(&self, aggs: &[Expr], combines: &[Combine]) -> PolarsResult<Vec<DslPlan>> {
+        let partial_names = (0..aggs.len())
+            .map(|i| format_pl_smallstr!("{PARTIAL_PREFIX}{i}"))
+            .collect::<Vec<_>>();
+        let partials = aggs
+            .iter()
+            .zip(&partial_names)
+            .map(|(agg, name)| agg.clone().alias(name.clone()))
+            .collect::<Vec<_>>();
+        let finest = self
+            .input
+            .clone()
+            .group_by(self.keys.clone())
+            .agg(partials)
+            .cache();
+
+        let key_names = self
+            .keys
+            .iter()
+            .map(expr_output_name)
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let combined = aggs
+            .iter()
+            .zip(combines)
+            .zip(&partial_names)
+            .map(|((agg, combine), partial)| {
+                Ok(combine
+                    .apply(col(partial.clone()))
+                    .alias(expr_output_name(agg)?))
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        Ok(self
+            .sets
+            .iter()
+            .map(|set| {
+                let lf = if set.is_empty() {
+                    finest.clone().select(combined.clone())
+                } else {
+                    let keys = set
+                        .iter()
+                        .map(|i| col(key_names[*i].clone()))
+                        .collect::<Vec<_>>();
+                    finest.clone().group_by(keys).agg(combined.clone())
+                };
+                self.with_grouping_id(lf, set)
+            })
+            .collect())
+    }
+
+    /// Aggregate the cached input by every grouping set.
+    fn aggregate_per_set(&self, aggs: &[Expr]) -> Vec<DslPlan> {
+        let input = self.input.clone().cache();
+        self.sets
+            .iter()
+            .map(|set| {
+                let lf = if set.is_empty() {
+                    input.clone().select(aggs)
+                } else {
+                    let keys = set
+                        .iter()
+                        .map(|i| self.keys[*i].clone())
+                        .collect::<Vec<_>>();
+                    input.clone().group_by(keys).agg(aggs)
+                };
+                self.with_grouping_id(lf, set)
+            })
+            .collect()
+    }
+}
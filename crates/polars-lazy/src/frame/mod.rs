@@ -6,6 +6,7 @@ mod cached_arenas;
 mod err;
 #[cfg(not(target_arch = "wasm32"))]
 mod exitable;
+mod grouping_sets;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
@@ -24,6 +25,7 @@ pub use csv::*;
 #[cfg(not(target_arch = "wasm32"))]
 pub use exitable::*;
 pub use file_list_reader::*;
+pub use grouping_sets::*;
 #[cfg(feature = "ipc")]
 pub use ipc::*;
 #[cfg(feature = "json")]
@@ -38,10 +40,12 @@ use polars_ops::frame::JoinCoalesce;
 pub use polars_plan::frame::{AllowedOptimizations, OptFlags};
 use polars_plan::global::FETCH_ROWS;
 use polars_utils::pl_str::PlSmallStr;
+#[cfg(feature = "rows")]
+pub use rows::*;
 
 use crate::frame::cached_arenas::CachedArena;
 #[cfg(feature = "streaming")]
-use crate::physical_plan::streaming::insert_streaming_nodes;
+use crate::physical_plan::streaming::{insert_streaming_nodes, prefer_streaming};
 use crate::prelude::*;
 
 pub trait IntoLazy {
@@ -66,6 +70,20 @@ impl IntoLazy for LazyFrame {
     }
 }
 
+/// Seed the random functions of an optimized plan that have no seed of their own: the `i`-th
+/// in the arena gets `seed + i`, so that they all draw different values.
+fn seed_random_functions(expr_arena: &mut Arena<AExpr>, seed: u64) {
+    let mut n_seeded = 0u64;
+    for i in 0..expr_arena.len() {
+        if let AExpr::Function { function, .. } = expr_arena.get_mut(Node(i)) {
+            if let Some(seeded) = function.with_seed(seed.wrapping_add(n_seeded)) {
+                *function = seeded;
+                n_seeded += 1;
+            }
+        }
+    }
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 ///
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
@@ -190,6 +208,17 @@ impl LazyFrame {
         self
     }
 
+    /// Run on the streaming engine when the estimated size of the scanned data is large compared
+    /// to the available memory, even if streaming is not toggled on.
+    ///
+    /// The engine is selected before the plan is optimized, so the estimate covers all columns
+    /// and rows of the scanned files.
+    #[cfg(feature = "streaming")]
+    pub fn with_auto_streaming(mut self, toggle: bool) -> Self {
+        self.opt_state.set(OptFlags::AUTO_STREAMING, toggle);
+        self
+    }
+
     #[cfg(feature = "new_streaming")]
     pub fn with_new_streaming(mut self, toggle: bool) -> Self {
         self.opt_state.set(OptFlags::NEW_STREAMING, toggle);
@@ -256,6 +285,14 @@ impl LazyFrame {
         }
     }
 
+    /// Report how the unions in the plan reconcile the columns of their inputs, to audit a
+    /// concatenation of sources with differing schemas.
+    ///
+    /// See [`DslPlan::union_reconciliation`] and [`UnionColumnMatching`].
+    pub fn union_reconciliation(&self) -> PolarsResult<DataFrame> {
+        self.logical_plan.union_reconciliation()
+    }
+
     /// Add a sort operation to the logical plan.
     ///
     /// Sorts the LazyFrame by the column name specified using the provided options.
@@ -584,8 +621,33 @@ impl LazyFrame {
     ) -> PolarsResult<Node> {
         #[allow(unused_mut)]
         let mut opt_state = self.opt_state;
-        let streaming = self.opt_state.contains(OptFlags::STREAMING);
+        #[allow(unused_mut)]
+        let mut streaming = self.opt_state.contains(OptFlags::STREAMING);
         let new_streaming = self.opt_state.contains(OptFlags::NEW_STREAMING);
+
+        // The engine must be known before optimizing, as the optimizations depend on it. The
+        // conversion of the scans is cached, so converting the plan twice is cheap.
+        #[cfg(feature = "streaming")]
+        if !streaming
+            && !new_streaming
+            && !opt_state.contains(OptFlags::EAGER)
+            && opt_state.contains(OptFlags::AUTO_STREAMING)
+        {
+            let mut lp_arena = Arena::new();
+            let mut expr_arena = Arena::new();
+            let mut conversion_state = opt_state;
+            let root = to_alp(
+                self.logical_plan.clone(),
+                &mut expr_arena,
+                &mut lp_arena,
+                &mut conversion_state,
+            )?;
+            if prefer_streaming(root, &lp_arena, scratch) {
+                streaming = true;
+                opt_state |= OptFlags::STREAMING;
+            }
+        }
+
         #[cfg(feature = "cse")]
         if streaming && !new_streaming {
             opt_state &= !OptFlags::COMM_SUBPLAN_ELIM;
@@ -704,6 +766,22 @@ impl LazyFrame {
     /// }
     /// ```
     pub fn collect(self) -> PolarsResult<DataFrame> {
+        self.collect_impl(None)
+    }
+
+    /// Execute all the lazy operations with `seed` as the seed of the query: the random
+    /// operations without a seed of their own get seeds derived from it, so that the result
+    /// is reproducible regardless of the engine and of thread scheduling.
+    pub fn collect_with_seed(self, seed: u64) -> PolarsResult<DataFrame> {
+        self.collect_impl(Some(seed))
+    }
+
+    fn collect_impl(self, seed: Option<u64>) -> PolarsResult<DataFrame> {
+        let seed_plan = |expr_arena: &mut Arena<AExpr>| {
+            if let Some(seed) = seed {
+                seed_random_functions(expr_arena, seed)
+            }
+        };
         #[cfg(feature = "new_streaming")]
         {
             let auto_new_streaming =
@@ -715,6 +793,7 @@ impl LazyFrame {
                 new_stream_lazy.opt_state |= OptFlags::NEW_STREAMING;
                 new_stream_lazy.opt_state &= !OptFlags::STREAMING;
                 let mut alp_plan = new_stream_lazy.to_alp_optimized()?;
+                seed_plan(&mut alp_plan.expr_arena);
                 let stream_lp_top = alp_plan.lp_arena.add(IR::Sink {
                     input: alp_plan.lp_top,
                     payload: SinkType::Memory,
@@ -748,6 +827,7 @@ impl LazyFrame {
             }
 
             let mut alp_plan = self.to_alp_optimized()?;
+            seed_plan(&mut alp_plan.expr_arena);
             let mut physical_plan = create_physical_plan(
                 alp_plan.lp_top,
                 &mut alp_plan.lp_arena,
@@ -757,7 +837,10 @@ impl LazyFrame {
             physical_plan.execute(&mut state)
         }
         #[cfg(not(feature = "new_streaming"))]
-        self._collect_post_opt(|_, _, _| Ok(()))
+        self._collect_post_opt(|_, _, expr_arena| {
+            seed_plan(expr_arena);
+            Ok(())
+        })
     }
 
     /// Profile a LazyFrame.
@@ -775,6 +858,26 @@ impl LazyFrame {
         Ok((out, timer_df))
     }
 
+    /// Profile a LazyFrame down to the expression level.
+    ///
+    /// This runs the query like [`LazyFrame::profile`], but additionally returns a report that
+    /// attributes time to the individual expressions (including UDFs) evaluated by `select`,
+    /// `with_columns` and `filter` nodes. The report has the columns
+    /// `node`, `expression`, `calls` and `total_time` and is ranked by `total_time`, so the
+    /// heaviest expression comes first.
+    ///
+    /// The units of the timings are microseconds. Expressions that are evaluated in parallel
+    /// are timed individually, so their times can add up to more than the node's time.
+    pub fn profile_expressions(self) -> PolarsResult<(DataFrame, DataFrame, DataFrame)> {
+        let (mut state, mut physical_plan, _) = self.prepare_collect(false)?;
+        state.time_nodes();
+        state.time_expressions();
+        let out = physical_plan.execute(&mut state)?;
+        let expr_df = state.finish_expr_timer()?;
+        let timer_df = state.finish_timer()?;
+        Ok((out, timer_df, expr_df))
+    }
+
     /// Stream a query result into a parquet file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
@@ -1121,6 +1224,118 @@ impl LazyFrame {
         }
     }
 
+    /// Find the islands of consecutive values of `on`, e.g. the uptime of a sensor, and the gaps
+    /// between them, per group of `by`.
+    ///
+    /// Within every group the rows are sorted by `on`, and a new island starts where `on`
+    /// advances by more than `max_gap`. The output has a row for every island and every gap
+    /// with the keys of `by`, the `island` number within the group, the `start` and `end` of
+    /// the interval, the number of rows `len` and whether the row `is_gap`. A gap runs from the
+    /// end of an island to the start of the next one, its `island` is null and its `len` is
+    /// zero. The rows are ordered by `start` within every group. `max_gap` is compared as a
+    /// fixed duration, or as an integer if it is an index duration such as `"3i"`.
+    ///
+    /// Every key of `by` must have an output name, as the keys are columns of the output.
+    #[cfg(feature = "gaps_and_islands")]
+    pub fn gaps_and_islands<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        on: Expr,
+        max_gap: polars_time::Duration,
+        by: E,
+    ) -> PolarsResult<LazyFrame> {
+        const ISLAND: &str = "island";
+        const START: &str = "start";
+        const END: &str = "end";
+        const LEN: &str = "len";
+        const IS_GAP: &str = "is_gap";
+
+        let by = by
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let key_names = by
+            .iter()
+            .map(|e| {
+                expr_output_name(e).map_err(|_| {
+                    polars_err!(InvalidOperation: "gaps_and_islands key '{}' has no output name", e)
+                })
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let per_group = |expr: Expr, keys: &[Expr]| {
+            if keys.is_empty() {
+                expr
+            } else {
+                expr.over(keys)
+            }
+        };
+        let max_gap = if max_gap.parsed_int {
+            let steps = max_gap.duration_ns();
+            lit(if max_gap.negative() { -steps } else { steps })
+        } else {
+            lit(max_gap)
+        };
+
+        // The rows are sorted per group, so a scan over every group finds the starts of the
+        // islands and numbers them.
+        let step = per_group(on.clone() - on.clone().shift(lit(1)), &by);
+        let island = per_group(
+            col(ISLAND)
+                .gt(max_gap)
+                .fill_null(lit(false))
+                .cast(IDX_DTYPE)
+                .cum_sum(false),
+            &by,
+        );
+        let mut sort_by = by.clone();
+        sort_by.push(on.clone());
+        let mut island_keys = by.clone();
+        island_keys.push(col(ISLAND));
+        let opt_state = self.get_opt_state();
+        let islands = self
+            .filter(on.clone().is_not_null())
+            .sort_by_exprs(sort_by, Default::default())
+            .with_column(step.alias(ISLAND))
+            .with_column(island.alias(ISLAND))
+            .group_by_stable(island_keys)
+            .agg([
+                on.clone().first().alias(START),
+                on.last().alias(END),
+                len().alias(LEN),
+            ])
+            .cache();
+
+        let key_columns = key_names.iter().map(|name| col(name.clone()));
+        let next_start = per_group(
+            col(START).shift(lit(-1)),
+            &key_columns.clone().collect::<Vec<_>>(),
+        );
+        let gaps = islands
+            .clone()
+            .select(key_columns.clone().chain([
+                lit(Null {}).cast(IDX_DTYPE).alias(ISLAND),
+                col(END).alias(START),
+                next_start.alias(END),
+                lit(0).cast(IDX_DTYPE).alias(LEN),
+                lit(true).alias(IS_GAP),
+            ]))
+            .filter(col(END).is_not_null());
+        let lp = DslPlan::Union {
+            inputs: vec![
+                islands.with_column(lit(false).alias(IS_GAP)).logical_plan,
+                gaps.logical_plan,
+            ],
+            args: Default::default(),
+        };
+
+        let mut sort_by = key_columns.collect::<Vec<_>>();
+        sort_by.extend([col(START), col(IS_GAP)]);
+        Ok(LazyFrame::from_logical_plan(lp, opt_state).sort_by_exprs(
+            sort_by,
+            SortMultipleOptions::default().with_maintain_order(true),
+        ))
+    }
+
     /// Similar to [`group_by`][`Self::group_by`], but order of the DataFrame is maintained.
     pub fn group_by_stable<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupBy {
         let keys = by
@@ -1153,6 +1368,44 @@ impl LazyFrame {
         }
     }
 
+    /// Group by every one of the grouping `sets` and aggregate, like SQL's
+    /// `GROUP BY GROUPING SETS`.
+    ///
+    /// An empty set aggregates the whole frame into a grand total. See
+    /// [`LazyGroupingSets::agg`] for the output.
+    pub fn group_by_grouping_sets<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        sets: &[E],
+    ) -> LazyGroupingSets {
+        let sets = sets
+            .iter()
+            .map(|set| set.as_ref().iter().map(|e| e.clone().into()).collect())
+            .collect();
+        LazyGroupingSets::new(self, sets)
+    }
+
+    /// Group by the hierarchy of `by` and aggregate every level, from the groups of all keys
+    /// down to the grand total, like SQL's `GROUP BY ROLLUP`.
+    ///
+    /// Rollup by `[a, b]` computes the groups of `(a, b)`, the subtotals of `(a)` and the grand
+    /// total. See [`LazyGroupingSets::agg`] for the output.
+    pub fn group_by_rollup<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+    ) -> LazyGroupingSets {
+        let keys = by.as_ref().iter().map(|e| e.clone().into()).collect();
+        LazyGroupingSets::rollup(self, keys)
+    }
+
+    /// Group by every combination of the keys `by` and aggregate, like SQL's `GROUP BY CUBE`.
+    ///
+    /// Cube by `[a, b]` computes the groups of `(a, b)`, the subtotals of `(a)` and of `(b)`
+    /// and the grand total. See [`LazyGroupingSets::agg`] for the output.
+    pub fn group_by_cube<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(self, by: E) -> LazyGroupingSets {
+        let keys = by.as_ref().iter().map(|e| e.clone().into()).collect();
+        LazyGroupingSets::cube(self, keys)
+    }
+
     /// Left anti join this query with another lazy query.
     ///
     /// Matches on the values of the expressions `left_on` and `right_on`. For more
@@ -1353,6 +1606,56 @@ impl LazyFrame {
         JoinBuilder::new(self)
     }
 
+    /// Look up the state of a slowly changing dimension as of the time `on` of every row.
+    ///
+    /// `changes` is a change log with a row for every version of the entity identified by the
+    /// keys `by`: the time `valid_from` which the version is valid from and its `attributes`. Every
+    /// row is joined with the last version of its entity that is valid at `on`, or nulls if
+    /// there is none yet. All attributes are looked up in a single backward as-of join, for which
+    /// both sides are sorted by their time column. The rows keep their order and get the
+    /// `valid_from` and `attributes` of the version appended.
+    #[cfg(feature = "asof_join")]
+    pub fn as_of_snapshot(
+        self,
+        changes: LazyFrame,
+        on: &str,
+        valid_from: &str,
+        by: &[&str],
+        attributes: &[&str],
+    ) -> LazyFrame {
+        const ROW_INDEX: &str = "__POLARS_AS_OF_ROW";
+
+        let by = by
+            .iter()
+            .map(|name| PlSmallStr::from_str(name))
+            .collect::<Vec<_>>();
+        let changes = changes
+            .select(
+                by.iter()
+                    .map(|name| col(name.clone()))
+                    .chain([col(valid_from)])
+                    .chain(attributes.iter().map(|name| col(*name)))
+                    .collect::<Vec<_>>(),
+            )
+            .sort([valid_from], Default::default());
+        let options = AsOfOptions {
+            strategy: AsofStrategy::Backward,
+            left_by: Some(by.clone()),
+            right_by: Some(by),
+            ..Default::default()
+        };
+        self.with_row_index(ROW_INDEX, None)
+            .sort([on], Default::default())
+            .join(
+                changes,
+                [col(on)],
+                [col(valid_from)],
+                JoinArgs::new(JoinType::AsOf(options)),
+            )
+            .sort([ROW_INDEX], Default::default())
+            .drop([ROW_INDEX])
+    }
+
     /// Add or replace a column, given as an expression, to a DataFrame.
     ///
     /// # Example
@@ -1784,6 +2087,127 @@ impl LazyFrame {
         }
     }
 
+    /// Split the rows into a random `fraction` and the remainder, e.g. a train and a test set.
+    ///
+    /// With `stratify_by` every group of those keys is split with the same fraction. With
+    /// `group_by` all rows with an equal key end up on the same side, the fraction then
+    /// applies to the number of keys. The `seed` is required so both sides agree on the split.
+    #[cfg(feature = "random")]
+    pub fn split_by_fraction(
+        self,
+        fraction: f64,
+        seed: u64,
+        stratify_by: Option<Vec<Expr>>,
+        group_by: Option<Expr>,
+    ) -> (LazyFrame, LazyFrame) {
+        const INDEX: &str = "__POLARS_SPLIT_INDEX";
+        let mut mask = match group_by {
+            Some(key) => key.split_mask(lit(fraction), true, Some(seed)),
+            None => col(INDEX).split_mask(lit(fraction), false, Some(seed)),
+        };
+        if let Some(stratify_by) = stratify_by {
+            mask = mask.over(stratify_by);
+        }
+        let lf = self.with_row_index(INDEX, None);
+        let selected = lf.clone().filter(mask.clone()).drop([INDEX]);
+        let remainder = lf.filter(mask.not()).drop([INDEX]);
+        (selected, remainder)
+    }
+
+    /// Sample `n` rows of every group of `by`, where `n` is taken from the `size_column` of
+    /// the `sizes` frame, which maps the keys of `by` to the sample size of their group.
+    ///
+    /// Groups that are not in `sizes` are not sampled. With `weights` the rows are sampled with
+    /// a probability proportional to that expression. The sampled rows keep their order.
+    #[cfg(feature = "random")]
+    pub fn sample_n_by_group<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        sizes: LazyFrame,
+        size_column: &str,
+        weights: Option<Expr>,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> LazyFrame {
+        let by = by.as_ref().iter().map(|e| e.clone().into()).collect();
+        self.sample_by_group(
+            by,
+            sizes,
+            size_column,
+            false,
+            weights,
+            with_replacement,
+            seed,
+        )
+    }
+
+    /// Sample a fraction of the rows of every group of `by`, where the fraction is taken from
+    /// the `size_column` of the `sizes` frame, see [`LazyFrame::sample_n_by_group`].
+    #[cfg(feature = "random")]
+    pub fn sample_frac_by_group<E: AsRef<[IE]>, IE: Into<Expr> + Clone>(
+        self,
+        by: E,
+        sizes: LazyFrame,
+        size_column: &str,
+        weights: Option<Expr>,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> LazyFrame {
+        let by = by.as_ref().iter().map(|e| e.clone().into()).collect();
+        self.sample_by_group(
+            by,
+            sizes,
+            size_column,
+            true,
+            weights,
+            with_replacement,
+            seed,
+        )
+    }
+
+    #[cfg(feature = "random")]
+    #[allow(clippy::too_many_arguments)]
+    fn sample_by_group(
+        self,
+        by: Vec<Expr>,
+        sizes: LazyFrame,
+        size_column: &str,
+        is_fraction: bool,
+        weights: Option<Expr>,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> LazyFrame {
+        const INDEX: &str = "__POLARS_SAMPLE_INDEX";
+        let lf = self.with_row_index(INDEX, None).cache();
+        let size = col(size_column).first();
+        let index = col(INDEX);
+        let sampled = match (weights, is_fraction) {
+            (Some(weights), false) => {
+                index.sample_n_weighted(size, weights, with_replacement, seed)
+            },
+            (Some(weights), true) => {
+                index.sample_frac_weighted(size, weights, with_replacement, seed)
+            },
+            (None, false) => index.sample_n(size, with_replacement, false, seed),
+            (None, true) => index.sample_frac(size, with_replacement, false, seed),
+        };
+        let sampled = lf
+            .clone()
+            .join(sizes, &by, &by, JoinArgs::new(JoinType::Inner))
+            .group_by(by)
+            .agg([sampled])
+            .explode([col(INDEX)])
+            .select([col(INDEX)]);
+        lf.join(
+            sampled,
+            [col(INDEX)],
+            [col(INDEX)],
+            JoinArgs::new(JoinType::Inner),
+        )
+        .sort([INDEX], Default::default())
+        .drop([INDEX])
+    }
+
     /// Return the number of non-null elements for each column.
     pub fn count(self) -> LazyFrame {
         self.select(vec![col(PlSmallStr::from_static("*")).count()])
@@ -1960,6 +2384,44 @@ impl LazyGroupBy {
         };
         LazyFrame::from_logical_plan(lp, self.opt_state)
     }
+
+    /// Group by and aggregate, and stream the aggregates of every partition into their own
+    /// parquet file, e.g. to produce the daily rollups of a log in a single pass.
+    ///
+    /// `partition_by` must name group keys. The aggregates are written as a hive partitioned
+    /// dataset below `path`, with a directory and a single file for every distinct value of the
+    /// `partition_by` keys. This methods will return an error if the query cannot be completely
+    /// done in a streaming fashion.
+    #[cfg(feature = "parquet")]
+    pub fn sink_parquet_partitioned<E: AsRef<[Expr]>>(
+        self,
+        aggs: E,
+        partition_by: &[&str],
+        path: impl AsRef<Path>,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        let key_names = self
+            .keys
+            .iter()
+            .filter_map(|expr| expr_output_name(expr).ok())
+            .collect::<Vec<_>>();
+        for name in partition_by {
+            polars_ensure!(
+                key_names.iter().any(|key| key == name),
+                InvalidOperation: "cannot partition the aggregates by `{}`, it is not a group key",
+                name
+            );
+        }
+
+        self.agg(aggs).sink(
+            SinkType::Partitioned {
+                path: Arc::new(path.as_ref().to_path_buf()),
+                file_type: FileType::Parquet(options),
+                partition_by: partition_by.iter().map(|name| (*name).into()).collect(),
+            },
+            "collect().write_parquet()",
+        )
+    }
 }
 
 #[must_use]
@@ -1975,6 +2437,7 @@ pub struct JoinBuilder {
     validation: JoinValidation,
     coalesce: JoinCoalesce,
     join_nulls: bool,
+    float_tolerance: Option<FloatTolerance>,
 }
 impl JoinBuilder {
     /// Create the `JoinBuilder` with the provided `LazyFrame` as the left table.
@@ -1991,6 +2454,7 @@ impl JoinBuilder {
             suffix: None,
             validation: Default::default(),
             coalesce: Default::default(),
+            float_tolerance: None,
         }
     }
 
@@ -2071,6 +2535,12 @@ impl JoinBuilder {
         self
     }
 
+    /// Match float keys on their nearest multiple of the tolerance's epsilon instead of exactly.
+    pub fn float_tolerance(mut self, float_tolerance: FloatTolerance) -> Self {
+        self.float_tolerance = Some(float_tolerance);
+        self
+    }
+
     /// Finish builder
     pub fn finish(self) -> LazyFrame {
         let mut opt_state = self.lf.opt_state;
@@ -2088,6 +2558,7 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            float_tolerance: self.float_tolerance,
         };
 
         let lp = self
@@ -2126,6 +2597,7 @@ impl JoinBuilder {
             slice: None,
             join_nulls: self.join_nulls,
             coalesce: self.coalesce,
+            float_tolerance: self.float_tolerance,
         };
         let options = JoinOptions {
             allow_parallel: self.allow_parallel,
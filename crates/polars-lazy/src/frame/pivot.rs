@@ -10,7 +10,8 @@
 
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
-use polars_ops::pivot::PivotAgg;
+pub use polars_ops::pivot::{CrosstabNormalize, PivotTableOptions};
+use polars_ops::pivot::{PivotAgg, CROSSTAB_COLUMN_KEY, CROSSTAB_VALUES_KEY};
 
 use crate::physical_plan::exotic::{prepare_eval_expr, prepare_expression_for_context};
 use crate::prelude::*;
@@ -88,3 +89,66 @@ where
     });
     polars_ops::pivot::pivot_stable(df, on, index, values, sort_columns, agg_expr, separator)
 }
+
+/// A spreadsheet-like pivot table with optional margin totals, see
+/// [`polars_ops::pivot::pivot_table`].
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_table<I0, I1, I2, S0, S1, S2>(
+    df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    sort_columns: bool,
+    agg_expr: Option<Expr>,
+    // used as separator/delimiter in generated column names.
+    separator: Option<&str>,
+    options: &PivotTableOptions,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+    S2: Into<PlSmallStr>,
+{
+    // make sure that the root column is replaced
+    let agg_expr = agg_expr.map(|agg_expr| {
+        let expr = prepare_eval_expr(agg_expr);
+        PivotAgg::Expr(Arc::new(PivotExpr(expr)))
+    });
+    polars_ops::pivot::pivot_table(
+        df,
+        on,
+        index,
+        values,
+        sort_columns,
+        agg_expr,
+        separator,
+        options,
+    )
+}
+
+/// Compute a contingency table of the `rows` keys against the `columns` keys, counting the rows
+/// or aggregating the `values` with `agg_expr`, see [`polars_ops::pivot::crosstab`].
+pub fn crosstab(
+    df: &DataFrame,
+    rows: Expr,
+    columns: Expr,
+    values: Option<Expr>,
+    agg_expr: Option<Expr>,
+    normalize: Option<CrosstabNormalize>,
+) -> PolarsResult<DataFrame> {
+    // the keys and values may have the same name
+    let mut exprs = vec![rows, columns.alias(CROSSTAB_COLUMN_KEY)];
+    exprs.extend(values.map(|values| values.alias(CROSSTAB_VALUES_KEY)));
+    let inputs = df.clone().lazy().select(exprs).collect()?;
+    let inputs = inputs.get_columns();
+
+    // make sure that the root column is replaced
+    let agg_expr = agg_expr.map(|agg_expr| {
+        let expr = prepare_eval_expr(agg_expr);
+        PivotAgg::Expr(Arc::new(PivotExpr(expr)))
+    });
+    polars_ops::pivot::crosstab(&inputs[0], &inputs[1], inputs.get(2), agg_expr, normalize)
+}
@@ -0,0 +1,90 @@
+use polars_core::config::verbose;
+use polars_core::prelude::*;
+use polars_plan::prelude::*;
+use polars_utils::sys::MEMINFO;
+
+/// `0` never and `1` always selects the streaming engine, bypassing the size estimate.
+const AUTO_STREAMING: &str = "POLARS_AUTO_STREAMING";
+/// The fraction of the available memory the scanned data may take up before the query is run on
+/// the streaming engine.
+const AUTO_STREAMING_MEMORY_FRACTION: &str = "POLARS_AUTO_STREAMING_MEMORY_FRACTION";
+const DEFAULT_MEMORY_FRACTION: f64 = 0.5;
+
+/// A rough estimate of the in-memory size of a single value of `dtype`.
+fn estimated_value_size(dtype: &DataType) -> usize {
+    match dtype {
+        DataType::Boolean => 1,
+        DataType::UInt8 | DataType::Int8 => 1,
+        DataType::UInt16 | DataType::Int16 => 2,
+        DataType::UInt32 | DataType::Int32 | DataType::Float32 | DataType::Date => 4,
+        // A view and a few bytes of data.
+        DataType::String | DataType::Binary => 32,
+        // An offset and a few values.
+        DataType::List(inner) => 8 + 4 * estimated_value_size(inner),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(fields) => fields.iter().map(|f| estimated_value_size(f.dtype())).sum(),
+        _ => 8,
+    }
+}
+
+/// Estimate the number of bytes that the file scans in the plan at `root` produce, based on their
+/// estimated row counts and projected schemas.
+pub(crate) fn estimated_scan_size(
+    root: Node,
+    lp_arena: &Arena<IR>,
+    scratch: &mut Vec<Node>,
+) -> usize {
+    scratch.clear();
+    scratch.push(root);
+    let mut size = 0usize;
+    while let Some(node) = scratch.pop() {
+        let lp = lp_arena.get(node);
+        if let IR::Scan {
+            file_info,
+            output_schema,
+            file_options,
+            ..
+        } = lp
+        {
+            let (known_rows, estimated_rows) = file_info.row_estimation;
+            let mut rows = known_rows.unwrap_or(estimated_rows);
+            if let Some((_, len)) = file_options.slice {
+                rows = rows.min(len);
+            }
+            let schema = output_schema.as_ref().unwrap_or(&file_info.schema);
+            let row_size = schema
+                .iter_values()
+                .map(estimated_value_size)
+                .sum::<usize>();
+            size = size.saturating_add(rows.saturating_mul(row_size));
+        }
+        lp.copy_inputs(scratch);
+    }
+    size
+}
+
+/// Whether the plan at `root` should run on the streaming engine because the data it scans is
+/// not expected to fit comfortably in the available memory.
+pub(crate) fn prefer_streaming(root: Node, lp_arena: &Arena<IR>, scratch: &mut Vec<Node>) -> bool {
+    match std::env::var(AUTO_STREAMING).as_deref() {
+        Ok("0") => return false,
+        Ok("1") => return true,
+        _ => {},
+    }
+    let size = estimated_scan_size(root, lp_arena, scratch);
+    if size == 0 {
+        return false;
+    }
+    let fraction = std::env::var(AUTO_STREAMING_MEMORY_FRACTION)
+        .ok()
+        .and_then(|v| v.parse::<f64>().ok())
+        .unwrap_or(DEFAULT_MEMORY_FRACTION);
+    let available = MEMINFO.free();
+    let prefer = size as f64 > fraction * available as f64;
+    if prefer && verbose() {
+        eprintln!(
+            "estimated scan size of {size} bytes exceeds {fraction} of the {available} bytes of available memory, running on the streaming engine"
+        );
+    }
+    prefer
+}
@@ -39,5 +39,6 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         JoinType::Full { .. } => true,
         _ => false,
     };
-    supported && !args.validation.needs_checks()
+    // The float keys are only quantized in the in-memory engine.
+    supported && !args.validation.needs_checks() && args.float_tolerance.is_none()
 }
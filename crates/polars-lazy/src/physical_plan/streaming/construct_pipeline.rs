@@ -47,7 +47,7 @@ impl PhysicalPipedExpr for Wrap {
     }
 }
 
-fn to_physical_piped_expr(
+pub(super) fn to_physical_piped_expr(
     expr: &ExprIR,
     expr_arena: &Arena<AExpr>,
     schema: Option<&SchemaRef>,
@@ -0,0 +1,315 @@
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+use polars_expr::state::ExecutionState;
+use polars_pipe::operators::chunks::DataChunk;
+use polars_pipe::operators::{FinalizedSink, PExecutionContext, Sink, SourceResult};
+use polars_pipe::pipeline::{can_convert_to_hash_agg, get_sink, CallBacks};
+use polars_utils::format_pl_smallstr;
+
+use super::construct_pipeline::to_physical_piped_expr;
+use crate::prelude::*;
+
+/// The column with the index of the `update` call that a row was passed in, it orders `first`
+/// and `last` over merged partial aggregates.
+const ROUND: &str = "__POLARS_GROUPED_AGG_ROUND";
+
+/// Incremental group-by aggregation state.
+///
+/// Accepts successive [`DataFrame`]s that share a schema via [`update`](Self::update) and
+/// keeps the partial aggregates of the streaming engine's group-by sinks in between. Empty
+/// states for other partitions (e.g. other threads or workers) are created with
+/// [`split`](Self::split); the result is produced with [`finalize`](Self::finalize).
+///
+/// [`into_partial`](Self::into_partial) turns a state into a [`DataFrame`] with the keys and
+/// the partial aggregates of every group, which can be serialized like any other frame and
+/// combined by key into a state with the same keys and aggregations with
+/// [`merge_partial`](Self::merge_partial). [`merge`](Self::merge) does both for a state in the
+/// same process.
+///
+/// Supported aggregations are `sum`, `min`, `max`, `count`/`len`, `mean` and `first` and
+/// `last`. `first` and `last` respect the order of the `update` calls. When merging, `self` is
+/// preferred for `first` and the merged state for `last` if both saw a group in the same
+/// `update` round.
+///
+/// # Example
+///
+/// ```rust
+/// use polars_core::prelude::*;
+/// use polars_lazy::prelude::*;
+///
+/// # fn example() -> PolarsResult<()> {
+/// let df = df!["g" => [1, 2, 1], "v" => [1.0, 2.0, 3.0]]?;
+/// let mut state = GroupedAggState::new(df.schema(), [col("g")], [col("v").sum()])?;
+/// state.update(df.clone())?;
+/// let mut other = state.split();
+/// other.update(df)?;
+/// state.merge_partial(other.into_partial()?)?;
+/// let out = state.finalize()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct GroupedAggState {
+    input_schema: SchemaRef,
+    output_schema: SchemaRef,
+    partial_schema: SchemaRef,
+    keys: Vec<PlSmallStr>,
+    /// Combine the partial aggregates of a group.
+    combine_aggs: Vec<Expr>,
+    /// Compute the output from the combined partial aggregates.
+    output_exprs: Vec<Expr>,
+    sink: Box<dyn Sink>,
+    context: PExecutionContext,
+    chunk_index: IdxSize,
+    /// The partial aggregates merged into this state.
+    merged: Vec<DataFrame>,
+}
+
+impl GroupedAggState {
+    pub fn new<E: AsRef<[IE]>, IE: Into<Expr> + Clone, A: AsRef<[Expr]>>(
+        input_schema: Schema,
+        keys: E,
+        aggs: A,
+    ) -> PolarsResult<Self> {
+        let keys = keys
+            .as_ref()
+            .iter()
+            .map(|e| e.clone().into())
+            .collect::<Vec<Expr>>();
+        let input_schema = Arc::new(input_schema);
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            expr_arena,
+        } = DataFrame::empty_with_schema(&input_schema)
+            .lazy()
+            .group_by(keys.clone())
+            .agg(aggs)
+            .to_alp()?;
+
+        let IR::GroupBy {
+            keys: key_irs,
+            aggs,
+            schema: output_schema,
+            ..
+        } = lp_arena.get(lp_top)
+        else {
+            polars_bail!(ComputeError: "expected a group-by plan");
+        };
+        let key_names = key_irs
+            .iter()
+            .map(|e| e.output_name().clone())
+            .collect::<Vec<_>>();
+
+        let mut partial_aggs = vec![];
+        let mut combine_aggs = vec![];
+        let mut output_exprs = key_names.iter().cloned().map(col).collect::<Vec<_>>();
+        for e in aggs {
+            polars_ensure!(
+                can_convert_to_hash_agg(e.node(), &expr_arena, &input_schema),
+                InvalidOperation: "aggregation '{:?}' is not supported by GroupedAggState; \
+                supported are sum, min, max, count, mean, first and last",
+                e.to_expr(&expr_arena)
+            );
+            let name = e.output_name().clone();
+            let agg = node_to_expr(e.node(), &expr_arena).alias(name.clone());
+            let round = format_pl_smallstr!("{name}_round");
+            let by_round = || [col(round.clone())];
+            let mut output = col(name.clone());
+            match expr_arena.get(e.node()) {
+                AExpr::Agg(IRAggExpr::Sum(_)) | AExpr::Agg(IRAggExpr::Count(..)) | AExpr::Len => {
+                    partial_aggs.push(agg);
+                    combine_aggs.push(col(name.clone()).sum());
+                },
+                AExpr::Agg(IRAggExpr::Min { .. }) => {
+                    partial_aggs.push(agg);
+                    combine_aggs.push(col(name.clone()).min());
+                },
+                AExpr::Agg(IRAggExpr::Max { .. }) => {
+                    partial_aggs.push(agg);
+                    combine_aggs.push(col(name.clone()).max());
+                },
+                AExpr::Agg(IRAggExpr::First(_)) => {
+                    let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+                    partial_aggs.push(agg);
+                    partial_aggs.push(col(ROUND).min().alias(round.clone()));
+                    combine_aggs.push(col(name.clone()).sort_by(by_round(), sort_options).first());
+                    combine_aggs.push(col(round.clone()).min());
+                },
+                AExpr::Agg(IRAggExpr::Last(_)) => {
+                    let sort_options = SortMultipleOptions::default().with_maintain_order(true);
+                    partial_aggs.push(agg);
+                    partial_aggs.push(col(ROUND).max().alias(round.clone()));
+                    combine_aggs.push(col(name.clone()).sort_by(by_round(), sort_options).last());
+                    combine_aggs.push(col(round.clone()).max());
+                },
+                AExpr::Agg(IRAggExpr::Mean(input)) => {
+                    let dtype = expr_arena.get(*input).to_dtype(
+                        &input_schema,
+                        Context::Default,
+                        &expr_arena,
+                    )?;
+                    polars_ensure!(
+                        dtype.is_numeric() || dtype.is_bool(),
+                        InvalidOperation: "GroupedAggState only supports the mean of numeric values, got {}",
+                        dtype
+                    );
+                    let input = node_to_expr(*input, &expr_arena).cast(DataType::Float64);
+                    let sum = format_pl_smallstr!("{name}_sum");
+                    let count = format_pl_smallstr!("{name}_count");
+                    partial_aggs.push(input.clone().sum().alias(sum.clone()));
+                    partial_aggs.push(input.count().alias(count.clone()));
+                    combine_aggs.push(col(sum.clone()).sum());
+                    combine_aggs.push(col(count.clone()).sum());
+                    output = when(col(count.clone()).eq(lit(0)))
+                        .then(lit(Null {}))
+                        .otherwise(col(sum) / col(count).cast(DataType::Float64));
+                },
+                _ => unreachable!(),
+            }
+            let dtype = output_schema.get(&name).unwrap().clone();
+            output_exprs.push(output.cast(dtype).alias(name));
+        }
+
+        // The sink computes the partial aggregates of the input and the round it was passed in.
+        let mut partial_input_schema = input_schema.as_ref().clone();
+        partial_input_schema.with_column(PlSmallStr::from_static(ROUND), IDX_DTYPE);
+        let IRPlan {
+            lp_top,
+            lp_arena,
+            mut expr_arena,
+        } = DataFrame::empty_with_schema(&partial_input_schema)
+            .lazy()
+            .group_by(keys)
+            .agg(partial_aggs)
+            .to_alp()?;
+        let partial_schema = lp_arena.get(lp_top).schema(&lp_arena).into_owned();
+        let sink = get_sink(
+            lp_top,
+            &lp_arena,
+            &mut expr_arena,
+            &to_physical_piped_expr,
+            &mut CallBacks::default(),
+        )?;
+
+        Ok(Self {
+            input_schema,
+            output_schema: output_schema.clone(),
+            partial_schema,
+            keys: key_names,
+            combine_aggs,
+            output_exprs,
+            sink,
+            context: PExecutionContext::new(ExecutionState::new(), false),
+            chunk_index: 0,
+            merged: vec![],
+        })
+    }
+
+    /// Create an empty state with the same keys and aggregations, e.g. for another partition.
+    pub fn split(&self) -> Self {
+        Self {
+            input_schema: self.input_schema.clone(),
+            output_schema: self.output_schema.clone(),
+            partial_schema: self.partial_schema.clone(),
+            keys: self.keys.clone(),
+            combine_aggs: self.combine_aggs.clone(),
+            output_exprs: self.output_exprs.clone(),
+            sink: self.sink.split(0),
+            context: PExecutionContext::new(ExecutionState::new(), false),
+            chunk_index: 0,
+            merged: vec![],
+        }
+    }
+
+    /// The schema of the [`DataFrame`] produced by [`finalize`](Self::finalize).
+    pub fn schema(&self) -> &SchemaRef {
+        &self.output_schema
+    }
+
+    /// The schema of the [`DataFrame`] produced by [`into_partial`](Self::into_partial).
+    pub fn partial_schema(&self) -> &SchemaRef {
+        &self.partial_schema
+    }
+
+    /// Aggregate another batch of rows into the state.
+    pub fn update(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            df.schema() == *self.input_schema,
+            SchemaMismatch: "schema of update does not match the schema of the GroupedAggState"
+        );
+        if df.is_empty() {
+            return Ok(());
+        }
+        let round = IdxCa::full(
+            PlSmallStr::from_static(ROUND),
+            self.chunk_index,
+            df.height(),
+        );
+        df.with_column(round.into_column())?;
+        df.as_single_chunk_par();
+        let chunk = DataChunk {
+            chunk_index: self.chunk_index,
+            data: df,
+        };
+        self.chunk_index += 1;
+        self.sink.sink(&self.context, chunk)?;
+        Ok(())
+    }
+
+    /// Combine the partial aggregates of `other` into `self`, by key.
+    pub fn merge(&mut self, other: GroupedAggState) -> PolarsResult<()> {
+        self.merge_partial(other.into_partial()?)
+    }
+
+    /// Combine partial aggregates produced by [`into_partial`](Self::into_partial) of a state
+    /// with the same keys and aggregations into `self`, by key.
+    pub fn merge_partial(&mut self, partial: DataFrame) -> PolarsResult<()> {
+        polars_ensure!(
+            partial.schema() == *self.partial_schema,
+            SchemaMismatch: "schema of the partial aggregates does not match the schema of the GroupedAggState"
+        );
+        self.merged.push(partial);
+        Ok(())
+    }
+
+    /// Produce the keys and the partial aggregates of every group, including those that were
+    /// merged into this state.
+    pub fn into_partial(mut self) -> PolarsResult<DataFrame> {
+        let partial = match self.sink.finalize(&self.context)? {
+            FinalizedSink::Finished(df) => df,
+            FinalizedSink::Source(mut source) => {
+                let mut out = DataFrame::empty_with_schema(&self.partial_schema);
+                loop {
+                    match source.get_batches(&self.context)? {
+                        SourceResult::Finished => break,
+                        SourceResult::GotMoreData(chunks) => {
+                            for chunk in chunks {
+                                out.vstack_mut(&chunk.data)?;
+                            }
+                        },
+                    }
+                }
+                out
+            },
+            FinalizedSink::Operator => unreachable!(),
+        };
+        if self.merged.is_empty() {
+            let mut partial = partial;
+            partial.as_single_chunk_par();
+            return Ok(partial);
+        }
+
+        let partials = std::iter::once(partial).chain(self.merged);
+        accumulate_dataframes_vertical(partials)?
+            .lazy()
+            .group_by(self.keys.iter().cloned().map(col).collect::<Vec<_>>())
+            .agg(self.combine_aggs)
+            .collect()
+    }
+
+    /// Produce the aggregated result. Groups are returned in no particular order.
+    pub fn finalize(self) -> PolarsResult<DataFrame> {
+        let output_exprs = self.output_exprs.clone();
+        self.into_partial()?.lazy().select(output_exprs).collect()
+    }
+}
@@ -1,6 +1,12 @@
+mod auto;
 mod checks;
 mod construct_pipeline;
 mod convert_alp;
+mod grouped_agg_state;
 mod tree;
 
+#[cfg(test)]
+pub(crate) use auto::estimated_scan_size;
+pub(crate) use auto::prefer_streaming;
 pub(crate) use convert_alp::insert_streaming_nodes;
+pub use grouped_agg_state::GroupedAggState;
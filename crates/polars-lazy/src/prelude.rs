@@ -7,17 +7,39 @@ pub use polars_io::ipc::IpcWriterOptions;
 pub use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 pub use polars_io::parquet::write::ParquetWriteOptions;
-pub use polars_ops::prelude::{JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "to_dummies")]
+pub use polars_ops::prelude::OneHotEncoder;
+#[cfg(feature = "quantile_sketch")]
+pub use polars_ops::prelude::TDigest;
+#[cfg(feature = "target_encoding")]
+pub use polars_ops::prelude::TargetEncoder;
+#[cfg(feature = "asof_join")]
+pub use polars_ops::prelude::{AsOfOptions, AsofStrategy};
+#[cfg(feature = "text_search")]
+pub use polars_ops::prelude::{
+    Bm25Options, TermVectorizer, TextLanguage, TokenizeOptions, VectorizeOptions,
+};
+pub use polars_ops::prelude::{FloatTolerance, JoinArgs, JoinType, JoinValidation};
+#[cfg(feature = "hypothesis_test")]
+pub use polars_ops::prelude::{HypothesisTest, TestResult};
+#[cfg(feature = "outliers")]
+pub use polars_ops::prelude::{IsolationForestOptions, OutlierMethod};
 #[cfg(feature = "rank")]
 pub use polars_ops::prelude::{RankMethod, RankOptions};
+#[cfg(feature = "scale")]
+pub use polars_ops::prelude::{ScaleMethod, ScaleParams};
 #[cfg(feature = "polars_cloud")]
 pub use polars_plan::client::prepare_cloud_plan;
 pub use polars_plan::plans::{
     AnonymousScan, AnonymousScanArgs, AnonymousScanOptions, DslPlan, Literal, LiteralValue, Null,
     NULL,
 };
-pub use polars_plan::prelude::UnionArgs;
 pub(crate) use polars_plan::prelude::*;
+#[cfg(feature = "unstable_rewrite_rules")]
+pub use polars_plan::prelude::{
+    register_expr_rewrite_rule, unregister_expr_rewrite_rule, ExprRewriteRule,
+};
+pub use polars_plan::prelude::{UnionArgs, UnionColumnMatching};
 #[cfg(feature = "rolling_window_by")]
 pub use polars_time::Duration;
 #[cfg(feature = "dynamic_group_by")]
@@ -26,4 +48,6 @@ pub(crate) use polars_utils::arena::{Arena, Node};
 
 pub use crate::dsl::*;
 pub use crate::frame::*;
+#[cfg(feature = "streaming")]
+pub use crate::physical_plan::streaming::GroupedAggState;
 pub(crate) use crate::scan::*;
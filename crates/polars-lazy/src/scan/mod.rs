@@ -8,3 +8,5 @@ pub(super) mod ipc;
 pub(super) mod ndjson;
 #[cfg(feature = "parquet")]
 pub(super) mod parquet;
+#[cfg(feature = "rows")]
+pub(super) mod rows;
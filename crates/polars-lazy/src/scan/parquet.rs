@@ -2,7 +2,7 @@ use std::path::{Path, PathBuf};
 
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
-use polars_io::parquet::read::ParallelStrategy;
+use polars_io::parquet::read::{ParallelStrategy, SkippedRowGroups};
 use polars_io::{HiveOptions, RowIndex};
 
 use crate::prelude::*;
@@ -15,6 +15,11 @@ pub struct ScanArgsParquet {
     pub cloud_options: Option<CloudOptions>,
     pub hive_options: HiveOptions,
     pub use_statistics: bool,
+    /// Resolve the columns of the files by their Parquet field ids instead of their names.
+    pub use_field_ids: bool,
+    /// Skip the row groups that cannot be decoded instead of failing the scan, and record them
+    /// in the given collector. Keep a clone of it to take the skipped row groups after the query.
+    pub skip_corrupt_row_groups: Option<SkippedRowGroups>,
     pub low_memory: bool,
     pub rechunk: bool,
     pub cache: bool,
@@ -32,6 +37,8 @@ impl Default for ScanArgsParquet {
             cloud_options: None,
             hive_options: Default::default(),
             use_statistics: true,
+            use_field_ids: false,
+            skip_corrupt_row_groups: None,
             rechunk: false,
             low_memory: false,
             cache: true,
@@ -71,6 +78,8 @@ impl LazyFileListReader for LazyParquetReader {
             self.args.low_memory,
             self.args.cloud_options,
             self.args.use_statistics,
+            self.args.use_field_ids,
+            self.args.skip_corrupt_row_groups,
             self.args.hive_options,
             self.args.glob,
             self.args.include_file_paths,
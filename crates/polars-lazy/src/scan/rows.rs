@@ -0,0 +1,154 @@
+use std::sync::Mutex;
+
+use polars_core::frame::row::Row;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+
+use crate::prelude::*;
+
+/// Produces the rows of a [`LazyFrame::scan_rows`] source.
+pub trait RowsProducer: Send {
+    /// The schema of the produced rows.
+    fn schema(&self) -> SchemaRef;
+
+    /// Produce the next row, `None` signals that the producer is exhausted.
+    fn next_row(&mut self) -> Option<PolarsResult<Row<'static>>>;
+}
+
+/// A [`RowsProducer`] over an iterator of rows.
+pub struct RowsIter<I> {
+    schema: SchemaRef,
+    iter: I,
+}
+
+impl<I> RowsIter<I>
+where
+    I: Iterator<Item = Row<'static>> + Send,
+{
+    pub fn new(schema: SchemaRef, iter: I) -> Self {
+        Self { schema, iter }
+    }
+}
+
+impl<I> RowsProducer for RowsIter<I>
+where
+    I: Iterator<Item = Row<'static>> + Send,
+{
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    fn next_row(&mut self) -> Option<PolarsResult<Row<'static>>> {
+        self.iter.next().map(Ok)
+    }
+}
+
+#[derive(Clone)]
+pub struct ScanArgsRows {
+    /// Number of rows that are collected into a single [`DataFrame`] batch.
+    pub batch_size: usize,
+    pub name: &'static str,
+}
+
+impl Default for ScanArgsRows {
+    fn default() -> Self {
+        Self {
+            batch_size: 50_000,
+            name: "ROWS SCAN",
+        }
+    }
+}
+
+struct RowsScan {
+    producer: Mutex<Box<dyn RowsProducer>>,
+    schema: SchemaRef,
+    batch_size: usize,
+}
+
+impl AnonymousScan for RowsScan {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+        let mut n_rows = scan_opts.n_rows;
+        let mut dfs = vec![];
+        while let Some(df) = self.next_batch(AnonymousScanArgs {
+            n_rows,
+            with_columns: None,
+            schema: self.schema.clone(),
+            output_schema: None,
+            predicate: None,
+        })? {
+            if let Some(n_rows) = &mut n_rows {
+                *n_rows -= df.height();
+            }
+            dfs.push(df);
+        }
+        if dfs.is_empty() {
+            Ok(DataFrame::empty_with_schema(&self.schema))
+        } else {
+            Ok(accumulate_dataframes_vertical_unchecked(dfs))
+        }
+    }
+
+    fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+        let batch_size = scan_opts
+            .n_rows
+            .map_or(self.batch_size, |n_rows| n_rows.min(self.batch_size));
+        let mut producer = self.producer.lock().unwrap();
+        let mut rows = Vec::with_capacity(batch_size);
+        while rows.len() < batch_size {
+            match producer.next_row() {
+                Some(row) => rows.push(row?),
+                None => break,
+            }
+        }
+        if rows.is_empty() {
+            return Ok(None);
+        }
+        DataFrame::from_rows_and_schema(&rows, &self.schema).map(Some)
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<SchemaRef> {
+        Ok(self.schema.clone())
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_streaming(&self) -> bool {
+        true
+    }
+}
+
+impl LazyFrame {
+    /// Create a [`LazyFrame`] that pulls its rows from a [`RowsProducer`].
+    ///
+    /// The rows are only produced once the query is executed. The streaming engine
+    /// consumes them in batches of `args.batch_size` rows, so the rows never have to
+    /// be collected up front.
+    ///
+    /// A query can only be executed once, as the producer is exhausted afterwards.
+    pub fn scan_rows<P: RowsProducer + 'static>(
+        producer: P,
+        args: ScanArgsRows,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(args.batch_size > 0, InvalidOperation: "batch_size must be positive");
+        let schema = producer.schema();
+        let function = Arc::new(RowsScan {
+            producer: Mutex::new(Box::new(producer)),
+            schema: schema.clone(),
+            batch_size: args.batch_size,
+        });
+        LazyFrame::anonymous_scan(
+            function,
+            ScanArgsAnonymous {
+                schema: Some(schema),
+                name: args.name,
+                ..Default::default()
+            },
+        )
+    }
+}
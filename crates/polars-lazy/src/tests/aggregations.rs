@@ -571,3 +571,47 @@ fn test_take_in_groups() -> PolarsResult<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_count_if_sum_if() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b", "b"],
+        "p" => [Some(true), Some(false), None, Some(true), Some(true)],
+        "v" => [Some(1i64), Some(2), Some(3), None, Some(5)],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([
+            count_if(col("p")).alias("n"),
+            sum_if(col("p"), col("v")).alias("s"),
+            sum_if(col("v").gt(lit(1i64)), lit(1i64).cast(DataType::Int64)).alias("s_lit"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("n")?.idx()?), &[Some(1), Some(2)]);
+    assert_eq!(Vec::from(out.column("s")?.i64()?), &[Some(1), Some(5)]);
+    assert_eq!(Vec::from(out.column("s_lit")?.i64()?), &[Some(1), Some(2)]);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            count_if(col("p")).alias("n"),
+            sum_if(col("p"), col("v")).alias("s"),
+        ])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("n")?.idx()?), &[Some(3)]);
+    assert_eq!(Vec::from(out.column("s")?.i64()?), &[Some(6)]);
+
+    let out = df
+        .lazy()
+        .select([sum_if(col("p"), col("v")).over([col("g")]).alias("s")])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("s")?.i64()?),
+        &[Some(1), Some(1), Some(5), Some(5), Some(5)]
+    );
+    Ok(())
+}
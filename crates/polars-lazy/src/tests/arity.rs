@@ -36,6 +36,51 @@ fn test_pearson_corr() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "cov", feature = "rank"))]
+fn test_kendall_rank_corr() -> PolarsResult<()> {
+    let df = df! {
+        "uid" => [0, 0, 0, 1, 1, 1],
+        "feature" => [1, 2, 4, 3, 1, 2],
+        "target" => [10, 12, 15, 25, 30, 41]
+    }?;
+
+    let out = df
+        .lazy()
+        .group_by_stable([col("uid")])
+        .agg([kendall_rank_corr(col("feature"), col("target")).alias("kendall_tau")])
+        .collect()?;
+    let s = out.column("kendall_tau")?.f64()?;
+    assert_eq!(s.get(0), Some(1.0));
+    assert!((s.get(1).unwrap() + 1.0 / 3.0).abs() < 0.000001);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "hypothesis_test", feature = "dtype-struct"))]
+fn test_t_test_per_group() -> PolarsResult<()> {
+    let df = df! {
+        "segment" => ["s1", "s1", "s1", "s1", "s1", "s1", "s1", "s1", "s2", "s2", "s2", "s2", "s2", "s2"],
+        "variant" => ["A", "B", "A", "B", "A", "B", "A", "B", "A", "A", "A", "B", "B", "B"],
+        "value" => [1.0, 3.0, 2.0, 4.0, 3.0, 5.0, 4.0, 6.0, 1.0, 2.0, 3.0, 3.0, 2.0, 1.0]
+    }?;
+
+    let variant = |v: &str| col("value").filter(col("variant").eq(lit(v)));
+    let out = df
+        .lazy()
+        .group_by_stable([col("segment")])
+        .agg([t_test(variant("A"), variant("B"), false).alias("t_test")])
+        .unnest(["t_test"])
+        .collect()?;
+    let statistic = out.column("statistic")?.f64()?;
+    let p_value = out.column("p_value")?.f64()?;
+    assert!((statistic.get(0).unwrap() + 4.8f64.sqrt()).abs() < 0.000001);
+    assert!(p_value.get(0).unwrap() > 0.05 && p_value.get(0).unwrap() < 0.1);
+    assert_eq!(statistic.get(1), Some(0.0));
+    assert!((p_value.get(1).unwrap() - 1.0).abs() < 0.000001);
+    Ok(())
+}
+
 // TODO! fix this we must get a token that prevents resetting the string cache until the plan has
 // finished running. We cannot store a mutexguard in the executionstate because they don't implement
 // send.
@@ -1,5 +1,7 @@
 use std::collections::BTreeSet;
 
+use polars_plan::constants::CSE_REPLACED;
+
 use super::*;
 
 fn cached_before_root(q: LazyFrame) {
@@ -353,3 +355,124 @@ fn test_cse_prune_scan_filter_difference() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_cache_prunes_chunks_by_statistics() -> PolarsResult<()> {
+    let mut df = df![
+        "a" => [1, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    df.vstack_mut(&df![
+        "a" => [Some(10), None, Some(12)],
+        "b" => ["x", "y", "z"],
+    ]?)?;
+    df.vstack_mut(&df![
+        "a" => [20, 21, 22],
+        "b" => ["u", "v", "w"],
+    ]?)?;
+    assert_eq!(df.n_chunks(), 3);
+    let lf = df.clone().lazy().cache();
+
+    let predicates = [
+        col("a").gt(lit(11)),
+        col("a").lt(lit(0)),
+        col("a").is_null(),
+        col("b").eq(lit("v")).or(col("a").eq(lit(2))),
+    ];
+    for predicate in predicates {
+        let q = concat(
+            [lf.clone().filter(predicate.clone()), lf.clone()],
+            Default::default(),
+        )?;
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+        assert!((&lp_arena).iter(lp).any(|(_, lp)| match lp {
+            IR::Filter { input, .. } => matches!(lp_arena.get(*input), IR::Cache { .. }),
+            _ => false,
+        }));
+
+        let expected = df.clone().lazy().filter(predicate).collect()?;
+        let out = q.collect()?;
+        assert!(out.slice(0, expected.height()).equals_missing(&expected));
+        assert!(out
+            .slice(expected.height() as i64, df.height())
+            .equals_missing(&df));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cache_shares_filter_masks() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some(1), Some(12), None, Some(20)],
+        "b" => ["x", "y", "z", "w"],
+    ]?;
+    let lf = df.clone().lazy().cache();
+    let q = concat(
+        [
+            lf.clone().filter(col("a").gt(lit(11))),
+            lf.clone()
+                .filter(col("a").gt(lit(11)))
+                .with_column(col("a") * lit(2)),
+            lf.clone().filter(col("a").lt(lit(11))),
+            lf,
+        ],
+        Default::default(),
+    )?;
+    let out = q.collect()?;
+    let expected = df![
+        "a" => [Some(12), Some(20), Some(24), Some(40), Some(1), Some(1), Some(12), None, Some(20)],
+        "b" => ["y", "w", "y", "w", "x", "x", "y", "z", "w"],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_cse_expr_in_projection() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1.0, 2.0, 3.0],
+        "b" => [4.0, 5.0, 6.0],
+    ]?;
+    let ratio = || col("a") / col("b");
+    let q = df.lazy().select([
+        ratio().alias("r"),
+        (ratio() + lit(1.0)).alias("r1"),
+        (ratio() * ratio()).alias("r2"),
+    ]);
+
+    let plan = q
+        .clone()
+        .with_comm_subexpr_elim(true)
+        .describe_optimized_plan()?;
+    assert!(plan.contains(CSE_REPLACED));
+    assert_eq!(
+        q.clone().with_comm_subexpr_elim(true).collect()?,
+        q.with_comm_subexpr_elim(false).collect()?
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_cse_expr_non_deterministic() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1.0, 2.0, 3.0, 4.0, 5.0],
+    ]?;
+    let has_cse = |seed: Option<u64>| -> PolarsResult<bool> {
+        let plan = df
+            .clone()
+            .lazy()
+            .select([
+                (col("a").shuffle(seed) + lit(1.0)).alias("x"),
+                (col("a").shuffle(seed) + lit(2.0)).alias("y"),
+            ])
+            .with_comm_subexpr_elim(true)
+            .describe_optimized_plan()?;
+        Ok(plan.contains(CSE_REPLACED))
+    };
+    // Two unseeded shuffles must be drawn independently.
+    assert!(!has_cse(None)?);
+    assert!(has_cse(Some(0))?);
+    Ok(())
+}
@@ -711,3 +711,267 @@ fn scan_small_dtypes() -> PolarsResult<()> {
     }
     Ok(())
 }
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+fn test_parquet_string_to_categorical() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let categorical = DataType::Categorical(None, Default::default());
+
+    for par in [true, false] {
+        let q = scan_foods_parquet(par)
+            .group_by([col("category").cast(categorical.clone())])
+            .agg([col("calories").sum()]);
+        // The cast is replaced by reading the Parquet dictionary as categorical.
+        assert!(!q.describe_optimized_plan()?.contains("cast"));
+
+        let out = q
+            .collect()?
+            .lazy()
+            .with_column(col("category").cast(DataType::String))
+            .sort(["category"], Default::default())
+            .collect()?;
+        let expected = scan_foods_parquet(par)
+            .group_by([col("category")])
+            .agg([col("calories").sum()])
+            .sort(["category"], Default::default())
+            .collect()?;
+        assert!(out.equals(&expected));
+
+        // The string column is still needed by the filter.
+        let q = scan_foods_parquet(par)
+            .with_column(col("category").cast(categorical.clone()))
+            .filter(col("category").eq(lit("seafood")));
+        let out = q.clone().collect()?;
+        assert_eq!(out.column("category")?.dtype(), &categorical);
+        assert_eq!(out.height(), 8);
+    }
+
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_prefilter_on_dictionary() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    init_files();
+    let scan = |parallel| {
+        let args = ScanArgsParquet {
+            parallel,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet(FOODS_PARQUET, args).unwrap()
+    };
+
+    let mut predicates = vec![
+        col("category").eq(lit("seafood")),
+        lit("fruit").eq(col("category")),
+        col("category").eq(lit("missing")),
+    ];
+    #[cfg(feature = "is_in")]
+    predicates.push(col("category").is_in(lit(Series::new("".into(), ["seafood", "meat"]))));
+
+    for predicate in predicates {
+        let out = scan(ParallelStrategy::Prefiltered)
+            .filter(predicate.clone())
+            .collect()?;
+        let expected = scan(ParallelStrategy::None).filter(predicate).collect()?;
+        assert!(out.equals(&expected));
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_parquet_min_max_from_statistics() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let scans_file = |q: LazyFrame| {
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        (&lp_arena)
+            .iter(lp)
+            .any(|(_, lp)| matches!(lp, IR::Scan { .. }))
+    };
+    let aggs = [
+        col("calories").min(),
+        col("calories").max().alias("max_calories"),
+        col("sugars_g").max(),
+    ];
+
+    let q = scan_foods_parquet(false).select(aggs.clone());
+    assert!(!scans_file(q.clone()));
+    let expected = scan_foods_csv().select(aggs.clone()).collect()?;
+    assert!(q.collect()?.equals(&expected));
+
+    // Floating point statistics are not trusted.
+    let q = scan_foods_parquet(false).select([col("fats_g").min()]);
+    assert!(scans_file(q));
+
+    let args = ScanArgsParquet {
+        use_statistics: false,
+        ..Default::default()
+    };
+    let q = LazyFrame::scan_parquet(FOODS_PARQUET, args)?.select(aggs);
+    assert!(scans_file(q));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "parquet", feature = "streaming"))]
+fn test_parquet_statistics_in_row_estimation() -> PolarsResult<()> {
+    let _guard = SINGLE_LOCK.lock().unwrap();
+    let estimated_join_rows = |predicate: Expr| {
+        let q = scan_foods_parquet(false).filter(predicate).inner_join(
+            scan_foods_parquet(false),
+            col("category"),
+            col("category"),
+        );
+        let (mut expr_arena, mut lp_arena) = get_arenas();
+        let lp = q.optimize(&mut lp_arena, &mut expr_arena).unwrap();
+        set_estimated_row_counts(lp, &mut lp_arena, &expr_arena, 0, &mut vec![]);
+        (&lp_arena)
+            .iter(lp)
+            .find_map(|(_, lp)| match lp {
+                IR::Join { options, .. } => Some((options.rows_left, options.rows_right)),
+                _ => None,
+            })
+            .unwrap()
+    };
+
+    // The file has no nulls. Both sides scan the same file, so the scan is shared through a cache
+    // and the filter stays above it.
+    let (left, right) = estimated_join_rows(col("calories").is_null());
+    assert_eq!(left, (None, 0));
+    assert_eq!(right, (Some(27), 27));
+    let (left, _) = estimated_join_rows(col("calories").is_not_null());
+    assert_eq!(left, (None, 27));
+    let (left, _) = estimated_join_rows(col("calories").is_null().or(col("fats_g").is_null()));
+    assert_eq!(left, (None, 0));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_partition_wise_group_by_and_join() -> PolarsResult<()> {
+    let write_partitions = |name: &str, partitions: &[&str]| -> PolarsResult<String> {
+        let root = std::env::temp_dir().join(format!("polars_partition_wise_{name}"));
+        let _ = std::fs::remove_dir_all(&root);
+        for (i, partition) in partitions.iter().enumerate() {
+            let dir = root.join(partition);
+            std::fs::create_dir_all(&dir)?;
+            let i = i as i32;
+            let mut df = df![
+                "id" => [0, 1, 2, i],
+                "val" => [i, 10 * i, 100 * i, 1000 * i],
+            ]?;
+            let f = std::fs::File::create(dir.join("0.parquet"))?;
+            ParquetWriter::new(f).finish(&mut df)?;
+        }
+        Ok(format!("{}/**/*.parquet", root.display()))
+    };
+    let scan = |path: &str| {
+        let mut args = ScanArgsParquet::default();
+        args.hive_options.enabled = Some(true);
+        LazyFrame::scan_parquet(path, args)
+    };
+    let has_union = |lf: LazyFrame| -> PolarsResult<bool> {
+        let plan = lf.to_alp_optimized()?;
+        Ok((&plan.lp_arena)
+            .iter(plan.lp_top)
+            .any(|(_, lp)| matches!(lp, IR::Union { .. })))
+    };
+    let sorted = |df: DataFrame| df.sort(df.get_column_names_owned(), Default::default());
+
+    let left = write_partitions(
+        "left",
+        &[
+            "year=2020/month=1",
+            "year=2020/month=2",
+            "year=2021/month=1",
+        ],
+    )?;
+    let right = write_partitions("right", &["year=2020", "year=2022"])?;
+    let left_df = scan(&left)?.collect()?;
+    let right_df = scan(&right)?.collect()?;
+
+    let q = |lf: LazyFrame| {
+        lf.group_by([col("year"), col("id")])
+            .agg([col("val").sum(), col("month").max()])
+    };
+    assert!(has_union(q(scan(&left)?))?);
+    assert!(
+        sorted(q(scan(&left)?).collect()?)?.equals(&sorted(q(left_df.clone().lazy()).collect()?)?)
+    );
+
+    for how in [JoinType::Inner, JoinType::Left] {
+        let q = |left: LazyFrame, right: LazyFrame| {
+            left.join(
+                right,
+                [col("year"), col("id")],
+                [col("year"), col("id")],
+                how.clone().into(),
+            )
+        };
+        assert!(has_union(q(scan(&left)?, scan(&right)?))?);
+        let out = q(scan(&left)?, scan(&right)?).collect()?;
+        let expected = q(left_df.clone().lazy(), right_df.clone().lazy()).collect()?;
+        assert!(sorted(out)?.equals_missing(&sorted(expected)?));
+    }
+
+    // The order of the groups is not kept within the partitions.
+    let q = scan(&left)?
+        .group_by_stable([col("year")])
+        .agg([col("val").sum()]);
+    assert!(!has_union(q)?);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "parquet")]
+fn test_sink_parquet_partitioned() -> PolarsResult<()> {
+    let root = std::env::temp_dir().join(format!(
+        "polars_sink_parquet_partitioned_{}",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_dir_all(&root);
+    let df = df![
+        "day" => [1, 2, 1, 2, 1],
+        "user" => ["a", "a", "b", "a", "a"],
+        "bytes" => [10, 20, 30, 40, 50],
+    ]?;
+    df.lazy()
+        .group_by([col("day"), col("user")])
+        .sink_parquet_partitioned(
+            [col("bytes").sum()],
+            &["day"],
+            &root,
+            ParquetWriteOptions::default(),
+        )?;
+
+    let read = |day: &str| -> PolarsResult<DataFrame> {
+        let path = root.join(format!("day={day}")).join("00000000.parquet");
+        LazyFrame::scan_parquet(path, Default::default())?
+            .select([col("user"), col("bytes")])
+            .sort(["user"], Default::default())
+            .collect()
+    };
+    assert!(read("1")?.equals(&df![
+        "user" => ["a", "b"],
+        "bytes" => [60, 30],
+    ]?));
+    assert!(read("2")?.equals(&df![
+        "user" => ["a"],
+        "bytes" => [60],
+    ]?));
+
+    let sink = df![
+        "day" => [1],
+        "user" => ["a"],
+    ]?
+    .lazy()
+    .group_by([col("day")])
+    .sink_parquet_partitioned([len()], &["user"], &root, Default::default());
+    std::fs::remove_dir_all(&root)?;
+    assert!(sink.is_err());
+    Ok(())
+}
@@ -41,6 +41,8 @@ use polars_core::prelude::*;
 pub(crate) use polars_core::SINGLE_LOCK;
 use polars_io::prelude::*;
 
+#[cfg(all(feature = "cov", feature = "rank"))]
+use crate::dsl::kendall_rank_corr;
 #[cfg(feature = "cov")]
 use crate::dsl::pearson_corr;
 use crate::prelude::*;
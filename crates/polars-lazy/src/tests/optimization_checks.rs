@@ -694,3 +694,26 @@ fn test_cluster_with_columns_chain() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "csv")]
+fn test_count_star_with_filter_projects_predicate_columns() -> PolarsResult<()> {
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let q = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .select([len()]);
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena)?;
+    assert!((&lp_arena).iter(lp).all(|(_, lp)| match lp {
+        IR::Scan { file_options, .. } => {
+            file_options.with_columns.as_deref() == Some(&["calories".into()][..])
+        },
+        _ => true,
+    }));
+
+    let expected = scan_foods_csv()
+        .filter(col("calories").gt(lit(100)))
+        .collect()?
+        .height() as IdxSize;
+    assert_eq!(q.collect()?.column("len")?.idx()?.get(0), Some(expected));
+    Ok(())
+}
@@ -1206,6 +1206,54 @@ fn test_cross_join() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_join_float_tolerance() -> PolarsResult<()> {
+    let left = df![
+        "k" => [0.1 + 0.2, 1.0, 2.0],
+        "a" => [1, 2, 3],
+    ]?;
+    let right = df![
+        "k" => [0.3, 1.0000001, 2.1],
+        "b" => [10, 20, 30],
+    ]?;
+    let join = |tolerance: Option<f64>| -> PolarsResult<DataFrame> {
+        let mut builder = left
+            .clone()
+            .lazy()
+            .join_builder()
+            .with(right.clone().lazy())
+            .on([col("k")]);
+        if let Some(epsilon) = tolerance {
+            builder = builder.float_tolerance(FloatTolerance::new(epsilon)?);
+        }
+        builder.finish().sort(["a"], Default::default()).collect()
+    };
+
+    assert_eq!(join(None)?.height(), 0);
+    let out = join(Some(1e-6))?;
+    assert_eq!(Vec::from(out.column("a")?.i32()?), &[Some(1), Some(2)]);
+    assert_eq!(Vec::from(out.column("b")?.i32()?), &[Some(10), Some(20)]);
+    // The keys of the left side are kept as is.
+    assert_eq!(out.column("k")?.f64()?.get(0), Some(0.1 + 0.2));
+    assert!(FloatTolerance::new(0.0).is_err());
+
+    // Keys whose multiples of epsilon don't fit in an integer still match, keys whose
+    // multiples don't fit in a float are an error.
+    let large = |k: f64| df!["k" => [k]].unwrap().lazy();
+    let join_large = |k: f64| {
+        large(k)
+            .join_builder()
+            .with(large(k))
+            .on([col("k")])
+            .float_tolerance(FloatTolerance::new(1e-6).unwrap())
+            .finish()
+            .collect()
+    };
+    assert_eq!(join_large(1e20)?.height(), 1);
+    assert!(join_large(f64::MAX).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_select_empty_df() -> PolarsResult<()> {
     // https://github.com/pola-rs/polars/issues/1056
@@ -1947,3 +1995,866 @@ fn test_sort_maintain_order_true() -> PolarsResult<()> {
     ]?));
     Ok(())
 }
+
+#[test]
+fn test_profile_expressions() -> PolarsResult<()> {
+    let (out, nodes, exprs) = fruits_cars()
+        .lazy()
+        .with_columns([col("A").alias("A2"), col("B").neq(lit(1)).alias("B1")])
+        // Refers to a computed column, so it can't be pushed into the scan.
+        .filter(col("B1"))
+        .profile_expressions()?;
+
+    assert_eq!(out.height(), 4);
+    assert!(nodes.height() > 0);
+    assert_eq!(
+        exprs.get_column_names(),
+        &["node", "expression", "calls", "total_time"]
+    );
+    let node = exprs.column("node")?.str()?;
+    assert_eq!(node.into_iter().filter(|n| *n == Some("filter")).count(), 1);
+    assert_eq!(
+        node.into_iter()
+            .filter(|n| *n == Some("with_columns"))
+            .count(),
+        2
+    );
+    // Ranked by time spent, heaviest first.
+    let total = exprs.column("total_time")?.u64()?;
+    assert!(total
+        .into_no_null_iter()
+        .collect::<Vec<_>>()
+        .windows(2)
+        .all(|w| w[0] >= w[1]));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(
+    feature = "string_parse",
+    feature = "dtype-date",
+    feature = "dtype-struct"
+))]
+fn test_str_parse() -> PolarsResult<()> {
+    let df = df![
+        "num" => ["1.234,5", " 7 ", "x"],
+        "bool" => ["Yes", "no", "maybe"],
+        "date" => ["2024-01-31", "31/01/2024", "2024"],
+    ]?;
+
+    let options = StrParseOptions {
+        decimal_separator: ',',
+        thousands_separator: Some('.'),
+        true_values: vec!["yes".into()],
+        false_values: vec!["no".into()],
+        formats: vec!["%Y-%m-%d".into(), "%d/%m/%Y".into()],
+        strict: false,
+        ..Default::default()
+    };
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("num").str().parse(DataType::Float64, options.clone()),
+            col("bool").str().parse(DataType::Boolean, options.clone()),
+            col("date").str().parse(DataType::Date, options.clone()),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("num")?.f64()?),
+        &[Some(1234.5), Some(7.0), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("bool")?.bool()?),
+        &[Some(true), Some(false), None]
+    );
+    let date = out.column("date")?.cast(&DataType::String)?;
+    assert_eq!(
+        Vec::from(date.str()?),
+        &[Some("2024-01-31"), Some("2024-01-31"), None]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("bool").str().parse(
+            DataType::Boolean,
+            StrParseOptions {
+                with_errors: true,
+                ..options.clone()
+            },
+        )])
+        .unnest(["bool"])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("error")?.str()?),
+        &[None, None, Some("cannot parse 'maybe' as bool")]
+    );
+
+    let strict = df
+        .lazy()
+        .select([col("num").str().parse(DataType::Int64, Default::default())])
+        .collect();
+    assert!(strict.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "strings", feature = "temporal", feature = "dtype-struct"))]
+fn test_infer_datetime_format() -> PolarsResult<()> {
+    let df = df![
+        "date" => [Some("01/02/2024"), None, Some("03/04/2024")],
+    ]?;
+    let out = df
+        .lazy()
+        .select([col("date").str().infer_datetime_format(100)])
+        .unnest(["date"])
+        .collect()?;
+
+    assert_eq!(out.shape(), (1, 4));
+    assert_eq!(out.column("format")?.str()?.get(0), Some("%d/%m/%Y"));
+    assert_eq!(out.column("n_sampled")?.idx()?.get(0), Some(2));
+    assert_eq!(out.column("n_matched")?.idx()?.get(0), Some(2));
+    let ambiguous_with = out
+        .column("ambiguous_with")?
+        .list()?
+        .get_as_series(0)
+        .unwrap();
+    assert_eq!(Vec::from(ambiguous_with.str()?), &[Some("%m/%d/%Y")]);
+    Ok(())
+}
+
+#[test]
+fn test_list_numeric_arithmetic() -> PolarsResult<()> {
+    let list = Series::new(
+        "l".into(),
+        [
+            Series::new("".into(), [1.0f64, 2.0]),
+            Series::new("".into(), [3.0f64]),
+        ],
+    );
+    let df = DataFrame::new(vec![list.into(), Column::new("n".into(), [2i32, 4])])?;
+    let q = df.lazy().select([
+        (col("l") + lit(1.0)).alias("plus"),
+        (col("l") * col("n")).alias("times"),
+        (lit(10.0) - col("l")).alias("minus"),
+        (col("l") / col("n")).alias("div"),
+    ]);
+    let schema = q.clone().collect_schema()?;
+    let out = q.collect()?;
+    assert_eq!(out.schema(), *schema);
+
+    let values = |name: &str| -> PolarsResult<Vec<Series>> {
+        Ok(out.column(name)?.list()?.into_iter().flatten().collect())
+    };
+    let expected = [
+        ("plus", vec![vec![2.0, 3.0], vec![4.0]]),
+        ("times", vec![vec![2.0, 4.0], vec![12.0]]),
+        ("minus", vec![vec![9.0, 8.0], vec![7.0]]),
+        ("div", vec![vec![0.5, 1.0], vec![0.75]]),
+    ];
+    for (name, expected) in expected {
+        for (s, expected) in values(name)?.iter().zip(expected) {
+            assert_eq!(
+                Vec::from(s.f64()?),
+                expected.into_iter().map(Some).collect::<Vec<_>>()
+            );
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "to_dummies", feature = "target_encoding"))]
+fn test_fitted_encoders() -> PolarsResult<()> {
+    let train = df![
+        "color" => ["red", "blue", "red", "green"],
+        "y" => [1.0, 0.0, 3.0, 4.0],
+    ]?;
+    let colors = train.column("color")?.as_materialized_series();
+    let one_hot = OneHotEncoder::fit(colors, None, false)?;
+    let target = TargetEncoder::fit(colors, train.column("y")?.as_materialized_series(), 0.0)?;
+
+    // Unseen categories don't add fields.
+    let test = df!["color" => ["red", "purple"]]?;
+    let q = test.lazy().select([
+        col("color").one_hot(one_hot).alias("dummies"),
+        col("color").target_encode(target).alias("encoded"),
+    ]);
+    let schema = q.clone().collect_schema()?;
+    let out = q.collect()?.unnest(["dummies"])?;
+    assert_eq!(schema.len(), 2);
+    assert_eq!(
+        out.get_column_names(),
+        &["color_blue", "color_green", "color_red", "encoded"]
+    );
+    assert_eq!(
+        Vec::from(out.column("color_red")?.cast(&DataType::Int32)?.i32()?),
+        &[Some(1), Some(0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("encoded")?.f64()?),
+        &[Some(2.0), Some(2.0)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "scale")]
+fn test_scale() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "b", "b"],
+        "x" => [1.0, 3.0, 10.0, 30.0],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("x").scale_minmax().over([col("g")])])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("x")?.f64()?),
+        &[Some(0.0), Some(1.0), Some(0.0), Some(1.0)]
+    );
+
+    let params = df
+        .lazy()
+        .group_by_stable([col("g")])
+        .agg([col("x").scale_params(ScaleMethod::MinMax)])
+        .collect()?
+        .unnest(["x"])?;
+    assert_eq!(
+        Vec::from(params.column("scale")?.f64()?),
+        &[Some(2.0), Some(20.0)]
+    );
+
+    // Reuse the parameters of group "a" on new data.
+    let params = ScaleParams {
+        center: 1.0,
+        scale: 2.0,
+    };
+    let out = df!["x" => [5, 0]]?
+        .lazy()
+        .select([col("x").scale_with(params)])
+        .collect()?;
+    assert_eq!(Vec::from(out.column("x")?.f64()?), &[Some(2.0), Some(-0.5)]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "outliers")]
+fn test_outliers() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "a", "b", "b", "b", "b"],
+        "x" => [1.0, 2.0, 3.0, 100.0, 100.0, 101.0, 102.0, 103.0],
+    ]?;
+    let out = df
+        .lazy()
+        .select([
+            col("x")
+                .is_outlier(OutlierMethod::default())
+                .over([col("g")])
+                .alias("per_group"),
+            col("x")
+                .is_outlier(OutlierMethod::default())
+                .alias("global"),
+            isolation_forest_score(
+                [col("x")],
+                IsolationForestOptions {
+                    seed: Some(0),
+                    ..Default::default()
+                },
+            )
+            .alias("score"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("per_group")?.bool()?.sum(), Some(1));
+    assert!(out.column("per_group")?.bool()?.get(3).unwrap());
+    assert_eq!(out.column("global")?.bool()?.sum(), Some(0));
+    assert_eq!(out.column("score")?.null_count(), 0);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "quantile_sketch")]
+fn test_quantile_sketch() -> PolarsResult<()> {
+    let batch = |offset: i32| {
+        df![
+            "g" => ["a", "b"].repeat(50),
+            "x" => (offset..offset + 100).collect::<Vec<_>>(),
+        ]
+    };
+
+    // Sketch every batch per group and merge the sketches of the batches afterwards.
+    let sketches = [batch(0)?, batch(100)?]
+        .into_iter()
+        .map(|df| {
+            df.lazy()
+                .group_by([col("g")])
+                .agg([col("x").quantile_sketch(100.0)])
+        })
+        .collect::<Vec<_>>();
+    let out = concat(sketches, Default::default())?
+        .group_by([col("g")])
+        .agg([col("x").quantile_sketch_merge()])
+        .select([
+            col("g"),
+            col("x").quantile_sketch_query(0.0).alias("min"),
+            col("x").quantile_sketch_query(1.0).alias("max"),
+        ])
+        .sort(["g"], Default::default())
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("min")?.f64()?),
+        &[Some(0.0), Some(1.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("max")?.f64()?),
+        &[Some(198.0), Some(199.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_rollup_cube() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "b" => [Some(1), Some(2), None],
+        "v" => [1, 2, 3],
+    ]?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .group_by_rollup([col("a"), col("b")])
+        .agg([col("v").sum()])?
+        .sort([GROUPING_ID_NAME, "a", "b"], Default::default())
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "v", GROUPING_ID_NAME]);
+    assert_eq!(
+        Vec::from(out.column(GROUPING_ID_NAME)?.u32()?),
+        &[Some(0), Some(0), Some(0), Some(1), Some(1), Some(3)]
+    );
+    // The null key of group ("y", null) is told apart from the subtotal of "y" by the
+    // grouping id.
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(1), Some(2), None, None, None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(3), Some(3), Some(6)]
+    );
+
+    let out = df
+        .lazy()
+        .group_by_cube([col("a"), col("b")])
+        .agg([col("v").sum()])?
+        .filter(col(GROUPING_ID_NAME).eq(lit(2u32)))
+        .sort(["b"], Default::default())
+        .collect()?;
+    assert_eq!(out.column("a")?.null_count(), 3);
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(3), Some(1), Some(2)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_group_by_grouping_sets_partials() -> PolarsResult<()> {
+    let df = df![
+        "a" => ["x", "x", "y"],
+        "b" => [Some(1), Some(2), None],
+        "v" => [Some(1.0), None, Some(3.0)],
+    ]?;
+    let rollup = |df: DataFrame, aggs: &[Expr]| {
+        df.lazy()
+            .group_by_rollup([col("a"), col("b")])
+            .agg(aggs)?
+            .sort([GROUPING_ID_NAME, "a", "b"], Default::default())
+            .collect()
+    };
+
+    // Rolled up from the partial aggregates, or aggregated per grouping set.
+    let decomposable = [
+        col("v").sum().alias("sum"),
+        col("v").max().alias("max"),
+        col("v").count().alias("count"),
+        len(),
+    ];
+    let out = rollup(df.clone(), &decomposable)?;
+    assert_eq!(
+        Vec::from(out.column("sum")?.f64()?),
+        &[
+            Some(1.0),
+            Some(0.0),
+            Some(3.0),
+            Some(1.0),
+            Some(3.0),
+            Some(4.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("max")?.f64()?),
+        &[Some(1.0), None, Some(3.0), Some(1.0), Some(3.0), Some(3.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("count")?.idx()?),
+        &[Some(1), Some(0), Some(1), Some(1), Some(1), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("len")?.idx()?),
+        &[Some(1), Some(1), Some(1), Some(2), Some(1), Some(3)]
+    );
+    let out = rollup(df.clone(), &[col("v").mean()])?;
+    assert_eq!(
+        Vec::from(out.column("v")?.f64()?),
+        &[Some(1.0), None, Some(3.0), Some(1.0), Some(3.0), Some(2.0)]
+    );
+
+    // The grand total of an empty input is a single row.
+    for aggs in [&decomposable[..], &[col("v").mean()]] {
+        let out = rollup(df.clear(), aggs)?;
+        assert_eq!(out.height(), 1);
+        assert_eq!(out.column(GROUPING_ID_NAME)?.u32()?.get(0), Some(3));
+    }
+
+    // The grouping id column cannot be shadowed.
+    let df = df.lazy().with_column(col("v").alias(GROUPING_ID_NAME));
+    let out = df
+        .clone()
+        .group_by_rollup([col("a")])
+        .agg([col(GROUPING_ID_NAME).sum()])?;
+    assert!(out.collect().is_err());
+
+    // Every key has a bit in the grouping id.
+    let keys = (0..33)
+        .map(|i| lit(i).alias(format!("k{i}")))
+        .collect::<Vec<_>>();
+    assert!(df.clone().group_by_cube(&keys).agg([len()]).is_err());
+    assert!(df.group_by_rollup(&keys[..32]).agg([len()]).is_ok());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_split_by_fraction() -> PolarsResult<()> {
+    let df = df![
+        "g" => [0, 0, 0, 0, 1, 1, 1, 1],
+        "user" => [1, 1, 2, 2, 3, 3, 4, 4],
+    ]?;
+
+    let (train, test) = df
+        .clone()
+        .lazy()
+        .split_by_fraction(0.5, 0, Some(vec![col("g")]), None);
+    let train = train.collect()?;
+    let test = test.collect()?;
+    assert_eq!(train.height(), 4);
+    assert_eq!(test.height(), 4);
+    // Every stratum is split in half.
+    assert_eq!(train.column("g")?.as_materialized_series().sum::<i32>()?, 2);
+
+    // Keys don't leak between the sides.
+    let (train, test) = df.lazy().split_by_fraction(0.5, 0, None, Some(col("user")));
+    let train = train.collect()?;
+    let test = test.collect()?;
+    let train_users = train.column("user")?.as_materialized_series().unique()?;
+    let test_users = test.column("user")?.as_materialized_series().unique()?;
+    assert_eq!(train_users.len() + test_users.len(), 4);
+    assert_eq!(train.height() + test.height(), 8);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "gaps_and_islands")]
+fn test_gaps_and_islands() -> PolarsResult<()> {
+    let df = df![
+        "sensor" => ["a", "b", "a", "a", "b", "a", "a"],
+        "t" => [Some(8), Some(6), Some(1), Some(3), Some(5), Some(2), Some(7)],
+    ]?;
+    let out = df
+        .clone()
+        .lazy()
+        .gaps_and_islands(
+            col("t"),
+            polars_time::Duration::parse("1i"),
+            [col("sensor")],
+        )?
+        .collect()?;
+
+    let expected = df![
+        "sensor" => ["a", "a", "a", "b"],
+        "island" => [Some(0 as IdxSize), None, Some(1), Some(0)],
+        "start" => [1, 3, 7, 5],
+        "end" => [3, 7, 8, 6],
+        "len" => [3 as IdxSize, 0, 2, 2],
+        "is_gap" => [false, true, false, false],
+    ]?;
+    assert!(out.equals_missing(&expected));
+
+    // A negative index gap splits every row into an island of its own.
+    let out = df
+        .clone()
+        .lazy()
+        .gaps_and_islands(
+            col("t"),
+            polars_time::Duration::parse("-1i"),
+            [col("sensor")],
+        )?
+        .filter(col("is_gap").not())
+        .collect()?;
+    assert_eq!(out.height(), 7);
+
+    // The keys are output columns, so they must have a name.
+    assert!(df
+        .lazy()
+        .gaps_and_islands(col("t"), polars_time::Duration::parse("1i"), [lit(1)])
+        .is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_as_of_snapshot() -> PolarsResult<()> {
+    let events = df![
+        "customer" => ["a", "b", "a", "a"],
+        "t" => [5, 3, 1, 12],
+    ]?;
+    let changes = df![
+        "customer" => ["a", "a", "b"],
+        "valid_from" => [0, 10, 4],
+        "tier" => ["free", "pro", "gold"],
+        "region" => ["eu", "us", "eu"],
+        "note" => ["x", "y", "z"],
+    ]?;
+    let out = events
+        .lazy()
+        .as_of_snapshot(
+            changes.lazy(),
+            "t",
+            "valid_from",
+            &["customer"],
+            &["tier", "region"],
+        )
+        .collect()?;
+
+    let expected = df![
+        "customer" => ["a", "b", "a", "a"],
+        "t" => [5, 3, 1, 12],
+        "valid_from" => [Some(0), None, Some(0), Some(10)],
+        "tier" => [Some("free"), None, Some("free"), Some("pro")],
+        "region" => [Some("eu"), None, Some("eu"), Some("us")],
+    ]?;
+    assert!(out.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "event_windows")]
+fn test_session_window_over() -> PolarsResult<()> {
+    let df = df![
+        "user" => ["a", "b", "a", "a", "b"],
+        "t" => [0i64, 1, 5, 30, 3],
+    ]?;
+    let out = df
+        .lazy()
+        .select([(col("t") * lit(60_000i64))
+            .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+            .dt()
+            .session_window(polars_time::Duration::parse("10m"))
+            .over([col("user")])
+            .struct_()
+            .field_by_name("start")
+            .cast(DataType::Int64)
+            / lit(60_000i64)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("t")?.i64()?),
+        &[Some(0), Some(1), Some(0), Some(30), Some(1)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "assertions")]
+fn test_expr_assert() -> PolarsResult<()> {
+    let df = df![
+        "age" => [Some(31), None, Some(-4), Some(50), Some(-1)],
+    ]?;
+    let q = |min: i32| {
+        df.clone()
+            .lazy()
+            .select([col("age").assert(col("age").gt_eq(lit(min)), "age is not negative") * lit(2)])
+    };
+    let out = q(-10).collect()?;
+    assert_eq!(
+        Vec::from(out.column("age")?.i32()?),
+        &[Some(62), None, Some(-8), Some(100), Some(-2)]
+    );
+
+    let err = q(0).collect().unwrap_err().to_string();
+    assert!(err.contains("age is not negative"));
+    assert!(err.contains("2 of 5"));
+    assert!(err.contains("row 2: -4"));
+
+    // Rows are numbered in the input, not in the chunk or batch that contains them.
+    let mut chunked = df.head(Some(2));
+    chunked.vstack_mut(&df.tail(Some(3)))?;
+    let err = chunked
+        .lazy()
+        .with_streaming(true)
+        .select([col("age").assert(col("age").gt_eq(lit(0)), "age is not negative")])
+        .collect()
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("row 2: -4"));
+    assert!(err.contains("row 4: -1"));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "unstable_rewrite_rules")]
+fn test_expr_rewrite_rule() -> PolarsResult<()> {
+    struct CastToConstant;
+
+    impl ExprRewriteRule for CastToConstant {
+        fn rewrite(
+            &self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _input_schema: &Schema,
+        ) -> PolarsResult<Option<AExpr>> {
+            Ok(match expr_arena.get(expr_node) {
+                AExpr::Cast {
+                    expr,
+                    dtype: DataType::String,
+                    ..
+                } if matches!(expr_arena.get(*expr), AExpr::Column(name) if name == "rewritten") =>
+                {
+                    let constant =
+                        expr_arena.add(AExpr::Literal(LiteralValue::String("constant".into())));
+                    Some(AExpr::Alias(constant, "rewritten".into()))
+                },
+                _ => None,
+            })
+        }
+    }
+
+    struct ChangeDtype;
+
+    impl ExprRewriteRule for ChangeDtype {
+        fn rewrite(
+            &self,
+            expr_arena: &mut Arena<AExpr>,
+            expr_node: Node,
+            _input_schema: &Schema,
+        ) -> PolarsResult<Option<AExpr>> {
+            let AExpr::Cast { expr, .. } = expr_arena.get(expr_node) else {
+                return Ok(None);
+            };
+            let is_changed =
+                matches!(expr_arena.get(*expr), AExpr::Column(name) if name == "changed");
+            Ok(is_changed.then(|| AExpr::Column("changed".into())))
+        }
+    }
+
+    register_expr_rewrite_rule("cast_to_constant", Arc::new(CastToConstant));
+    let df = df!["rewritten" => [1, 2]]?;
+    let out = df
+        .lazy()
+        .select([
+            col("rewritten"),
+            col("rewritten").cast(DataType::String).alias("cast"),
+        ])
+        .collect();
+    assert!(unregister_expr_rewrite_rule("cast_to_constant"));
+
+    let cast = out?.column("cast")?.str()?.clone();
+    assert_eq!(Vec::from(&cast), &[Some("constant"), Some("constant")]);
+
+    // A rewrite must keep the dtype.
+    register_expr_rewrite_rule("change_dtype", Arc::new(ChangeDtype));
+    let out = df!["changed" => [1, 2]]?
+        .lazy()
+        .select([col("changed").cast(DataType::Float64)])
+        .collect();
+    assert!(unregister_expr_rewrite_rule("change_dtype"));
+    assert!(out.is_err());
+    Ok(())
+}
+
+#[test]
+fn test_union_column_matching() -> PolarsResult<()> {
+    let a = df!["a" => [1, 2], "b" => ["x", "y"]]?;
+    let b = df!["c" => [3i64], "d" => ["z"]]?;
+    let c = df!["b" => ["w"], "e" => [1]]?;
+
+    let args = UnionArgs {
+        matching: UnionColumnMatching::Position,
+        to_supertypes: true,
+        ..Default::default()
+    };
+    let q = concat([a.clone().lazy(), b.lazy()], args)?;
+    let report = q.union_reconciliation()?;
+    let expected = df![
+        "a" => [1i64, 2, 3],
+        "b" => ["x", "y", "z"],
+    ]?;
+    assert!(q.collect()?.equals(&expected));
+    assert_eq!(
+        Vec::from(report.column("action")?.str()?),
+        &[Some("cast"), Some("renamed"), Some("renamed")]
+    );
+    assert_eq!(
+        Vec::from(report.column("detail")?.str()?),
+        &[Some("from i32 to i64"), Some("from `c`"), Some("from `d`")]
+    );
+
+    let args = UnionArgs {
+        matching: UnionColumnMatching::Name,
+        ..Default::default()
+    };
+    let q = concat([a.lazy(), c.lazy()], args)?;
+    let report = q.union_reconciliation()?;
+    let expected = df![
+        "a" => [Some(1), Some(2), None],
+        "b" => ["x", "y", "w"],
+    ]?;
+    assert!(q.collect()?.equals_missing(&expected));
+    assert_eq!(
+        Vec::from(report.column("column")?.str()?),
+        &[Some("a"), Some("e")]
+    );
+    assert_eq!(
+        Vec::from(report.column("action")?.str()?),
+        &[Some("null_filled"), Some("dropped")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_collect_with_seed() -> PolarsResult<()> {
+    let df = df![
+        "g" => (0..100).map(|i| i % 4).collect::<Vec<i32>>(),
+        "a" => (0..100).collect::<Vec<i32>>(),
+    ]?;
+    let q = || {
+        df.clone().lazy().group_by_stable([col("g")]).agg([
+            col("a").shuffle(None).alias("shuffled"),
+            col("a")
+                .sample_n(lit(5), false, true, None)
+                .alias("sampled"),
+        ])
+    };
+    let out = q().collect_with_seed(0)?;
+    assert!(out.equals(&q().collect_with_seed(0)?));
+    assert!(!out.equals(&q().collect_with_seed(1)?));
+
+    // Equal random expressions in a seeded query still draw different values.
+    let out = df
+        .lazy()
+        .select([
+            col("a").shuffle(None).alias("x"),
+            col("a").shuffle(None).alias("y"),
+        ])
+        .collect_with_seed(0)?;
+    assert!(!out.column("x")?.equals(out.column("y")?));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "random")]
+fn test_sample_by_group() -> PolarsResult<()> {
+    let df = df![
+        "g" => ["a", "a", "a", "b", "b", "c"],
+        "v" => [1, 2, 3, 4, 5, 6],
+        "w" => [0.0, 1.0, 1.0, 1.0, 0.0, 1.0],
+    ]?;
+
+    // Rows with a zero weight are never sampled and groups without a size are skipped.
+    let sizes = df!["g" => ["a", "b"], "n" => [2, 1]]?;
+    let out = df
+        .clone()
+        .lazy()
+        .sample_n_by_group(
+            [col("g")],
+            sizes.lazy(),
+            "n",
+            Some(col("w")),
+            false,
+            Some(0),
+        )
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(2), Some(3), Some(4)]
+    );
+
+    let sizes = df!["g" => ["a", "b"], "n" => [2, 3]]?;
+    let out = df
+        .clone()
+        .lazy()
+        .sample_n_by_group([col("g")], sizes.lazy(), "n", Some(col("w")), true, Some(0))
+        .filter(col("g").eq(lit("b")))
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("v")?.i32()?),
+        &[Some(4), Some(4), Some(4)]
+    );
+
+    let sizes = df!["g" => ["a", "b", "c"], "frac" => [1.0, 0.5, 0.0]]?;
+    let out = df
+        .lazy()
+        .sample_frac_by_group([col("g")], sizes.lazy(), "frac", None, false, Some(0))
+        .collect()?;
+    assert_eq!(out.height(), 4);
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "text_search"))]
+fn test_term_vectorizer_deserialize_validates() -> PolarsResult<()> {
+    let train = StringChunked::new("".into(), &["the cat sat", "the dog"]);
+    let vectorizer = TermVectorizer::fit(&train, &VectorizeOptions::default());
+    let json = serde_json::to_value(&vectorizer).unwrap();
+    let roundtrip: TermVectorizer = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(roundtrip, vectorizer);
+
+    let mut duplicate = json.clone();
+    duplicate["vocabulary"] = serde_json::json!(["cat", "cat", "dog", "sat"]);
+    assert!(serde_json::from_value::<TermVectorizer>(duplicate).is_err());
+    let mut idf = json;
+    idf["idf"] = serde_json::json!([1.0]);
+    assert!(serde_json::from_value::<TermVectorizer>(idf).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "serde", feature = "to_dummies", feature = "target_encoding"))]
+fn test_fitted_encoders_deserialize_validates() -> PolarsResult<()> {
+    let colors = Series::new("color".into(), ["red", "blue", "red"]);
+    let one_hot = OneHotEncoder::fit(&colors, None, false)?;
+    let target = TargetEncoder::fit(&colors, &Series::new("y".into(), [1.0, 0.0, 3.0]), 0.0)?;
+
+    let json = serde_json::to_value(&one_hot).unwrap();
+    assert_eq!(
+        serde_json::from_value::<OneHotEncoder>(json.clone()).unwrap(),
+        one_hot
+    );
+    let mut duplicate = json;
+    duplicate["categories"] = serde_json::json!(["red", "red"]);
+    assert!(serde_json::from_value::<OneHotEncoder>(duplicate).is_err());
+
+    let json = serde_json::to_value(&target).unwrap();
+    assert_eq!(
+        serde_json::from_value::<TargetEncoder>(json.clone()).unwrap(),
+        target
+    );
+    let mut missing_encoding = json;
+    missing_encoding["encodings"] = serde_json::json!([1.0]);
+    assert!(serde_json::from_value::<TargetEncoder>(missing_encoding).is_err());
+    Ok(())
+}
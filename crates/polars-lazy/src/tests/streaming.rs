@@ -1,3 +1,4 @@
+use polars_core::series::IsSorted;
 use polars_ops::frame::JoinCoalesce;
 
 use super::*;
@@ -128,6 +129,47 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_streaming_group_by_sorted_keys() -> PolarsResult<()> {
+    let df = df![
+        "str" => [None, None, Some("a"), Some("a"), Some("b"), Some("c"), Some("c"), Some("c")],
+        "int" => [Some(1), Some(1), Some(2), Some(5), Some(5), Some(7), None, None],
+        "val" => [1, 2, 3, 4, 5, 6, 7, 8],
+    ]?;
+    // Vertically stack so that groups span several chunks.
+    let mut df = df.vstack(&df.slice(6, 2))?;
+    df.vstack_mut(&df![
+        "str" => [Some("d")],
+        "int" => [None::<i32>],
+        "val" => [9],
+    ]?)?;
+
+    for key in ["str", "int"] {
+        let q = |sorted: bool| {
+            let lf = df.clone().lazy();
+            let lf = if sorted {
+                lf.with_column(col(key).set_sorted_flag(IsSorted::Ascending))
+            } else {
+                lf
+            };
+            lf.group_by([col(key)])
+                .agg([
+                    col("val").sum().alias("sum"),
+                    col("val").first().alias("first"),
+                    col("val").count().alias("count"),
+                ])
+                .sort([key], Default::default())
+        };
+        let expected = q(false).with_streaming(false).collect()?;
+        assert_eq!(q(true).with_streaming(false).collect()?, expected);
+        assert!(optimization_checks::is_pipeline(
+            q(true).with_streaming(true)
+        ));
+        assert_eq!(q(true).with_streaming(true).collect()?, expected);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_unique() -> PolarsResult<()> {
     let q = get_csv_file();
@@ -428,3 +470,174 @@ fn test_streaming_full_outer_join() -> PolarsResult<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_grouped_agg_state() -> PolarsResult<()> {
+    let a = df![
+        "g" => [1, 2, 1, 3],
+        "v" => [1.0, 2.0, 3.0, 4.0],
+    ]?;
+    let b = df![
+        "g" => [2, 1, 2],
+        "v" => [5.0, 6.0, 7.0],
+    ]?;
+    let aggs = [
+        col("v").sum().alias("sum"),
+        col("v").min().alias("min"),
+        col("v").max().alias("max"),
+        col("v").count().alias("count"),
+        col("v").mean().alias("mean"),
+        col("v").first().alias("first"),
+        col("v").last().alias("last"),
+    ];
+    let sort = |df: DataFrame| df.sort(["g"], Default::default());
+
+    let expected = sort(
+        concat([a.clone().lazy(), b.clone().lazy()], Default::default())?
+            .group_by([col("g")])
+            .agg(aggs.clone())
+            .collect()?,
+    )?;
+
+    let mut state = GroupedAggState::new(a.schema(), [col("g")], aggs.clone())?;
+    state.update(a.clone())?;
+    state.update(b.clone())?;
+    assert_eq!(state.schema(), &expected.schema().into());
+    assert!(sort(state.finalize()?)?.equals_missing(&expected));
+
+    // Partial states of different partitions.
+    let mut left = GroupedAggState::new(a.schema(), [col("g")], aggs.clone())?;
+    left.update(a.clone())?;
+    let mut right = left.split();
+    right.update(b.clone())?;
+    left.merge(right)?;
+    assert!(sort(left.finalize()?)?.equals_missing(&expected));
+
+    // Partial aggregates of independently created states are merged by key.
+    let mut left = GroupedAggState::new(a.schema(), [col("g")], aggs.clone())?;
+    left.update(a.clone())?;
+    let mut right = GroupedAggState::new(a.schema(), [col("g")], aggs.clone())?;
+    right.update(b.clone())?;
+    let partial = right.into_partial()?;
+    assert_eq!(&partial.schema().into(), left.partial_schema());
+    left.merge_partial(partial)?;
+    assert!(sort(left.finalize()?)?.equals_missing(&expected));
+
+    let mut state = GroupedAggState::new(a.schema(), [col("g")], aggs.clone())?;
+    assert!(state.merge_partial(a.clone()).is_err());
+
+    assert!(GroupedAggState::new(a.schema(), [col("g")], [col("v").median()]).is_err());
+    let mut state = GroupedAggState::new(a.schema(), [col("g")], aggs)?;
+    assert!(state.update(a.select(["v"])?).is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "rows")]
+fn test_streaming_scan_rows() -> PolarsResult<()> {
+    use polars_core::frame::row::Row;
+
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a".into(), DataType::Int64),
+        Field::new("b".into(), DataType::String),
+    ]));
+    let rows = || {
+        (0..1000i64).map(|i| {
+            Row::new(vec![
+                AnyValue::Int64(i),
+                AnyValue::StringOwned(format!("{}", i % 3).into()),
+            ])
+        })
+    };
+    let args = ScanArgsRows {
+        batch_size: 64,
+        ..Default::default()
+    };
+    let q = |args: ScanArgsRows| {
+        LazyFrame::scan_rows(RowsIter::new(schema.clone(), rows()), args)
+            .unwrap()
+            .filter(col("a").gt(lit(10)))
+            .group_by([col("b")])
+            .agg([col("a").sum()])
+            .sort(["b"], Default::default())
+    };
+
+    assert!(optimization_checks::is_pipeline(
+        q(args.clone()).with_streaming(true)
+    ));
+    let out = q(args.clone()).with_streaming(true).collect()?;
+    let expected = q(args.clone()).collect()?;
+    assert_eq!(out, expected);
+    assert_eq!(
+        out.column("a")?.as_materialized_series().sum::<i64>()?,
+        (11..1000).sum::<i64>()
+    );
+
+    let out = LazyFrame::scan_rows(RowsIter::new(schema.clone(), rows()), args)?
+        .slice(100, 10)
+        .with_streaming(true)
+        .collect()?;
+    assert_eq!(out.column("a")?.i64()?.get(0), Some(100));
+    assert_eq!(out.height(), 10);
+    Ok(())
+}
+
+#[test]
+fn test_auto_streaming_estimated_scan_size() -> PolarsResult<()> {
+    use crate::physical_plan::streaming::estimated_scan_size;
+
+    let scan_size = |lf: LazyFrame| -> PolarsResult<usize> {
+        let plan = lf.to_alp_optimized()?;
+        Ok(estimated_scan_size(
+            plan.lp_top,
+            &plan.lp_arena,
+            &mut vec![],
+        ))
+    };
+
+    let full = scan_size(get_parquet_file())?;
+    let projected = scan_size(get_parquet_file().select([col("calories")]))?;
+    assert_eq!(projected, 27 * std::mem::size_of::<i64>());
+    assert!(full > projected);
+    assert_eq!(scan_size(get_parquet_file().slice(0, 3))?, full / 9);
+
+    // Data that is already in memory is not counted.
+    assert_eq!(scan_size(get_df().lazy())?, 0);
+
+    // Small scans run on the in-memory engine.
+    let plan = get_parquet_file()
+        .with_auto_streaming(true)
+        .to_alp_optimized()?;
+    assert!(!plan.describe().contains("STREAMING"));
+
+    // Without any available memory to spare, the same scan runs on the streaming engine.
+    std::env::set_var("POLARS_AUTO_STREAMING_MEMORY_FRACTION", "0");
+    let plan = get_parquet_file()
+        .with_auto_streaming(true)
+        .to_alp_optimized();
+    std::env::remove_var("POLARS_AUTO_STREAMING_MEMORY_FRACTION");
+    assert!(plan?.describe().contains("STREAMING"));
+    Ok(())
+}
+
+#[test]
+fn test_streaming_group_by_string_keys_share_buffers() -> PolarsResult<()> {
+    let keys = (0..1000)
+        .map(|i| (i % 10 != 0).then(|| format!("a key that is not inlined {}", i % 300)))
+        .collect::<StringChunked>();
+    let df = DataFrame::new(vec![
+        keys.with_name("key".into()).into_column(),
+        Column::new("val".into(), (0..1000).collect::<Vec<i32>>()),
+    ])?;
+
+    let q = df.lazy().group_by([col("key")]).agg([col("val").sum()]);
+    assert_streaming_with_default(q.clone().sort(["key"], Default::default()), true, false);
+
+    let out = q.with_streaming(true).collect()?;
+    let out_keys = out.column("key")?.str()?;
+    let first = out_keys.downcast_iter().next().unwrap().data_buffers();
+    assert!(out_keys
+        .downcast_iter()
+        .all(|arr| Arc::ptr_eq(arr.data_buffers(), first)));
+    Ok(())
+}
@@ -1,11 +1,88 @@
 use std::sync::atomic::Ordering;
 
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_io::predicates::{BatchStats, ColumnStats, PhysicalIoExpr};
+
 use super::*;
 
 pub struct CacheExec {
     pub input: Box<dyn Executor>,
     pub id: usize,
     pub count: u32,
+    /// The predicate of a filter on the output. Chunks of the cached frame that cannot pass it
+    /// are skipped.
+    pub predicate: Option<Arc<dyn PhysicalIoExpr>>,
+}
+
+/// Compute the null count, minimum and maximum of the columns of every chunk of `df`.
+fn chunk_statistics(df: &DataFrame) -> PolarsResult<Vec<BatchStats>> {
+    let schema = Arc::new(df.schema());
+    let mut df = df.clone();
+    df.split_chunks()
+        .map(|chunk| {
+            let stats = chunk
+                .get_columns()
+                .iter()
+                .map(|c| {
+                    let s = c.as_materialized_series();
+                    let (name, dtype) = (s.name().clone(), s.dtype());
+                    let null_count = Series::new(name.clone(), [s.null_count() as IdxSize]);
+                    // The minimum and maximum ignore NaN, but NaN compares greater than all
+                    // other values.
+                    let has_min_max = (dtype.is_numeric()
+                        || dtype.is_temporal()
+                        || dtype.is_bool()
+                        || dtype == &DataType::String)
+                        && !(dtype.is_float() && s.is_nan()?.any());
+                    let (min, max) = if has_min_max {
+                        (
+                            Some(s.min_reduce()?.into_series(name.clone())),
+                            Some(s.max_reduce()?.into_series(name.clone())),
+                        )
+                    } else {
+                        (None, None)
+                    };
+                    Ok(ColumnStats::new(
+                        Field::new(name, dtype.clone()),
+                        Some(null_count),
+                        min,
+                        max,
+                    ))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            Ok(BatchStats::new(schema.clone(), stats, Some(chunk.height())))
+        })
+        .collect()
+}
+
+/// Remove the chunks of `df` whose statistics show that no row can pass `predicate`.
+fn prune_chunks(
+    df: &DataFrame,
+    stats: &[BatchStats],
+    predicate: &dyn PhysicalIoExpr,
+) -> PolarsResult<DataFrame> {
+    let Some(evaluator) = predicate.as_stats_evaluator() else {
+        return Ok(df.clone());
+    };
+    let mut offset = 0;
+    let mut chunks = Vec::with_capacity(stats.len());
+    for stats in stats {
+        let len = stats.num_rows().unwrap();
+        let read = match evaluator.should_read(stats) {
+            // The predicate refers to a column without statistics.
+            Err(PolarsError::ColumnNotFound(_)) => true,
+            read => read?,
+        };
+        if read {
+            chunks.push(df.slice(offset as i64, len));
+        }
+        offset += len;
+    }
+    Ok(match chunks.len() {
+        0 => df.clear(),
+        n if n == stats.len() => df.clone(),
+        _ => accumulate_dataframes_vertical_unchecked(chunks),
+    })
 }
 
 impl Executor for CacheExec {
@@ -33,6 +110,21 @@ impl Executor for CacheExec {
             }
         }
 
-        Ok(df.clone())
+        match &self.predicate {
+            Some(predicate) if df.n_chunks() > 1 => {
+                let stats = cache.2.get_or_try_init(|| chunk_statistics(df))?;
+                let out = prune_chunks(df, stats, predicate.as_ref())?;
+                if state.verbose() {
+                    eprintln!(
+                        "CACHE PRUNE: cache id: {:x}, skipped {} of {} rows",
+                        self.id,
+                        df.height() - out.height(),
+                        df.height()
+                    );
+                }
+                Ok(out)
+            },
+            _ => Ok(df.clone()),
+        }
     }
 }
@@ -8,6 +8,9 @@ pub struct FilterExec {
     // if the predicate contains a window function
     has_window: bool,
     streamable: bool,
+    // the id of the cached input and the predicate, whose mask is shared with the identical
+    // filters on that input
+    shared_mask: Option<(usize, Expr)>,
 }
 
 fn series_to_mask(s: &Series) -> PolarsResult<&BooleanChunked> {
@@ -18,6 +21,14 @@ fn series_to_mask(s: &Series) -> PolarsResult<&BooleanChunked> {
     })
 }
 
+fn evaluate_predicate(
+    predicate: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+) -> PolarsResult<Series> {
+    state.record_expr(|| predicate.evaluate(df, state), "filter", predicate)
+}
+
 impl FilterExec {
     pub fn new(
         predicate: Arc<dyn PhysicalExpr>,
@@ -30,7 +41,38 @@ impl FilterExec {
             input,
             has_window,
             streamable,
+            shared_mask: None,
+        }
+    }
+
+    /// Share the mask of the predicate with the identical filters on the frame cached under
+    /// `cache_id`, e.g. in the other branches of a union. Filters are identical if their
+    /// `predicate` expressions are equal, which must be elementwise and deterministic.
+    pub(crate) fn with_shared_mask(mut self, cache_id: usize, predicate: Expr) -> Self {
+        self.shared_mask = Some((cache_id, predicate));
+        self
+    }
+
+    fn execute_shared(
+        &mut self,
+        df: DataFrame,
+        cache_id: usize,
+        predicate: Expr,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let cell = state.get_filter_mask(cache_id, predicate);
+        let mut hit = true;
+        let mask = cell.get_or_try_init(|| {
+            hit = false;
+            let s = evaluate_predicate(self.predicate.as_ref(), &df, state)?;
+            series_to_mask(&s).cloned()
+        })?;
+        if state.verbose() {
+            let action = if hit { "HIT" } else { "SET" };
+            eprintln!("FILTER MASK {action}: cache id: {cache_id:x}");
         }
+        debug_assert_eq!(mask.len(), df.height());
+        df.filter(mask)
     }
 
     fn execute_hor(
@@ -41,7 +83,7 @@ impl FilterExec {
         if self.has_window {
             state.insert_has_window_function_flag()
         }
-        let s = self.predicate.evaluate(&df, state)?;
+        let s = evaluate_predicate(self.predicate.as_ref(), &df, state)?;
         if self.has_window {
             state.clear_window_expr_cache()
         }
@@ -54,7 +96,7 @@ impl FilterExec {
         state: &ExecutionState,
     ) -> PolarsResult<DataFrame> {
         let iter = chunks.into_par_iter().map(|df| {
-            let s = self.predicate.evaluate(&df, state)?;
+            let s = evaluate_predicate(self.predicate.as_ref(), &df, state)?;
             df.filter(series_to_mask(&s)?)
         });
         let df = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
@@ -66,6 +108,9 @@ impl FilterExec {
         mut df: DataFrame,
         state: &mut ExecutionState,
     ) -> PolarsResult<DataFrame> {
+        if let Some((cache_id, predicate)) = self.shared_mask.clone() {
+            return self.execute_shared(df, cache_id, predicate, state);
+        }
         let n_partitions = POOL.current_num_threads();
         // Vertical parallelism.
         if self.streamable && df.height() > 0 {
@@ -36,6 +36,7 @@ impl ProjectionExec {
                     state,
                     self.has_windows,
                     self.options.run_parallel,
+                    "select",
                 )?;
                 check_expand_literals(&df, &self.expr, selected_cols, df.is_empty(), self.options)
             });
@@ -52,6 +53,7 @@ impl ProjectionExec {
                 state,
                 self.has_windows,
                 self.options.run_parallel,
+                "select",
             )?;
             check_expand_literals(&df, &self.expr, selected_cols, df.is_empty(), self.options)?
         };
@@ -15,11 +15,22 @@ pub(super) fn profile_name(
 
 type IdAndExpression = (u32, Arc<dyn PhysicalExpr>);
 
+/// Evaluate a single expression, attributing its time to `node` if expressions are profiled.
+fn evaluate_recorded(
+    expr: &dyn PhysicalExpr,
+    df: &DataFrame,
+    state: &ExecutionState,
+    node: &'static str,
+) -> PolarsResult<Series> {
+    state.record_expr(|| expr.evaluate(df, state), node, expr)
+}
+
 #[cfg(feature = "dynamic_group_by")]
 fn rolling_evaluate(
     df: &DataFrame,
     state: &ExecutionState,
     rolling: PlHashMap<&RollingGroupOptions, Vec<IdAndExpression>>,
+    node: &'static str,
 ) -> PolarsResult<Vec<Vec<(u32, Series)>>> {
     POOL.install(|| {
         rolling
@@ -40,7 +51,9 @@ fn rolling_evaluate(
                 }
                 partition
                     .par_iter()
-                    .map(|(idx, expr)| expr.evaluate(df, &state).map(|s| (*idx, s)))
+                    .map(|(idx, expr)| {
+                        evaluate_recorded(expr.as_ref(), df, &state, node).map(|s| (*idx, s))
+                    })
                     .collect::<PolarsResult<Vec<_>>>()
             })
             .collect()
@@ -51,6 +64,7 @@ fn window_evaluate(
     df: &DataFrame,
     state: &ExecutionState,
     window: PlHashMap<String, Vec<IdAndExpression>>,
+    node: &'static str,
 ) -> PolarsResult<Vec<Vec<(u32, Series)>>> {
     POOL.install(|| {
         window
@@ -86,7 +100,7 @@ fn window_evaluate(
                         state.remove_cache_window_flag();
                     }
 
-                    let s = e.evaluate(df, &state)?;
+                    let s = evaluate_recorded(e.as_ref(), df, &state, node)?;
                     out.push((*index, s));
                 }
                 Ok(out)
@@ -99,6 +113,7 @@ fn execute_projection_cached_window_fns(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &'static str,
 ) -> PolarsResult<Vec<Series>> {
     // We partition by normal expression and window expression
     // - the normal expressions can run in parallel
@@ -162,7 +177,7 @@ fn execute_projection_cached_window_fns(
     let mut selected_columns = POOL.install(|| {
         other
             .par_iter()
-            .map(|(idx, expr)| expr.evaluate(df, state).map(|s| (*idx, s)))
+            .map(|(idx, expr)| evaluate_recorded(*expr, df, state, node).map(|s| (*idx, s)))
             .collect::<PolarsResult<Vec<_>>>()
     })?;
 
@@ -172,8 +187,8 @@ fn execute_projection_cached_window_fns(
     #[cfg(feature = "dynamic_group_by")]
     {
         let (a, b) = POOL.join(
-            || rolling_evaluate(df, state, rolling),
-            || window_evaluate(df, state, windows),
+            || rolling_evaluate(df, state, rolling, node),
+            || window_evaluate(df, state, windows, node),
         );
 
         let partitions = a?;
@@ -187,7 +202,7 @@ fn execute_projection_cached_window_fns(
     }
     #[cfg(not(feature = "dynamic_group_by"))]
     {
-        let partitions = window_evaluate(df, state, windows)?;
+        let partitions = window_evaluate(df, state, windows, node)?;
         for part in partitions {
             selected_columns.extend_from_slice(&part)
         }
@@ -202,11 +217,12 @@ fn run_exprs_par(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &'static str,
 ) -> PolarsResult<Vec<Series>> {
     POOL.install(|| {
         exprs
             .par_iter()
-            .map(|expr| expr.evaluate(df, state))
+            .map(|expr| evaluate_recorded(expr.as_ref(), df, state, node))
             .collect()
     })
 }
@@ -215,16 +231,23 @@ fn run_exprs_seq(
     df: &DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
+    node: &'static str,
 ) -> PolarsResult<Vec<Series>> {
-    exprs.iter().map(|expr| expr.evaluate(df, state)).collect()
+    exprs
+        .iter()
+        .map(|expr| evaluate_recorded(expr.as_ref(), df, state, node))
+        .collect()
 }
 
+/// Evaluate the expressions of a projection-like node. `node` is the name under which
+/// the expression timings are recorded when profiling.
 pub(super) fn evaluate_physical_expressions(
     df: &mut DataFrame,
     exprs: &[Arc<dyn PhysicalExpr>],
     state: &ExecutionState,
     has_windows: bool,
     run_parallel: bool,
+    node: &'static str,
 ) -> PolarsResult<Vec<Series>> {
     let expr_runner = if has_windows {
         execute_projection_cached_window_fns
@@ -234,7 +257,7 @@ pub(super) fn evaluate_physical_expressions(
         run_exprs_seq
     };
 
-    let selected_columns = expr_runner(df, exprs, state)?;
+    let selected_columns = expr_runner(df, exprs, state, node)?;
 
     if has_windows {
         state.clear_window_expr_cache();
@@ -71,6 +71,15 @@ impl IpcExec {
             self.file_options.row_index.is_some(),
         );
 
+        // The predicate is evaluated while reading the record batches, unless it needs columns or
+        // rows that the reader does not produce.
+        let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
+        let push_down_predicate = self.hive_parts.is_none()
+            && self.file_options.include_file_paths.is_none()
+            && self.file_options.row_index.is_none()
+            && self.file_options.slice.is_none();
+        let reader_predicate = predicate.clone().filter(|_| push_down_predicate);
+
         let read_path = |index: usize, n_rows: Option<usize>| {
             let source = self.sources.at(index);
 
@@ -91,6 +100,7 @@ impl IpcExec {
                 .with_n_rows(n_rows)
                 .with_row_index(self.file_options.row_index.clone())
                 .with_projection(projection.clone())
+                .with_predicate(reader_predicate.clone())
                 .with_hive_partition_columns(
                     self.hive_parts
                         .as_ref()
@@ -145,8 +155,7 @@ impl IpcExec {
             }
         };
 
-        let dfs = if let Some(predicate) = self.predicate.clone() {
-            let predicate = phys_expr_to_io_expr(predicate);
+        let dfs = if let Some(predicate) = predicate.filter(|_| !push_down_predicate) {
             let predicate = Some(predicate.as_ref());
 
             POOL.install(|| {
@@ -6,6 +6,7 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_error::feature_gated;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetadataRef;
+use polars_io::parquet::read::SkippedRowGroups;
 use polars_io::utils::slice::split_slice_at_file;
 use polars_io::RowIndex;
 
@@ -22,6 +23,8 @@ pub struct ParquetExec {
     file_options: FileScanOptions,
     #[allow(dead_code)]
     metadata: Option<FileMetadataRef>,
+    /// Where the skipped row groups are recorded, if corrupt row groups are skipped.
+    skipped_row_groups: Option<SkippedRowGroups>,
 }
 
 impl ParquetExec {
@@ -35,6 +38,7 @@ impl ParquetExec {
         cloud_options: Option<CloudOptions>,
         file_options: FileScanOptions,
         metadata: Option<FileMetadataRef>,
+        skipped_row_groups: Option<SkippedRowGroups>,
     ) -> Self {
         ParquetExec {
             sources,
@@ -45,9 +49,26 @@ impl ParquetExec {
             cloud_options,
             file_options,
             metadata,
+            skipped_row_groups: options
+                .skip_corrupt_row_groups
+                .then(|| skipped_row_groups.unwrap_or_default()),
         }
     }
 
+    /// The metadata of the first file, whose field ids resolve the columns of all files if
+    /// [`ParquetOptions::use_field_ids`] is set.
+    fn field_id_reference(&self) -> PolarsResult<Option<FileMetadataRef>> {
+        if !self.options.use_field_ids {
+            return Ok(None);
+        }
+        if let Some(md) = &self.metadata {
+            return Ok(Some(md.clone()));
+        }
+        let memslice = self.sources.at(0).to_memslice()?;
+        let mut reader = ParquetReader::new(std::io::Cursor::new(memslice));
+        reader.get_metadata().cloned().map(Some)
+    }
+
     fn read_par(&mut self) -> PolarsResult<Vec<DataFrame>> {
         let parallel = match self.options.parallel {
             ParallelStrategy::Auto if self.sources.len() > POOL.current_num_threads() => {
@@ -140,6 +161,8 @@ impl ParquetExec {
             (0, usize::MAX)
         };
 
+        let field_id_reference = self.field_id_reference()?;
+        let field_id_reference = &field_id_reference;
         let mut current_offset = 0;
         let base_row_index = self.file_options.row_index.take();
         // Limit no. of files at a time to prevent open file limits.
@@ -175,6 +198,12 @@ impl ParquetExec {
                     .read_parallel(parallel)
                     .set_low_memory(self.options.low_memory)
                     .use_statistics(self.options.use_statistics)
+                    .skip_corrupt_row_groups(self.skipped_row_groups.as_ref().map(|skipped| {
+                        skipped.for_source(match source {
+                            ScanSourceRef::Path(path) => path.to_string_lossy().into(),
+                            _ => source.to_include_path_name().into(),
+                        })
+                    }))
                     .set_rechunk(false)
                     .with_hive_partition_columns(hive_partitions)
                     .with_include_file_path(
@@ -183,6 +212,9 @@ impl ParquetExec {
                             .as_ref()
                             .map(|x| (x.clone(), Arc::from(source.to_include_path_name()))),
                     );
+                if let Some(reference) = &field_id_reference {
+                    reader = reader.with_field_ids(reference)?;
+                }
 
                 reader.num_rows().map(|num_rows| (reader, num_rows))
             });
@@ -334,6 +366,18 @@ impl ParquetExec {
             (0, usize::MAX)
         };
 
+        let field_id_reference = match first_metadata {
+            _ if !self.options.use_field_ids => None,
+            Some(md) => Some(md.clone()),
+            None => Some(
+                ParquetAsyncReader::from_uri(paths[0].to_str().unwrap(), cloud_options, None)
+                    .await?
+                    .get_metadata()
+                    .await?
+                    .clone(),
+            ),
+        };
+        let field_id_reference = &field_id_reference;
         let mut current_offset = 0;
         let base_row_index = self.file_options.row_index.take();
         let mut processed = 0;
@@ -367,6 +411,9 @@ impl ParquetExec {
                 let mut reader =
                     ParquetAsyncReader::from_uri(&path.to_string_lossy(), cloud_options, metadata)
                         .await?;
+                if let Some(reference) = field_id_reference {
+                    reader = reader.with_field_ids(reference).await?;
+                }
 
                 let num_rows = reader.num_rows().await?;
                 PolarsResult::Ok((num_rows, reader))
@@ -390,6 +437,7 @@ impl ParquetExec {
 
             // Now read the actual data.
             let use_statistics = self.options.use_statistics;
+            let skipped_row_groups = self.skipped_row_groups.as_ref();
             let base_row_index_ref = &base_row_index;
             let include_file_paths = self.file_options.include_file_paths.as_ref();
             let first_schema = first_schema.clone();
@@ -427,6 +475,9 @@ impl ParquetExec {
                             )
                             .await?
                             .use_statistics(use_statistics)
+                            .skip_corrupt_row_groups(skipped_row_groups.map(|skipped| {
+                                skipped.for_source(paths[i].to_string_lossy().into())
+                            }))
                             .with_predicate(predicate)
                             .set_rechunk(false)
                             .with_hive_partition_columns(hive_partitions)
@@ -35,6 +35,7 @@ impl StackExec {
                     state,
                     self.has_windows,
                     self.options.run_parallel,
+                    "with_columns",
                 )?;
                 // We don't have to do a broadcast check as cse is not allowed to hit this.
                 df._add_series(res, schema)?;
@@ -52,6 +53,7 @@ impl StackExec {
                 state,
                 self.has_windows,
                 self.options.run_parallel,
+                "with_columns",
             )?;
             if !self.options.should_broadcast {
                 debug_assert!(
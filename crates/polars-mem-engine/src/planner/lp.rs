@@ -1,5 +1,6 @@
 use polars_core::prelude::*;
 use polars_core::POOL;
+use polars_expr::prelude::phys_expr_to_io_expr;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::plans::expr_ir::ExprIR;
 
@@ -208,7 +209,7 @@ fn create_physical_plan_impl(
             SinkType::Memory => {
                 polars_bail!(InvalidOperation: "memory sink not supported in the standard engine")
             },
-            SinkType::File { file_type, .. } => {
+            SinkType::File { file_type, .. } | SinkType::Partitioned { file_type, .. } => {
                 polars_bail!(InvalidOperation:
                     "sink_{file_type:?} not yet supported in standard engine. Use 'collect().write_parquet()'"
                 )
@@ -258,21 +259,55 @@ fn create_physical_plan_impl(
                         }
                     }
             }
-            let input = create_physical_plan_impl(input, lp_arena, expr_arena, state)?;
-            let mut state = ExpressionConversionState::new(true, state.expr_depth);
-            let predicate = create_physical_expr(
+            let mut expr_state = ExpressionConversionState::new(true, state.expr_depth);
+            let phys_predicate = create_physical_expr(
                 &predicate,
                 Context::Default,
                 expr_arena,
                 Some(&input_schema),
-                &mut state,
+                &mut expr_state,
             )?;
-            Ok(Box::new(executors::FilterExec::new(
-                predicate,
+            let mut shared_mask = None;
+            let input = match lp_arena.get(input) {
+                // The statistics of the chunks of a cached frame can only decide elementwise
+                // predicates.
+                Cache {
+                    input,
+                    id,
+                    cache_hits,
+                } if is_streamable(predicate.node(), expr_arena, Context::Default) => {
+                    let (id, count) = (*id, *cache_hits);
+                    // The identical filters on the cache compute the same mask, so they can
+                    // share it unless it depends on a user function or on randomness.
+                    if !has_aexpr(predicate.node(), expr_arena, |ae| match ae {
+                        AExpr::AnonymousFunction { .. } => true,
+                        AExpr::Function { options, .. } => {
+                            options.flags.contains(FunctionFlags::NON_DETERMINISTIC)
+                        },
+                        _ => false,
+                    }) {
+                        shared_mask = Some((id, predicate.to_expr(expr_arena)));
+                    }
+                    let input = create_physical_plan_impl(*input, lp_arena, expr_arena, state)?;
+                    Box::new(executors::CacheExec {
+                        input,
+                        id,
+                        count,
+                        predicate: Some(phys_expr_to_io_expr(phys_predicate.clone())),
+                    })
+                },
+                _ => create_physical_plan_impl(input, lp_arena, expr_arena, state)?,
+            };
+            let mut exec = executors::FilterExec::new(
+                phys_predicate,
                 input,
-                state.has_windows,
+                expr_state.has_windows,
                 streamable,
-            )))
+            );
+            if let Some((cache_id, predicate)) = shared_mask {
+                exec = exec.with_shared_mask(cache_id, predicate);
+            }
+            Ok(Box::new(exec))
         },
         #[allow(unused_variables)]
         Scan {
@@ -331,6 +366,7 @@ fn create_physical_plan_impl(
                     options,
                     cloud_options,
                     metadata,
+                    skipped_row_groups,
                 } => Ok(Box::new(executors::ParquetExec::new(
                     sources,
                     file_info,
@@ -340,6 +376,7 @@ fn create_physical_plan_impl(
                     cloud_options,
                     file_options,
                     metadata,
+                    skipped_row_groups,
                 ))),
                 #[cfg(feature = "json")]
                 FileScan::NDJson { options, .. } => Ok(Box::new(executors::JsonExec::new(
@@ -467,6 +504,7 @@ fn create_physical_plan_impl(
                 id,
                 input,
                 count: cache_hits,
+                predicate: None,
             }))
         },
         Distinct { input, options } => {
@@ -0,0 +1,138 @@
+//! Encryption of binary values with AES-256-GCM-SIV.
+//!
+//! Keys are referred to by an id and resolved by the globally registered [`KeyProvider`], so
+//! that expressions never contain key material and can be serialized.
+//!
+//! Every encrypted value holds the 12 byte nonce followed by the ciphertext and the
+//! authentication tag. Deterministic encryption uses a fixed nonce, so equal values produce
+//! equal ciphertexts and encrypted columns can still be joined and grouped on. This reveals
+//! which values are equal, use randomized encryption if that is not acceptable.
+use std::sync::{Arc, RwLock};
+
+use aes_gcm_siv::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm_siv::{Aes256GcmSiv, Nonce};
+use polars_core::export::once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+const NONCE_LEN: usize = 12;
+
+/// Provides the 256 bit keys used by [`encrypt`](super::BinaryNameSpaceImpl::encrypt) and
+/// [`decrypt`](super::BinaryNameSpaceImpl::decrypt).
+pub trait KeyProvider: Send + Sync {
+    /// Get the key with the given id.
+    fn get_key(&self, key_id: &str) -> PolarsResult<[u8; 32]>;
+}
+
+/// A [`KeyProvider`] that holds its keys in memory.
+#[derive(Default)]
+pub struct InMemoryKeyProvider {
+    keys: PlHashMap<PlSmallStr, [u8; 32]>,
+}
+
+impl InMemoryKeyProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_key(mut self, key_id: PlSmallStr, key: [u8; 32]) -> Self {
+        self.keys.insert(key_id, key);
+        self
+    }
+}
+
+impl KeyProvider for InMemoryKeyProvider {
+    fn get_key(&self, key_id: &str) -> PolarsResult<[u8; 32]> {
+        self.keys
+            .get(key_id)
+            .copied()
+            .ok_or_else(|| polars_err!(ComputeError: "unknown encryption key '{}'", key_id))
+    }
+}
+
+static GLOBAL_KEY_PROVIDER: Lazy<RwLock<Option<Arc<dyn KeyProvider>>>> =
+    Lazy::new(Default::default);
+
+/// Set the [`KeyProvider`] that resolves the key ids of encryption expressions.
+pub fn set_key_provider(provider: Arc<dyn KeyProvider>) {
+    *GLOBAL_KEY_PROVIDER.write().unwrap() = Some(provider);
+}
+
+fn get_cipher(key_id: &str) -> PolarsResult<Aes256GcmSiv> {
+    let provider = GLOBAL_KEY_PROVIDER.read().unwrap().clone();
+    let provider = provider.ok_or_else(
+        || polars_err!(ComputeError: "no encryption key provider is set; see `set_key_provider`"),
+    )?;
+    let key = provider.get_key(key_id)?;
+    Ok(Aes256GcmSiv::new(&key.into()))
+}
+
+pub(super) fn encrypt(
+    ca: &BinaryChunked,
+    key_id: &str,
+    deterministic: bool,
+) -> PolarsResult<BinaryChunked> {
+    let cipher = get_cipher(key_id)?;
+    ca.try_apply_nonnull_values_generic(|v| {
+        let nonce = if deterministic {
+            Nonce::default()
+        } else {
+            Aes256GcmSiv::generate_nonce(&mut OsRng)
+        };
+        let mut out = Vec::with_capacity(NONCE_LEN + v.len() + 16);
+        out.extend_from_slice(&nonce);
+        out.extend(
+            cipher
+                .encrypt(&nonce, v)
+                .map_err(|_| polars_err!(ComputeError: "encryption failed"))?,
+        );
+        PolarsResult::Ok(out)
+    })
+}
+
+pub(super) fn decrypt(ca: &BinaryChunked, key_id: &str) -> PolarsResult<BinaryChunked> {
+    let cipher = get_cipher(key_id)?;
+    ca.try_apply_nonnull_values_generic(|v| {
+        polars_ensure!(
+            v.len() >= NONCE_LEN,
+            ComputeError: "cannot decrypt value that is shorter than the nonce"
+        );
+        let (nonce, ciphertext) = v.split_at(NONCE_LEN);
+        cipher
+            .decrypt(Nonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                polars_err!(ComputeError: "decryption failed; the value was not encrypted with key '{}' or is corrupted", key_id)
+            })
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::super::BinaryNameSpaceImpl;
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt() -> PolarsResult<()> {
+        set_key_provider(Arc::new(
+            InMemoryKeyProvider::new()
+                .with_key("a".into(), [1; 32])
+                .with_key("b".into(), [2; 32]),
+        ));
+        let ca = BinaryChunked::new(
+            "s".into(),
+            [Some(b"secret".as_slice()), None, Some(b"secret".as_slice())],
+        );
+
+        let random = ca.encrypt("a", false)?;
+        assert_eq!(random.null_count(), 1);
+        assert_ne!(random.get(0), random.get(2));
+        assert!(random.decrypt("a")?.equal_missing(&ca).all());
+
+        let deterministic = ca.encrypt("a", true)?;
+        assert_eq!(deterministic.get(0), deterministic.get(2));
+        assert!(deterministic.decrypt("a")?.equal_missing(&ca).all());
+
+        assert!(deterministic.decrypt("b").is_err());
+        assert!(ca.encrypt("c", true).is_err());
+        Ok(())
+    }
+}
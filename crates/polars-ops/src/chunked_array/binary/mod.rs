@@ -1,5 +1,9 @@
+#[cfg(feature = "binary_encryption")]
+mod encryption;
 mod namespace;
 
+#[cfg(feature = "binary_encryption")]
+pub use encryption::{set_key_provider, InMemoryKeyProvider, KeyProvider};
 pub use namespace::*;
 use polars_core::prelude::*;
 
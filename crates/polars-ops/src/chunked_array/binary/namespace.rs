@@ -131,6 +131,20 @@ pub trait BinaryNameSpaceImpl: AsBinary {
                 .unwrap()
         }
     }
+
+    /// Encrypt the values with AES-256-GCM-SIV and the key `key_id` of the [`KeyProvider`].
+    ///
+    /// If `deterministic` is set, equal values produce equal ciphertexts.
+    #[cfg(feature = "binary_encryption")]
+    fn encrypt(&self, key_id: &str, deterministic: bool) -> PolarsResult<BinaryChunked> {
+        super::encryption::encrypt(self.as_binary(), key_id, deterministic)
+    }
+
+    /// Decrypt values that were encrypted with [`encrypt`](Self::encrypt) and the key `key_id`.
+    #[cfg(feature = "binary_encryption")]
+    fn decrypt(&self, key_id: &str) -> PolarsResult<BinaryChunked> {
+        super::encryption::decrypt(self.as_binary(), key_id)
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
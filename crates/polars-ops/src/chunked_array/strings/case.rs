@@ -1,4 +1,72 @@
-use polars_core::prelude::StringChunked;
+use std::sync::Arc;
+
+use arrow::array::{Array, Utf8ViewArray, View};
+use arrow::buffer::Buffer;
+use arrow::datatypes::ArrowDataType;
+use polars_core::prelude::{ChunkedArray, StringChunked};
+
+/// Apply `convert` to every byte of an array of only ASCII strings. The lengths of the strings do
+/// not change, so the views keep pointing at the same offsets and only their inlined bytes, or
+/// their prefix if the string is stored in a buffer, must be converted as well.
+fn convert_ascii_array(arr: &Utf8ViewArray, convert: fn(&u8) -> u8) -> Option<Utf8ViewArray> {
+    // Converting buffers that are mostly not referenced would be wasted work.
+    if arr.total_buffer_len() > 2 * arr.total_bytes_len() {
+        return None;
+    }
+    // The bytes of a view that hold string data: the whole string if it is inlined, otherwise
+    // only the prefix. The buffer index and offset that follow the prefix must stay untouched.
+    let data_len = |view: &View| {
+        if view.length <= View::MAX_INLINE_SIZE {
+            view.length as usize
+        } else {
+            4
+        }
+    };
+    let views_are_ascii = arr.views().iter().all(|view| {
+        view.length > View::MAX_INLINE_SIZE
+            || view.as_u128().to_le_bytes()[4..4 + data_len(view)].is_ascii()
+    });
+    if !(views_are_ascii && arr.data_buffers().iter().all(|buf| buf.is_ascii())) {
+        return None;
+    }
+
+    let views = arr
+        .views()
+        .iter()
+        .map(|view| {
+            let mut bytes = view.as_u128().to_le_bytes();
+            bytes[4..4 + data_len(view)]
+                .iter_mut()
+                .for_each(|b| *b = convert(b));
+            View::from(u128::from_le_bytes(bytes))
+        })
+        .collect::<Vec<_>>();
+    let buffers = arr
+        .data_buffers()
+        .iter()
+        .map(|buf| Buffer::from(buf.iter().map(convert).collect::<Vec<_>>()))
+        .collect::<Arc<[_]>>();
+    // SAFETY: ASCII stays ASCII and the lengths of the strings did not change.
+    Some(unsafe {
+        Utf8ViewArray::new_unchecked(
+            ArrowDataType::Utf8View,
+            views.into(),
+            buffers,
+            arr.validity().cloned(),
+            arr.total_bytes_len(),
+            arr.total_buffer_len(),
+        )
+    })
+}
+
+/// Convert the case of `ca` with whole-array kernels if it only contains ASCII.
+fn convert_ascii(ca: &StringChunked, convert: fn(&u8) -> u8) -> Option<StringChunked> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| convert_ascii_array(arr, convert))
+        .collect::<Option<Vec<_>>>()?;
+    Some(ChunkedArray::from_chunk_iter(ca.name().clone(), chunks))
+}
 
 // Inlined from std.
 fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8, out: &mut Vec<u8>) {
@@ -92,6 +160,9 @@ fn to_lowercase_helper(s: &str, buf: &mut Vec<u8>) {
 }
 
 pub(super) fn to_lowercase<'a>(ca: &'a StringChunked) -> StringChunked {
+    if let Some(out) = convert_ascii(ca, u8::to_ascii_lowercase) {
+        return out;
+    }
     // Amortize allocation.
     let mut buf = Vec::new();
     let f = |s: &'a str| -> &'a str {
@@ -105,6 +176,9 @@ pub(super) fn to_lowercase<'a>(ca: &'a StringChunked) -> StringChunked {
 
 // Inlined from std.
 pub(super) fn to_uppercase<'a>(ca: &'a StringChunked) -> StringChunked {
+    if let Some(out) = convert_ascii(ca, u8::to_ascii_uppercase) {
+        return out;
+    }
     // Amortize allocation.
     let mut buf = Vec::new();
     let f = |s: &'a str| -> &'a str {
@@ -166,3 +240,58 @@ pub(super) fn to_titlecase<'a>(ca: &'a StringChunked) -> StringChunked {
     };
     ca.apply_mut(f)
 }
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_case_ascii_and_unicode() {
+        let values = [
+            Some("Hello"),
+            None,
+            Some("a string that is not inlined"),
+            Some("Straße ΣΑΣ"),
+        ];
+        let ascii = StringChunked::new("a".into(), &values[..3]);
+        let mixed = StringChunked::new("a".into(), &values);
+
+        assert_eq!(
+            Vec::from(&to_uppercase(&ascii)),
+            &[Some("HELLO"), None, Some("A STRING THAT IS NOT INLINED")]
+        );
+        assert_eq!(
+            Vec::from(&to_lowercase(&ascii.slice(2, 1))),
+            &[Some("a string that is not inlined")]
+        );
+        assert_eq!(
+            Vec::from(&to_lowercase(&mixed)),
+            &[
+                Some("hello"),
+                None,
+                Some("a string that is not inlined"),
+                Some("straße σας")
+            ]
+        );
+        assert_eq!(to_uppercase(&mixed).get(3), Some("STRASSE ΣΑΣ"));
+    }
+
+    #[test]
+    fn test_case_ascii_not_inlined() {
+        // Enough strings that are longer than a view can inline for the buffer offsets to
+        // contain bytes that are ASCII letters.
+        let values = (0..20)
+            .map(|i| format!("Longer Than Twelve Bytes {i}"))
+            .collect::<Vec<_>>();
+        let ca = StringChunked::from_iter_values("a".into(), values.iter().map(|s| s.as_str()));
+
+        let upper = to_uppercase(&ca);
+        let lower = to_lowercase(&ca);
+        for (i, value) in values.iter().enumerate() {
+            assert_eq!(upper.get(i), Some(value.to_uppercase().as_str()));
+            assert_eq!(lower.get(i), Some(value.to_lowercase().as_str()));
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use arrow::array::{Array, BooleanArray, Utf8ViewArray, View};
+use arrow::bitmap::Bitmap;
+use arrow::datatypes::ArrowDataType;
+use memchr::memmem::Finder;
+use polars_core::prelude::{BooleanChunked, ChunkedArray, StringChunked};
+
+/// The start offsets of all, possibly overlapping, occurrences of the needle in `haystack`.
+fn match_offsets(haystack: &[u8], finder: &Finder) -> Vec<u32> {
+    let mut offsets = vec![];
+    let mut start = 0;
+    while let Some(idx) = finder.find(&haystack[start..]) {
+        offsets.push((start + idx) as u32);
+        start += idx + 1;
+    }
+    offsets
+}
+
+/// Check whether the strings of `arr` contain the needle of `finder`.
+///
+/// Rather than searching every string on its own, every data buffer is searched once and the
+/// occurrences are attributed to the views that cover them. Only the inlined strings are searched
+/// one by one.
+fn contains_literal_arr(arr: &Utf8ViewArray, finder: &Finder) -> BooleanArray {
+    let needle_len = finder.needle().len();
+    let values: Bitmap = if needle_len == 0 || arr.total_buffer_len() > 2 * arr.total_bytes_len() {
+        // Searching buffers that are mostly not referenced would be wasted work.
+        arr.values_iter()
+            .map(|s| finder.find(s.as_bytes()).is_some())
+            .collect()
+    } else {
+        let offsets = arr
+            .data_buffers()
+            .iter()
+            .map(|buf| match_offsets(buf, finder))
+            .collect::<Vec<_>>();
+        arr.views()
+            .iter()
+            .map(|view| {
+                if view.length <= View::MAX_INLINE_SIZE {
+                    let bytes = view.as_u128().to_le_bytes();
+                    return finder.find(&bytes[4..4 + view.length as usize]).is_some();
+                }
+                let offsets = &offsets[view.buffer_idx as usize];
+                // The first occurrence at or after the start of the string is the only one
+                // that can end before the string does.
+                let idx = offsets.partition_point(|&o| o < view.offset);
+                offsets.get(idx).is_some_and(|&o| {
+                    o as usize + needle_len <= view.offset as usize + view.length as usize
+                })
+            })
+            .collect()
+    };
+    BooleanArray::new(ArrowDataType::Boolean, values, arr.validity().cloned())
+}
+
+/// Check whether the strings of `ca` contain the literal `lit`.
+pub(super) fn contains_literal(ca: &StringChunked, lit: &str) -> BooleanChunked {
+    let finder = Finder::new(lit.as_bytes());
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| contains_literal_arr(arr, &finder));
+    ChunkedArray::from_chunk_iter(ca.name().clone(), chunks)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::prelude::*;
+
+    use super::*;
+
+    #[test]
+    fn test_contains_literal() {
+        let values = [
+            Some("a long string that is not inlined"),
+            Some("short"),
+            None,
+            Some("aab"),
+            Some("another long string, ending in a"),
+            Some("ab and more so that it is not inlined"),
+            Some(""),
+        ];
+        let ca = StringChunked::new("a".into(), values);
+        for lit in ["a", "aa", "ab", "long", "a long string", "inlined", "x", ""] {
+            let expected = values
+                .iter()
+                .map(|v| v.map(|v| v.contains(lit)))
+                .collect::<BooleanChunked>();
+            assert!(contains_literal(&ca, lit).equal_missing(&expected).all());
+            assert!(contains_literal(&ca.slice(1, 5), lit)
+                .equal_missing(&expected.slice(1, 5))
+                .all());
+        }
+    }
+}
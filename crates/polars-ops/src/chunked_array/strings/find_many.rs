@@ -33,6 +33,46 @@ pub fn contains_any(
     }))
 }
 
+/// Check for every pattern in `patterns` whether the strings of `ca` contain it.
+///
+/// All patterns are matched in a single pass over every string, rather than searching the
+/// strings once per pattern. The result has a mask for every pattern, in the same order.
+pub fn contains_literal_many(
+    ca: &StringChunked,
+    patterns: &[&str],
+    ascii_case_insensitive: bool,
+) -> PolarsResult<Vec<BooleanChunked>> {
+    let ac = AhoCorasickBuilder::new()
+        .ascii_case_insensitive(ascii_case_insensitive)
+        .build(patterns)
+        .map_err(|e| polars_err!(ComputeError: "could not build aho corasick automaton {}", e))?;
+
+    let mut builders = (0..patterns.len())
+        .map(|_| BooleanChunkedBuilder::new(ca.name().clone(), ca.len()))
+        .collect::<Vec<_>>();
+    let mut found = vec![false; patterns.len()];
+    for opt_val in ca.iter() {
+        let Some(val) = opt_val else {
+            builders.iter_mut().for_each(|b| b.append_null());
+            continue;
+        };
+        found.fill(false);
+        let mut n_found = 0;
+        for m in ac.find_overlapping_iter(val) {
+            let found = &mut found[m.pattern().as_usize()];
+            n_found += !*found as usize;
+            *found = true;
+            if n_found == patterns.len() {
+                break;
+            }
+        }
+        for (b, &found) in builders.iter_mut().zip(found.iter()) {
+            b.append_value(found);
+        }
+    }
+    Ok(builders.into_iter().map(|b| b.finish()).collect())
+}
+
 pub fn replace_all(
     ca: &StringChunked,
     patterns: &StringChunked,
@@ -121,3 +161,35 @@ pub fn extract_many(
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_contains_literal_many() -> PolarsResult<()> {
+        let values = [Some("foobar"), None, Some("barbaz"), Some("aaa"), Some("")];
+        let ca = StringChunked::new("a".into(), values);
+        let patterns = ["foo", "bar", "aa", "ob", "aaaa"];
+        let out = contains_literal_many(&ca, &patterns, false)?;
+        assert_eq!(out.len(), patterns.len());
+        for (pat, out) in patterns.iter().zip(out) {
+            let expected = values
+                .iter()
+                .map(|v| v.map(|v| v.contains(pat)))
+                .collect::<BooleanChunked>();
+            assert!(out.equal_missing(&expected).all());
+        }
+
+        let out = contains_literal_many(&ca, &["FOO", "Baz"], true)?;
+        assert_eq!(
+            Vec::from(&out[0]),
+            &[Some(true), None, Some(false), Some(false), Some(false)]
+        );
+        assert_eq!(
+            Vec::from(&out[1]),
+            &[Some(false), None, Some(true), Some(false), Some(false)]
+        );
+        Ok(())
+    }
+}
@@ -3,6 +3,8 @@ mod case;
 #[cfg(feature = "strings")]
 mod concat;
 #[cfg(feature = "strings")]
+mod contains;
+#[cfg(feature = "strings")]
 mod extract;
 #[cfg(feature = "find_many")]
 mod find_many;
@@ -20,6 +22,8 @@ mod split;
 mod strip;
 #[cfg(feature = "strings")]
 mod substring;
+#[cfg(feature = "text_search")]
+mod text_search;
 
 #[cfg(all(not(feature = "nightly"), feature = "strings"))]
 mod unicode_internals;
@@ -37,6 +41,8 @@ use polars_core::prelude::*;
 pub use split::*;
 #[cfg(feature = "strings")]
 pub use strip::*;
+#[cfg(feature = "text_search")]
+pub use text_search::*;
 
 pub trait AsString {
     fn as_string(&self) -> &StringChunked;
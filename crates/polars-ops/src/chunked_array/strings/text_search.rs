@@ -0,0 +1,883 @@
+use std::sync::Arc;
+
+use arrow::array::{Array, FixedSizeListArray};
+use arrow::bitmap::{Bitmap, MutableBitmap};
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::float_hash::impl_float_eq_hash;
+
+/// The language that determines the stop words and the stemmer of the text analysis.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum TextLanguage {
+    #[default]
+    English,
+}
+
+impl TextLanguage {
+    fn is_stop_word(&self, token: &str) -> bool {
+        match self {
+            TextLanguage::English => ENGLISH_STOP_WORDS.binary_search(&token).is_ok(),
+        }
+    }
+
+    fn stem(&self, token: &mut String) {
+        match self {
+            TextLanguage::English => {
+                // The Porter stemmer only applies to ASCII words.
+                if token.bytes().all(|b| b.is_ascii_lowercase()) {
+                    let mut bytes = std::mem::take(token).into_bytes();
+                    porter_stem(&mut bytes);
+                    // SAFETY: the stemmer only writes ASCII.
+                    *token = unsafe { String::from_utf8_unchecked(bytes) };
+                }
+            },
+        }
+    }
+}
+
+/// Sorted, such that it can be binary searched.
+static ENGLISH_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "if", "in", "into", "is", "it",
+    "no", "not", "of", "on", "or", "such", "that", "the", "their", "then", "there", "these",
+    "they", "this", "to", "was", "will", "with",
+];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TokenizeOptions {
+    pub language: TextLanguage,
+    /// Drop the stop words of the language.
+    pub remove_stop_words: bool,
+    /// Reduce the tokens to their stem.
+    pub stem: bool,
+}
+
+impl Default for TokenizeOptions {
+    fn default() -> Self {
+        Self {
+            language: TextLanguage::English,
+            remove_stop_words: true,
+            stem: true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bm25Options {
+    /// How both the documents and the query are tokenized.
+    pub tokenize: TokenizeOptions,
+    /// Saturation of the term frequency.
+    pub k1: f64,
+    /// Strength of the document length normalization, between 0 and 1.
+    pub b: f64,
+}
+
+impl Default for Bm25Options {
+    fn default() -> Self {
+        Self {
+            tokenize: TokenizeOptions::default(),
+            k1: 1.2,
+            b: 0.75,
+        }
+    }
+}
+
+impl_float_eq_hash!(Bm25Options, [tokenize], floats: [k1, b]);
+
+/// Call `f` on every token of `text`.
+///
+/// Tokens are the lowercased alphanumeric runs of the text, optionally without stop words and
+/// reduced to their stem. `buf` is scratch space that is reused between calls.
+fn for_each_token<F: FnMut(&str)>(
+    text: &str,
+    options: &TokenizeOptions,
+    buf: &mut String,
+    mut f: F,
+) {
+    for word in text.split(|c: char| !c.is_alphanumeric()) {
+        if word.is_empty() {
+            continue;
+        }
+        buf.clear();
+        buf.extend(word.chars().flat_map(char::to_lowercase));
+        if options.remove_stop_words && options.language.is_stop_word(buf) {
+            continue;
+        }
+        if options.stem {
+            options.language.stem(buf);
+        }
+        f(buf)
+    }
+}
+
+/// Split every string into its tokens, see [`TokenizeOptions`].
+pub fn tokenize(ca: &StringChunked, options: &TokenizeOptions) -> ListChunked {
+    let mut builder =
+        ListStringChunkedBuilder::new(ca.name().clone(), ca.len(), ca.get_values_size());
+    let mut buf = String::new();
+    let mut tokens = String::new();
+    let mut ends = vec![];
+
+    for opt_s in ca.iter() {
+        match opt_s {
+            Some(s) => {
+                tokens.clear();
+                ends.clear();
+                for_each_token(s, options, &mut buf, |token| {
+                    tokens.push_str(token);
+                    ends.push(tokens.len());
+                });
+                let starts = std::iter::once(0).chain(ends.iter().copied());
+                builder.append_values_iter(
+                    starts
+                        .zip(ends.iter())
+                        .map(|(start, end)| &tokens[start..*end]),
+                );
+            },
+            None => builder.append_null(),
+        }
+    }
+    builder.finish()
+}
+
+/// Score every document in `ca` against `query` with Okapi BM25.
+///
+/// The column is the corpus: document frequencies and the average document length are
+/// computed over its non-null values. The query is tokenized the same way as the documents.
+/// Null documents get a null score.
+pub fn bm25_score(
+    ca: &StringChunked,
+    query: &str,
+    options: &Bm25Options,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        options.k1 >= 0.0,
+        InvalidOperation: "bm25 parameter `k1` must be non-negative, got {}", options.k1
+    );
+    polars_ensure!(
+        (0.0..=1.0).contains(&options.b),
+        InvalidOperation: "bm25 parameter `b` must be between 0 and 1, got {}", options.b
+    );
+
+    let mut buf = String::new();
+
+    // Every distinct query term gets a slot, repeated terms weigh multiple times.
+    let mut term_slots = PlHashMap::<String, usize>::new();
+    let mut query_weights = vec![];
+    for_each_token(query, &options.tokenize, &mut buf, |token| {
+        let n_terms = term_slots.len();
+        let slot = *term_slots.entry(token.to_string()).or_insert(n_terms);
+        if slot == query_weights.len() {
+            query_weights.push(0.0);
+        }
+        query_weights[slot] += 1.0;
+    });
+    let n_terms = term_slots.len();
+
+    // Term frequencies of the query terms per document, flattened.
+    let mut term_freqs = Vec::with_capacity(ca.len() * n_terms);
+    let mut doc_lens = Vec::with_capacity(ca.len());
+    for opt_s in ca.iter() {
+        let Some(s) = opt_s else {
+            doc_lens.push(None);
+            continue;
+        };
+        let offset = term_freqs.len();
+        term_freqs.resize(offset + n_terms, 0u32);
+        let mut len = 0usize;
+        for_each_token(s, &options.tokenize, &mut buf, |token| {
+            len += 1;
+            if let Some(slot) = term_slots.get(token) {
+                term_freqs[offset + slot] += 1;
+            }
+        });
+        doc_lens.push(Some(len));
+    }
+
+    let n_docs = doc_lens.iter().flatten().count();
+    let total_len = doc_lens.iter().flatten().sum::<usize>();
+    let avg_len = if n_docs > 0 {
+        total_len as f64 / n_docs as f64
+    } else {
+        0.0
+    };
+
+    let mut doc_freqs = vec![0usize; n_terms];
+    for freqs in term_freqs.chunks_exact(n_terms.max(1)) {
+        for (df, &tf) in doc_freqs.iter_mut().zip(freqs) {
+            *df += (tf > 0) as usize;
+        }
+    }
+    let idf = doc_freqs
+        .iter()
+        .map(|&df| {
+            let df = df as f64;
+            ((n_docs as f64 - df + 0.5) / (df + 0.5) + 1.0).ln()
+        })
+        .collect::<Vec<_>>();
+
+    let Bm25Options { k1, b, .. } = *options;
+    let mut freqs = term_freqs.chunks_exact(n_terms.max(1));
+    let out: Float64Chunked = doc_lens
+        .into_iter()
+        .map(|opt_len| {
+            let len = opt_len?;
+            if n_terms == 0 {
+                return Some(0.0);
+            }
+            let freqs = freqs.next().unwrap();
+            let norm = if avg_len > 0.0 {
+                k1 * (1.0 - b + b * len as f64 / avg_len)
+            } else {
+                k1
+            };
+            let score = freqs
+                .iter()
+                .zip(&idf)
+                .zip(&query_weights)
+                .map(|((&tf, idf), weight)| {
+                    let tf = tf as f64;
+                    weight * idf * tf * (k1 + 1.0) / (tf + norm)
+                })
+                .sum::<f64>();
+            Some(score)
+        })
+        .collect();
+    Ok(out.with_name(ca.name().clone()))
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct VectorizeOptions {
+    /// How the documents are tokenized.
+    pub tokenize: TokenizeOptions,
+    /// Minimal number of documents a term must occur in to be part of the vocabulary.
+    pub min_df: usize,
+    /// Only keep the terms with the highest document frequency.
+    pub max_features: Option<usize>,
+    /// Weigh the term counts with the inverse document frequency of the terms.
+    pub tf_idf: bool,
+}
+
+impl Default for VectorizeOptions {
+    fn default() -> Self {
+        Self {
+            tokenize: TokenizeOptions::default(),
+            min_df: 1,
+            max_features: None,
+            tf_idf: false,
+        }
+    }
+}
+
+/// A fitted vocabulary that turns documents into term-frequency vectors.
+///
+/// Every term of the vocabulary has a fixed position in the vectors, so documents that are
+/// vectorized by the same vectorizer can be compared, whether or not they were in the corpus it
+/// was fitted on. Terms that are not in the vocabulary are ignored.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TermVectorizerFields"))]
+pub struct TermVectorizer {
+    tokenize: TokenizeOptions,
+    vocabulary: Arc<[PlSmallStr]>,
+    idf: Option<Arc<[f64]>>,
+}
+
+/// The serialized form of a [`TermVectorizer`], validated when it is deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct TermVectorizerFields {
+    tokenize: TokenizeOptions,
+    vocabulary: Arc<[PlSmallStr]>,
+    idf: Option<Arc<[f64]>>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TermVectorizerFields> for TermVectorizer {
+    type Error = PolarsError;
+
+    fn try_from(fields: TermVectorizerFields) -> PolarsResult<Self> {
+        Self::new(fields.vocabulary, fields.idf, fields.tokenize)
+    }
+}
+
+impl_float_eq_hash!(TermVectorizer, [tokenize, vocabulary], floats: [idf]);
+
+impl TermVectorizer {
+    /// Create a vectorizer from a supplied vocabulary and optionally the inverse document
+    /// frequencies of its terms.
+    pub fn new(
+        vocabulary: Arc<[PlSmallStr]>,
+        idf: Option<Arc<[f64]>>,
+        tokenize: TokenizeOptions,
+    ) -> PolarsResult<Self> {
+        let n_unique = vocabulary.iter().collect::<PlHashSet<_>>().len();
+        polars_ensure!(
+            n_unique == vocabulary.len(),
+            Duplicate: "the vocabulary contains duplicate terms"
+        );
+        if let Some(idf) = &idf {
+            polars_ensure!(
+                idf.len() == vocabulary.len(),
+                ShapeMismatch: "got {} idf weights for a vocabulary of {} terms",
+                idf.len(), vocabulary.len()
+            );
+        }
+        Ok(Self {
+            tokenize,
+            vocabulary,
+            idf,
+        })
+    }
+
+    /// Build the vocabulary over the non-null documents in `ca`.
+    ///
+    /// The vocabulary is sorted lexically. With `tf_idf` the smoothed inverse document
+    /// frequency `ln((1 + n) / (1 + df)) + 1` of every term is stored as well.
+    pub fn fit(ca: &StringChunked, options: &VectorizeOptions) -> Self {
+        let mut buf = String::new();
+        // Per term the document frequency and the last document it was seen in.
+        let mut doc_freqs = PlHashMap::<String, (usize, usize)>::new();
+        let mut n_docs = 0;
+        for s in ca.iter().flatten() {
+            n_docs += 1;
+            for_each_token(s, &options.tokenize, &mut buf, |token| {
+                match doc_freqs.get_mut(token) {
+                    Some((df, last_doc)) => {
+                        if *last_doc != n_docs {
+                            *df += 1;
+                            *last_doc = n_docs;
+                        }
+                    },
+                    None => {
+                        doc_freqs.insert(token.to_string(), (1, n_docs));
+                    },
+                }
+            });
+        }
+
+        let mut terms = doc_freqs
+            .into_iter()
+            .filter(|(_, (df, _))| *df >= options.min_df)
+            .map(|(term, (df, _))| (term, df))
+            .collect::<Vec<_>>();
+        if let Some(max_features) = options.max_features {
+            terms.sort_unstable_by(|(a, df_a), (b, df_b)| df_b.cmp(df_a).then_with(|| a.cmp(b)));
+            terms.truncate(max_features);
+        }
+        terms.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        let idf = options.tf_idf.then(|| {
+            terms
+                .iter()
+                .map(|(_, df)| ((1 + n_docs) as f64 / (1 + df) as f64).ln() + 1.0)
+                .collect()
+        });
+        let vocabulary = terms
+            .into_iter()
+            .map(|(term, _)| PlSmallStr::from_string(term))
+            .collect();
+        Self {
+            tokenize: options.tokenize,
+            vocabulary,
+            idf,
+        }
+    }
+
+    pub fn vocabulary(&self) -> &[PlSmallStr] {
+        &self.vocabulary
+    }
+
+    pub fn idf(&self) -> Option<&[f64]> {
+        self.idf.as_deref()
+    }
+
+    fn value_dtype(&self) -> DataType {
+        if self.idf.is_some() {
+            DataType::Float64
+        } else {
+            DataType::UInt32
+        }
+    }
+
+    /// The dtype of [`TermVectorizer::transform`].
+    ///
+    /// Dense vectors are an `Array` with a value per term of the vocabulary, sparse vectors a
+    /// `List` of `{index, value}` structs of the terms that occur in the document.
+    pub fn output_dtype(&self, sparse: bool) -> DataType {
+        if sparse {
+            DataType::List(Box::new(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("index"), DataType::UInt32),
+                Field::new(PlSmallStr::from_static("value"), self.value_dtype()),
+            ])))
+        } else {
+            DataType::Array(Box::new(self.value_dtype()), self.vocabulary.len())
+        }
+    }
+
+    /// Turn every document in `ca` into the counts of the vocabulary terms, weighted by the
+    /// inverse document frequency if the vectorizer has them. Null documents stay null.
+    pub fn transform(&self, ca: &StringChunked, sparse: bool) -> PolarsResult<Series> {
+        let width = self.vocabulary.len();
+        polars_ensure!(width > 0, ComputeError: "cannot vectorize with an empty vocabulary");
+
+        let index = self
+            .vocabulary
+            .iter()
+            .enumerate()
+            .map(|(i, term)| (term.as_str(), i as u32))
+            .collect::<PlHashMap<_, _>>();
+        let mut buf = String::new();
+        let mut counts = PlHashMap::<u32, u32>::new();
+        let mut term_ids = vec![];
+        let mut term_counts = vec![];
+        let mut offsets = Vec::with_capacity(ca.len() + 1);
+        offsets.push(0i64);
+        let mut validity = MutableBitmap::with_capacity(ca.len());
+
+        for opt_s in ca.iter() {
+            if let Some(s) = opt_s {
+                counts.clear();
+                for_each_token(s, &self.tokenize, &mut buf, |token| {
+                    if let Some(id) = index.get(token) {
+                        *counts.entry(*id).or_insert(0) += 1;
+                    }
+                });
+                let start = term_ids.len();
+                term_ids.extend(counts.keys().copied());
+                term_ids[start..].sort_unstable();
+                term_counts.extend(term_ids[start..].iter().map(|id| counts[id]));
+            }
+            validity.push(opt_s.is_some());
+            offsets.push(term_ids.len() as i64);
+        }
+        let validity: Option<Bitmap> = validity.into();
+
+        let arr = if sparse {
+            let values = self.weigh(&term_ids, term_counts);
+            let indices = UInt32Chunked::from_vec(PlSmallStr::from_static("index"), term_ids);
+            let fields = [indices.into_series(), values];
+            let entries = StructChunked::from_series(PlSmallStr::EMPTY, fields.iter())?.rechunk();
+            let entries = entries.chunks()[0].clone();
+            // SAFETY: the offsets are monotonically increasing and end at the number of entries.
+            let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+            let dtype = LargeListArray::default_datatype(entries.dtype().clone());
+            LargeListArray::new(dtype, offsets, entries, validity).boxed()
+        } else {
+            let mut dense_ids = Vec::with_capacity(term_ids.len());
+            for (row, window) in offsets.windows(2).enumerate() {
+                let ids = &term_ids[window[0] as usize..window[1] as usize];
+                dense_ids.extend(ids.iter().map(|id| row * width + *id as usize));
+            }
+            let values = self.weigh(&term_ids, term_counts);
+            let dense = match values.dtype() {
+                DataType::Float64 => scatter(values.f64()?, &dense_ids, ca.len() * width),
+                _ => scatter(values.u32()?, &dense_ids, ca.len() * width),
+            };
+            let values = dense.chunks()[0].clone();
+            let dtype = FixedSizeListArray::default_datatype(values.dtype().clone(), width);
+            FixedSizeListArray::new(dtype, values, validity).boxed()
+        };
+
+        // SAFETY: the array matches the output dtype.
+        Ok(unsafe {
+            Series::from_chunks_and_dtype_unchecked(
+                ca.name().clone(),
+                vec![arr],
+                &self.output_dtype(sparse),
+            )
+        })
+    }
+
+    fn weigh(&self, term_ids: &[u32], term_counts: Vec<u32>) -> Series {
+        let name = PlSmallStr::from_static("value");
+        match &self.idf {
+            Some(idf) => {
+                let values = term_ids
+                    .iter()
+                    .zip(term_counts)
+                    .map(|(id, count)| count as f64 * idf[*id as usize])
+                    .collect();
+                Float64Chunked::from_vec(name, values).into_series()
+            },
+            None => UInt32Chunked::from_vec(name, term_counts).into_series(),
+        }
+    }
+}
+
+/// Write the `values` at the `positions` of a zeroed column of length `len`.
+fn scatter<T>(values: &ChunkedArray<T>, positions: &[usize], len: usize) -> Series
+where
+    T: PolarsNumericType,
+{
+    let mut out = vec![T::Native::default(); len];
+    for (pos, v) in positions.iter().zip(values.into_no_null_iter()) {
+        out[*pos] = v;
+    }
+    ChunkedArray::<T>::from_vec(PlSmallStr::from_static("value"), out).into_series()
+}
+
+/// Reduce an ASCII lowercase word to its stem with the Porter stemming algorithm.
+///
+/// This follows the reference implementation of Martin Porter, `k` is the index of the last
+/// byte of the word and `j` the index of the last byte before a matched suffix.
+fn porter_stem(word: &mut Vec<u8>) {
+    if word.len() <= 2 {
+        return;
+    }
+    let mut stemmer = PorterStemmer {
+        b: std::mem::take(word),
+        k: 0,
+        j: 0,
+    };
+    stemmer.k = stemmer.b.len() as isize - 1;
+    stemmer.step1ab();
+    if stemmer.k > 0 {
+        stemmer.step1c();
+        stemmer.step2();
+        stemmer.step3();
+        stemmer.step4();
+        stemmer.step5();
+    }
+    stemmer.b.truncate(stemmer.k as usize + 1);
+    *word = stemmer.b;
+}
+
+struct PorterStemmer {
+    b: Vec<u8>,
+    k: isize,
+    j: isize,
+}
+
+impl PorterStemmer {
+    fn at(&self, i: isize) -> u8 {
+        self.b[i as usize]
+    }
+
+    /// Whether the byte at `i` is a consonant.
+    fn cons(&self, i: isize) -> bool {
+        match self.at(i) {
+            b'a' | b'e' | b'i' | b'o' | b'u' => false,
+            b'y' => i == 0 || !self.cons(i - 1),
+            _ => true,
+        }
+    }
+
+    /// The number of vowel-consonant sequences in `0..=j`.
+    fn m(&self) -> usize {
+        let mut n = 0;
+        let mut i = 0;
+        loop {
+            if i > self.j {
+                return n;
+            }
+            if !self.cons(i) {
+                break;
+            }
+            i += 1;
+        }
+        i += 1;
+        loop {
+            loop {
+                if i > self.j {
+                    return n;
+                }
+                if self.cons(i) {
+                    break;
+                }
+                i += 1;
+            }
+            i += 1;
+            n += 1;
+            loop {
+                if i > self.j {
+                    return n;
+                }
+                if !self.cons(i) {
+                    break;
+                }
+                i += 1;
+            }
+            i += 1;
+        }
+    }
+
+    fn vowel_in_stem(&self) -> bool {
+        (0..=self.j).any(|i| !self.cons(i))
+    }
+
+    /// Whether `i - 1..=i` is a double consonant.
+    fn double_cons(&self, i: isize) -> bool {
+        i >= 1 && self.at(i) == self.at(i - 1) && self.cons(i)
+    }
+
+    /// Whether `i - 2..=i` is consonant-vowel-consonant and the last consonant is not w, x or
+    /// y. This restores an e in words such as hop(e) or cav(e).
+    fn cvc(&self, i: isize) -> bool {
+        if i < 2 || !self.cons(i) || self.cons(i - 1) || !self.cons(i - 2) {
+            return false;
+        }
+        !matches!(self.at(i), b'w' | b'x' | b'y')
+    }
+
+    fn ends(&mut self, s: &[u8]) -> bool {
+        let len = s.len() as isize;
+        if len > self.k + 1 {
+            return false;
+        }
+        if &self.b[(self.k + 1 - len) as usize..=self.k as usize] != s {
+            return false;
+        }
+        self.j = self.k - len;
+        true
+    }
+
+    fn set_to(&mut self, s: &[u8]) {
+        self.b.truncate((self.j + 1) as usize);
+        self.b.extend_from_slice(s);
+        self.k = self.j + s.len() as isize;
+    }
+
+    fn replace(&mut self, s: &[u8]) {
+        if self.m() > 0 {
+            self.set_to(s)
+        }
+    }
+
+    /// Remove plurals and -ed or -ing.
+    fn step1ab(&mut self) {
+        if self.at(self.k) == b's' {
+            if self.ends(b"sses") {
+                self.k -= 2;
+            } else if self.ends(b"ies") {
+                self.set_to(b"i");
+            } else if self.at(self.k - 1) != b's' {
+                self.k -= 1;
+            }
+        }
+        if self.ends(b"eed") {
+            if self.m() > 0 {
+                self.k -= 1;
+            }
+        } else if (self.ends(b"ed") || self.ends(b"ing")) && self.vowel_in_stem() {
+            self.k = self.j;
+            if self.ends(b"at") {
+                self.set_to(b"ate");
+            } else if self.ends(b"bl") {
+                self.set_to(b"ble");
+            } else if self.ends(b"iz") {
+                self.set_to(b"ize");
+            } else if self.double_cons(self.k) {
+                self.k -= 1;
+                if matches!(self.at(self.k), b'l' | b's' | b'z') {
+                    self.k += 1;
+                }
+            } else if self.m() == 1 && self.cvc(self.k) {
+                self.set_to(b"e");
+            }
+        }
+    }
+
+    /// Turn a terminal y into i when there is another vowel in the stem.
+    fn step1c(&mut self) {
+        if self.ends(b"y") && self.vowel_in_stem() {
+            self.b[self.k as usize] = b'i';
+        }
+    }
+
+    /// Map double suffixes to single ones.
+    fn step2(&mut self) {
+        let rules: &[(&[u8], &[u8])] = match self.at(self.k - 1) {
+            b'a' => &[(b"ational", b"ate"), (b"tional", b"tion")],
+            b'c' => &[(b"enci", b"ence"), (b"anci", b"ance")],
+            b'e' => &[(b"izer", b"ize")],
+            b'l' => &[
+                (b"bli", b"ble"),
+                (b"alli", b"al"),
+                (b"entli", b"ent"),
+                (b"eli", b"e"),
+                (b"ousli", b"ous"),
+            ],
+            b'o' => &[(b"ization", b"ize"), (b"ation", b"ate"), (b"ator", b"ate")],
+            b's' => &[
+                (b"alism", b"al"),
+                (b"iveness", b"ive"),
+                (b"fulness", b"ful"),
+                (b"ousness", b"ous"),
+            ],
+            b't' => &[(b"aliti", b"al"), (b"iviti", b"ive"), (b"biliti", b"ble")],
+            b'g' => &[(b"logi", b"log")],
+            _ => &[],
+        };
+        self.apply_first(rules);
+    }
+
+    /// Handle -ic-, -full, -ness etc.
+    fn step3(&mut self) {
+        let rules: &[(&[u8], &[u8])] = match self.at(self.k) {
+            b'e' => &[(b"icate", b"ic"), (b"ative", b""), (b"alize", b"al")],
+            b'i' => &[(b"iciti", b"ic")],
+            b'l' => &[(b"ical", b"ic"), (b"ful", b"")],
+            b's' => &[(b"ness", b"")],
+            _ => &[],
+        };
+        self.apply_first(rules);
+    }
+
+    fn apply_first(&mut self, rules: &[(&[u8], &[u8])]) {
+        for (suffix, replacement) in rules {
+            if self.ends(suffix) {
+                self.replace(replacement);
+                return;
+            }
+        }
+    }
+
+    /// Remove -ant, -ence etc. in a context of vowel-consonant sequences.
+    fn step4(&mut self) {
+        let suffixes: &[&[u8]] = match self.at(self.k - 1) {
+            b'a' => &[b"al"],
+            b'c' => &[b"ance", b"ence"],
+            b'e' => &[b"er"],
+            b'i' => &[b"ic"],
+            b'l' => &[b"able", b"ible"],
+            b'n' => &[b"ant", b"ement", b"ment", b"ent"],
+            b'o' => {
+                if !(self.ends(b"ion") && self.j >= 0 && matches!(self.at(self.j), b's' | b't'))
+                    && !self.ends(b"ou")
+                {
+                    return;
+                }
+                &[]
+            },
+            b's' => &[b"ism"],
+            b't' => &[b"ate", b"iti"],
+            b'u' => &[b"ous"],
+            b'v' => &[b"ive"],
+            b'z' => &[b"ize"],
+            _ => return,
+        };
+        if !suffixes.is_empty() && !suffixes.iter().any(|s| self.ends(s)) {
+            return;
+        }
+        if self.m() > 1 {
+            self.k = self.j;
+        }
+    }
+
+    /// Remove a final -e and turn -ll into -l when the stem is long enough.
+    fn step5(&mut self) {
+        self.j = self.k;
+        if self.at(self.k) == b'e' {
+            let a = self.m();
+            if a > 1 || a == 1 && !self.cvc(self.k - 1) {
+                self.k -= 1;
+            }
+        }
+        if self.at(self.k) == b'l' && self.double_cons(self.k) && self.m() > 1 {
+            self.k -= 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn stem(word: &str) -> String {
+        let mut bytes = word.as_bytes().to_vec();
+        porter_stem(&mut bytes);
+        String::from_utf8(bytes).unwrap()
+    }
+
+    #[test]
+    fn test_porter_stem() {
+        for (word, expected) in [
+            ("caresses", "caress"),
+            ("ponies", "poni"),
+            ("cats", "cat"),
+            ("agreed", "agre"),
+            ("plastered", "plaster"),
+            ("motoring", "motor"),
+            ("hopping", "hop"),
+            ("filing", "file"),
+            ("happy", "happi"),
+            ("relational", "relat"),
+            ("generalization", "gener"),
+            ("hopefulness", "hope"),
+            ("electrical", "electr"),
+            ("adjustment", "adjust"),
+            ("controlling", "control"),
+            ("running", "run"),
+        ] {
+            assert_eq!(stem(word), expected, "{word}");
+        }
+    }
+
+    #[test]
+    fn test_bm25_score() {
+        let ca = StringChunked::new(
+            PlSmallStr::EMPTY,
+            &[
+                Some("The quick brown fox"),
+                Some("Foxes are running in the forest"),
+                None,
+                Some("A lazy dog"),
+            ],
+        );
+        let tokens = tokenize(&ca, &TokenizeOptions::default());
+        let first = tokens.get_as_series(1).unwrap();
+        assert_eq!(
+            first.str().unwrap().into_no_null_iter().collect::<Vec<_>>(),
+            &["fox", "run", "forest"]
+        );
+
+        let scores = bm25_score(&ca, "running foxes", &Bm25Options::default()).unwrap();
+        let scores = scores.into_iter().collect::<Vec<_>>();
+        assert!(scores[1].unwrap() > scores[0].unwrap());
+        assert!(scores[0].unwrap() > 0.0);
+        assert_eq!(scores[2], None);
+        assert_eq!(scores[3], Some(0.0));
+    }
+
+    #[test]
+    fn test_term_vectorizer() -> PolarsResult<()> {
+        let train = StringChunked::new(
+            PlSmallStr::EMPTY,
+            &[Some("The cat sat"), Some("The cat and the dog"), None],
+        );
+        let vectorizer = TermVectorizer::fit(&train, &VectorizeOptions::default());
+        assert_eq!(["cat", "dog", "sat"], vectorizer.vocabulary());
+
+        let test = StringChunked::new(PlSmallStr::EMPTY, &[Some("Cats, cats and a bird"), None]);
+        let dense = vectorizer.transform(&test, false)?;
+        assert_eq!(dense.dtype(), &vectorizer.output_dtype(false));
+        let first = dense.array()?.get_as_series(0).unwrap();
+        assert_eq!(
+            first.u32()?.into_no_null_iter().collect::<Vec<_>>(),
+            &[2, 0, 0]
+        );
+        assert_eq!(dense.null_count(), 1);
+
+        let sparse = vectorizer.transform(&test, true)?;
+        assert_eq!(sparse.dtype(), &vectorizer.output_dtype(true));
+        assert_eq!(sparse.list()?.get_as_series(0).unwrap().len(), 1);
+        assert_eq!(sparse.null_count(), 1);
+
+        let options = VectorizeOptions {
+            min_df: 2,
+            tf_idf: true,
+            ..Default::default()
+        };
+        let vectorizer = TermVectorizer::fit(&train, &options);
+        assert_eq!(["cat"], vectorizer.vocabulary());
+        assert_eq!(vectorizer.idf(), Some([1.0].as_slice()));
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use polars_utils::total_ord::canonical_f64;
+
+/// Hashing of floats that agrees with their `PartialEq`, as `-0.0` and `0.0` hash the same.
+///
+/// Used for options and fitted parameters that hold floats, but have to be `Hash` to be part of
+/// an expression.
+pub(crate) trait FloatHash {
+    fn float_hash<H: Hasher>(&self, state: &mut H);
+}
+
+impl FloatHash for f64 {
+    fn float_hash<H: Hasher>(&self, state: &mut H) {
+        canonical_f64(*self).to_bits().hash(state)
+    }
+}
+
+impl FloatHash for [f64] {
+    fn float_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        self.iter().for_each(|v| v.float_hash(state))
+    }
+}
+
+impl<T: FloatHash + ?Sized> FloatHash for Arc<T> {
+    fn float_hash<H: Hasher>(&self, state: &mut H) {
+        self.as_ref().float_hash(state)
+    }
+}
+
+impl<T: FloatHash> FloatHash for Option<T> {
+    fn float_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(v) = self {
+            v.float_hash(state)
+        }
+    }
+}
+
+/// Implement `Eq` and `Hash` for a struct that derives `PartialEq`, hashing the fields after
+/// `floats` with [`FloatHash`].
+macro_rules! impl_float_eq_hash {
+    ($T:ty, [$($field:ident),*], floats: [$($float:ident),*]) => {
+        impl Eq for $T {}
+
+        impl std::hash::Hash for $T {
+            fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+                $(std::hash::Hash::hash(&self.$field, state);)*
+                $($crate::float_hash::FloatHash::float_hash(&self.$float, state);)*
+            }
+        }
+    };
+}
+
+pub(crate) use impl_float_eq_hash;
@@ -0,0 +1,331 @@
+//! Graph algorithms over edge lists, e.g. to resolve entities from pairwise matches.
+
+use polars_core::chunked_array::builder::get_list_builder;
+use polars_core::prelude::*;
+use polars_core::utils::try_get_supertype;
+
+/// The distinct non-null values of `keys` in order of first occurrence, and the position of
+/// every value of `keys` among them.
+fn dense_ids(keys: &Series) -> PolarsResult<(Series, Vec<Option<IdxSize>>)> {
+    let groups = keys.group_tuples(true, true)?;
+    let valid = keys.is_not_null();
+    let mut ids = vec![None; keys.len()];
+    let mut first = vec![];
+    for group in groups.iter() {
+        if !valid.get(group.first() as usize).unwrap_or(false) {
+            continue;
+        }
+        let id = Some(first.len() as IdxSize);
+        first.push(group.first());
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for i in idx.iter() {
+                    ids[*i as usize] = id;
+                }
+            },
+            GroupsIndicator::Slice([offset, len]) => {
+                ids[offset as usize..(offset + len) as usize].fill(id)
+            },
+        }
+    }
+    Ok((keys.take_slice(&first)?, ids))
+}
+
+/// `a` followed by `b`, cast to their supertype.
+fn concat_keys(a: &Series, b: &Series) -> PolarsResult<Series> {
+    let dtype = try_get_supertype(a.dtype(), b.dtype())?;
+    let mut keys = a.cast(&dtype)?;
+    keys.append(&b.cast(&dtype)?)?;
+    Ok(keys)
+}
+
+/// An edge list with its nodes hashed to dense ids once up front, so that the algorithms only
+/// gather by id.
+struct EdgeList {
+    /// The distinct non-null nodes, in order of first occurrence.
+    nodes: Series,
+    /// The edges between the ids of `nodes`, edges with a null endpoint are left out.
+    edges: Vec<(IdxSize, IdxSize)>,
+}
+
+impl EdgeList {
+    fn new(df: &DataFrame, src: &str, dst: &str) -> PolarsResult<Self> {
+        let src = df.column(src)?.as_materialized_series();
+        let dst = df.column(dst)?.as_materialized_series();
+        let (nodes, ids) = dense_ids(&concat_keys(src, dst)?)?;
+
+        let (src_ids, dst_ids) = ids.split_at(src.len());
+        let edges = src_ids
+            .iter()
+            .zip(dst_ids)
+            .filter_map(|(a, b)| Some(((*a)?, (*b)?)))
+            .collect();
+        Ok(Self { nodes, edges })
+    }
+
+    /// The outgoing neighbours of every node in compressed sparse row form.
+    fn adjacency(&self) -> (Vec<usize>, Vec<IdxSize>) {
+        let mut offsets = vec![0; self.nodes.len() + 1];
+        for (a, _) in &self.edges {
+            offsets[*a as usize + 1] += 1;
+        }
+        for i in 0..self.nodes.len() {
+            offsets[i + 1] += offsets[i];
+        }
+        let mut next = offsets.clone();
+        let mut neighbours = vec![0; self.edges.len()];
+        for (a, b) in &self.edges {
+            neighbours[next[*a as usize]] = *b;
+            next[*a as usize] += 1;
+        }
+        (offsets, neighbours)
+    }
+}
+
+/// Label every node of the undirected graph given by the edges `src -> dst` of `df` with its
+/// connected component.
+///
+/// Returns a frame with the distinct nodes, named after `src`, and their `component`, both
+/// numbered in order of first occurrence. A row with a null `dst` adds `src` as a node without
+/// edges, and vice versa.
+///
+/// Every node starts with its own id as label and takes the smallest label among its neighbours
+/// until a round changes no label. After each round the labels are shortcut to the label of
+/// their label, which makes the number of rounds logarithmic in the diameter of the components
+/// for most graphs.
+pub fn connected_components(df: &DataFrame, src: &str, dst: &str) -> PolarsResult<DataFrame> {
+    let graph = EdgeList::new(df, src, dst)?;
+    let n_nodes = graph.nodes.len();
+    let mut labels = (0..n_nodes as IdxSize).collect::<Vec<_>>();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for (a, b) in &graph.edges {
+            let (a, b) = (*a as usize, *b as usize);
+            let min = labels[a].min(labels[b]);
+            if labels[a] != min || labels[b] != min {
+                labels[a] = min;
+                labels[b] = min;
+                changed = true;
+            }
+        }
+        for i in 0..n_nodes {
+            labels[i] = labels[labels[i] as usize];
+        }
+    }
+
+    // A label is the smallest id of its component, so it is numbered before its other nodes.
+    let mut components = vec![0 as IdxSize; n_nodes];
+    let mut n_components = 0;
+    for (i, label) in labels.iter().enumerate() {
+        components[i] = if *label as usize == i {
+            n_components += 1;
+            n_components - 1
+        } else {
+            components[*label as usize]
+        };
+    }
+
+    let nodes = graph.nodes.with_name(PlSmallStr::from_str(src));
+    let components = IdxCa::from_vec(PlSmallStr::from_static("component"), components);
+    DataFrame::new(vec![nodes.into_column(), components.into_column()])
+}
+
+/// The transitive closure of the directed graph given by the edges `src -> dst` of `df`: every
+/// pair of nodes such that `dst` is reachable from `src`.
+///
+/// A node is only paired with itself if it lies on a cycle. Edges with a null endpoint are
+/// ignored.
+pub fn transitive_closure(df: &DataFrame, src: &str, dst: &str) -> PolarsResult<DataFrame> {
+    let graph = EdgeList::new(df, src, dst)?;
+    let n_nodes = graph.nodes.len();
+    let (offsets, neighbours) = graph.adjacency();
+
+    // Breadth-first search from every node, `visited` holds the last start that reached a node.
+    let mut visited = vec![IdxSize::MAX; n_nodes];
+    let mut queue = vec![];
+    let mut src_idx = vec![];
+    let mut dst_idx = vec![];
+    for start in 0..n_nodes as IdxSize {
+        queue.clear();
+        queue.push(start);
+        let mut head = 0;
+        while head < queue.len() {
+            let node = queue[head] as usize;
+            head += 1;
+            for next in &neighbours[offsets[node]..offsets[node + 1]] {
+                if visited[*next as usize] != start {
+                    visited[*next as usize] = start;
+                    src_idx.push(start);
+                    dst_idx.push(*next);
+                    queue.push(*next);
+                }
+            }
+        }
+    }
+
+    let src_nodes = graph.nodes.take_slice(&src_idx)?;
+    let dst_nodes = graph.nodes.take_slice(&dst_idx)?;
+    DataFrame::new(vec![
+        src_nodes.with_name(PlSmallStr::from_str(src)).into_column(),
+        dst_nodes.with_name(PlSmallStr::from_str(dst)).into_column(),
+    ])
+}
+
+/// Flatten the hierarchy given by the `id` and `parent_id` columns of `df`, e.g. an org chart or
+/// a bill of materials.
+///
+/// Appends the `depth` of every row, the id of the `root` of its tree and the `path` of ids from
+/// the root to the row. Rows whose parent is null, themselves or not among the ids are roots.
+/// The ids must be unique and not null, and the hierarchy must not have cycles.
+pub fn propagate_hierarchy(df: &DataFrame, id: &str, parent_id: &str) -> PolarsResult<DataFrame> {
+    let ids = df.column(id)?.as_materialized_series();
+    let parent_ids = df.column(parent_id)?.as_materialized_series();
+    let n_rows = df.height();
+    // The first occurrence of a unique id is its own row.
+    let (_, key_ids) = dense_ids(&concat_keys(ids, parent_ids)?)?;
+    for (row, key_id) in key_ids[..n_rows].iter().enumerate() {
+        polars_ensure!(
+            *key_id == Some(row as IdxSize),
+            ComputeError: "hierarchy ids must be unique and not null, found {} twice or null",
+            ids.get(row)?
+        );
+    }
+    let parents = key_ids[n_rows..]
+        .iter()
+        .enumerate()
+        .map(|(row, parent)| parent.filter(|p| (*p as usize) < n_rows && *p as usize != row))
+        .collect::<Vec<_>>();
+
+    // Walk up to the first row with a known depth, then assign the depths on the way back.
+    let mut depth = vec![IdxSize::MAX; n_rows];
+    let mut root = vec![0 as IdxSize; n_rows];
+    let mut on_stack = vec![false; n_rows];
+    let mut stack = vec![];
+    for row in 0..n_rows {
+        let mut node = row;
+        while depth[node] == IdxSize::MAX {
+            match parents[node] {
+                None => {
+                    depth[node] = 0;
+                    root[node] = node as IdxSize;
+                },
+                Some(parent) => {
+                    polars_ensure!(
+                        !on_stack[node],
+                        ComputeError: "hierarchy contains a cycle through id {}",
+                        ids.get(node)?
+                    );
+                    on_stack[node] = true;
+                    stack.push(node);
+                    node = parent as usize;
+                },
+            }
+        }
+        while let Some(node) = stack.pop() {
+            let parent = parents[node].unwrap() as usize;
+            depth[node] = depth[parent] + 1;
+            root[node] = root[parent];
+            on_stack[node] = false;
+        }
+    }
+
+    let n_values = depth.iter().map(|d| *d as usize + 1).sum();
+    let mut path_rows = Vec::with_capacity(n_values);
+    for row in 0..n_rows {
+        let start = path_rows.len();
+        let mut node = Some(row as IdxSize);
+        while let Some(n) = node {
+            path_rows.push(n);
+            node = parents[n as usize];
+        }
+        path_rows[start..].reverse();
+    }
+    let path_values = ids.take_slice(&path_rows)?;
+    let mut builder = get_list_builder(
+        ids.dtype(),
+        n_values,
+        n_rows,
+        PlSmallStr::from_static("path"),
+    )?;
+    let mut offset = 0;
+    for d in &depth {
+        let len = *d as usize + 1;
+        builder.append_series(&path_values.slice(offset as i64, len))?;
+        offset += len;
+    }
+
+    let root = ids
+        .take_slice(&root)?
+        .with_name(PlSmallStr::from_static("root"));
+    let depth = IdxCa::from_vec(PlSmallStr::from_static("depth"), depth);
+    df.hstack(&[
+        depth.into_column(),
+        root.into_column(),
+        builder.finish().into_column(),
+    ])
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_connected_components() -> PolarsResult<()> {
+        let edges = df![
+            "a" => [Some("x"), Some("q"), Some("z"), Some("y"), Some("r"), None],
+            "b" => [Some("y"), Some("r"), Some("y"), Some("z"), None, Some("s")],
+        ]?;
+        let out = connected_components(&edges, "a", "b")?;
+        let expected = df![
+            "a" => ["x", "q", "z", "y", "r", "s"],
+            "component" => [0 as IdxSize, 1, 0, 0, 1, 2],
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_transitive_closure() -> PolarsResult<()> {
+        let edges = df![
+            "a" => [1, 2, 3, 4],
+            "b" => [2, 3, 2, 4],
+        ]?;
+        let out = transitive_closure(&edges, "a", "b")?;
+        let expected = df![
+            "a" => [1, 1, 2, 2, 3, 3, 4],
+            "b" => [2, 3, 3, 2, 2, 3, 4],
+        ]?;
+        assert!(out.equals(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_propagate_hierarchy() -> PolarsResult<()> {
+        let df = df![
+            "id" => [3, 1, 2, 4, 5],
+            "parent" => [Some(1), None, Some(1), Some(3), Some(5)],
+        ]?;
+        let out = propagate_hierarchy(&df, "id", "parent")?;
+        assert_eq!(
+            Vec::from(out.column("depth")?.idx()?),
+            &[Some(1), Some(0), Some(1), Some(2), Some(0)]
+        );
+        assert_eq!(
+            Vec::from(out.column("root")?.i32()?),
+            &[Some(1), Some(1), Some(1), Some(1), Some(5)]
+        );
+        let path = out.column("path")?.list()?.get_as_series(3).unwrap();
+        assert_eq!(Vec::from(path.i32()?), &[Some(1), Some(3), Some(4)]);
+
+        let df = df![
+            "id" => [1, 2, 3],
+            "parent" => [3, 1, 2],
+        ]?;
+        assert!(propagate_hierarchy(&df, "id", "parent").is_err());
+        Ok(())
+    }
+}
@@ -28,6 +28,7 @@ pub struct JoinArgs {
     pub slice: Option<(i64, usize)>,
     pub join_nulls: bool,
     pub coalesce: JoinCoalesce,
+    pub float_tolerance: Option<FloatTolerance>,
 }
 
 impl JoinArgs {
@@ -36,6 +37,64 @@ impl JoinArgs {
     }
 }
 
+/// Join float keys on their nearest multiple of `epsilon` instead of their exact value, so that
+/// keys that only differ by rounding errors still match.
+///
+/// The keys of both sides are quantized before they are hashed and compared, which keeps hashing
+/// and equality consistent. Note that keys closer than `epsilon` can still be quantized to
+/// different multiples if they lie on either side of a midpoint. NaN and infinite keys are
+/// treated as nulls.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FloatTolerance {
+    // Stored as bits so that the join arguments stay hashable.
+    epsilon: u64,
+}
+
+impl FloatTolerance {
+    pub fn new(epsilon: f64) -> PolarsResult<Self> {
+        polars_ensure!(
+            epsilon.is_finite() && epsilon > 0.0,
+            InvalidOperation: "join float tolerance must be positive and finite, got {}", epsilon
+        );
+        Ok(Self {
+            epsilon: epsilon.to_bits(),
+        })
+    }
+
+    pub fn epsilon(&self) -> f64 {
+        f64::from_bits(self.epsilon)
+    }
+
+    /// Replace float keys by the rounded number of times `epsilon` fits in them, other keys are
+    /// returned as is. The multiples are kept as floats, so that large keys don't overflow.
+    pub(super) fn quantize(&self, s: &Series) -> PolarsResult<Series> {
+        if !s.dtype().is_float() {
+            return Ok(s.clone());
+        }
+        let epsilon = self.epsilon();
+        let ca = s.cast(&DataType::Float64)?;
+        let ca = ca.f64()?;
+        let quantized = ca
+            .iter()
+            .map(|opt_v| {
+                // NaN and infinite keys are treated as nulls.
+                let Some(v) = opt_v.filter(|v| v.is_finite()) else {
+                    return Ok(None);
+                };
+                // Adding zero turns a rounded -0.0 into 0.0, so that both hash the same.
+                let q = (v / epsilon).round() + 0.0;
+                polars_ensure!(
+                    q.is_finite(),
+                    InvalidOperation: "join key {} is too large for the float tolerance {}", v, epsilon
+                );
+                Ok(Some(q))
+            })
+            .collect::<PolarsResult<Float64Chunked>>()?;
+        Ok(quantized.with_name(ca.name().clone()).into_series())
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, Debug, Hash, Default)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum JoinCoalesce {
@@ -76,6 +135,7 @@ impl Default for JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            float_tolerance: None,
         }
     }
 }
@@ -89,6 +149,7 @@ impl JoinArgs {
             slice: None,
             join_nulls: false,
             coalesce: Default::default(),
+            float_tolerance: None,
         }
     }
 
@@ -102,6 +163,11 @@ impl JoinArgs {
         self
     }
 
+    pub fn with_float_tolerance(mut self, float_tolerance: Option<FloatTolerance>) -> Self {
+        self.float_tolerance = float_tolerance;
+        self
+    }
+
     pub fn suffix(&self) -> &PlSmallStr {
         static DEFAULT: Lazy<PlSmallStr> = Lazy::new(|| PlSmallStr::from_static("_right"));
         self.suffix.as_ref().unwrap_or(&*DEFAULT)
@@ -230,8 +230,14 @@ pub trait DataFrameJoinOps: IntoDf {
             );
         }
 
-        // Single keys.
-        if selected_left.len() == 1 {
+        if let Some(tolerance) = args.float_tolerance.filter(|_| !args.how.is_asof()) {
+            for s in selected_left.iter_mut().chain(selected_right.iter_mut()) {
+                *s = tolerance.quantize(s)?;
+            }
+        }
+
+        // Single keys. Nested keys are row-encoded like multiple keys.
+        if selected_left.len() == 1 && !is_row_encoded_key(selected_left[0].dtype()) {
             let s_left = &selected_left[0];
             let s_right = &selected_right[0];
             let drop_names: Option<Vec<PlSmallStr>> =
@@ -523,18 +529,60 @@ trait DataFrameJoinOpsPrivate: IntoDf {
 impl DataFrameJoinOps for DataFrame {}
 impl DataFrameJoinOpsPrivate for DataFrame {}
 
+/// Whether a single join key must be joined on its row encoding.
+fn is_row_encoded_key(dtype: &DataType) -> bool {
+    match dtype {
+        DataType::List(_) => true,
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => true,
+        _ => false,
+    }
+}
+
+/// Take the physical representation of a key with canonical floats, such that equal
+/// values have equal row encodings. Floats nested in lists and structs are canonicalized as well.
+fn to_canonical_key(s: &Series) -> PolarsResult<Series> {
+    let out = match s.dtype() {
+        DataType::Float32 => s.f32().unwrap().to_canonical().into_series(),
+        DataType::Float64 => s.f64().unwrap().to_canonical().into_series(),
+        DataType::List(inner) => {
+            polars_ensure!(
+                !inner.is_nested(),
+                InvalidOperation: "cannot join on keys of dtype `{}`, lists of nested values are not supported",
+                s.dtype()
+            );
+            s.list()
+                .unwrap()
+                .apply_to_inner(&|s| to_canonical_nested_key(&s))?
+                .into_series()
+        },
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => s
+            .struct_()
+            .unwrap()
+            .try_apply_fields(to_canonical_nested_key)?
+            .into_series(),
+        _ => s.to_physical_repr().into_owned(),
+    };
+    Ok(out)
+}
+
+/// Canonicalize the values of a list or struct key. Only top-level categoricals are made
+/// compatible between the two sides, so nested categoricals are joined on their strings rather
+/// than on their codes.
+fn to_canonical_nested_key(s: &Series) -> PolarsResult<Series> {
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_, _) | DataType::Enum(_, _) => s.cast(&DataType::String),
+        _ => to_canonical_key(s),
+    }
+}
+
 fn prepare_keys_multiple(s: &[Series], join_nulls: bool) -> PolarsResult<BinaryOffsetChunked> {
     let keys = s
         .iter()
-        .map(|s| {
-            let phys = s.to_physical_repr();
-            match phys.dtype() {
-                DataType::Float32 => phys.f32().unwrap().to_canonical().into_series(),
-                DataType::Float64 => phys.f64().unwrap().to_canonical().into_series(),
-                _ => phys.into_owned(),
-            }
-        })
-        .collect::<Vec<_>>();
+        .map(to_canonical_key)
+        .collect::<PolarsResult<Vec<_>>>()?;
 
     if join_nulls {
         encode_rows_vertical_par_unordered(&keys)
@@ -1,3 +1,5 @@
+#[cfg(feature = "graph")]
+pub mod graph;
 pub mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
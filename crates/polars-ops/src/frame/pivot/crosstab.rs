@@ -0,0 +1,162 @@
+use super::*;
+
+/// The name of the `columns` keys of a [`crosstab`] while it is computed.
+pub const CROSSTAB_COLUMN_KEY: &str = "__POLARS_CROSSTAB_COLUMN";
+/// The name of the `values` of a [`crosstab`] while it is computed.
+pub const CROSSTAB_VALUES_KEY: &str = "__POLARS_CROSSTAB_VALUES";
+
+/// How the cells of a [`crosstab`] are normalized.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CrosstabNormalize {
+    /// Divide every cell by the total of its row.
+    Row,
+    /// Divide every cell by the total of its column.
+    Column,
+    /// Divide every cell by the grand total.
+    Total,
+}
+
+fn normalize_cells(cells: Vec<Column>, normalize: CrosstabNormalize) -> PolarsResult<Vec<Column>> {
+    let cells = cells
+        .iter()
+        .map(|c| c.cast(&DataType::Float64))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    match normalize {
+        CrosstabNormalize::Row => {
+            let df = DataFrame::new(cells.clone())?;
+            let Some(totals) = df.sum_horizontal(NullStrategy::Ignore)? else {
+                return Ok(cells);
+            };
+            let totals = totals.f64()?;
+            cells
+                .iter()
+                .map(|c| Ok((c.f64()? / totals).into_column()))
+                .collect()
+        },
+        CrosstabNormalize::Column => cells
+            .iter()
+            .map(|c| {
+                let ca = c.f64()?;
+                Ok((ca / ca.sum().unwrap_or(0.0)).into_column())
+            })
+            .collect(),
+        CrosstabNormalize::Total => {
+            let total = cells
+                .iter()
+                .map(|c| Ok(c.f64()?.sum().unwrap_or(0.0)))
+                .sum::<PolarsResult<f64>>()?;
+            cells
+                .iter()
+                .map(|c| Ok((c.f64()? / total).into_column()))
+                .collect()
+        },
+    }
+}
+
+/// Compute a contingency table of `rows` against `columns`.
+///
+/// Without `values` every cell counts the rows with its pair of keys, with `values` the cells
+/// aggregate them with `agg_fn`. The output has a column with the distinct `rows` keys, followed
+/// by a column for every distinct `columns` key, both in order of first occurrence. Both keys are
+/// grouped on in a single group by, whose groups are positioned in the table directly as in a
+/// [`pivot`]. Cells without rows are zero when counting and null otherwise, and are ignored
+/// by the `normalize` totals.
+pub fn crosstab(
+    rows: &Column,
+    columns: &Column,
+    values: Option<&Column>,
+    agg_fn: Option<PivotAgg>,
+    normalize: Option<CrosstabNormalize>,
+) -> PolarsResult<DataFrame> {
+    polars_ensure!(
+        rows.len() == columns.len() && values.map_or(true, |v| v.len() == rows.len()),
+        ShapeMismatch: "crosstab expects keys and values of equal length"
+    );
+    let (agg_fn, is_count) = match (values, agg_fn) {
+        (None, None | Some(PivotAgg::Count)) => (PivotAgg::Count, true),
+        (None, Some(_)) => {
+            polars_bail!(InvalidOperation: "crosstab can only count the rows without `values`")
+        },
+        (Some(_), Some(agg_fn)) => {
+            let is_count = matches!(agg_fn, PivotAgg::Count);
+            (agg_fn, is_count)
+        },
+        (Some(_), None) => {
+            polars_bail!(InvalidOperation: "crosstab with `values` requires an aggregation function")
+        },
+    };
+
+    let column_key = PlSmallStr::from_static(CROSSTAB_COLUMN_KEY);
+    let values_key = PlSmallStr::from_static(CROSSTAB_VALUES_KEY);
+    let df = DataFrame::new(vec![
+        rows.clone(),
+        columns.clone().with_name(column_key.clone()),
+        values.unwrap_or(rows).clone().with_name(values_key.clone()),
+    ])?;
+    let out = pivot_impl_single_column(
+        &df,
+        &[rows.name().clone()],
+        &column_key,
+        &[values_key],
+        Some(agg_fn),
+        false,
+        None,
+        false,
+        None,
+    )?;
+
+    let mut out = out.take_columns();
+    let mut cells = out.split_off(1);
+    if is_count {
+        cells = cells
+            .iter()
+            .map(|c| c.fill_null(FillNullStrategy::Zero))
+            .collect::<PolarsResult<_>>()?;
+    }
+    if let Some(normalize) = normalize {
+        cells = normalize_cells(cells, normalize)?;
+    }
+    out.extend(cells);
+    DataFrame::new(out)
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_crosstab() -> PolarsResult<()> {
+        let rows = Column::new("sex".into(), &["m", "f", "m", "f", "m"]);
+        let columns = Column::new("smoker".into(), &["yes", "no", "no", "no", "yes"]);
+        let values = Column::new("age".into(), &[20.0, 30.0, 40.0, 50.0, 60.0]);
+
+        let out = crosstab(&rows, &columns, None, None, None)?;
+        let expected = df![
+            "sex" => ["m", "f"],
+            "yes" => [2 as IdxSize, 0],
+            "no" => [1 as IdxSize, 2],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = crosstab(&rows, &columns, None, None, Some(CrosstabNormalize::Row))?;
+        let expected = df![
+            "sex" => ["m", "f"],
+            "yes" => [2.0 / 3.0, 0.0],
+            "no" => [1.0 / 3.0, 1.0],
+        ]?;
+        assert!(out.equals(&expected));
+
+        let out = crosstab(&rows, &columns, Some(&values), Some(PivotAgg::Mean), None)?;
+        let expected = df![
+            "sex" => ["m", "f"],
+            "yes" => [Some(40.0), None],
+            "no" => [Some(40.0), Some(40.0)],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        assert!(crosstab(&rows, &columns, Some(&values), None, None).is_err());
+        Ok(())
+    }
+}
@@ -1,14 +1,19 @@
+mod crosstab;
 mod positioning;
+mod table;
 mod unpivot;
 
 use std::borrow::Cow;
 
+pub use crosstab::{crosstab, CrosstabNormalize, CROSSTAB_COLUMN_KEY, CROSSTAB_VALUES_KEY};
 use polars_core::export::rayon::prelude::*;
 use polars_core::frame::group_by::expr::PhysicalAggExpr;
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
 use polars_core::{downcast_as_macro_arg_physical, POOL};
 use polars_utils::format_pl_smallstr;
+use polars_utils::idx_vec::IdxVec;
+pub use table::{pivot_table, PivotTableOptions};
 pub use unpivot::UnpivotDF;
 
 const HASHMAP_INIT_SIZE: usize = 512;
@@ -113,6 +118,7 @@ where
         sort_columns,
         false,
         separator,
+        &PivotTableOptions::default(),
     )
 }
 
@@ -149,6 +155,7 @@ where
         sort_columns,
         true,
         separator,
+        &PivotTableOptions::default(),
     )
 }
 
@@ -217,12 +224,15 @@ fn pivot_impl(
     stable: bool,
     // used as separator/delimiter in generated column names.
     separator: Option<&str>,
+    // the margins and the naming of the columns of multiple `on` keys.
+    options: &PivotTableOptions,
 ) -> PolarsResult<DataFrame> {
     polars_ensure!(!index.is_empty(), ComputeError: "index cannot be zero length");
     polars_ensure!(!on.is_empty(), ComputeError: "`on` cannot be zero length");
     if !stable {
         println!("unstable pivot not yet supported, using stable pivot");
     };
+    let margins_name = options.margins.then_some(&options.margins_name);
     if on.len() > 1 {
         let schema = Arc::new(pivot_df.schema());
         let binding = pivot_df.select_with_schema(on.iter().cloned(), &schema)?;
@@ -246,6 +256,8 @@ fn pivot_impl(
             agg_fn,
             sort_columns,
             separator,
+            options.hierarchical_headers,
+            margins_name,
         )
     } else {
         pivot_impl_single_column(
@@ -256,10 +268,118 @@ fn pivot_impl(
             agg_fn,
             sort_columns,
             separator,
+            false,
+            margins_name,
         )
     }
 }
 
+/// Join the fields of the struct `keys` with `separator`, e.g. `{"a","x"}` becomes `a_x`.
+fn hierarchical_header_names(keys: &Series, separator: &str) -> PolarsResult<StringChunked> {
+    let fields = keys
+        .struct_()?
+        .fields_as_series()
+        .iter()
+        .map(|s| s.cast(&DataType::String))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let fields = fields
+        .iter()
+        .map(|s| s.str())
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let out: StringChunked = (0..keys.len())
+        .map(|i| {
+            let levels = fields
+                .iter()
+                .map(|f| f.get(i).unwrap_or("null"))
+                .collect::<Vec<_>>();
+            Some(levels.join(separator))
+        })
+        .collect();
+    Ok(out.with_name(keys.name().clone()))
+}
+
+/// Aggregate the values of every group with `agg_fn`.
+///
+/// # Safety
+/// The groups must be in bounds of `value_col`.
+unsafe fn aggregate_groups(
+    value_col: &Column,
+    value_col_name: &PlSmallStr,
+    groups: &GroupsProxy,
+    agg_fn: &Option<PivotAgg>,
+) -> PolarsResult<Column> {
+    use PivotAgg::*;
+    let value_agg = match agg_fn {
+        None => match value_col.len() > groups.len() {
+            true => polars_bail!(
+                ComputeError:
+                "found multiple elements in the same group, \
+                please specify an aggregation function"
+            ),
+            false => value_col.agg_first(groups),
+        },
+        Some(agg_fn) => match agg_fn {
+            Sum => value_col.agg_sum(groups),
+            Min => value_col.agg_min(groups),
+            Max => value_col.agg_max(groups),
+            Last => value_col.agg_last(groups),
+            First => value_col.agg_first(groups),
+            Mean => value_col.agg_mean(groups),
+            Median => value_col.agg_median(groups),
+            Count => groups.group_count().into_column(),
+            Expr(ref expr) => {
+                let name = expr.root_name()?.clone();
+                let mut value_col = value_col.clone();
+                value_col.rename(name);
+                let tmp_df = value_col.into_frame();
+                let mut aggregated = Column::from(expr.evaluate(&tmp_df, groups)?);
+                aggregated.rename(value_col_name.clone());
+                aggregated
+            },
+        },
+    };
+    Ok(value_agg)
+}
+
+/// Merge the `groups` of the cells into a group per row or per column, given the row or column
+/// location of every cell. Every location must have at least one cell.
+fn merge_groups(groups: &GroupsProxy, locations: &[IdxSize], n_locations: usize) -> GroupsProxy {
+    let mut merged = vec![Vec::<IdxSize>::new(); n_locations];
+    for (group, location) in groups.iter().zip(locations) {
+        let merged = &mut merged[*location as usize];
+        match group {
+            GroupsIndicator::Idx((_, idx)) => merged.extend_from_slice(idx),
+            GroupsIndicator::Slice([first, len]) => merged.extend(first..first + len),
+        }
+    }
+    merged
+        .into_iter()
+        .map(|mut idx| {
+            // Keep the order of the rows for order dependent aggregations like `first`.
+            idx.sort_unstable();
+            (idx[0], IdxVec::from(idx))
+        })
+        .collect::<GroupsIdx>()
+        .into()
+}
+
+/// The key of the margin row: `margins_name` in the first `index` column if it is a string
+/// column, and null in the other `index` columns.
+fn margin_row_index(index: &[Column], margins_name: &PlSmallStr) -> Vec<Column> {
+    index
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            if i == 0 && c.dtype() == &DataType::String {
+                StringChunked::full(c.name().clone(), margins_name.as_str(), 1).into_column()
+            } else {
+                Column::full_null(c.name().clone(), 1, c.dtype())
+            }
+        })
+        .collect()
+}
+
+#[allow(clippy::too_many_arguments)]
 fn pivot_impl_single_column(
     pivot_df: &DataFrame,
     index: &[PlSmallStr],
@@ -268,9 +388,13 @@ fn pivot_impl_single_column(
     agg_fn: Option<PivotAgg>,
     sort_columns: bool,
     separator: Option<&str>,
+    hierarchical_headers: bool,
+    // append margin totals, with this name for the margin column and row.
+    margins_name: Option<&PlSmallStr>,
 ) -> PolarsResult<DataFrame> {
     let sep = separator.unwrap_or("_");
     let mut final_cols = vec![];
+    let mut margin_row = vec![];
     let mut count = 0;
     let out: PolarsResult<()> = POOL.install(|| {
         let mut group_by = index.to_vec();
@@ -287,45 +411,30 @@ fn pivot_impl_single_column(
 
         for value_col_name in values {
             let value_col = pivot_df.column(value_col_name)?;
+            let value_agg =
+                unsafe { aggregate_groups(value_col, value_col_name, &groups, &agg_fn)? };
 
-            use PivotAgg::*;
-            let value_agg = unsafe {
-                match &agg_fn {
-                    None => match value_col.len() > groups.len() {
-                        true => polars_bail!(
-                            ComputeError:
-                            "found multiple elements in the same group, \
-                            please specify an aggregation function"
-                        ),
-                        false => value_col.agg_first(&groups),
-                    },
-                    Some(agg_fn) => match agg_fn {
-                        Sum => value_col.agg_sum(&groups),
-                        Min => value_col.agg_min(&groups),
-                        Max => value_col.agg_max(&groups),
-                        Last => value_col.agg_last(&groups),
-                        First => value_col.agg_first(&groups),
-                        Mean => value_col.agg_mean(&groups),
-                        Median => value_col.agg_median(&groups),
-                        Count => groups.group_count().into_column(),
-                        Expr(ref expr) => {
-                            let name = expr.root_name()?.clone();
-                            let mut value_col = value_col.clone();
-                            value_col.rename(name);
-                            let tmp_df = value_col.into_frame();
-                            let mut aggregated = Column::from(expr.evaluate(&tmp_df, &groups)?);
-                            aggregated.rename(value_col_name.clone());
-                            aggregated
-                        },
-                    },
-                }
+            let headers = column_agg.unique_stable()?;
+            let mut headers = if hierarchical_headers {
+                hierarchical_header_names(headers.as_materialized_series(), sep)?
+            } else {
+                let headers = headers.cast(&DataType::String)?;
+                headers.str().unwrap().clone()
             };
-
-            let headers = column_agg.unique_stable()?.cast(&DataType::String)?;
-            let mut headers = headers.str().unwrap().clone();
             if values.len() > 1 {
                 headers = headers.apply_values(|v| Cow::from(format!("{value_col_name}{sep}{v}")))
             }
+            let margin_name = margins_name.map(|name| match values.len() > 1 {
+                true => format_pl_smallstr!("{value_col_name}{sep}{name}"),
+                false => name.clone(),
+            });
+            if let Some(name) = &margin_name {
+                polars_ensure!(
+                    !headers.iter().any(|h| h == Some(name.as_str())),
+                    Duplicate: "pivot margins name '{}' is also a value of the `on` columns, \
+                    choose another `margins_name`", name
+                );
+            }
 
             let n_cols = headers.len();
             let value_agg_phys = value_agg.to_physical_repr();
@@ -361,7 +470,40 @@ fn pivot_impl_single_column(
                 )
             };
 
-            if sort_columns {
+            if let Some(name) = margin_name {
+                // The margins aggregate the groups of the cells of a row, of a column and of the
+                // whole frame, so they are also correct for aggregations that cannot be combined
+                // from the aggregated cells, like the mean or the median.
+                let row_groups = merge_groups(&groups, &row_locations, n_rows);
+                let col_groups = merge_groups(&groups, &col_locations, n_cols);
+                let all_groups = GroupsProxy::Slice {
+                    groups: vec![[0, pivot_df.height() as IdxSize]],
+                    rolling: false,
+                };
+                let (row_margins, col_margins, total) = unsafe {
+                    (
+                        aggregate_groups(value_col, value_col_name, &row_groups, &agg_fn)?,
+                        aggregate_groups(value_col, value_col_name, &col_groups, &agg_fn)?,
+                        aggregate_groups(value_col, value_col_name, &all_groups, &agg_fn)?,
+                    )
+                };
+                let mut margins = headers
+                    .iter()
+                    .enumerate()
+                    .map(|(i, header)| {
+                        col_margins
+                            .slice(i as i64, 1)
+                            .with_name(PlSmallStr::from_str(header.unwrap_or("null")))
+                    })
+                    .collect::<Vec<_>>();
+                if sort_columns {
+                    cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                    margins.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
+                }
+                cols.push(row_margins.with_name(name.clone()));
+                margins.push(total.with_name(name));
+                margin_row.extend(margins);
+            } else if sort_columns {
                 cols.sort_unstable_by(|a, b| a.name().partial_cmp(b.name()).unwrap());
             }
 
@@ -380,5 +522,13 @@ fn pivot_impl_single_column(
     out?;
 
     // SAFETY: length has already been checked.
-    unsafe { DataFrame::new_no_length_checks(final_cols) }
+    let out = unsafe { DataFrame::new_no_length_checks(final_cols) }?;
+    match margins_name {
+        Some(margins_name) => {
+            let mut key = margin_row_index(&out.get_columns()[..index.len()], margins_name);
+            key.extend(margin_row);
+            out.vstack(&DataFrame::new(key)?)
+        },
+        None => Ok(out),
+    }
 }
@@ -0,0 +1,144 @@
+use super::*;
+
+/// Options of a [`pivot_table`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PivotTableOptions {
+    /// Append a column with the totals of every row and a row with the totals of every column.
+    pub margins: bool,
+    /// The name of the margin column, also used as key of the margin row.
+    pub margins_name: PlSmallStr,
+    /// With multiple `on` columns, name the pivoted columns by joining the values of the keys
+    /// with the separator, e.g. `large_egg`, instead of formatting them as a struct,
+    /// e.g. `{"large","egg"}`.
+    pub hierarchical_headers: bool,
+}
+
+impl Default for PivotTableOptions {
+    fn default() -> Self {
+        Self {
+            margins: false,
+            margins_name: PlSmallStr::from_static("All"),
+            hierarchical_headers: false,
+        }
+    }
+}
+
+/// A spreadsheet-like pivot table: a stable [`pivot`] that can append margin totals and name
+/// the columns of multiple `on` keys hierarchically, see [`PivotTableOptions`].
+///
+/// The margins aggregate the original values of the groups of the cells with `agg_fn`, so they
+/// are also correct for aggregations that cannot be combined from the aggregated cells, like the
+/// mean or the median. The margin column is named after `margins_name`, prefixed with the value
+/// column if there are multiple `values`. In the margin row the first `index` column holds
+/// `margins_name` if it is a string column, the other `index` columns are null. It is an error
+/// if `margins_name` is also a value of the `on` columns.
+#[allow(clippy::too_many_arguments)]
+pub fn pivot_table<I0, I1, I2, S0, S1, S2>(
+    pivot_df: &DataFrame,
+    on: I0,
+    index: Option<I1>,
+    values: Option<I2>,
+    sort_columns: bool,
+    agg_fn: Option<PivotAgg>,
+    separator: Option<&str>,
+    options: &PivotTableOptions,
+) -> PolarsResult<DataFrame>
+where
+    I0: IntoIterator<Item = S0>,
+    I1: IntoIterator<Item = S1>,
+    I2: IntoIterator<Item = S2>,
+    S0: Into<PlSmallStr>,
+    S1: Into<PlSmallStr>,
+    S2: Into<PlSmallStr>,
+{
+    let on = on.into_iter().map(Into::into).collect::<Vec<_>>();
+    let (index, values) = assign_remaining_columns(pivot_df, &on, index, values)?;
+    polars_ensure!(
+        !options.margins || agg_fn.is_some(),
+        InvalidOperation: "pivot margins require an aggregation function"
+    );
+    pivot_impl(
+        pivot_df,
+        &on,
+        &index,
+        &values,
+        agg_fn,
+        sort_columns,
+        true,
+        separator,
+        options,
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use polars_core::df;
+
+    use super::*;
+
+    #[test]
+    fn test_pivot_table() -> PolarsResult<()> {
+        let df = df![
+            "index" => ["a", "a", "b", "b"],
+            "cols1" => ["x", "y", "x", "x"],
+            "cols2" => ["u", "u", "u", "v"],
+            "values" => [1.0, 2.0, 3.0, 5.0],
+        ]?;
+        let options = PivotTableOptions {
+            margins: true,
+            ..Default::default()
+        };
+        let out = pivot_table(
+            &df,
+            ["cols1"],
+            Some(["index"]),
+            Some(["values"]),
+            false,
+            Some(PivotAgg::Mean),
+            None,
+            &options,
+        )?;
+        let expected = df![
+            "index" => ["a", "b", "All"],
+            "x" => [Some(1.0), Some(4.0), Some(3.0)],
+            "y" => [Some(2.0), None, Some(2.0)],
+            "All" => [1.5, 4.0, 2.75],
+        ]?;
+        assert!(out.equals_missing(&expected));
+
+        // A margin column named like a pivoted column is ambiguous.
+        let options = PivotTableOptions {
+            margins: true,
+            margins_name: PlSmallStr::from_static("x"),
+            ..Default::default()
+        };
+        let out = pivot_table(
+            &df,
+            ["cols1"],
+            Some(["index"]),
+            Some(["values"]),
+            false,
+            Some(PivotAgg::Sum),
+            None,
+            &options,
+        );
+        assert!(out.is_err());
+
+        let options = PivotTableOptions {
+            hierarchical_headers: true,
+            ..Default::default()
+        };
+        let out = pivot_table(
+            &df,
+            ["cols1", "cols2"],
+            Some(["index"]),
+            Some(["values"]),
+            true,
+            Some(PivotAgg::Sum),
+            None,
+            &options,
+        )?;
+        assert_eq!(out.get_column_names_str(), &["index", "x_u", "x_v", "y_u"]);
+        Ok(())
+    }
+}
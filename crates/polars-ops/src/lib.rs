@@ -3,6 +3,12 @@
 #![cfg_attr(feature = "nightly", allow(internal_features))]
 
 pub mod chunked_array;
+#[cfg(any(
+    feature = "scale",
+    feature = "target_encoding",
+    feature = "text_search"
+))]
+mod float_hash;
 #[cfg(feature = "pivot")]
 pub use frame::pivot;
 pub mod frame;
@@ -4,6 +4,8 @@ pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
+#[cfg(feature = "graph")]
+pub use crate::frame::graph::{connected_components, propagate_hierarchy, transitive_closure};
 pub use crate::frame::join::*;
 #[cfg(feature = "pivot")]
 pub use crate::frame::pivot::UnpivotDF;
@@ -0,0 +1,256 @@
+//! Partial aggregation states.
+//!
+//! An [`AggregationState`] holds the partial result of an aggregation over a part of the
+//! data. States can be serialized, shipped to a coordinator and combined there with
+//! [`merge_aggregation_states`], which allows computing aggregations over data that is
+//! spread across multiple machines.
+use polars_core::prelude::*;
+use polars_core::scalar::reduce::mean_reduce;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "approx_unique")]
+use super::approx_algo::{HyperLogLog, MAX_REGISTER_VALUE};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PartialAggregation {
+    Sum,
+    /// Number of non-null values.
+    Count,
+    Min,
+    Max,
+    Mean,
+    /// Approximate number of unique values, see [`approx_n_unique`](super::approx_n_unique).
+    #[cfg(feature = "approx_unique")]
+    ApproxNUnique,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AggregationState {
+    Sum(Scalar),
+    Count(u64),
+    Min(Scalar),
+    Max(Scalar),
+    /// Sum and number of the non-null values and the dtype of the input.
+    Mean {
+        sum: f64,
+        count: u64,
+        dtype: DataType,
+    },
+    /// The registers of a HyperLogLog sketch.
+    #[cfg(feature = "approx_unique")]
+    ApproxNUnique(Vec<u8>),
+}
+
+/// Combine two scalars of the same dtype with a reduction over a [`Series`] holding both.
+fn reduce_scalars(
+    lhs: &Scalar,
+    rhs: &Scalar,
+    reduce: impl Fn(&Series) -> PolarsResult<Scalar>,
+) -> PolarsResult<Scalar> {
+    polars_ensure!(
+        lhs.dtype() == rhs.dtype(),
+        SchemaMismatch: "cannot merge aggregation states of dtype {} and {}", lhs.dtype(), rhs.dtype()
+    );
+    let s = Series::from_any_values_and_dtype(
+        PlSmallStr::EMPTY,
+        &[lhs.value().clone(), rhs.value().clone()],
+        lhs.dtype(),
+        true,
+    )?;
+    reduce(&s)
+}
+
+#[cfg(feature = "approx_unique")]
+fn hll_from_registers(registers: &[u8]) -> PolarsResult<HyperLogLog<()>> {
+    polars_ensure!(
+        registers.iter().all(|r| *r <= MAX_REGISTER_VALUE),
+        ComputeError: "invalid HyperLogLog sketch: register values must be at most {}",
+        MAX_REGISTER_VALUE
+    );
+    let registers = registers.try_into().map_err(
+        |_| polars_err!(ComputeError: "invalid HyperLogLog sketch with {} registers", registers.len()),
+    )?;
+    Ok(HyperLogLog::new_with_registers(registers))
+}
+
+impl AggregationState {
+    /// Compute the partial state of `agg` over `s`.
+    pub fn new(s: &Series, agg: PartialAggregation) -> PolarsResult<Self> {
+        use PartialAggregation as P;
+        Ok(match agg {
+            P::Sum => Self::Sum(s.sum_reduce()?),
+            P::Count => Self::Count((s.len() - s.null_count()) as u64),
+            P::Min => Self::Min(s.min_reduce()?),
+            P::Max => Self::Max(s.max_reduce()?),
+            P::Mean => {
+                let dtype = s.dtype();
+                polars_ensure!(
+                    dtype.is_numeric()
+                        || dtype.is_bool()
+                        || dtype.is_temporal()
+                        || dtype.is_decimal(),
+                    opq = mean,
+                    dtype
+                );
+                let values = if dtype.is_temporal() {
+                    s.to_physical_repr().cast(&DataType::Float64)?
+                } else {
+                    s.cast(&DataType::Float64)?
+                };
+                Self::Mean {
+                    sum: values.f64()?.sum().unwrap_or(0.0),
+                    count: (values.len() - values.null_count()) as u64,
+                    dtype: dtype.clone(),
+                }
+            },
+            #[cfg(feature = "approx_unique")]
+            P::ApproxNUnique => Self::ApproxNUnique(super::approx_unique::hll_registers(s)?),
+        })
+    }
+
+    /// Combine the partial state `other` into `self`.
+    pub fn merge(&mut self, other: &Self) -> PolarsResult<()> {
+        use AggregationState as A;
+        match (self, other) {
+            (A::Sum(lhs), A::Sum(rhs)) => *lhs = reduce_scalars(lhs, rhs, |s| s.sum_reduce())?,
+            (A::Count(lhs), A::Count(rhs)) => *lhs += rhs,
+            (A::Min(lhs), A::Min(rhs)) => *lhs = reduce_scalars(lhs, rhs, |s| s.min_reduce())?,
+            (A::Max(lhs), A::Max(rhs)) => *lhs = reduce_scalars(lhs, rhs, |s| s.max_reduce())?,
+            (
+                A::Mean { sum, count, dtype },
+                A::Mean {
+                    sum: other_sum,
+                    count: other_count,
+                    dtype: other_dtype,
+                },
+            ) => {
+                polars_ensure!(
+                    dtype == other_dtype,
+                    SchemaMismatch: "cannot merge aggregation states of dtype {} and {}", dtype, other_dtype
+                );
+                *sum += other_sum;
+                *count += other_count;
+            },
+            #[cfg(feature = "approx_unique")]
+            (A::ApproxNUnique(lhs), A::ApproxNUnique(rhs)) => {
+                let mut hll = hll_from_registers(lhs)?;
+                hll.merge(&hll_from_registers(rhs)?);
+                *lhs = hll.as_ref().to_vec();
+            },
+            (lhs, rhs) => polars_bail!(
+                InvalidOperation: "cannot merge aggregation states of {:?} and {:?}",
+                lhs.aggregation(), rhs.aggregation()
+            ),
+        }
+        Ok(())
+    }
+
+    /// The aggregation this is the state of.
+    pub fn aggregation(&self) -> PartialAggregation {
+        use {AggregationState as A, PartialAggregation as P};
+        match self {
+            A::Sum(_) => P::Sum,
+            A::Count(_) => P::Count,
+            A::Min(_) => P::Min,
+            A::Max(_) => P::Max,
+            A::Mean { .. } => P::Mean,
+            #[cfg(feature = "approx_unique")]
+            A::ApproxNUnique(_) => P::ApproxNUnique,
+        }
+    }
+
+    /// Produce the result of the aggregation.
+    pub fn finalize(&self) -> PolarsResult<Scalar> {
+        use AggregationState as A;
+        Ok(match self {
+            A::Sum(v) | A::Min(v) | A::Max(v) => v.clone(),
+            A::Count(count) => Scalar::new(IDX_DTYPE, (*count as IdxSize).into()),
+            A::Mean { sum, count, dtype } => {
+                mean_reduce((*count > 0).then(|| sum / *count as f64), dtype.clone())
+            },
+            #[cfg(feature = "approx_unique")]
+            A::ApproxNUnique(registers) => {
+                let count = hll_from_registers(registers)?.count() as IdxSize;
+                Scalar::new(IDX_DTYPE, count.into())
+            },
+        })
+    }
+}
+
+/// Merge the partial states of the same aggregation, e.g. computed on different machines,
+/// into a single state.
+pub fn merge_aggregation_states<I>(states: I) -> PolarsResult<AggregationState>
+where
+    I: IntoIterator<Item = AggregationState>,
+{
+    let mut states = states.into_iter();
+    let mut out = states.next().ok_or_else(
+        || polars_err!(InvalidOperation: "cannot merge an empty set of aggregation states"),
+    )?;
+    for state in states {
+        out.merge(&state)?;
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_aggregation_states() -> PolarsResult<()> {
+        let parts = [
+            Series::new("a".into(), [Some(1i32), None, Some(5)]),
+            Series::new("a".into(), [3i32, -2]),
+            Series::new_empty("a".into(), &DataType::Int32),
+        ];
+        let merged = |agg| {
+            merge_aggregation_states(parts.iter().map(|s| AggregationState::new(s, agg).unwrap()))
+                .unwrap()
+                .finalize()
+                .unwrap()
+        };
+
+        assert_eq!(merged(PartialAggregation::Sum).value(), &AnyValue::Int32(7));
+        assert_eq!(
+            merged(PartialAggregation::Count).value(),
+            &AnyValue::from(4 as IdxSize)
+        );
+        assert_eq!(
+            merged(PartialAggregation::Min).value(),
+            &AnyValue::Int32(-2)
+        );
+        assert_eq!(merged(PartialAggregation::Max).value(), &AnyValue::Int32(5));
+        assert_eq!(
+            merged(PartialAggregation::Mean).value(),
+            &AnyValue::Float64(1.75)
+        );
+        #[cfg(feature = "approx_unique")]
+        assert_eq!(
+            merged(PartialAggregation::ApproxNUnique).value(),
+            &AnyValue::from(5 as IdxSize)
+        );
+
+        let mut sum = AggregationState::new(&parts[0], PartialAggregation::Sum)?;
+        let count = AggregationState::new(&parts[0], PartialAggregation::Count)?;
+        assert!(sum.merge(&count).is_err());
+        assert!(merge_aggregation_states([]).is_err());
+
+        #[cfg(feature = "approx_unique")]
+        {
+            let AggregationState::ApproxNUnique(mut registers) =
+                AggregationState::new(&parts[0], PartialAggregation::ApproxNUnique)?
+            else {
+                unreachable!()
+            };
+            registers[0] = MAX_REGISTER_VALUE + 1;
+            assert!(AggregationState::ApproxNUnique(registers)
+                .finalize()
+                .is_err());
+        }
+        Ok(())
+    }
+}
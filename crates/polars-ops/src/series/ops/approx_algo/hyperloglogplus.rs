@@ -27,6 +27,9 @@ const HLL_P: usize = 14_usize;
 /// The number of bits of the hash value used determining the number of leading zeros
 const HLL_Q: usize = 64_usize - HLL_P;
 const NUM_REGISTERS: usize = 1_usize << HLL_P;
+/// The largest value of a register: the position of the first set bit in the `HLL_Q` bits of a
+/// hash, or `HLL_Q + 1` if none is set.
+pub(crate) const MAX_REGISTER_VALUE: u8 = HLL_Q as u8 + 1;
 /// Mask to obtain index into the registers
 const HLL_P_MASK: u64 = (NUM_REGISTERS as u64) - 1;
 
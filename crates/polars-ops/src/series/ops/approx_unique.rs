@@ -20,30 +20,55 @@ where
     Ok(Series::new(ca.name().clone(), &[c]))
 }
 
+/// The registers of a HyperLogLog sketch of the values in `ca`.
+#[cfg(feature = "aggregation_state")]
+fn hll_registers_ca<'a, T>(ca: &'a ChunkedArray<T>) -> PolarsResult<Vec<u8>>
+where
+    T: PolarsDataType,
+    T::Physical<'a>: TotalHash + TotalEq + Copy + ToTotalOrd,
+    <Option<T::Physical<'a>> as ToTotalOrd>::TotalOrdItem: Hash + Eq,
+{
+    let mut hllp = HyperLogLog::new();
+    ca.iter().for_each(|item| hllp.add(&item.to_total_ord()));
+    Ok(hllp.as_ref().to_vec())
+}
+
+macro_rules! dispatch {
+    ($s:expr, $f:ident) => {{
+        let s = $s.to_physical_repr();
+        use DataType::*;
+        match s.dtype() {
+            Boolean => s.bool().and_then($f),
+            Binary => s.binary().and_then($f),
+            String => {
+                let ca = s.str().unwrap().as_binary();
+                $f(&ca)
+            },
+            Float32 => $f(AsRef::<ChunkedArray<Float32Type>>::as_ref(
+                s.as_ref().as_ref(),
+            )),
+            Float64 => $f(AsRef::<ChunkedArray<Float64Type>>::as_ref(
+                s.as_ref().as_ref(),
+            )),
+            dt if dt.is_numeric() => {
+                with_match_physical_integer_polars_type!(s.dtype(), |$T| {
+                    let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
+                    $f(ca)
+                })
+            },
+            dt => polars_bail!(opq = approx_n_unique, dt),
+        }
+    }};
+}
+
 fn dispatcher(s: &Series) -> PolarsResult<Series> {
-    let s = s.to_physical_repr();
-    use DataType::*;
-    match s.dtype() {
-        Boolean => s.bool().and_then(approx_n_unique_ca),
-        Binary => s.binary().and_then(approx_n_unique_ca),
-        String => {
-            let ca = s.str().unwrap().as_binary();
-            approx_n_unique_ca(&ca)
-        },
-        Float32 => approx_n_unique_ca(AsRef::<ChunkedArray<Float32Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
-        Float64 => approx_n_unique_ca(AsRef::<ChunkedArray<Float64Type>>::as_ref(
-            s.as_ref().as_ref(),
-        )),
-        dt if dt.is_numeric() => {
-            with_match_physical_integer_polars_type!(s.dtype(), |$T| {
-                let ca: &ChunkedArray<$T> = s.as_ref().as_ref().as_ref();
-                approx_n_unique_ca(ca)
-            })
-        },
-        dt => polars_bail!(opq = approx_n_unique, dt),
-    }
+    dispatch!(s, approx_n_unique_ca)
+}
+
+/// The registers of a HyperLogLog sketch of the values in `s`.
+#[cfg(feature = "aggregation_state")]
+pub(super) fn hll_registers(s: &Series) -> PolarsResult<Vec<u8>> {
+    dispatch!(s, hll_registers_ca)
 }
 
 /// Approx count unique values.
@@ -0,0 +1,394 @@
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+const MAX_ITER: usize = 300;
+const EPS: f64 = 1e-14;
+const TINY: f64 = 1e-300;
+
+/// A statistical test of the hypothesis that two samples come from the same distribution, or,
+/// for the chi-square test, that two categorical variables are independent.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HypothesisTest {
+    /// Two-sided t-test of the difference between the means of two independent samples. Without
+    /// `equal_var` this is Welch's t-test, which does not assume equal variances.
+    TTest { equal_var: bool },
+    /// Pearson's chi-square test of independence on the contingency table of two columns.
+    ChiSquare,
+    /// Two-sample Kolmogorov-Smirnov test, with an asymptotic p-value.
+    KolmogorovSmirnov,
+}
+
+/// The outcome of a [`HypothesisTest`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TestResult {
+    pub statistic: f64,
+    pub p_value: f64,
+}
+
+impl TestResult {
+    /// The dtype of [`TestResult::into_struct`].
+    pub fn dtype() -> DataType {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("statistic"), DataType::Float64),
+            Field::new(PlSmallStr::from_static("p_value"), DataType::Float64),
+        ])
+    }
+
+    /// The result as a single struct value with a `statistic` and a `p_value` field.
+    pub fn into_struct(result: Option<Self>, name: PlSmallStr) -> PolarsResult<StructChunked> {
+        let statistic = Series::new(
+            PlSmallStr::from_static("statistic"),
+            &[result.map(|r| r.statistic)],
+        );
+        let p_value = Series::new(
+            PlSmallStr::from_static("p_value"),
+            &[result.map(|r| r.p_value)],
+        );
+        let out = StructChunked::from_series(name, [statistic, p_value].iter())?;
+        let validity = result.is_none().then(|| Bitmap::new_zeroed(1));
+        Ok(out.with_outer_validity(validity))
+    }
+}
+
+/// The natural logarithm of the gamma function for `x >= 0.5`, with the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+    const G: f64 = 7.0;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let a = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |a, (i, c)| a + c / (x + i as f64 + 1.0));
+    let t = x + G + 0.5;
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + a.ln()
+}
+
+/// Continued fraction of the incomplete beta function, evaluated with Lentz's method.
+fn beta_continued_fraction(x: f64, a: f64, b: f64) -> f64 {
+    let clamp = |v: f64| if v.abs() < TINY { TINY } else { v };
+    let mut c = 1.0;
+    let mut d = 1.0 / clamp(1.0 - (a + b) * x / (a + 1.0));
+    let mut h = d;
+    for m in 1..=MAX_ITER {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+        let aa = m * (b - m) * x / ((a - 1.0 + m2) * (a + m2));
+        d = 1.0 / clamp(1.0 + aa * d);
+        c = clamp(1.0 + aa / c);
+        h *= d * c;
+        let aa = -(a + m) * (a + b + m) * x / ((a + m2) * (a + 1.0 + m2));
+        d = 1.0 / clamp(1.0 + aa * d);
+        c = clamp(1.0 + aa / c);
+        let delta = d * c;
+        h *= delta;
+        if (delta - 1.0).abs() < EPS {
+            break;
+        }
+    }
+    h
+}
+
+/// The regularized incomplete beta function `I_x(a, b)`.
+fn regularized_beta(x: f64, a: f64, b: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+    let ln_front = ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) + a * x.ln() + b * (1.0 - x).ln();
+    // The continued fraction converges fast on this side of the mode, use the symmetry
+    // `I_x(a, b) = 1 - I_{1-x}(b, a)` on the other.
+    if x < (a + 1.0) / (a + b + 2.0) {
+        ln_front.exp() * beta_continued_fraction(x, a, b) / a
+    } else {
+        1.0 - ln_front.exp() * beta_continued_fraction(1.0 - x, b, a) / b
+    }
+}
+
+/// The regularized upper incomplete gamma function `Q(a, x)`, from its series for small `x` and
+/// its continued fraction for large `x`.
+fn regularized_gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let ln_front = -x + a * x.ln() - ln_gamma(a);
+    if x < a + 1.0 {
+        let mut ap = a;
+        let mut delta = 1.0 / a;
+        let mut sum = delta;
+        for _ in 0..MAX_ITER {
+            ap += 1.0;
+            delta *= x / ap;
+            sum += delta;
+            if delta.abs() < sum.abs() * EPS {
+                break;
+            }
+        }
+        1.0 - sum * ln_front.exp()
+    } else {
+        let clamp = |v: f64| if v.abs() < TINY { TINY } else { v };
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..=MAX_ITER {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = 1.0 / clamp(an * d + b);
+            c = clamp(b + an / c);
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < EPS {
+                break;
+            }
+        }
+        ln_front.exp() * h
+    }
+}
+
+/// The probability that the Kolmogorov distribution exceeds `lambda`.
+fn kolmogorov_q(lambda: f64) -> f64 {
+    let mut sign = 2.0;
+    let mut sum = 0.0;
+    let mut previous = 0.0;
+    for j in 1..=100 {
+        let j = j as f64;
+        let term = sign * (-2.0 * j * j * lambda * lambda).exp();
+        sum += term;
+        if term.abs() <= 1e-3 * previous || term.abs() <= 1e-8 * sum {
+            return sum.clamp(0.0, 1.0);
+        }
+        sign = -sign;
+        previous = term.abs();
+    }
+    // The series does not converge for small `lambda`, where the probability tends to one.
+    1.0
+}
+
+/// The non-null and non-NaN values of `s` as floats.
+fn float_sample(s: &Series) -> PolarsResult<Vec<f64>> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "hypothesis test expects numeric samples, got dtype {}", s.dtype()
+    );
+    let s = s.cast(&DataType::Float64)?;
+    Ok(s.f64()?.iter().flatten().filter(|v| !v.is_nan()).collect())
+}
+
+fn mean_var(sample: &[f64]) -> (f64, f64) {
+    let n = sample.len() as f64;
+    let mean = sample.iter().sum::<f64>() / n;
+    let var = sample.iter().map(|v| (v - mean) * (v - mean)).sum::<f64>() / (n - 1.0);
+    (mean, var)
+}
+
+fn t_test(a: &Series, b: &Series, equal_var: bool) -> PolarsResult<Option<TestResult>> {
+    let (a, b) = (float_sample(a)?, float_sample(b)?);
+    if a.len() < 2 || b.len() < 2 {
+        return Ok(None);
+    }
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+    let ((mean_a, var_a), (mean_b, var_b)) = (mean_var(&a), mean_var(&b));
+    let (std_err, df) = if equal_var {
+        let df = n_a + n_b - 2.0;
+        let pooled_var = ((n_a - 1.0) * var_a + (n_b - 1.0) * var_b) / df;
+        ((pooled_var * (1.0 / n_a + 1.0 / n_b)).sqrt(), df)
+    } else {
+        // Welch-Satterthwaite approximation of the degrees of freedom.
+        let (se_a, se_b) = (var_a / n_a, var_b / n_b);
+        let df = (se_a + se_b).powi(2) / (se_a * se_a / (n_a - 1.0) + se_b * se_b / (n_b - 1.0));
+        ((se_a + se_b).sqrt(), df)
+    };
+    let statistic = (mean_a - mean_b) / std_err;
+    let p_value = if statistic.is_nan() {
+        f64::NAN
+    } else {
+        regularized_beta(df / (df + statistic * statistic), df / 2.0, 0.5)
+    };
+    Ok(Some(TestResult { statistic, p_value }))
+}
+
+/// The group of every value of `s`, and the number of groups.
+fn group_ids(s: &Series) -> PolarsResult<(Vec<usize>, usize)> {
+    let groups = s.group_tuples(true, false)?;
+    let mut ids = vec![0; s.len()];
+    for (id, group) in groups.iter().enumerate() {
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for i in idx.iter() {
+                    ids[*i as usize] = id;
+                }
+            },
+            GroupsIndicator::Slice([offset, len]) => {
+                ids[offset as usize..(offset + len) as usize].fill(id)
+            },
+        }
+    }
+    Ok((ids, groups.len()))
+}
+
+fn chi_square_test(a: &Series, b: &Series) -> PolarsResult<Option<TestResult>> {
+    polars_ensure!(
+        a.len() == b.len(),
+        ShapeMismatch: "chi-square test expects columns of equal length"
+    );
+    let mask = &a.is_not_null() & &b.is_not_null();
+    let (a, b) = (a.filter(&mask)?, b.filter(&mask)?);
+    if a.is_empty() {
+        return Ok(None);
+    }
+    let (a_ids, n_rows) = group_ids(&a)?;
+    let (b_ids, n_cols) = group_ids(&b)?;
+    let mut observed = vec![0u64; n_rows * n_cols];
+    for (i, j) in a_ids.iter().zip(&b_ids) {
+        observed[i * n_cols + j] += 1;
+    }
+
+    let total = a.len() as f64;
+    let mut row_totals = vec![0u64; n_rows];
+    let mut col_totals = vec![0u64; n_cols];
+    for (k, count) in observed.iter().enumerate() {
+        row_totals[k / n_cols] += count;
+        col_totals[k % n_cols] += count;
+    }
+    let statistic = observed
+        .iter()
+        .enumerate()
+        .map(|(k, count)| {
+            let expected = row_totals[k / n_cols] as f64 * col_totals[k % n_cols] as f64 / total;
+            (*count as f64 - expected).powi(2) / expected
+        })
+        .sum::<f64>();
+    let df = ((n_rows - 1) * (n_cols - 1)) as f64;
+    let p_value = if df == 0.0 {
+        1.0
+    } else {
+        regularized_gamma_q(df / 2.0, statistic / 2.0)
+    };
+    Ok(Some(TestResult { statistic, p_value }))
+}
+
+fn kolmogorov_smirnov_test(a: &Series, b: &Series) -> PolarsResult<Option<TestResult>> {
+    let (mut a, mut b) = (float_sample(a)?, float_sample(b)?);
+    if a.is_empty() || b.is_empty() {
+        return Ok(None);
+    }
+    a.sort_unstable_by(f64::total_cmp);
+    b.sort_unstable_by(f64::total_cmp);
+    let (n_a, n_b) = (a.len() as f64, b.len() as f64);
+
+    // The largest distance between the empirical distribution functions, which can only
+    // change at the values of the samples.
+    let (mut i, mut j) = (0, 0);
+    let mut statistic = 0.0f64;
+    while i < a.len() && j < b.len() {
+        let v = a[i].min(b[j]);
+        while i < a.len() && a[i] <= v {
+            i += 1;
+        }
+        while j < b.len() && b[j] <= v {
+            j += 1;
+        }
+        statistic = statistic.max((i as f64 / n_a - j as f64 / n_b).abs());
+    }
+    let en = (n_a * n_b / (n_a + n_b)).sqrt();
+    let p_value = kolmogorov_q((en + 0.12 + 0.11 / en) * statistic);
+    Ok(Some(TestResult { statistic, p_value }))
+}
+
+/// Run the hypothesis `test` on the samples `a` and `b`, giving a single struct value with the
+/// `statistic` and the `p_value` of the test.
+///
+/// The t-test and the Kolmogorov-Smirnov test compare two independent samples, which may have
+/// different lengths, and ignore null and NaN values. The chi-square test pairs up the values
+/// of `a` and `b`, ignoring the rows where either is null. The result is null if a sample is
+/// too small for the test.
+pub fn hypothesis_test(
+    a: &Series,
+    b: &Series,
+    test: HypothesisTest,
+) -> PolarsResult<StructChunked> {
+    let result = match test {
+        HypothesisTest::TTest { equal_var } => t_test(a, b, equal_var)?,
+        HypothesisTest::ChiSquare => chi_square_test(a, b)?,
+        HypothesisTest::KolmogorovSmirnov => kolmogorov_smirnov_test(a, b)?,
+    };
+    TestResult::into_struct(result, a.name().clone())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn run(a: &Series, b: &Series, test: HypothesisTest) -> PolarsResult<Option<TestResult>> {
+        match test {
+            HypothesisTest::TTest { equal_var } => t_test(a, b, equal_var),
+            HypothesisTest::ChiSquare => chi_square_test(a, b),
+            HypothesisTest::KolmogorovSmirnov => kolmogorov_smirnov_test(a, b),
+        }
+    }
+
+    #[test]
+    fn test_distributions() {
+        // The critical values of the 5% significance level.
+        assert!(
+            (regularized_beta(10.0 / (10.0 + 2.228_139f64.powi(2)), 5.0, 0.5) - 0.05).abs() < 1e-6
+        );
+        assert!((regularized_gamma_q(0.5, 3.841_459 / 2.0) - 0.05).abs() < 1e-6);
+        assert!((regularized_gamma_q(2.0, 9.487_729 / 2.0) - 0.05).abs() < 1e-6);
+        assert!((kolmogorov_q(1.358_099) - 0.05).abs() < 1e-5);
+        assert_eq!(kolmogorov_q(0.01), 1.0);
+    }
+
+    #[test]
+    fn test_hypothesis_test() -> PolarsResult<()> {
+        let a = Series::new(
+            "a".into(),
+            &[Some(1.0), Some(2.0), None, Some(3.0), Some(4.0)],
+        );
+        let b = Series::new("b".into(), &[3.0, 4.0, 5.0, 6.0]);
+
+        // Equal variances and sizes, so Welch's t-test agrees with Student's t-test.
+        let r = run(&a, &b, HypothesisTest::TTest { equal_var: true })?.unwrap();
+        assert!((r.statistic + 4.8f64.sqrt()).abs() < 1e-12);
+        let welch = run(&a, &b, HypothesisTest::TTest { equal_var: false })?.unwrap();
+        assert!((welch.statistic - r.statistic).abs() < 1e-12);
+        assert!((welch.p_value - r.p_value).abs() < 1e-12);
+        assert!(r.p_value > 0.05 && r.p_value < 0.1);
+        assert!(run(
+            &a.slice(0, 1),
+            &b,
+            HypothesisTest::TTest { equal_var: false }
+        )?
+        .is_none());
+
+        // The empirical distribution functions are at most half apart.
+        let r = run(&a, &b, HypothesisTest::KolmogorovSmirnov)?.unwrap();
+        assert_eq!(r.statistic, 0.5);
+
+        let group = Series::new("g".into(), &["x", "x", "y", "y", "y", "x"]);
+        let outcome = Series::new("o".into(), &[true, true, false, false, true, true]);
+        let r = run(&group, &outcome, HypothesisTest::ChiSquare)?.unwrap();
+        // The expected counts are 2 `true` and 1 `false` in both groups.
+        assert!((r.statistic - 3.0).abs() < 1e-12);
+        assert!((r.p_value - regularized_gamma_q(0.5, 1.5)).abs() < 1e-12);
+
+        let out = hypothesis_test(&a, &b, HypothesisTest::ChiSquare);
+        assert!(out.is_err());
+        Ok(())
+    }
+}
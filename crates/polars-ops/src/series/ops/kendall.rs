@@ -0,0 +1,118 @@
+use polars_core::prelude::*;
+
+use super::{RankMethod, RankOptions, SeriesRank};
+
+fn dense_rank(s: &Series) -> PolarsResult<IdxCa> {
+    let options = RankOptions {
+        method: RankMethod::Dense,
+        descending: false,
+    };
+    let rank = s.rank(options, None).cast(&IDX_DTYPE)?;
+    Ok(rank.idx()?.clone())
+}
+
+/// The number of pairs within the runs of equal values of the sorted `values`.
+fn tied_pairs<T: PartialEq>(values: &[T]) -> u64 {
+    values
+        .chunk_by(|a, b| a == b)
+        .map(|run| {
+            let n = run.len() as u64;
+            n * (n - 1) / 2
+        })
+        .sum()
+}
+
+/// Sort `values` with a merge sort and return the number of inversions: the pairs `i < j`
+/// with `values[i] > values[j]`.
+fn sort_count_inversions(values: &mut [IdxSize], buf: &mut [IdxSize]) -> u64 {
+    let n = values.len();
+    if n < 2 {
+        return 0;
+    }
+    let mid = n / 2;
+    let (left, right) = values.split_at_mut(mid);
+    let mut count = sort_count_inversions(left, &mut buf[..mid]);
+    count += sort_count_inversions(right, &mut buf[mid..]);
+
+    let (mut i, mut j) = (0, 0);
+    for out in buf.iter_mut() {
+        if j == right.len() || (i < left.len() && left[i] <= right[j]) {
+            *out = left[i];
+            i += 1;
+        } else {
+            *out = right[j];
+            j += 1;
+            count += (left.len() - i) as u64;
+        }
+    }
+    values.copy_from_slice(buf);
+    count
+}
+
+/// Kendall's tau-b rank correlation between `a` and `b`, ignoring the rows where either is
+/// null.
+///
+/// The values are replaced by their dense ranks, after which the concordant and discordant
+/// pairs are counted in `O(n log n)` with Knight's algorithm. Returns `None` with fewer than two
+/// rows, and `NaN` if either side is constant.
+pub fn kendall_tau(a: &Series, b: &Series) -> PolarsResult<Option<f64>> {
+    polars_ensure!(
+        a.len() == b.len(),
+        ShapeMismatch: "kendall tau expects columns of equal length"
+    );
+    let a = dense_rank(a)?;
+    let b = dense_rank(b)?;
+    let mut pairs = a
+        .iter()
+        .zip(b.iter())
+        .filter_map(|(a, b)| Some((a?, b?)))
+        .collect::<Vec<_>>();
+    if pairs.len() < 2 {
+        return Ok(None);
+    }
+    pairs.sort_unstable();
+
+    let n = pairs.len() as u64;
+    let n_pairs = n * (n - 1) / 2;
+    let a_ties = tied_pairs(&pairs.iter().map(|p| p.0).collect::<Vec<_>>());
+    let joint_ties = tied_pairs(&pairs);
+    let mut b_values = pairs.iter().map(|p| p.1).collect::<Vec<_>>();
+    let mut buf = vec![0; b_values.len()];
+    let discordant = sort_count_inversions(&mut b_values, &mut buf);
+    let b_ties = tied_pairs(&b_values);
+
+    let numerator = n_pairs as f64 - a_ties as f64 - b_ties as f64 + joint_ties as f64
+        - 2.0 * discordant as f64;
+    let denominator = ((n_pairs - a_ties) as f64 * (n_pairs - b_ties) as f64).sqrt();
+    Ok(Some(numerator / denominator))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kendall_tau() -> PolarsResult<()> {
+        let a = Series::new("a".into(), &[1, 2, 3, 4, 5]);
+        let b = Series::new("b".into(), &[5.0, 4.0, 3.0, 2.0, 1.0]);
+        assert_eq!(kendall_tau(&a, &a)?, Some(1.0));
+        assert_eq!(kendall_tau(&a, &b)?, Some(-1.0));
+
+        // With ties: 7 concordant and 1 discordant pair out of 10, 1 tie in `a` and 1 in `b`.
+        let a = Series::new(
+            "a".into(),
+            &[Some(1), Some(2), Some(2), Some(3), None, Some(4)],
+        );
+        let b = Series::new(
+            "b".into(),
+            &[Some(1), Some(3), Some(2), Some(2), Some(9), Some(5)],
+        );
+        let tau = kendall_tau(&a, &b)?.unwrap();
+        assert!((tau - 6.0 / 9.0).abs() < 1e-12);
+
+        let constant = Series::new("c".into(), &[1, 1, 1, 1, 1]);
+        assert!(kendall_tau(&a.slice(0, 5), &constant)?.unwrap().is_nan());
+        assert_eq!(kendall_tau(&a.slice(0, 1), &b.slice(0, 1))?, None);
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use arrow::compute::utils::combine_validities_and;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+
+/// Set the values of `s` at which `mask` is `false` or null to null.
+///
+/// Only the validity is combined with the mask, the values are not copied. The result can be
+/// aggregated directly, which avoids filtering `s` group by group.
+pub fn mask_nulls(s: &Series, mask: &BooleanChunked) -> PolarsResult<Series> {
+    if mask.len() == 1 {
+        return Ok(match mask.get(0) {
+            Some(true) => s.clone(),
+            _ => Series::full_null(s.name().clone(), s.len(), s.dtype()),
+        });
+    }
+    if s.len() == 1 {
+        return mask_nulls(&s.new_from_index(0, mask.len()), mask);
+    }
+    polars_ensure!(
+        s.len() == mask.len(),
+        ShapeMismatch: "cannot mask a series of length {} with a mask of length {}",
+        s.len(), mask.len()
+    );
+    if s.dtype().is_null() {
+        return Ok(s.clone());
+    }
+    #[cfg(feature = "object")]
+    if s.dtype().is_object() {
+        return s.zip_with(
+            mask,
+            &Series::full_null(s.name().clone(), s.len(), s.dtype()),
+        );
+    }
+
+    let aligned = s.chunk_lengths().eq(mask.chunk_lengths());
+    let (s, mask) = if aligned {
+        (s.clone(), mask.clone())
+    } else {
+        (s.rechunk(), mask.rechunk())
+    };
+    let chunks = s
+        .chunks()
+        .iter()
+        .zip(mask.downcast_iter())
+        .map(|(arr, mask)| {
+            let keep = match mask.validity() {
+                Some(validity) => mask.values() & validity,
+                None => mask.values().clone(),
+            };
+            let validity = combine_validities_and(arr.validity(), Some(&keep));
+            arr.with_validity(validity)
+        })
+        .collect();
+    // SAFETY: the chunks keep the physical type of `s`.
+    let mut out =
+        unsafe { Series::from_chunks_and_dtype_unchecked(s.name().clone(), chunks, s.dtype()) };
+    // Nulls may now appear anywhere.
+    if s.is_sorted_flag() != IsSorted::Not {
+        out.set_sorted_flag(IsSorted::Not);
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_mask_nulls() -> PolarsResult<()> {
+        let s = Series::new("a".into(), [Some(1i32), None, Some(3), Some(4)]);
+        let mask = BooleanChunked::new("m".into(), [Some(true), Some(true), Some(false), None]);
+        let expected = Series::new("a".into(), [Some(1i32), None, None, None]);
+        assert!(mask_nulls(&s, &mask)?.equals_missing(&expected));
+
+        // Unaligned chunks.
+        let mut s2 = s.slice(0, 1);
+        s2.append(&s.slice(1, 3))?;
+        assert!(mask_nulls(&s2, &mask)?.equals_missing(&expected));
+
+        // Broadcasting.
+        let one = Series::new("a".into(), [2i32]);
+        let expected = Series::new("a".into(), [Some(2i32), Some(2), None, None]);
+        assert!(mask_nulls(&one, &mask)?.equals_missing(&expected));
+        let all = BooleanChunked::new("m".into(), [true]);
+        assert!(mask_nulls(&s, &all)?.equals_missing(&s));
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "abs")]
 mod abs;
+#[cfg(feature = "aggregation_state")]
+mod aggregation_state;
 #[cfg(feature = "approx_unique")]
 mod approx_algo;
 #[cfg(feature = "approx_unique")]
@@ -23,6 +25,8 @@ mod floor_divide;
 #[cfg(feature = "fused")]
 mod fused;
 mod horizontal;
+#[cfg(feature = "hypothesis_test")]
+mod hypothesis_test;
 mod index;
 mod int_range;
 #[cfg(any(feature = "interpolate_by", feature = "interpolate"))]
@@ -37,13 +41,20 @@ mod is_in;
 mod is_last_distinct;
 #[cfg(feature = "is_unique")]
 mod is_unique;
+#[cfg(feature = "rank")]
+mod kendall;
 #[cfg(feature = "log")]
 mod log;
+mod mask;
 #[cfg(feature = "moment")]
 mod moment;
 mod negate;
+#[cfg(feature = "outliers")]
+mod outliers;
 #[cfg(feature = "pct_change")]
 mod pct_change;
+#[cfg(feature = "quantile_sketch")]
+mod quantile_sketch;
 #[cfg(feature = "rank")]
 mod rank;
 #[cfg(feature = "reinterpret")]
@@ -56,16 +67,26 @@ mod rle;
 mod rolling;
 #[cfg(feature = "round_series")]
 mod round;
+#[cfg(feature = "scale")]
+mod scale;
 #[cfg(feature = "search_sorted")]
 mod search_sorted;
+#[cfg(feature = "random")]
+mod split;
+#[cfg(feature = "target_encoding")]
+mod target_encoding;
 #[cfg(feature = "to_dummies")]
 mod to_dummies;
 #[cfg(feature = "unique_counts")]
 mod unique;
 mod various;
+#[cfg(feature = "random")]
+mod weighted_sample;
 
 #[cfg(feature = "abs")]
 pub use abs::*;
+#[cfg(feature = "aggregation_state")]
+pub use aggregation_state::*;
 #[cfg(feature = "approx_unique")]
 pub use approx_algo::*;
 #[cfg(feature = "approx_unique")]
@@ -89,6 +110,8 @@ pub use floor_divide::*;
 #[cfg(feature = "fused")]
 pub use fused::*;
 pub use horizontal::*;
+#[cfg(feature = "hypothesis_test")]
+pub use hypothesis_test::*;
 pub use index::*;
 pub use int_range::*;
 #[cfg(feature = "interpolate")]
@@ -107,15 +130,22 @@ pub use is_in::*;
 pub use is_last_distinct::*;
 #[cfg(feature = "is_unique")]
 pub use is_unique::*;
+#[cfg(feature = "rank")]
+pub use kendall::*;
 #[cfg(feature = "log")]
 pub use log::*;
+pub use mask::*;
 #[cfg(feature = "moment")]
 pub use moment::*;
 pub use negate::*;
+#[cfg(feature = "outliers")]
+pub use outliers::*;
 #[cfg(feature = "pct_change")]
 pub use pct_change::*;
 pub use polars_core::chunked_array::ops::search_sorted::SearchSortedSide;
 use polars_core::prelude::*;
+#[cfg(feature = "quantile_sketch")]
+pub use quantile_sketch::*;
 #[cfg(feature = "rank")]
 pub use rank::*;
 #[cfg(feature = "reinterpret")]
@@ -128,13 +158,21 @@ pub use rle::*;
 pub use rolling::*;
 #[cfg(feature = "round_series")]
 pub use round::*;
+#[cfg(feature = "scale")]
+pub use scale::*;
 #[cfg(feature = "search_sorted")]
 pub use search_sorted::*;
+#[cfg(feature = "random")]
+pub use split::*;
+#[cfg(feature = "target_encoding")]
+pub use target_encoding::*;
 #[cfg(feature = "to_dummies")]
 pub use to_dummies::*;
 #[cfg(feature = "unique_counts")]
 pub use unique::*;
 pub use various::*;
+#[cfg(feature = "random")]
+pub use weighted_sample::*;
 mod not;
 
 #[cfg(feature = "dtype-duration")]
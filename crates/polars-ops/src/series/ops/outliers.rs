@@ -0,0 +1,374 @@
+use std::hash::{Hash, Hasher};
+
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Scale factor that makes the median absolute deviation consistent with the standard deviation
+/// of a normal distribution.
+const MAD_FACTOR: f64 = 0.6745;
+/// Scale factor for the mean absolute deviation, used when the median absolute deviation is zero.
+const MEAN_AD_FACTOR: f64 = 1.253314;
+const EULER_GAMMA: f64 = 0.5772156649;
+
+/// How outliers are flagged.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutlierMethod {
+    /// Values more than `k` interquartile ranges below the first or above the third quartile.
+    Iqr { k: f64 },
+    /// Values whose absolute modified z-score exceeds `threshold`.
+    ModifiedZScore { threshold: f64 },
+}
+
+impl Eq for OutlierMethod {}
+
+impl Hash for OutlierMethod {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            Self::Iqr { k } => k.to_bits().hash(state),
+            Self::ModifiedZScore { threshold } => threshold.to_bits().hash(state),
+        }
+    }
+}
+
+impl Default for OutlierMethod {
+    fn default() -> Self {
+        Self::Iqr { k: 1.5 }
+    }
+}
+
+fn to_float(s: &Series) -> PolarsResult<Series> {
+    polars_ensure!(
+        s.dtype().is_numeric(),
+        InvalidOperation: "cannot detect outliers in values of dtype {}", s.dtype()
+    );
+    s.cast(&DataType::Float64)
+}
+
+/// The modified z-score of every value: its distance to the median in units of the median
+/// absolute deviation (MAD), scaled to be comparable to a standard score.
+///
+/// If the MAD is zero the mean absolute deviation is used instead. Nulls remain null.
+pub fn modified_zscore(s: &Series) -> PolarsResult<Float64Chunked> {
+    let s = to_float(s)?;
+    let ca = s.f64()?;
+    let Some(median) = ca.median() else {
+        return Ok(Float64Chunked::full_null(s.name().clone(), s.len()));
+    };
+    let deviations = ca.apply_values(|v| (v - median).abs());
+    let mad = deviations.median().unwrap();
+    let out = if mad != 0.0 {
+        ca.apply_values(|v| MAD_FACTOR * (v - median) / mad)
+    } else {
+        let mean_ad = deviations.mean().unwrap();
+        if mean_ad == 0.0 {
+            ca.apply_values(|_| 0.0)
+        } else {
+            ca.apply_values(|v| (v - median) / (MEAN_AD_FACTOR * mean_ad))
+        }
+    };
+    Ok(out)
+}
+
+/// Flag the outliers among the values of `s` according to `method`. Nulls remain null.
+pub fn is_outlier(s: &Series, method: OutlierMethod) -> PolarsResult<BooleanChunked> {
+    match method {
+        OutlierMethod::Iqr { k } => {
+            polars_ensure!(
+                k >= 0.0,
+                InvalidOperation: "the IQR fence factor must be non-negative, got {}", k
+            );
+            let s = to_float(s)?;
+            let ca = s.f64()?;
+            let q1 = ca.quantile(0.25, QuantileInterpolOptions::Linear)?;
+            let q3 = ca.quantile(0.75, QuantileInterpolOptions::Linear)?;
+            let (Some(q1), Some(q3)) = (q1, q3) else {
+                return Ok(BooleanChunked::full_null(s.name().clone(), s.len()));
+            };
+            let iqr = q3 - q1;
+            let (lower, upper) = (q1 - k * iqr, q3 + k * iqr);
+            Ok(ca.apply_nonnull_values_generic(DataType::Boolean, |v| v < lower || v > upper))
+        },
+        OutlierMethod::ModifiedZScore { threshold } => {
+            let scores = modified_zscore(s)?;
+            Ok(scores.apply_nonnull_values_generic(DataType::Boolean, |v| v.abs() > threshold))
+        },
+    }
+}
+
+/// Options of an isolation forest.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IsolationForestOptions {
+    /// The number of trees.
+    pub n_trees: usize,
+    /// The number of rows every tree is grown on.
+    pub sample_size: usize,
+    pub seed: Option<u64>,
+}
+
+impl Default for IsolationForestOptions {
+    fn default() -> Self {
+        Self {
+            n_trees: 100,
+            sample_size: 256,
+            seed: None,
+        }
+    }
+}
+
+/// The average path length of an unsuccessful search in a binary search tree of `n` values.
+fn average_path_length(n: usize) -> f64 {
+    match n {
+        0 | 1 => 0.0,
+        2 => 1.0,
+        n => {
+            let n = n as f64;
+            2.0 * ((n - 1.0).ln() + EULER_GAMMA) - 2.0 * (n - 1.0) / n
+        },
+    }
+}
+
+enum Node {
+    Leaf {
+        size: usize,
+    },
+    Split {
+        feature: usize,
+        value: f64,
+        left: usize,
+        right: usize,
+    },
+}
+
+/// A tree that isolates rows by splitting on a random feature at a random value.
+struct IsolationTree {
+    nodes: Vec<Node>,
+}
+
+impl IsolationTree {
+    fn fit(
+        features: &[Vec<f64>],
+        rows: &mut [usize],
+        height_limit: usize,
+        rng: &mut SmallRng,
+    ) -> Self {
+        let mut tree = Self { nodes: vec![] };
+        tree.grow(features, rows, 0, height_limit, rng);
+        tree
+    }
+
+    fn grow(
+        &mut self,
+        features: &[Vec<f64>],
+        rows: &mut [usize],
+        depth: usize,
+        height_limit: usize,
+        rng: &mut SmallRng,
+    ) -> usize {
+        let id = self.nodes.len();
+        self.nodes.push(Node::Leaf { size: rows.len() });
+        if depth >= height_limit || rows.len() <= 1 {
+            return id;
+        }
+
+        // Only features that are not constant in this node can split it.
+        let candidates = features
+            .iter()
+            .enumerate()
+            .filter_map(|(feature, values)| {
+                let (min, max) = rows
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), row| {
+                        (min.min(values[*row]), max.max(values[*row]))
+                    });
+                (min < max).then_some((feature, min, max))
+            })
+            .collect::<Vec<_>>();
+        let Some(&(feature, min, max)) = candidates.choose(rng) else {
+            return id;
+        };
+        // Interpolate between the bounds, as the width of `min..max` overflows for extreme values.
+        let t = rng.gen::<f64>();
+        let value = min * (1.0 - t) + max * t;
+
+        let mut n_left = 0;
+        for i in 0..rows.len() {
+            if features[feature][rows[i]] < value {
+                rows.swap(i, n_left);
+                n_left += 1;
+            }
+        }
+        let (left_rows, right_rows) = rows.split_at_mut(n_left);
+        let left = self.grow(features, left_rows, depth + 1, height_limit, rng);
+        let right = self.grow(features, right_rows, depth + 1, height_limit, rng);
+        self.nodes[id] = Node::Split {
+            feature,
+            value,
+            left,
+            right,
+        };
+        id
+    }
+
+    fn path_length(&self, features: &[Vec<f64>], row: usize) -> f64 {
+        let mut node = 0;
+        let mut depth = 0.0;
+        loop {
+            match self.nodes[node] {
+                Node::Leaf { size } => return depth + average_path_length(size),
+                Node::Split {
+                    feature,
+                    value,
+                    left,
+                    right,
+                } => {
+                    node = if features[feature][row] < value {
+                        left
+                    } else {
+                        right
+                    };
+                    depth += 1.0;
+                },
+            }
+        }
+    }
+}
+
+/// Score the rows formed by `columns` with an isolation forest fitted on the rows themselves.
+///
+/// Every tree is grown on a random subsample of the rows. Rows that are isolated in few
+/// splits are anomalous: the score lies in `(0, 1]` and scores close to one indicate outliers,
+/// while scores well below `0.5` indicate regular rows. Rows with a null, NaN or infinite value
+/// are not used for fitting and get a null score.
+pub fn isolation_forest_score(
+    columns: &[Series],
+    options: &IsolationForestOptions,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(
+        !columns.is_empty(),
+        ComputeError: "isolation forest expects at least one column"
+    );
+    polars_ensure!(
+        options.n_trees > 0 && options.sample_size > 1,
+        InvalidOperation: "isolation forest needs at least one tree and a sample size of at least two"
+    );
+    let name = columns[0].name().clone();
+    let len = columns[0].len();
+    let mut validity = vec![true; len];
+    let features = columns
+        .iter()
+        .map(|s| {
+            polars_ensure!(
+                s.len() == len,
+                ShapeMismatch: "isolation forest expects columns of equal length"
+            );
+            let s = to_float(s)?;
+            Ok(s.f64()?
+                .iter()
+                .zip(validity.iter_mut())
+                .map(|(opt_v, valid)| {
+                    // Random split values can only be drawn between finite bounds.
+                    *valid &= opt_v.is_some_and(f64::is_finite);
+                    opt_v.unwrap_or_default()
+                })
+                .collect::<Vec<_>>())
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    let valid_rows = (0..len).filter(|i| validity[*i]).collect::<Vec<_>>();
+    if valid_rows.len() < 2 {
+        return Ok(Float64Chunked::full_null(name, len));
+    }
+
+    let sample_size = options.sample_size.min(valid_rows.len());
+    let height_limit = (sample_size as f64).log2().ceil() as usize;
+    let mut rng = SmallRng::seed_from_u64(options.seed.unwrap_or_else(get_global_random_u64));
+    let trees = (0..options.n_trees)
+        .map(|_| {
+            let mut sample = rand::seq::index::sample(&mut rng, valid_rows.len(), sample_size)
+                .into_iter()
+                .map(|i| valid_rows[i])
+                .collect::<Vec<_>>();
+            IsolationTree::fit(&features, &mut sample, height_limit, &mut rng)
+        })
+        .collect::<Vec<_>>();
+
+    let normalization = average_path_length(sample_size);
+    let out: Float64Chunked = (0..len)
+        .map(|row| {
+            validity[row].then(|| {
+                let mean_path = trees
+                    .iter()
+                    .map(|tree| tree.path_length(&features, row))
+                    .sum::<f64>()
+                    / trees.len() as f64;
+                2f64.powf(-mean_path / normalization)
+            })
+        })
+        .collect();
+    Ok(out.with_name(name))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_outliers() -> PolarsResult<()> {
+        let s = Series::new(
+            "a".into(),
+            &[Some(1.0), Some(2.0), None, Some(3.0), Some(100.0)],
+        );
+        let flags = is_outlier(&s, OutlierMethod::default())?;
+        assert_eq!(
+            Vec::from(&flags),
+            &[Some(false), Some(false), None, Some(false), Some(true)]
+        );
+
+        let scores = modified_zscore(&s)?;
+        assert_eq!(scores.get(2), None);
+        let flags = is_outlier(&s, OutlierMethod::ModifiedZScore { threshold: 3.5 })?;
+        assert_eq!(flags.sum(), Some(1));
+
+        // A zero MAD falls back to the mean absolute deviation.
+        let s = Series::new("a".into(), &[1.0, 1.0, 1.0, 1.0, 9.0]);
+        assert!(modified_zscore(&s)?.get(4).unwrap() > 3.5);
+
+        let x = Series::new("x".into(), &[0.0, 0.1, 0.2, 0.1, 0.0, 10.0]);
+        let y = Series::new(
+            "y".into(),
+            &[Some(0.0), Some(0.2), Some(0.1), Some(0.0), None, Some(10.0)],
+        );
+        let options = IsolationForestOptions {
+            seed: Some(0),
+            ..Default::default()
+        };
+        let scores = isolation_forest_score(&[x, y], &options)?;
+        assert_eq!(scores.get(4), None);
+        let outlier = scores.get(5).unwrap();
+        assert!((0..4).all(|i| scores.get(i).unwrap() < outlier));
+
+        // Non-finite values cannot be split on.
+        let x = Series::new(
+            "x".into(),
+            &[0.0, f64::INFINITY, 0.1, f64::NEG_INFINITY, f64::NAN, 10.0],
+        );
+        let scores = isolation_forest_score(&[x], &options)?;
+        assert_eq!(scores.null_count(), 3);
+        assert!(scores.get(5).unwrap() > scores.get(0).unwrap());
+
+        // Constant and extreme values do not leave a range to split on.
+        let x = Series::new("x".into(), &[1.0, 1.0, 1.0, 1.0]);
+        let scores = isolation_forest_score(&[x], &options)?;
+        assert_eq!(scores.n_unique()?, 1);
+        let x = Series::new("x".into(), &[f64::MIN, 0.0, 1.0, f64::MAX]);
+        let scores = isolation_forest_score(&[x], &options)?;
+        assert_eq!(scores.null_count(), 0);
+        Ok(())
+    }
+}
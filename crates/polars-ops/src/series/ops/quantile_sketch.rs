@@ -0,0 +1,298 @@
+use std::f64::consts::PI;
+
+use polars_core::prelude::*;
+
+const FORMAT_VERSION: u8 = 1;
+/// The size of the serialized header: version, compression, count, min, max and the number of
+/// centroids.
+const HEADER_LEN: usize = 1 + 4 * 8 + 4;
+const CENTROID_LEN: usize = 2 * 8;
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+/// A t-digest: a mergeable sketch of a distribution that answers quantile queries with a small
+/// relative error, most accurately near the tails.
+///
+/// The sketch keeps at most about `compression` centroids, independent of the number of values
+/// it summarizes. Sketches of different batches can be merged into the sketch of their union,
+/// and serialized with [`TDigest::to_bytes`] to be stored in between.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    count: f64,
+    min: f64,
+    max: f64,
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> PolarsResult<Self> {
+        polars_ensure!(
+            compression.is_finite() && compression >= 10.0,
+            InvalidOperation: "t-digest compression must be at least 10, got {}", compression
+        );
+        Ok(Self {
+            compression,
+            centroids: vec![],
+            count: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        })
+    }
+
+    /// Sketch the non-null, non-NaN values of `s`.
+    pub fn from_series(s: &Series, compression: f64) -> PolarsResult<Self> {
+        polars_ensure!(
+            s.dtype().is_numeric(),
+            InvalidOperation: "cannot sketch the quantiles of dtype {}", s.dtype()
+        );
+        let mut digest = Self::new(compression)?;
+        let s = s.cast(&DataType::Float64)?;
+        // Compress in batches to bound the memory of the unmerged values.
+        let batch_size = (10.0 * compression) as usize;
+        let mut batch = Vec::with_capacity(batch_size);
+        for v in s.f64()?.iter().flatten().filter(|v| !v.is_nan()) {
+            batch.push(Centroid {
+                mean: v,
+                weight: 1.0,
+            });
+            if batch.len() == batch_size {
+                digest.add_centroids(&mut batch);
+            }
+        }
+        digest.add_centroids(&mut batch);
+        Ok(digest)
+    }
+
+    pub fn compression(&self) -> f64 {
+        self.compression
+    }
+
+    /// The number of values summarized by the sketch.
+    pub fn count(&self) -> f64 {
+        self.count
+    }
+
+    /// Merge `other` into this sketch, keeping the compression of this sketch.
+    pub fn merge(&mut self, other: &TDigest) {
+        let mut centroids = other.centroids.clone();
+        self.add_centroids(&mut centroids);
+    }
+
+    fn add_centroids(&mut self, centroids: &mut Vec<Centroid>) {
+        if centroids.is_empty() {
+            return;
+        }
+        for c in centroids.iter() {
+            self.count += c.weight;
+            self.min = self.min.min(c.mean);
+            self.max = self.max.max(c.mean);
+        }
+        centroids.append(&mut self.centroids);
+        centroids.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+        self.compress(centroids);
+        centroids.clear();
+    }
+
+    /// The scale function `k_1`, mapping a quantile to an index such that every centroid spans
+    /// at most one unit of it.
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    /// The largest quantile a centroid starting at `q` may grow to.
+    fn q_limit(&self, q: f64) -> f64 {
+        let k = self.k(q) + 1.0;
+        if k >= self.compression / 4.0 {
+            1.0
+        } else {
+            ((k * 2.0 * PI / self.compression).sin() + 1.0) / 2.0
+        }
+    }
+
+    /// Merge neighbouring sorted `centroids` as long as they stay within the size limit.
+    fn compress(&mut self, centroids: &[Centroid]) {
+        let mut iter = centroids.iter().copied();
+        let mut current = iter.next().unwrap();
+        let mut q_start = 0.0;
+        let mut q_limit = self.q_limit(q_start);
+        for next in iter {
+            let q = q_start + (current.weight + next.weight) / self.count;
+            if q <= q_limit {
+                let weight = current.weight + next.weight;
+                current.mean += (next.mean - current.mean) * next.weight / weight;
+                current.weight = weight;
+            } else {
+                q_start += current.weight / self.count;
+                q_limit = self.q_limit(q_start);
+                self.centroids.push(current);
+                current = next;
+            }
+        }
+        self.centroids.push(current);
+    }
+
+    /// Estimate the `quantile` of the summarized values, `None` if the sketch is empty.
+    pub fn quantile(&self, quantile: f64) -> PolarsResult<Option<f64>> {
+        polars_ensure!(
+            (0.0..=1.0).contains(&quantile),
+            ComputeError: "quantile should be between 0.0 and 1.0"
+        );
+        let (Some(first), Some(last)) = (self.centroids.first(), self.centroids.last()) else {
+            return Ok(None);
+        };
+        let target = quantile * self.count;
+
+        // The tails are interpolated between the extrema and the outer centroids.
+        if target <= first.weight / 2.0 {
+            let fraction = target / (first.weight / 2.0);
+            return Ok(Some(self.min + (first.mean - self.min) * fraction));
+        }
+        if target >= self.count - last.weight / 2.0 {
+            let fraction = (self.count - target) / (last.weight / 2.0);
+            return Ok(Some(self.max - (self.max - last.mean) * fraction));
+        }
+
+        // Interpolate between the centers of the centroids around the target.
+        let mut center = first.weight / 2.0;
+        for window in self.centroids.windows(2) {
+            let (left, right) = (window[0], window[1]);
+            let next_center = center + (left.weight + right.weight) / 2.0;
+            if target <= next_center {
+                let fraction = (target - center) / (next_center - center);
+                return Ok(Some(left.mean + (right.mean - left.mean) * fraction));
+            }
+            center = next_center;
+        }
+        Ok(Some(last.mean))
+    }
+
+    /// Serialize the sketch into a portable little-endian binary representation.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(HEADER_LEN + self.centroids.len() * CENTROID_LEN);
+        out.push(FORMAT_VERSION);
+        for v in [self.compression, self.count, self.min, self.max] {
+            out.extend_from_slice(&v.to_le_bytes());
+        }
+        out.extend_from_slice(&(self.centroids.len() as u32).to_le_bytes());
+        for c in &self.centroids {
+            out.extend_from_slice(&c.mean.to_le_bytes());
+            out.extend_from_slice(&c.weight.to_le_bytes());
+        }
+        out
+    }
+
+    /// Deserialize a sketch serialized with [`TDigest::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> PolarsResult<Self> {
+        let invalid = || polars_err!(ComputeError: "invalid t-digest sketch");
+        let read_f64 = |offset: usize| {
+            bytes
+                .get(offset..offset + 8)
+                .map(|b| f64::from_le_bytes(b.try_into().unwrap()))
+                .ok_or_else(invalid)
+        };
+        polars_ensure!(
+            bytes.first() == Some(&FORMAT_VERSION),
+            ComputeError: "unsupported t-digest sketch version"
+        );
+        let n_centroids = bytes
+            .get(HEADER_LEN - 4..HEADER_LEN)
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()) as usize)
+            .ok_or_else(invalid)?;
+        polars_ensure!(
+            bytes.len() == HEADER_LEN + n_centroids * CENTROID_LEN,
+            ComputeError: "invalid t-digest sketch"
+        );
+        let mut digest = Self::new(read_f64(1)?)?;
+        digest.count = read_f64(9)?;
+        digest.min = read_f64(17)?;
+        digest.max = read_f64(25)?;
+        digest.centroids = (0..n_centroids)
+            .map(|i| {
+                let offset = HEADER_LEN + i * CENTROID_LEN;
+                Ok(Centroid {
+                    mean: read_f64(offset)?,
+                    weight: read_f64(offset + 8)?,
+                })
+            })
+            .collect::<PolarsResult<_>>()?;
+        Ok(digest)
+    }
+}
+
+/// Sketch the values of `s` into a single serialized [`TDigest`].
+pub fn quantile_sketch(s: &Series, compression: f64) -> PolarsResult<BinaryChunked> {
+    let digest = TDigest::from_series(s, compression)?;
+    Ok(BinaryChunked::from_slice(
+        s.name().clone(),
+        &[digest.to_bytes()],
+    ))
+}
+
+/// Merge the serialized sketches of `s` into a single serialized sketch, ignoring nulls.
+///
+/// The merged sketch has the compression of the first sketch.
+pub fn quantile_sketch_merge(s: &Series) -> PolarsResult<BinaryChunked> {
+    let ca = s.binary()?;
+    let mut merged: Option<TDigest> = None;
+    for bytes in ca.iter().flatten() {
+        let digest = TDigest::from_bytes(bytes)?;
+        match merged.as_mut() {
+            Some(merged) => merged.merge(&digest),
+            None => merged = Some(digest),
+        }
+    }
+    let out = merged.map(|digest| digest.to_bytes());
+    Ok(BinaryChunked::from_slice_options(s.name().clone(), &[out]))
+}
+
+/// Estimate the `quantile` of every serialized sketch of `s`.
+pub fn quantile_sketch_query(s: &Series, quantile: f64) -> PolarsResult<Float64Chunked> {
+    let ca = s.binary()?;
+    let out = ca
+        .iter()
+        .map(|opt_bytes| match opt_bytes {
+            Some(bytes) => TDigest::from_bytes(bytes)?.quantile(quantile),
+            None => Ok(None),
+        })
+        .collect::<PolarsResult<Float64Chunked>>()?;
+    Ok(out.with_name(s.name().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_tdigest() -> PolarsResult<()> {
+        let values = (0..10_000).map(|i| i as f64).collect::<Vec<_>>();
+        let s = Series::new("a".into(), &values);
+        let digest = TDigest::from_series(&s, 100.0)?;
+        assert_eq!(digest.count(), 10_000.0);
+        assert!(digest.centroids.len() < 200);
+        assert_eq!(digest.quantile(0.0)?, Some(0.0));
+        assert_eq!(digest.quantile(1.0)?, Some(9999.0));
+        let median = digest.quantile(0.5)?.unwrap();
+        assert!((median - 4999.5).abs() < 50.0);
+        let p99 = digest.quantile(0.99)?.unwrap();
+        assert!((p99 - 9899.0).abs() < 10.0);
+
+        // Merging the sketches of two halves approximates the sketch of the whole.
+        let mut merged = TDigest::from_series(&s.slice(0, 5_000), 100.0)?;
+        merged.merge(&TDigest::from_series(&s.slice(5_000, 5_000), 100.0)?);
+        assert_eq!(merged.count(), 10_000.0);
+        assert!((merged.quantile(0.5)?.unwrap() - 4999.5).abs() < 50.0);
+
+        let roundtrip = TDigest::from_bytes(&merged.to_bytes())?;
+        assert_eq!(roundtrip, merged);
+        assert!(TDigest::from_bytes(&[FORMAT_VERSION, 0]).is_err());
+
+        let empty = TDigest::new(100.0)?;
+        assert_eq!(empty.quantile(0.5)?, None);
+        Ok(())
+    }
+}
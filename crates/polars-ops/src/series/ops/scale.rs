@@ -0,0 +1,156 @@
+use arrow::bitmap::Bitmap;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::float_hash::impl_float_eq_hash;
+
+/// How the center and the spread of the values are estimated.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScaleMethod {
+    /// Subtract the mean and divide by the standard deviation (`ddof = 1`).
+    Standard,
+    /// Subtract the minimum and divide by the range, mapping the values onto `[0, 1]`.
+    MinMax,
+    /// Subtract the median and divide by the interquartile range, which is insensitive to
+    /// outliers.
+    Robust,
+}
+
+/// Fitted parameters of a scaler: the values are transformed as `(x - center) / scale`.
+///
+/// [`fit`](Self::fit) never produces a `scale` of zero, parameters that are constructed directly
+/// with one turn every value into an infinity or NaN.
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScaleParams {
+    pub center: f64,
+    pub scale: f64,
+}
+
+impl_float_eq_hash!(ScaleParams, [], floats: [center, scale]);
+
+impl ScaleParams {
+    /// Fit the parameters of `method` on the non-null values of `s`.
+    ///
+    /// Returns `None` if `s` has no non-null values. A spread of zero, or one that cannot be
+    /// estimated, results in a `scale` of one so that constant values are only centered.
+    pub fn fit(s: &Series, method: ScaleMethod) -> PolarsResult<Option<Self>> {
+        polars_ensure!(
+            s.dtype().is_numeric(),
+            InvalidOperation: "cannot scale values of dtype {}", s.dtype()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let ca = s.f64()?;
+        let (center, spread) = match method {
+            ScaleMethod::Standard => (ca.mean(), ca.std(1)),
+            ScaleMethod::MinMax => {
+                let min = ca.min();
+                (min, ca.max().zip(min).map(|(max, min)| max - min))
+            },
+            ScaleMethod::Robust => {
+                let q1 = ca.quantile(0.25, QuantileInterpolOptions::Linear)?;
+                let q3 = ca.quantile(0.75, QuantileInterpolOptions::Linear)?;
+                (ca.median(), q3.zip(q1).map(|(q3, q1)| q3 - q1))
+            },
+        };
+        Ok(center.map(|center| Self {
+            center,
+            scale: spread.filter(|v| *v != 0.0).unwrap_or(1.0),
+        }))
+    }
+
+    /// The dtype of [`ScaleParams::into_struct`].
+    pub fn dtype() -> DataType {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("center"), DataType::Float64),
+            Field::new(PlSmallStr::from_static("scale"), DataType::Float64),
+        ])
+    }
+
+    /// The parameters as a single struct value with a `center` and a `scale` field.
+    pub fn into_struct(params: Option<Self>, name: PlSmallStr) -> PolarsResult<StructChunked> {
+        let center = Series::new(
+            PlSmallStr::from_static("center"),
+            &[params.map(|p| p.center)],
+        );
+        let scale = Series::new(PlSmallStr::from_static("scale"), &[params.map(|p| p.scale)]);
+        let out = StructChunked::from_series(name, [center, scale].iter())?;
+        let validity = params.is_none().then(|| Bitmap::new_zeroed(1));
+        Ok(out.with_outer_validity(validity))
+    }
+
+    /// Transform the values of `s` as `(x - center) / scale`.
+    pub fn transform(&self, s: &Series) -> PolarsResult<Float64Chunked> {
+        polars_ensure!(
+            s.dtype().is_numeric(),
+            InvalidOperation: "cannot scale values of dtype {}", s.dtype()
+        );
+        let s = s.cast(&DataType::Float64)?;
+        let out = s.f64()?.apply_values(|v| (v - self.center) / self.scale);
+        Ok(out)
+    }
+}
+
+/// Fit the parameters of `method` on `s` and transform `s` with them.
+///
+/// If `s` has no non-null values the output is all null.
+pub fn scale(s: &Series, method: ScaleMethod) -> PolarsResult<Float64Chunked> {
+    match ScaleParams::fit(s, method)? {
+        Some(params) => params.transform(s),
+        None => Ok(Float64Chunked::full_null(s.name().clone(), s.len())),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_scale() -> PolarsResult<()> {
+        let s = Series::new("a".into(), &[Some(1i32), Some(2), None, Some(3), Some(10)]);
+
+        let params = ScaleParams::fit(&s, ScaleMethod::MinMax)?.unwrap();
+        assert_eq!(
+            params,
+            ScaleParams {
+                center: 1.0,
+                scale: 9.0
+            }
+        );
+        let out = scale(&s, ScaleMethod::MinMax)?;
+        assert_eq!(out.get(0), Some(0.0));
+        assert_eq!(out.get(2), None);
+        assert_eq!(out.get(4), Some(1.0));
+
+        let params = ScaleParams::fit(&s, ScaleMethod::Robust)?.unwrap();
+        assert_eq!(
+            params,
+            ScaleParams {
+                center: 2.5,
+                scale: 3.0
+            }
+        );
+
+        let out = scale(&s, ScaleMethod::Standard)?;
+        assert!(out.mean().unwrap().abs() < 1e-12);
+        assert!((out.std(1).unwrap() - 1.0).abs() < 1e-12);
+
+        // Constant values are only centered.
+        let s = Series::new("a".into(), &[4.0, 4.0]);
+        let params = ScaleParams::fit(&s, ScaleMethod::Standard)?.unwrap();
+        assert_eq!(
+            params,
+            ScaleParams {
+                center: 4.0,
+                scale: 1.0
+            }
+        );
+
+        let s = Series::new_null("a".into(), 2).cast(&DataType::Float64)?;
+        assert_eq!(ScaleParams::fit(&s, ScaleMethod::MinMax)?, None);
+        assert_eq!(scale(&s, ScaleMethod::MinMax)?.null_count(), 2);
+        Ok(())
+    }
+}
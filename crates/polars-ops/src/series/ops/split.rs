@@ -0,0 +1,145 @@
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+
+fn rng(seed: Option<u64>) -> SmallRng {
+    SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64))
+}
+
+fn validate_k(k: IdxSize) -> PolarsResult<()> {
+    polars_ensure!(k > 0, InvalidOperation: "the number of folds must be positive");
+    Ok(())
+}
+
+fn validate_fraction(fraction: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        (0.0..=1.0).contains(&fraction),
+        InvalidOperation: "split fraction must be between 0 and 1, got {}", fraction
+    );
+    Ok(())
+}
+
+/// The group id of every value of `keys` and the number of groups, nulls form a group as well.
+fn key_group_ids(keys: &Series) -> PolarsResult<(Vec<IdxSize>, usize)> {
+    let groups = keys.group_tuples(false, true)?;
+    let mut ids = vec![0; keys.len()];
+    for (group_id, group) in groups.iter().enumerate() {
+        match group {
+            GroupsIndicator::Idx((_, idx)) => {
+                for i in idx.iter() {
+                    ids[*i as usize] = group_id as IdxSize;
+                }
+            },
+            GroupsIndicator::Slice([offset, len]) => {
+                ids[offset as usize..(offset + len) as usize].fill(group_id as IdxSize)
+            },
+        }
+    }
+    Ok((ids, groups.len()))
+}
+
+/// Assign every position to one of `k` folds in random order, such that the fold sizes differ
+/// by at most one.
+fn balanced_folds(len: usize, k: IdxSize, seed: Option<u64>) -> Vec<IdxSize> {
+    let mut order = (0..len as IdxSize).collect::<Vec<_>>();
+    order.shuffle(&mut rng(seed));
+    let mut folds = vec![0; len];
+    for (i, pos) in order.into_iter().enumerate() {
+        folds[pos as usize] = i as IdxSize % k;
+    }
+    folds
+}
+
+/// Mark a random `fraction` of `len` positions, rounded to the nearest count.
+fn fraction_mask(len: usize, fraction: f64, seed: Option<u64>) -> Vec<bool> {
+    let n = (fraction * len as f64).round() as usize;
+    let mut mask = vec![false; len];
+    for pos in rand::seq::index::sample(&mut rng(seed), len, n) {
+        mask[pos] = true;
+    }
+    mask
+}
+
+/// Assign each of the `len` rows to one of `k` cross-validation folds.
+///
+/// The rows are shuffled with `seed` and dealt round-robin over the folds, such that the fold
+/// sizes differ by at most one.
+pub fn fold_id(len: usize, k: IdxSize, seed: Option<u64>) -> PolarsResult<IdxCa> {
+    validate_k(k)?;
+    Ok(IdxCa::from_vec(
+        PlSmallStr::from_static("fold_id"),
+        balanced_folds(len, k, seed),
+    ))
+}
+
+/// Assign each row to one of `k` cross-validation folds, keeping equal `keys` in one fold.
+///
+/// The distinct keys are dealt over the folds in random order, so the folds get a (nearly)
+/// equal number of keys rather than of rows.
+pub fn fold_id_by_key(keys: &Series, k: IdxSize, seed: Option<u64>) -> PolarsResult<IdxCa> {
+    validate_k(k)?;
+    let (group_ids, n_groups) = key_group_ids(keys)?;
+    let group_folds = balanced_folds(n_groups, k, seed);
+    let folds = group_ids
+        .into_iter()
+        .map(|group| group_folds[group as usize])
+        .collect();
+    Ok(IdxCa::from_vec(keys.name().clone(), folds))
+}
+
+/// Mark a random `fraction` of the `len` rows, e.g. as the training set of a train/test split.
+pub fn split_mask(len: usize, fraction: f64, seed: Option<u64>) -> PolarsResult<BooleanChunked> {
+    validate_fraction(fraction)?;
+    let mask = fraction_mask(len, fraction, seed);
+    Ok(BooleanChunked::from_slice(
+        PlSmallStr::from_static("split"),
+        &mask,
+    ))
+}
+
+/// Mark a random `fraction` of the distinct `keys`, keeping all rows of a key on one side of
+/// the split.
+pub fn split_mask_by_key(
+    keys: &Series,
+    fraction: f64,
+    seed: Option<u64>,
+) -> PolarsResult<BooleanChunked> {
+    validate_fraction(fraction)?;
+    let (group_ids, n_groups) = key_group_ids(keys)?;
+    let group_mask = fraction_mask(n_groups, fraction, seed);
+    Ok(BooleanChunked::from_iter_values(
+        keys.name().clone(),
+        group_ids
+            .into_iter()
+            .map(|group| group_mask[group as usize]),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_fold_id() -> PolarsResult<()> {
+        let folds = fold_id(10, 3, Some(0))?;
+        let mut sizes = [0; 3];
+        folds
+            .into_no_null_iter()
+            .for_each(|f| sizes[f as usize] += 1);
+        sizes.sort_unstable();
+        assert_eq!(sizes, [3, 3, 4]);
+        assert_eq!(folds, fold_id(10, 3, Some(0))?);
+
+        let keys = Series::new("k".into(), &[Some("a"), Some("b"), Some("a"), None, None]);
+        let folds = fold_id_by_key(&keys, 2, Some(0))?;
+        assert_eq!(folds.get(0), folds.get(2));
+        assert_eq!(folds.get(3), folds.get(4));
+
+        let mask = split_mask(10, 0.75, Some(1))?;
+        assert_eq!(mask.sum(), Some(8));
+        let mask = split_mask_by_key(&keys, 0.5, Some(1))?;
+        assert_eq!(mask.get(0), mask.get(2));
+        assert!(fold_id(3, 0, None).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::float_hash::impl_float_eq_hash;
+
+/// Sum and count of the non-null targets.
+#[derive(Copy, Clone, Default)]
+struct TargetStats {
+    sum: f64,
+    count: f64,
+}
+
+impl TargetStats {
+    fn add(&mut self, target: f64) {
+        self.sum += target;
+        self.count += 1.0;
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            sum: self.sum - other.sum,
+            count: self.count - other.count,
+        }
+    }
+
+    fn mean(&self) -> f64 {
+        self.sum / self.count
+    }
+
+    /// The mean shrunk towards `prior`, `smoothing` is the weight of the prior in observations.
+    fn smoothed_mean(&self, prior: f64, smoothing: f64) -> f64 {
+        if self.count + smoothing == 0.0 {
+            prior
+        } else {
+            (self.sum + smoothing * prior) / (self.count + smoothing)
+        }
+    }
+}
+
+fn validate_smoothing(smoothing: f64) -> PolarsResult<()> {
+    polars_ensure!(
+        smoothing >= 0.0,
+        InvalidOperation: "target encoding `smoothing` must be non-negative, got {}", smoothing
+    );
+    Ok(())
+}
+
+fn validate_lengths(keys: &Series, other: &Series) -> PolarsResult<()> {
+    polars_ensure!(
+        keys.len() == other.len(),
+        ShapeMismatch: "target encoding expects inputs of equal length, got {} and {}",
+        keys.len(), other.len()
+    );
+    Ok(())
+}
+
+/// Encodes every category with the mean of the target of its rows, shrunk towards the global
+/// mean of the target to regularize rare categories.
+///
+/// Categories are matched on their string representation, so keys of a different dtype than the
+/// ones it was fitted on are encoded as well. Null keys and unseen categories get the prior.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "TargetEncoderFields"))]
+pub struct TargetEncoder {
+    categories: Arc<[PlSmallStr]>,
+    encodings: Arc<[f64]>,
+    prior: f64,
+}
+
+/// The serialized form of a [`TargetEncoder`], validated when it is deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct TargetEncoderFields {
+    categories: Arc<[PlSmallStr]>,
+    encodings: Arc<[f64]>,
+    prior: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<TargetEncoderFields> for TargetEncoder {
+    type Error = PolarsError;
+
+    fn try_from(fields: TargetEncoderFields) -> PolarsResult<Self> {
+        Self::new(fields.categories, fields.encodings, fields.prior)
+    }
+}
+
+impl_float_eq_hash!(TargetEncoder, [categories], floats: [encodings, prior]);
+
+impl TargetEncoder {
+    /// Create an encoder from supplied categories, the encoding of every category and the
+    /// encoding of unseen categories.
+    pub fn new(
+        categories: Arc<[PlSmallStr]>,
+        encodings: Arc<[f64]>,
+        prior: f64,
+    ) -> PolarsResult<Self> {
+        let n_unique = categories.iter().collect::<PlHashSet<_>>().len();
+        polars_ensure!(
+            n_unique == categories.len(),
+            Duplicate: "the target encoding categories contain duplicates"
+        );
+        polars_ensure!(
+            encodings.len() == categories.len(),
+            ShapeMismatch: "got {} encodings for {} categories",
+            encodings.len(), categories.len()
+        );
+        Ok(Self {
+            categories,
+            encodings,
+            prior,
+        })
+    }
+
+    /// Fit the encoding of the `keys` on the `target`.
+    ///
+    /// Rows with a null key or a null target are ignored. `smoothing` is the weight of the
+    /// global mean in number of observations.
+    pub fn fit(keys: &Series, target: &Series, smoothing: f64) -> PolarsResult<Self> {
+        validate_smoothing(smoothing)?;
+        validate_lengths(keys, target)?;
+        let keys = keys.cast(&DataType::String)?;
+        let target = target.cast(&DataType::Float64)?;
+
+        let mut total = TargetStats::default();
+        let mut stats = PlIndexMap::<&str, TargetStats>::new();
+        for (opt_key, opt_target) in keys.str()?.iter().zip(target.f64()?.iter()) {
+            let Some(target) = opt_target else {
+                continue;
+            };
+            total.add(target);
+            if let Some(key) = opt_key {
+                stats.entry(key).or_default().add(target);
+            }
+        }
+        polars_ensure!(
+            total.count > 0.0,
+            ComputeError: "cannot fit a target encoding without non-null targets"
+        );
+
+        let prior = total.mean();
+        let categories = stats.keys().map(|key| PlSmallStr::from_str(key)).collect();
+        let encodings = stats
+            .values()
+            .map(|stats| stats.smoothed_mean(prior, smoothing))
+            .collect();
+        Ok(Self {
+            categories,
+            encodings,
+            prior,
+        })
+    }
+
+    pub fn categories(&self) -> &[PlSmallStr] {
+        &self.categories
+    }
+
+    pub fn encodings(&self) -> &[f64] {
+        &self.encodings
+    }
+
+    /// The global mean of the target, used for unseen categories.
+    pub fn prior(&self) -> f64 {
+        self.prior
+    }
+
+    /// Replace every key by the encoding of its category.
+    ///
+    /// Null keys and categories that were not seen when fitting get the prior.
+    pub fn transform(&self, keys: &Series) -> PolarsResult<Float64Chunked> {
+        let index = self
+            .categories
+            .iter()
+            .zip(self.encodings.iter())
+            .map(|(category, encoding)| (category.as_str(), *encoding))
+            .collect::<PlHashMap<_, _>>();
+        let keys = keys.cast(&DataType::String)?;
+        let out = keys
+            .str()?
+            .iter()
+            .map(|opt_key| {
+                opt_key
+                    .and_then(|key| index.get(key).copied())
+                    .unwrap_or(self.prior)
+            })
+            .collect();
+        Ok(Float64Chunked::from_vec(keys.name().clone(), out))
+    }
+}
+
+/// Target encode the `keys` without leaking the target of a row into its own encoding.
+///
+/// Every row is encoded with the statistics of all the other folds, as if the encoder was
+/// fitted without the fold of the row. Rows whose other folds have no non-null target are null.
+pub fn target_encode_out_of_fold(
+    keys: &Series,
+    target: &Series,
+    folds: &Series,
+    smoothing: f64,
+) -> PolarsResult<Float64Chunked> {
+    validate_smoothing(smoothing)?;
+    validate_lengths(keys, target)?;
+    validate_lengths(keys, folds)?;
+    polars_ensure!(
+        folds.null_count() == 0,
+        ComputeError: "target encoding folds must not contain nulls"
+    );
+    let keys_str = keys.cast(&DataType::String)?;
+    let keys_ca = keys_str.str()?;
+    let target = target.cast(&DataType::Float64)?;
+    let target = target.f64()?;
+    let folds = folds.strict_cast(&IDX_DTYPE)?;
+    let folds = folds.idx()?;
+
+    let mut key_ids = PlHashMap::<&str, usize>::new();
+    // Per fold the total and per key the statistics in that fold.
+    let mut fold_totals = PlHashMap::<IdxSize, TargetStats>::new();
+    let mut fold_stats = PlHashMap::<(usize, IdxSize), TargetStats>::new();
+    let mut key_totals = vec![];
+    let mut total = TargetStats::default();
+
+    let row_keys = keys_ca
+        .iter()
+        .map(|opt_key| {
+            opt_key.map(|key| {
+                let n_keys = key_ids.len();
+                let id = *key_ids.entry(key).or_insert(n_keys);
+                if id == key_totals.len() {
+                    key_totals.push(TargetStats::default());
+                }
+                id
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for ((opt_key, opt_target), fold) in row_keys
+        .iter()
+        .zip(target.iter())
+        .zip(folds.into_no_null_iter())
+    {
+        let Some(target) = opt_target else {
+            continue;
+        };
+        total.add(target);
+        fold_totals.entry(fold).or_default().add(target);
+        if let Some(key) = opt_key {
+            key_totals[*key].add(target);
+            fold_stats.entry((*key, fold)).or_default().add(target);
+        }
+    }
+
+    let out: Float64Chunked = row_keys
+        .iter()
+        .zip(folds.into_no_null_iter())
+        .map(|(opt_key, fold)| {
+            let outside_fold = total.sub(fold_totals.get(&fold).copied().unwrap_or_default());
+            if outside_fold.count == 0.0 {
+                return None;
+            }
+            let prior = outside_fold.mean();
+            let Some(key) = opt_key else {
+                return Some(prior);
+            };
+            let in_fold = fold_stats.get(&(*key, fold)).copied().unwrap_or_default();
+            let stats = key_totals[*key].sub(in_fold);
+            Some(stats.smoothed_mean(prior, smoothing))
+        })
+        .collect();
+    Ok(out.with_name(keys.name().clone()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_target_encoding() -> PolarsResult<()> {
+        let keys = Series::new("k".into(), &[Some("a"), Some("a"), Some("b"), None]);
+        let target = Series::new("t".into(), &[1.0, 3.0, 6.0, 2.0]);
+
+        let encoder = TargetEncoder::fit(&keys, &target, 0.0)?;
+        assert_eq!(encoder.prior(), 3.0);
+        let new_keys = Series::new("k".into(), &[Some("b"), Some("c"), None, Some("a")]);
+        let out = encoder.transform(&new_keys)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(6.0), Some(3.0), Some(3.0), Some(2.0)]
+        );
+
+        // The prior weighs as two observations.
+        let encoder = TargetEncoder::fit(&keys, &target, 2.0)?;
+        let out = encoder.transform(&new_keys)?;
+        assert_eq!(out.get(0), Some(4.0));
+
+        let folds = Series::new("f".into(), &[0u32, 1, 0, 1]);
+        let out = target_encode_out_of_fold(&keys, &target, &folds, 0.0)?;
+        // Row 0 only sees fold 1: "a" has target 3.0 there.
+        // Row 2 only sees fold 1: there is no "b", so the smoothed mean falls back to the
+        // prior of fold 1.
+        assert_eq!(
+            Vec::from(&out),
+            &[Some(3.0), Some(1.0), Some(2.5), Some(3.5)]
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,8 @@
+use std::sync::Arc;
+
 use polars_utils::format_pl_smallstr;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
@@ -50,6 +54,133 @@ impl ToDummies for Series {
     }
 }
 
+/// A one-hot encoding with a fixed set of categories.
+///
+/// Unlike [`ToDummies`], the output columns don't depend on the data, such that the encoding
+/// can be fitted once and applied to other data, e.g. at inference time.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "OneHotEncoderFields"))]
+pub struct OneHotEncoder {
+    categories: Arc<[PlSmallStr]>,
+    separator: PlSmallStr,
+    drop_first: bool,
+}
+
+/// The serialized form of a [`OneHotEncoder`], validated when it is deserialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct OneHotEncoderFields {
+    categories: Arc<[PlSmallStr]>,
+    separator: PlSmallStr,
+    drop_first: bool,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<OneHotEncoderFields> for OneHotEncoder {
+    type Error = PolarsError;
+
+    fn try_from(fields: OneHotEncoderFields) -> PolarsResult<Self> {
+        Self::new(
+            fields.categories,
+            Some(fields.separator.as_str()),
+            fields.drop_first,
+        )
+    }
+}
+
+impl OneHotEncoder {
+    /// Create an encoder from supplied categories, in the order of the output fields.
+    pub fn new(
+        categories: Arc<[PlSmallStr]>,
+        separator: Option<&str>,
+        drop_first: bool,
+    ) -> PolarsResult<Self> {
+        let n_unique = categories.iter().collect::<PlHashSet<_>>().len();
+        polars_ensure!(
+            n_unique == categories.len(),
+            Duplicate: "the one-hot categories contain duplicates"
+        );
+        Ok(Self {
+            categories,
+            separator: separator.unwrap_or("_").into(),
+            drop_first,
+        })
+    }
+
+    /// Use the sorted, distinct non-null values of `s` as categories.
+    pub fn fit(s: &Series, separator: Option<&str>, drop_first: bool) -> PolarsResult<Self> {
+        let s = s.drop_nulls().unique()?.cast(&DataType::String)?;
+        let mut categories = s
+            .str()?
+            .into_no_null_iter()
+            .map(PlSmallStr::from_str)
+            .collect::<Vec<_>>();
+        categories.sort_unstable();
+        Self::new(categories.into(), separator, drop_first)
+    }
+
+    pub fn categories(&self) -> &[PlSmallStr] {
+        &self.categories
+    }
+
+    fn encoded_categories(&self) -> &[PlSmallStr] {
+        &self.categories[(self.drop_first as usize).min(self.categories.len())..]
+    }
+
+    fn field_name(&self, name: &str, category: &str) -> PlSmallStr {
+        format_pl_smallstr!("{name}{}{category}", self.separator)
+    }
+
+    /// The `Struct` dtype with a field per category of the encoding of a column `name`.
+    pub fn output_dtype(&self, name: &str) -> DataType {
+        DataType::Struct(
+            self.encoded_categories()
+                .iter()
+                .map(|category| {
+                    Field::new(
+                        self.field_name(name, category),
+                        <DummyType as NumericNative>::PolarsType::get_dtype(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Encode the values of `s` as a struct with a dummy field per category.
+    ///
+    /// Null values and values that are not one of the categories have all fields set to 0.
+    pub fn transform(&self, s: &Series) -> PolarsResult<StructChunked> {
+        let categories = self.encoded_categories();
+        polars_ensure!(
+            !categories.is_empty(),
+            ComputeError: "cannot one-hot encode without categories"
+        );
+        let index = categories
+            .iter()
+            .enumerate()
+            .map(|(i, category)| (category.as_str(), i))
+            .collect::<PlHashMap<_, _>>();
+
+        let values = s.cast(&DataType::String)?;
+        let mut dummies = vec![vec![0 as DummyType; s.len()]; categories.len()];
+        for (row, opt_v) in values.str()?.iter().enumerate() {
+            if let Some(i) = opt_v.and_then(|v| index.get(v)) {
+                dummies[*i][row] = 1;
+            }
+        }
+
+        let fields = categories
+            .iter()
+            .zip(dummies)
+            .map(|(category, values)| {
+                DummyCa::from_vec(self.field_name(s.name(), category), values).into_series()
+            })
+            .collect::<Vec<_>>();
+        StructChunked::from_series(s.name().clone(), fields.iter())
+    }
+}
+
 fn dummies_helper_idx(groups: &[IdxSize], len: usize, name: PlSmallStr) -> DummyCa {
     let mut av = vec![0 as DummyType; len];
 
@@ -0,0 +1,137 @@
+use polars_core::prelude::*;
+use polars_core::random::get_global_random_u64;
+use rand::prelude::*;
+
+/// Walker's alias table: after a linear setup, it draws an index with a probability
+/// proportional to its weight in constant time, independent of the spread of the weights.
+struct AliasTable {
+    prob: Vec<f64>,
+    alias: Vec<usize>,
+}
+
+impl AliasTable {
+    /// The `weights` must be positive.
+    fn new(weights: &[f64]) -> Self {
+        let n = weights.len();
+        let total = weights.iter().sum::<f64>();
+        let mut prob = weights
+            .iter()
+            .map(|w| w / total * n as f64)
+            .collect::<Vec<_>>();
+        let mut alias = (0..n).collect::<Vec<_>>();
+        let (mut small, mut large): (Vec<_>, Vec<_>) = (0..n).partition(|i| prob[*i] < 1.0);
+        while let (Some(s), Some(l)) = (small.last().copied(), large.last().copied()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] -= 1.0 - prob[s];
+            if prob[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+        // The remaining entries have a probability of one, up to rounding errors.
+        for i in small.into_iter().chain(large) {
+            prob[i] = 1.0;
+        }
+        Self { prob, alias }
+    }
+
+    fn sample(&self, rng: &mut SmallRng) -> usize {
+        let i = rng.gen_range(0..self.prob.len());
+        if rng.gen::<f64>() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+/// The positions and values of the positive `weights`, rows with a null or zero weight are
+/// never sampled.
+fn positive_weights(weights: &Series) -> PolarsResult<(Vec<IdxSize>, Vec<f64>)> {
+    polars_ensure!(
+        weights.dtype().is_numeric(),
+        InvalidOperation: "sample weights must be numeric, got dtype {}", weights.dtype()
+    );
+    let weights = weights.cast(&DataType::Float64)?;
+    let mut positions = vec![];
+    let mut values = vec![];
+    for (i, opt_w) in weights.f64()?.iter().enumerate() {
+        let w = opt_w.unwrap_or(0.0);
+        polars_ensure!(
+            w.is_finite() && w >= 0.0,
+            InvalidOperation: "sample weights must be finite and non-negative, got {}", w
+        );
+        if w > 0.0 {
+            positions.push(i as IdxSize);
+            values.push(w);
+        }
+    }
+    Ok((positions, values))
+}
+
+/// Draw `n` row indices with a probability proportional to `weights`.
+///
+/// With replacement the rows are drawn from an alias table. Without replacement every row gets
+/// the key `ln(u) / w` for a uniform `u` and the rows with the largest keys are taken
+/// (Efraimidis-Spirakis), in random order. Rows with a null or zero weight are never drawn.
+pub fn weighted_sample_idx(
+    weights: &Series,
+    n: usize,
+    with_replacement: bool,
+    seed: Option<u64>,
+) -> PolarsResult<IdxCa> {
+    let (positions, values) = positive_weights(weights)?;
+    let mut rng = SmallRng::seed_from_u64(seed.unwrap_or_else(get_global_random_u64));
+    let idx = if n == 0 {
+        vec![]
+    } else if with_replacement {
+        polars_ensure!(
+            !positions.is_empty(),
+            ShapeMismatch: "cannot sample from rows that all have a zero weight"
+        );
+        let table = AliasTable::new(&values);
+        (0..n).map(|_| positions[table.sample(&mut rng)]).collect()
+    } else {
+        polars_ensure!(
+            n <= positions.len(),
+            ShapeMismatch: "cannot take a larger sample than the number of rows with a positive \
+            weight when `with_replacement=false`"
+        );
+        let mut keys = values
+            .iter()
+            .zip(positions)
+            .map(|(w, i)| (rng.gen::<f64>().ln() / w, i))
+            .collect::<Vec<_>>();
+        keys.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+        keys.into_iter().take(n).map(|(_, i)| i).collect()
+    };
+    Ok(IdxCa::from_vec(weights.name().clone(), idx))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_weighted_sample_idx() -> PolarsResult<()> {
+        let weights = Series::new("w".into(), &[Some(1.0), Some(0.0), None, Some(3.0)]);
+        let idx = weighted_sample_idx(&weights, 4000, true, Some(0))?;
+        let n_last = idx.into_no_null_iter().filter(|i| *i == 3).count();
+        assert_eq!(
+            idx.into_no_null_iter().filter(|i| *i == 0).count(),
+            4000 - n_last
+        );
+        assert!((2800..3200).contains(&n_last));
+
+        let idx = weighted_sample_idx(&weights, 2, false, Some(0))?;
+        let mut idx = idx.into_no_null_iter().collect::<Vec<_>>();
+        idx.sort_unstable();
+        assert_eq!(idx, &[0, 3]);
+        assert!(weighted_sample_idx(&weights, 3, false, Some(0)).is_err());
+
+        let weights = Series::new("w".into(), &[1.0, -1.0]);
+        assert!(weighted_sample_idx(&weights, 1, true, None).is_err());
+        Ok(())
+    }
+}
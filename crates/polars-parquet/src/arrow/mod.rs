@@ -6,3 +6,5 @@ pub mod write;
 pub use crate::parquet::bloom_filter;
 
 const ARROW_SCHEMA_META_KEY: &str = "ARROW:schema";
+/// The key of the metadata of an Arrow field that holds its Parquet field id.
+pub const FIELD_ID_META_KEY: &str = "PARQUET:field_id";
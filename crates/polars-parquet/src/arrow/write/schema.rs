@@ -2,10 +2,10 @@ use arrow::datatypes::{ArrowDataType, ArrowSchema, Field, TimeUnit};
 use arrow::io::ipc::write::{default_ipc_fields, schema_to_bytes};
 use base64::engine::general_purpose;
 use base64::Engine as _;
-use polars_error::{polars_bail, PolarsResult};
+use polars_error::{polars_bail, polars_err, PolarsResult};
 use polars_utils::pl_str::PlSmallStr;
 
-use super::super::ARROW_SCHEMA_META_KEY;
+use super::super::{ARROW_SCHEMA_META_KEY, FIELD_ID_META_KEY};
 use crate::arrow::write::decimal_length_from_precision;
 use crate::parquet::metadata::KeyValue;
 use crate::parquet::schema::types::{
@@ -77,7 +77,20 @@ pub fn schema_to_metadata_key(schema: &ArrowSchema) -> KeyValue {
 }
 
 /// Creates a [`ParquetType`] from a [`Field`].
+///
+/// The field id is taken from the [`FIELD_ID_META_KEY`] metadata of the field, if present.
 pub fn to_parquet_type(field: &Field) -> PolarsResult<ParquetType> {
+    let mut parquet_type = to_parquet_type_without_id(field)?;
+    if let Some(id) = field.metadata.get(FIELD_ID_META_KEY) {
+        let id = id.parse().map_err(|_| {
+            polars_err!(InvalidOperation: "invalid Parquet field id '{}' of field '{}'", id, field.name)
+        })?;
+        parquet_type.field_info_mut().id = Some(id);
+    }
+    Ok(parquet_type)
+}
+
+fn to_parquet_type_without_id(field: &Field) -> PolarsResult<ParquetType> {
     let name = field.name.clone();
     let repetition = if field.is_nullable {
         Repetition::Optional
@@ -17,6 +17,9 @@ pub enum Feature {
     Zstd,
 }
 
+const OUT_OF_SPEC_MSG: &str = "File out of specification: ";
+const POLARS_ERROR_PREFIX: &str = "parquet: ";
+
 /// Errors generated by this crate
 #[derive(Debug, Clone)]
 #[non_exhaustive]
@@ -51,7 +54,7 @@ impl std::fmt::Display for ParquetError {
     fn fmt(&self, fmt: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             ParquetError::OutOfSpec(message) => {
-                write!(fmt, "File out of specification: {}", message)
+                write!(fmt, "{}{}", OUT_OF_SPEC_MSG, message)
             },
             ParquetError::FeatureNotActive(feature, reason) => {
                 write!(
@@ -129,7 +132,19 @@ pub type ParquetResult<T> = std::result::Result<T, ParquetError>;
 
 impl From<ParquetError> for polars_error::PolarsError {
     fn from(e: ParquetError) -> polars_error::PolarsError {
-        polars_error::PolarsError::ComputeError(format!("parquet: {}", e).into())
+        polars_error::PolarsError::ComputeError(format!("{}{}", POLARS_ERROR_PREFIX, e).into())
+    }
+}
+
+/// Whether `e` was converted from a [`ParquetError::OutOfSpec`], e.g. because a page could not be
+/// deserialized or decompressed.
+pub fn is_out_of_spec(e: &polars_error::PolarsError) -> bool {
+    match e {
+        polars_error::PolarsError::ComputeError(msg) => msg
+            .strip_prefix(POLARS_ERROR_PREFIX)
+            .is_some_and(|msg| msg.starts_with(OUT_OF_SPEC_MSG)),
+        polars_error::PolarsError::Context { error, .. } => is_out_of_spec(error),
+        _ => false,
     }
 }
 
@@ -166,6 +166,13 @@ impl ColumnChunkMetadata {
         &self.metadata().encodings
     }
 
+    /// Returns whether pages of this column are dictionary encoded
+    pub fn is_dictionary_encoded(&self) -> bool {
+        self.column_encoding()
+            .iter()
+            .any(|e| *e == Encoding::PLAIN_DICTIONARY || *e == Encoding::RLE_DICTIONARY)
+    }
+
     /// Returns the offset and length in bytes of the column chunk within the file
     pub fn byte_range(&self) -> core::ops::Range<u64> {
         // this has been validated in [`try_from_thrift`]
@@ -61,6 +61,15 @@ impl RowGroupMetadata {
             .map(|&x| &self.columns[x])
     }
 
+    /// Rename the root names under which the columns are fetched. Roots that are not in
+    /// `renames` can no longer be fetched.
+    pub fn rename_roots(&mut self, renames: &PlHashMap<PlSmallStr, PlSmallStr>) {
+        self.column_lookup = std::mem::take(&mut self.column_lookup)
+            .into_iter()
+            .filter_map(|(root_name, columns)| Some((renames.get(&root_name)?.clone(), columns)))
+            .collect();
+    }
+
     /// Number of rows in this row group.
     pub fn num_rows(&self) -> usize {
         self.num_rows
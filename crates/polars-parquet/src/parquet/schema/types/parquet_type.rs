@@ -66,6 +66,14 @@ impl ParquetType {
         }
     }
 
+    /// Returns a mutable reference to the [`FieldInfo`] of the type.
+    pub fn field_info_mut(&mut self) -> &mut FieldInfo {
+        match self {
+            Self::PrimitiveType(primitive) => &mut primitive.field_info,
+            Self::GroupType { field_info, .. } => field_info,
+        }
+    }
+
     /// Returns this type's field name.
     pub fn name(&self) -> &str {
         &self.get_field_info().name
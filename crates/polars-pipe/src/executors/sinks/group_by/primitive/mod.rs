@@ -225,11 +225,37 @@ where
         }
         let arr = ca.downcast_iter().next().unwrap();
         let values = arr.values().as_slice();
-        partition_to_groups_amortized(values, 0, false, 0, &mut self.sort_partitions);
+
+        // The nulls of sorted keys are all at the start or at the end, and the values behind
+        // them are arbitrary. So they form a single group that is split off.
+        let null_count = ca.null_count();
+        let nulls_first = null_count > 0 && ca.get(0).is_none();
+        let non_null_values = if nulls_first {
+            &values[null_count..]
+        } else {
+            &values[..values.len() - null_count]
+        };
+        if non_null_values.is_empty() {
+            self.sort_partitions.clear();
+            self.sort_partitions.push([0, values.len() as IdxSize]);
+        } else {
+            partition_to_groups_amortized(
+                non_null_values,
+                null_count as IdxSize,
+                nulls_first,
+                0,
+                &mut self.sort_partitions,
+            );
+        }
+        // Ensure that the hash of the null group is equal to the default non-sorted sink.
+        if null_count > 0 {
+            ca.clone()
+                .into_series()
+                .vec_hash(self.hb.clone(), &mut self.hashes)
+                .unwrap();
+        }
 
         let pre_agg_len = self.pre_agg_partitions.len();
-        let null: Option<K::Native> = None;
-        let null_hash = self.hb.hash_one(null);
 
         for group in &self.sort_partitions {
             let [offset, length] = group;
@@ -240,7 +266,7 @@ where
                 // let h = integer_hash(first_g_value);
                 (Some(first_g_value), h)
             } else {
-                (null, null_hash)
+                (None, self.hashes[*offset as usize])
             };
 
             let agg_idx = insert_and_get(
@@ -367,7 +393,7 @@ where
         let ca: &ChunkedArray<K> = s.as_ref().as_ref();
 
         // sorted fast path
-        if matches!(ca.is_sorted_flag(), IsSorted::Ascending) {
+        if !matches!(ca.is_sorted_flag(), IsSorted::Not) {
             return self.sink_sorted(ca, chunk);
         }
 
@@ -6,6 +6,7 @@ use hashbrown::hash_map::RawEntryMut;
 use num_traits::NumCast;
 use polars_core::frame::row::AnyValueBuffer;
 use polars_core::prelude::*;
+use polars_core::series::IsSorted;
 use polars_core::utils::_set_partition_size;
 use polars_core::{IdBuildHasher, POOL};
 use polars_utils::hashing::hash_to_partition;
@@ -158,8 +159,8 @@ impl StringGroupbySink {
 
         let slices = compute_slices(&self.pre_agg_partitions, self.slice);
 
-        POOL.install(|| {
-            let dfs =
+        let partitions =
+            POOL.install(|| {
                 self.pre_agg_partitions
                     .par_iter()
                     .zip(slices.par_iter())
@@ -186,14 +187,10 @@ impl StringGroupbySink {
                             .collect::<Vec<_>>();
 
                         let cap = std::cmp::min(slice_len, agg_map.len());
-                        let mut key_builder = StringChunkedBuilder::new(PlSmallStr::EMPTY, cap);
+                        let mut key_offsets = Vec::with_capacity(cap);
                         agg_map.into_iter().skip(offset).take(slice_len).for_each(
                             |(k, &offset)| {
-                                let key_offset = k.idx as usize;
-                                let key = unsafe {
-                                    self.keys.get_unchecked_release(key_offset).as_deref()
-                                };
-                                key_builder.append_option(key);
+                                key_offsets.push(k.idx as usize);
 
                                 for (i, buffer) in (offset as usize
                                     ..offset as usize + self.aggregation_columns.len())
@@ -208,20 +205,38 @@ impl StringGroupbySink {
                             },
                         );
 
-                        let mut cols = Vec::with_capacity(1 + self.number_of_aggs());
-                        cols.push(key_builder.finish().into_series().into_column());
-                        cols.extend(
-                            buffers
-                                .into_iter()
-                                .map(|buf| buf.into_series().into_column()),
-                        );
-                        physical_agg_to_logical(&mut cols, &self.output_schema);
-                        Some(unsafe { DataFrame::new_no_checks(cols) })
+                        let aggs = buffers
+                            .into_iter()
+                            .map(|buf| buf.into_series().into_column())
+                            .collect::<Vec<_>>();
+                        Some((key_offsets, aggs))
                     })
-                    .collect::<Vec<_>>();
+                    .collect::<Vec<_>>()
+            });
 
-            Ok(dfs)
-        })
+        // The keys of all partitions are written to a single array that the output of every
+        // partition slices into, so that the concatenated result shares one set of string
+        // buffers rather than holding a copy per partition.
+        let keys = partitions
+            .iter()
+            .flat_map(|(key_offsets, _)| key_offsets.iter())
+            .map(|&key_offset| unsafe { self.keys.get_unchecked_release(key_offset).as_deref() })
+            .collect::<StringChunked>();
+
+        let mut keys_offset = 0;
+        let dfs = partitions
+            .into_iter()
+            .map(|(key_offsets, aggs)| {
+                let mut cols = Vec::with_capacity(1 + aggs.len());
+                let key = keys.slice(keys_offset as i64, key_offsets.len());
+                keys_offset += key_offsets.len();
+                cols.push(key.into_series().into_column());
+                cols.extend(aggs);
+                physical_agg_to_logical(&mut cols, &self.output_schema);
+                unsafe { DataFrame::new_no_checks(cols) }
+            })
+            .collect();
+        Ok(dfs)
     }
     fn prepare_key_and_aggregation_series(
         &mut self,
@@ -352,46 +367,61 @@ impl Sink for StringGroupbySink {
         // array of the keys
         let keys_arr = s.str().unwrap().downcast_iter().next().unwrap().clone();
 
-        for (iteration_idx, (key_val, &h)) in keys_arr.iter().zip(&hashes).enumerate() {
-            let current_partition = self.get_partitions(h);
-            let entry = get_entry(key_val, h, current_partition, &keys);
-
-            let agg_idx = match entry {
-                RawEntryMut::Vacant(entry) => {
-                    let value_offset =
-                        unsafe { NumCast::from(aggregators.len()).unwrap_unchecked_release() };
-                    let keys_offset = unsafe {
-                        Key::new(h, NumCast::from(keys.len()).unwrap_unchecked_release())
-                    };
-                    entry.insert(keys_offset, value_offset);
-
-                    keys.push(key_val.map(|s| s.into()));
+        // sorted fast path
+        if !matches!(s.is_sorted_flag(), IsSorted::Not) {
+            let mut start = 0;
+            while start < keys_arr.len() {
+                // The keys are sorted, so every run of equal keys is a complete group of this
+                // chunk, for which the hash table is probed only once.
+                let key_val = keys_arr.get(start);
+                let mut end = start + 1;
+                while end < keys_arr.len() && keys_arr.get(end) == key_val {
+                    end += 1;
+                }
+                let h = hashes[start];
+                let current_partition = self.get_partitions(h);
+                let entry = get_entry(key_val, h, current_partition, &keys);
+                let agg_idx =
+                    insert_agg_idx(entry, key_val, h, &mut keys, &mut aggregators, &agg_fns);
+
+                for (i, aggregation_s) in
+                    (0..self.number_of_aggs() as IdxSize).zip(&self.aggregation_series)
+                {
+                    let agg_fn =
+                        unsafe { aggregators.get_unchecked_release_mut((agg_idx + i) as usize) };
+                    agg_fn.pre_agg_ordered(
+                        chunk.chunk_index,
+                        start as IdxSize,
+                        (end - start) as IdxSize,
+                        aggregation_s,
+                    )
+                }
+                start = end;
+            }
+        } else {
+            for (iteration_idx, (key_val, &h)) in keys_arr.iter().zip(&hashes).enumerate() {
+                let current_partition = self.get_partitions(h);
+                let entry = get_entry(key_val, h, current_partition, &keys);
+                let agg_idx =
+                    insert_agg_idx(entry, key_val, h, &mut keys, &mut aggregators, &agg_fns);
+                // # Safety
+                // we write to the hashes buffer we iterate over at the moment.
+                // this is sound because we writes are trailing from iteration
+                unsafe { write_agg_idx(agg_idx_ptr, iteration_idx, agg_idx) };
+            }
 
-                    // initialize the aggregators
-                    for agg_fn in &agg_fns {
-                        aggregators.push(agg_fn.split())
-                    }
-                    value_offset
-                },
-                RawEntryMut::Occupied(entry) => *entry.get(),
-            };
-            // # Safety
-            // we write to the hashes buffer we iterate over at the moment.
-            // this is sound because we writes are trailing from iteration
-            unsafe { write_agg_idx(agg_idx_ptr, iteration_idx, agg_idx) };
+            // note that this slice looks into the self.hashes buffer
+            let agg_idxs = unsafe { std::slice::from_raw_parts(agg_idx_ptr, keys_arr.len()) };
+
+            apply_aggregation(
+                agg_idxs,
+                &chunk,
+                self.number_of_aggs(),
+                &self.aggregation_series,
+                &agg_fns,
+                &mut aggregators,
+            );
         }
-
-        // note that this slice looks into the self.hashes buffer
-        let agg_idxs = unsafe { std::slice::from_raw_parts(agg_idx_ptr, keys_arr.len()) };
-
-        apply_aggregation(
-            agg_idxs,
-            &chunk,
-            self.number_of_aggs(),
-            &self.aggregation_series,
-            &agg_fns,
-            &mut aggregators,
-        );
         self.aggregation_series.clear();
         self.hashes = hashes;
         self.keys = keys;
@@ -581,6 +611,37 @@ pub(super) fn apply_aggregate(
     }
 }
 
+/// Get the offset of the aggregators of the key of `entry`, inserting the key and its
+/// aggregators if it is new.
+#[inline]
+fn insert_agg_idx(
+    entry: RawEntryMut<'_, Key, IdxSize, IdBuildHasher>,
+    key_val: Option<&str>,
+    h: u64,
+    keys: &mut Vec<Option<PlSmallStr>>,
+    aggregators: &mut Vec<AggregateFunction>,
+    agg_fns: &[AggregateFunction],
+) -> IdxSize {
+    match entry {
+        RawEntryMut::Vacant(entry) => {
+            let value_offset =
+                unsafe { NumCast::from(aggregators.len()).unwrap_unchecked_release() };
+            let keys_offset =
+                unsafe { Key::new(h, NumCast::from(keys.len()).unwrap_unchecked_release()) };
+            entry.insert(keys_offset, value_offset);
+
+            keys.push(key_val.map(|s| s.into()));
+
+            // initialize the aggregators
+            for agg_fn in agg_fns {
+                aggregators.push(agg_fn.split())
+            }
+            value_offset
+        },
+        RawEntryMut::Occupied(entry) => *entry.get(),
+    }
+}
+
 #[inline]
 fn get_entry<'a>(
     key_val: Option<&str>,
@@ -1,5 +1,6 @@
 use std::any::Any;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
@@ -7,6 +8,8 @@ use polars_core::prelude::*;
 use polars_io::parquet::write::{
     BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
 };
+use polars_io::partition::get_hive_path_part;
+use polars_utils::aliases::PlHashMap;
 
 use crate::executors::sinks::output::file_sink::{init_writer_thread, FilesSink, SinkWriter};
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
@@ -139,6 +142,105 @@ impl Sink for ParquetSink {
     }
 }
 
+/// Streams its input into a hive partitioned parquet dataset, with a single file for every
+/// distinct value of the partition columns. The file of a partition is created when its first
+/// rows arrive and finished once the input is exhausted, so only the open writers are kept in
+/// memory.
+#[derive(Clone)]
+pub struct PartitionedParquetSink {
+    path: Arc<PathBuf>,
+    partition_by: Arc<[PlSmallStr]>,
+    partition_by_col_idx: Arc<[usize]>,
+    options: ParquetWriteOptions,
+    schema: SchemaRef,
+    writers: Arc<Mutex<PlHashMap<String, Arc<BatchedWriter<std::fs::File>>>>>,
+}
+
+impl PartitionedParquetSink {
+    pub fn new(
+        path: &Path,
+        partition_by: &[PlSmallStr],
+        options: ParquetWriteOptions,
+        schema: &Schema,
+    ) -> PolarsResult<Self> {
+        let partition_by_col_idx: Arc<[usize]> = partition_by
+            .iter()
+            .map(|name| schema.try_index_of(name))
+            .collect::<PolarsResult<_>>()?;
+        Ok(Self {
+            path: Arc::new(path.to_path_buf()),
+            partition_by: partition_by.into(),
+            partition_by_col_idx,
+            options,
+            schema: Arc::new(schema.clone()),
+            writers: Default::default(),
+        })
+    }
+
+    /// The writer of the partition at `path_part`, created on first use.
+    fn writer(&self, path_part: String) -> PolarsResult<Arc<BatchedWriter<std::fs::File>>> {
+        let mut writers = self.writers.lock().unwrap();
+        if let Some(writer) = writers.get(&path_part) {
+            return Ok(writer.clone());
+        }
+        let dir = self.path.join(&path_part);
+        std::fs::create_dir_all(&dir)?;
+        let file = std::fs::File::create(dir.join("00000000.parquet"))?;
+        let writer = ParquetWriter::new(file)
+            .with_compression(self.options.compression)
+            .with_data_page_size(self.options.data_page_size)
+            .with_statistics(self.options.statistics)
+            .with_row_group_size(self.options.row_group_size)
+            // The row groups are encoded on the pipeline threads.
+            .set_parallel(false)
+            .batched(&self.schema)?;
+        let writer = Arc::new(writer);
+        writers.insert(path_part, writer.clone());
+        Ok(writer)
+    }
+}
+
+impl Sink for PartitionedParquetSink {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        for part in chunk
+            .data
+            .partition_by(self.partition_by.iter().cloned(), true)?
+        {
+            let writer = self.writer(get_hive_path_part(&part, &self.partition_by_col_idx))?;
+            let row_groups = writer
+                .encode_and_compress(&part)
+                .collect::<PolarsResult<Vec<_>>>()?;
+            writer.write_row_groups(row_groups)?;
+        }
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, _other: &mut dyn Sink) {
+        // Nothing to do, the writers are shared.
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(self.clone())
+    }
+
+    fn finalize(&mut self, _context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        let writers = std::mem::take(&mut *self.writers.lock().unwrap());
+        for writer in writers.into_values() {
+            writer.finish()?;
+        }
+        // return a dummy dataframe;
+        Ok(FinalizedSink::Finished(Default::default()))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "partitioned_parquet_sink"
+    }
+}
+
 #[cfg(feature = "cloud")]
 pub struct ParquetCloudSink {}
 #[cfg(feature = "cloud")]
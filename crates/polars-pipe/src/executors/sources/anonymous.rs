@@ -0,0 +1,110 @@
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_core::POOL;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs};
+use polars_plan::prelude::FileScanOptions;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::IdxSize;
+
+use crate::executors::sources::get_source_index;
+use crate::operators::{DataChunk, PExecutionContext, Source, SourceResult};
+
+/// Streams the batches produced by [`AnonymousScan::next_batch`].
+pub struct AnonymousSource {
+    function: Arc<dyn AnonymousScan>,
+    schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    /// Number of rows that still have to be skipped.
+    offset: usize,
+    /// Number of rows that may still be produced.
+    n_rows: Option<usize>,
+    n_threads: usize,
+    finished: bool,
+}
+
+impl AnonymousSource {
+    pub(crate) fn new(
+        function: Arc<dyn AnonymousScan>,
+        schema: SchemaRef,
+        output_schema: Option<SchemaRef>,
+        file_options: FileScanOptions,
+    ) -> Self {
+        // Negative offsets are not streamed, see `insert_streaming_nodes`.
+        let (offset, n_rows) = match file_options.slice {
+            Some((offset, len)) => (offset as usize, Some(len)),
+            None => (0, None),
+        };
+        Self {
+            function,
+            schema,
+            output_schema,
+            with_columns: file_options.with_columns,
+            offset,
+            n_rows,
+            n_threads: POOL.current_num_threads(),
+            finished: false,
+        }
+    }
+
+    fn next_batch(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.finished || self.n_rows == Some(0) {
+            return Ok(None);
+        }
+        let args = AnonymousScanArgs {
+            n_rows: self.n_rows.map(|n_rows| n_rows + self.offset),
+            with_columns: self.with_columns.clone(),
+            schema: self.schema.clone(),
+            output_schema: self.output_schema.clone(),
+            predicate: None,
+        };
+        let Some(mut df) = self.function.next_batch(args)? else {
+            self.finished = true;
+            return Ok(None);
+        };
+        if self.offset > 0 {
+            let skip = self.offset.min(df.height());
+            df = df.slice(skip as i64, df.height() - skip);
+            self.offset -= skip;
+        }
+        if let Some(n_rows) = &mut self.n_rows {
+            df = df.head(Some(*n_rows));
+            *n_rows -= df.height();
+        }
+        Ok(Some(df))
+    }
+}
+
+impl Source for AnonymousSource {
+    fn get_batches(&mut self, _context: &PExecutionContext) -> PolarsResult<SourceResult> {
+        let mut chunks = Vec::with_capacity(self.n_threads);
+        while chunks.len() < self.n_threads {
+            match self.next_batch()? {
+                Some(data) if data.height() == 0 => continue,
+                Some(data) => chunks.push(data),
+                None => break,
+            }
+        }
+        if chunks.is_empty() {
+            return Ok(SourceResult::Finished);
+        }
+
+        let idx_offset = get_source_index(chunks.len() as u32);
+        let chunks = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, data)| DataChunk {
+                chunk_index: (idx_offset + i as u32) as IdxSize,
+                data,
+            })
+            .collect();
+        Ok(SourceResult::GotMoreData(chunks))
+    }
+
+    fn fmt(&self) -> &str {
+        "anonymous"
+    }
+}
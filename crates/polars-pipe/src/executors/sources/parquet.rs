@@ -12,7 +12,9 @@ use polars_core::prelude::Series;
 use polars_core::POOL;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::metadata::FileMetadataRef;
-use polars_io::parquet::read::{BatchedParquetReader, ParquetOptions, ParquetReader};
+use polars_io::parquet::read::{
+    BatchedParquetReader, ParquetOptions, ParquetReader, SkippedRowGroups,
+};
 use polars_io::path_utils::is_cloud_url;
 use polars_io::pl_async::get_runtime;
 use polars_io::predicates::PhysicalIoExpr;
@@ -38,6 +40,8 @@ pub struct ParquetSource {
     iter: Range<usize>,
     sources: ScanSources,
     options: ParquetOptions,
+    /// Where the skipped row groups are recorded, if corrupt row groups are skipped.
+    skipped_row_groups: Option<SkippedRowGroups>,
     file_options: FileScanOptions,
     #[allow(dead_code)]
     cloud_options: Option<CloudOptions>,
@@ -130,6 +134,10 @@ impl ParquetSource {
                 }
             }
 
+            if options.use_field_ids {
+                reader = reader.with_field_ids(self.first_metadata.as_ref().unwrap())?;
+            }
+
             let mut reader = reader
                 .with_arrow_schema_projection(
                     &self.first_schema,
@@ -138,6 +146,11 @@ impl ParquetSource {
                 .with_row_index(file_options.row_index)
                 .with_predicate(predicate.clone())
                 .use_statistics(options.use_statistics)
+                .skip_corrupt_row_groups(
+                    self.skipped_row_groups
+                        .as_ref()
+                        .map(|skipped| skipped.for_source(path.to_string_lossy().into())),
+                )
                 .with_hive_partition_columns(hive_partitions)
                 .with_include_file_path(
                     self.file_options
@@ -193,23 +206,33 @@ impl ParquetSource {
             let uri = path.to_string_lossy();
 
             let mut async_reader =
-                ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref(), metadata)
-                    .await?
-                    .with_row_index(file_options.row_index)
-                    .with_arrow_schema_projection(
-                        &self.first_schema,
-                        self.projected_arrow_schema.as_deref(),
-                    )
-                    .await?
-                    .with_predicate(predicate.clone())
-                    .use_statistics(options.use_statistics)
-                    .with_hive_partition_columns(hive_partitions)
-                    .with_include_file_path(
-                        self.file_options
-                            .include_file_paths
-                            .as_ref()
-                            .map(|x| (x.clone(), Arc::from(path.to_str().unwrap()))),
-                    );
+                ParquetAsyncReader::from_uri(&uri, cloud_options.as_ref(), metadata).await?;
+            if options.use_field_ids {
+                async_reader = async_reader
+                    .with_field_ids(self.first_metadata.as_ref().unwrap())
+                    .await?;
+            }
+            let mut async_reader = async_reader
+                .with_row_index(file_options.row_index)
+                .with_arrow_schema_projection(
+                    &self.first_schema,
+                    self.projected_arrow_schema.as_deref(),
+                )
+                .await?
+                .with_predicate(predicate.clone())
+                .use_statistics(options.use_statistics)
+                .skip_corrupt_row_groups(
+                    self.skipped_row_groups
+                        .as_ref()
+                        .map(|skipped| skipped.for_source(path.to_string_lossy().into())),
+                )
+                .with_hive_partition_columns(hive_partitions)
+                .with_include_file_path(
+                    self.file_options
+                        .include_file_paths
+                        .as_ref()
+                        .map(|x| (x.clone(), Arc::from(path.to_str().unwrap()))),
+                );
 
             let n_rows_this_file = async_reader.num_rows().await?;
             let current_row_offset = self
@@ -240,6 +263,7 @@ impl ParquetSource {
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
         first_metadata: Option<FileMetadataRef>,
+        skipped_row_groups: Option<SkippedRowGroups>,
         file_options: FileScanOptions,
         file_info: FileInfo,
         hive_parts: Option<Arc<Vec<HivePartitions>>>,
@@ -259,6 +283,29 @@ impl ParquetSource {
         }
         let run_async = paths.first().map(is_cloud_url).unwrap_or(false) || config::force_async();
 
+        // The field ids of the first file resolve the columns of all files.
+        let first_metadata = match first_metadata {
+            None if options.use_field_ids && !run_async => {
+                let file = polars_utils::open_file(&paths[0])?;
+                Some(ParquetReader::new(file).get_metadata()?.clone())
+            },
+            #[cfg(feature = "async")]
+            None if options.use_field_ids => {
+                Some(get_runtime().block_on_potential_spawn(async {
+                    ParquetAsyncReader::from_uri(
+                        &paths[0].to_string_lossy(),
+                        cloud_options.as_ref(),
+                        None,
+                    )
+                    .await?
+                    .get_metadata()
+                    .await
+                    .cloned()
+                })?)
+            },
+            first_metadata => first_metadata,
+        };
+
         let first_schema = file_info.reader_schema.clone().unwrap().unwrap_left();
 
         let projected_arrow_schema = {
@@ -275,6 +322,9 @@ impl ParquetSource {
             processed_paths: 0,
             processed_rows: AtomicUsize::new(0),
             options,
+            skipped_row_groups: options
+                .skip_corrupt_row_groups
+                .then(|| skipped_row_groups.unwrap_or_default()),
             file_options,
             iter,
             sources,
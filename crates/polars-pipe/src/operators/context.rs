@@ -7,7 +7,7 @@ pub struct PExecutionContext {
 }
 
 impl PExecutionContext {
-    pub(crate) fn new(state: ExecutionState, verbose: bool) -> Self {
+    pub fn new(state: ExecutionState, verbose: bool) -> Self {
         PExecutionContext {
             execution_state: state,
             verbose,
@@ -9,4 +9,4 @@ pub use context::*;
 pub(crate) use operator::*;
 pub(crate) use polars_core::prelude::*;
 pub use sink::*;
-pub(crate) use source::*;
+pub use source::*;
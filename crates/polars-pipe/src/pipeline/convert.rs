@@ -117,6 +117,7 @@ where
                     options: parquet_options,
                     cloud_options,
                     metadata,
+                    skipped_row_groups,
                 } => {
                     let predicate = predicate
                         .as_ref()
@@ -150,6 +151,7 @@ where
                         parquet_options,
                         cloud_options,
                         metadata,
+                        skipped_row_groups,
                         file_options,
                         file_info,
                         hive_parts,
@@ -158,6 +160,16 @@ where
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
                 },
+                FileScan::Anonymous { function, .. } => {
+                    let src = sources::AnonymousSource::new(
+                        function,
+                        file_info.schema,
+                        output_schema,
+                        file_options,
+                    );
+                    Ok(Box::new(src) as Box<dyn Source>)
+                },
+                #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
         },
@@ -213,6 +225,24 @@ where
                         _ => unreachable!(),
                     }
                 },
+                #[allow(unused_variables)]
+                SinkType::Partitioned {
+                    path,
+                    file_type,
+                    partition_by,
+                } => match &file_type {
+                    #[cfg(feature = "parquet")]
+                    FileType::Parquet(options) => Box::new(PartitionedParquetSink::new(
+                        path.as_ref().as_path(),
+                        partition_by,
+                        *options,
+                        input_schema.as_ref(),
+                    )?) as Box<dyn SinkTrait>,
+                    #[allow(unreachable_patterns)]
+                    other_file_type => polars_bail!(
+                        nyi = "partitioned sinking of the file type {other_file_type:?}"
+                    ),
+                },
                 #[cfg(feature = "cloud")]
                 SinkType::Cloud {
                     #[cfg(any(feature = "parquet", feature = "ipc"))]
@@ -64,4 +64,26 @@ impl BinaryNameSpace {
         self.0
             .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Base64Encode))
     }
+
+    /// Encrypt the values with the key `key_id` of the registered key provider.
+    ///
+    /// If `deterministic` is set, equal values produce equal ciphertexts, so the encrypted
+    /// column can still be joined and grouped on.
+    #[cfg(feature = "binary_encryption")]
+    pub fn encrypt(self, key_id: &str, deterministic: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Encrypt {
+                key_id: key_id.into(),
+                deterministic,
+            }))
+    }
+
+    /// Decrypt values that were encrypted with [`encrypt`](Self::encrypt).
+    #[cfg(feature = "binary_encryption")]
+    pub fn decrypt(self, key_id: &str) -> Expr {
+        self.0
+            .map_private(FunctionExpr::BinaryExpr(BinaryFunction::Decrypt {
+                key_id: key_id.into(),
+            }))
+    }
 }
@@ -8,4 +8,9 @@ impl CategoricalNameSpace {
         self.0
             .apply_private(CategoricalFunction::GetCategories.into())
     }
+
+    /// Drop the categories that don't occur in the column from its mapping.
+    pub fn compact(self) -> Expr {
+        self.0.map_private(CategoricalFunction::Compact.into())
+    }
 }
@@ -250,6 +250,42 @@ impl DateLikeNameSpace {
         )
     }
 
+    /// Assign every Datetime/Date to the tumbling window of length `every`, shifted by `offset`,
+    /// that contains it. Returns a struct with the `start` and `end` of the window.
+    #[cfg(feature = "event_windows")]
+    pub fn tumbling_window(self, every: Duration, offset: Duration) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::EventWindow(
+                EventWindow::Tumbling { every, offset },
+            )))
+    }
+
+    /// Assign every Datetime/Date to all windows of length `period` that start every `every`,
+    /// shifted by `offset`, that contain it. Returns a list of structs with the `start` and `end`
+    /// of the windows, explode it to get a row per window.
+    #[cfg(feature = "event_windows")]
+    pub fn hopping_window(self, every: Duration, period: Duration, offset: Duration) -> Expr {
+        self.0
+            .map_private(FunctionExpr::TemporalExpr(TemporalFunction::EventWindow(
+                EventWindow::Hopping {
+                    every,
+                    period,
+                    offset,
+                },
+            )))
+    }
+
+    /// Assign every Datetime/Date to its session: the run of sorted values that follow each
+    /// other by at most `gap`. Returns a struct with the first value of the session as `start`
+    /// and `gap` after its last value as `end`. Use it with `over` to find the sessions per key.
+    #[cfg(feature = "event_windows")]
+    pub fn session_window(self, gap: Duration) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::TemporalExpr(TemporalFunction::EventWindow(
+                EventWindow::Session { gap },
+            )))
+    }
+
     /// Offset this `Date/Datetime` by a given offset [`Duration`].
     /// This will take leap years/ months into account.
     #[cfg(feature = "offset_by")]
@@ -18,6 +18,15 @@ pub enum BinaryFunction {
     Base64Decode(bool),
     #[cfg(feature = "binary_encoding")]
     Base64Encode,
+    #[cfg(feature = "binary_encryption")]
+    Encrypt {
+        key_id: PlSmallStr,
+        deterministic: bool,
+    },
+    #[cfg(feature = "binary_encryption")]
+    Decrypt {
+        key_id: PlSmallStr,
+    },
     Size,
 }
 
@@ -31,6 +40,8 @@ impl BinaryFunction {
             HexDecode(_) | Base64Decode(_) => mapper.with_same_dtype(),
             #[cfg(feature = "binary_encoding")]
             HexEncode | Base64Encode => mapper.with_dtype(DataType::String),
+            #[cfg(feature = "binary_encryption")]
+            Encrypt { .. } | Decrypt { .. } => mapper.with_dtype(DataType::Binary),
             Size => mapper.with_dtype(DataType::UInt32),
         }
     }
@@ -51,6 +62,10 @@ impl Display for BinaryFunction {
             Base64Decode(_) => "base64_decode",
             #[cfg(feature = "binary_encoding")]
             Base64Encode => "base64_encode",
+            #[cfg(feature = "binary_encryption")]
+            Encrypt { .. } => "encrypt",
+            #[cfg(feature = "binary_encryption")]
+            Decrypt { .. } => "decrypt",
             Size => "size_bytes",
         };
         write!(f, "bin.{s}")
@@ -78,6 +93,13 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Base64Decode(strict) => map!(base64_decode, strict),
             #[cfg(feature = "binary_encoding")]
             Base64Encode => map!(base64_encode),
+            #[cfg(feature = "binary_encryption")]
+            Encrypt {
+                key_id,
+                deterministic,
+            } => map!(encrypt, &key_id, deterministic),
+            #[cfg(feature = "binary_encryption")]
+            Decrypt { key_id } => map!(decrypt, &key_id),
             Size => map!(size_bytes),
         }
     }
@@ -141,6 +163,21 @@ pub(super) fn base64_encode(s: &Column) -> PolarsResult<Column> {
     Ok(ca.base64_encode().into())
 }
 
+#[cfg(feature = "binary_encryption")]
+pub(super) fn encrypt(s: &Column, key_id: &str, deterministic: bool) -> PolarsResult<Column> {
+    let ca = match s.dtype() {
+        DataType::String => s.str()?.as_binary(),
+        _ => s.binary()?.clone(),
+    };
+    ca.encrypt(key_id, deterministic).map(|ok| ok.into_column())
+}
+
+#[cfg(feature = "binary_encryption")]
+pub(super) fn decrypt(s: &Column, key_id: &str) -> PolarsResult<Column> {
+    let ca = s.binary()?;
+    ca.decrypt(key_id).map(|ok| ok.into_column())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
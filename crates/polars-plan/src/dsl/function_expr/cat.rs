@@ -5,6 +5,7 @@ use crate::map;
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum CategoricalFunction {
     GetCategories,
+    Compact,
 }
 
 impl CategoricalFunction {
@@ -12,6 +13,7 @@ impl CategoricalFunction {
         use CategoricalFunction::*;
         match self {
             GetCategories => mapper.with_dtype(DataType::String),
+            Compact => mapper.with_same_dtype(),
         }
     }
 }
@@ -21,6 +23,7 @@ impl Display for CategoricalFunction {
         use CategoricalFunction::*;
         let s = match self {
             GetCategories => "get_categories",
+            Compact => "compact",
         };
         write!(f, "cat.{s}")
     }
@@ -31,6 +34,7 @@ impl From<CategoricalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
         use CategoricalFunction::*;
         match func {
             GetCategories => map!(get_categories),
+            Compact => map!(compact),
         }
     }
 }
@@ -48,3 +52,8 @@ fn get_categories(s: &Column) -> PolarsResult<Column> {
     let arr = rev_map.get_categories().clone().boxed();
     Series::try_from((ca.name().clone(), arr)).map(Column::from)
 }
+
+fn compact(s: &Column) -> PolarsResult<Column> {
+    let ca = s.categorical()?;
+    Ok(ca.compact().into_series().into())
+}
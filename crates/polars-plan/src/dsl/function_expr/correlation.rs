@@ -9,6 +9,8 @@ pub enum CorrelationMethod {
     Pearson,
     #[cfg(all(feature = "rank", feature = "propagate_nans"))]
     SpearmanRank(bool),
+    #[cfg(feature = "rank")]
+    KendallTau,
     Covariance,
 }
 
@@ -19,6 +21,8 @@ impl Display for CorrelationMethod {
             Pearson => "pearson",
             #[cfg(all(feature = "rank", feature = "propagate_nans"))]
             SpearmanRank(_) => "spearman_rank",
+            #[cfg(feature = "rank")]
+            KendallTau => "kendall_tau",
             Covariance => return write!(f, "covariance"),
         };
         write!(f, "{}_correlation", s)
@@ -32,6 +36,8 @@ pub(super) fn corr(s: &[Column], ddof: u8, method: CorrelationMethod) -> PolarsR
         CorrelationMethod::SpearmanRank(propagate_nans) => {
             spearman_rank_corr(s, ddof, propagate_nans)
         },
+        #[cfg(feature = "rank")]
+        CorrelationMethod::KendallTau => kendall_tau_corr(s),
         CorrelationMethod::Covariance => covariance(s, ddof),
     }
 }
@@ -136,3 +142,17 @@ fn spearman_rank_corr(s: &[Column], ddof: u8, propagate_nans: bool) -> PolarsRes
 
     pearson_corr(&[a_rank, b_rank], ddof)
 }
+
+#[cfg(feature = "rank")]
+fn kendall_tau_corr(s: &[Column]) -> PolarsResult<Column> {
+    let a = &s[0];
+    let b = &s[1];
+    let name = PlSmallStr::from_static("kendall_tau_correlation");
+
+    let ret =
+        polars_ops::series::kendall_tau(a.as_materialized_series(), b.as_materialized_series())?;
+    if a.dtype() == &DataType::Float32 {
+        return Ok(Column::new(name, &[ret.map(|v| v as f32)]));
+    }
+    Ok(Column::new(name, &[ret]))
+}
@@ -1,11 +1,13 @@
 #[cfg(feature = "timezones")]
-use chrono_tz::Tz;
+use polars_core::chunked_array::temporal::parse_time_zone;
 #[cfg(feature = "timezones")]
 use polars_core::chunked_array::temporal::validate_time_zone;
 #[cfg(feature = "timezones")]
 use polars_time::base_utc_offset as base_utc_offset_fn;
 #[cfg(feature = "timezones")]
 use polars_time::dst_offset as dst_offset_fn;
+#[cfg(feature = "event_windows")]
+use polars_time::event_window as event_window_fn;
 #[cfg(feature = "offset_by")]
 use polars_time::impl_offset_by;
 #[cfg(feature = "serde")]
@@ -69,6 +71,8 @@ pub enum TemporalFunction {
         time_unit: TimeUnit,
         time_zone: Option<TimeZone>,
     },
+    #[cfg(feature = "event_windows")]
+    EventWindow(EventWindow),
 }
 
 impl TemporalFunction {
@@ -133,6 +137,13 @@ impl TemporalFunction {
                     polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
                 },
             }),
+            #[cfg(feature = "event_windows")]
+            EventWindow(window) => mapper.try_map_dtype(|dt| match dt {
+                DataType::Datetime(_, _) | DataType::Date => Ok(window.output_dtype(dt)),
+                dtype => {
+                    polars_bail!(ComputeError: "expected Date or Datetime, got {}", dtype)
+                },
+            }),
         }
     }
 }
@@ -191,6 +202,12 @@ impl Display for TemporalFunction {
             ReplaceTimeZone(_, _) => "replace_time_zone",
             DatetimeFunction { .. } => return write!(f, "dt.datetime"),
             Combine(_) => "combine",
+            #[cfg(feature = "event_windows")]
+            EventWindow(window) => match window {
+                polars_time::EventWindow::Tumbling { .. } => "tumbling_window",
+                polars_time::EventWindow::Hopping { .. } => "hopping_window",
+                polars_time::EventWindow::Session { .. } => "session_window",
+            },
         };
         write!(f, "dt.{s}")
     }
@@ -446,7 +463,7 @@ pub(super) fn truncate(s: &[Column]) -> PolarsResult<Column> {
             #[cfg(feature = "timezones")]
             Some(tz) => time_series
                 .datetime()?
-                .truncate(tz.parse::<Tz>().ok().as_ref(), every)?
+                .truncate(parse_time_zone(tz).ok().as_ref(), every)?
                 .into_column(),
             _ => time_series.datetime()?.truncate(None, every)?.into_column(),
         },
@@ -470,7 +487,7 @@ pub(super) fn month_start(s: &Column) -> PolarsResult<Column> {
             Some(tz) => s
                 .datetime()
                 .unwrap()
-                .month_start(tz.parse::<Tz>().ok().as_ref())?
+                .month_start(parse_time_zone(tz).ok().as_ref())?
                 .into_column(),
             _ => s.datetime().unwrap().month_start(None)?.into_column(),
         },
@@ -487,7 +504,7 @@ pub(super) fn month_end(s: &Column) -> PolarsResult<Column> {
             Some(tz) => s
                 .datetime()
                 .unwrap()
-                .month_end(tz.parse::<Tz>().ok().as_ref())?
+                .month_end(parse_time_zone(tz).ok().as_ref())?
                 .into_column(),
             _ => s.datetime().unwrap().month_end(None)?.into_column(),
         },
@@ -500,9 +517,7 @@ pub(super) fn month_end(s: &Column) -> PolarsResult<Column> {
 pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
         DataType::Datetime(time_unit, Some(tz)) => {
-            let tz = tz
-                .parse::<Tz>()
-                .expect("Time zone has already been validated");
+            let tz = parse_time_zone(tz)?;
             Ok(base_utc_offset_fn(s.datetime().unwrap(), time_unit, &tz).into_column())
         },
         dt => polars_bail!(
@@ -516,9 +531,7 @@ pub(super) fn base_utc_offset(s: &Column) -> PolarsResult<Column> {
 pub(super) fn dst_offset(s: &Column) -> PolarsResult<Column> {
     match s.dtype() {
         DataType::Datetime(time_unit, Some(tz)) => {
-            let tz = tz
-                .parse::<Tz>()
-                .expect("Time zone has already been validated");
+            let tz = parse_time_zone(tz)?;
             Ok(dst_offset_fn(s.datetime().unwrap(), time_unit, &tz).into_column())
         },
         dt => polars_bail!(
@@ -529,6 +542,26 @@ pub(super) fn dst_offset(s: &Column) -> PolarsResult<Column> {
     }
 }
 
+#[cfg(feature = "event_windows")]
+pub(super) fn event_window(s: &Column, window: EventWindow) -> PolarsResult<Column> {
+    let out = match s.dtype() {
+        DataType::Datetime(_, tz) => match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => {
+                event_window_fn(s.datetime().unwrap(), window, Some(&parse_time_zone(tz)?))?
+            },
+            _ => event_window_fn(s.datetime().unwrap(), window, None)?,
+        },
+        DataType::Date => {
+            let ms = s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?;
+            event_window_fn(ms.datetime().unwrap(), window, None)?
+                .cast(&window.output_dtype(&DataType::Date))?
+        },
+        dt => polars_bail!(opq = event_window, got = dt, expected = "date/datetime"),
+    };
+    Ok(out.into_column())
+}
+
 pub(super) fn round(s: &[Column]) -> PolarsResult<Column> {
     let time_series = &s[0];
     let every = s[1].str()?;
@@ -539,7 +572,7 @@ pub(super) fn round(s: &[Column]) -> PolarsResult<Column> {
             Some(tz) => time_series
                 .datetime()
                 .unwrap()
-                .round(every, tz.parse::<Tz>().ok().as_ref())?
+                .round(every, parse_time_zone(tz).ok().as_ref())?
                 .into_column(),
             _ => time_series
                 .datetime()
@@ -206,6 +206,143 @@ pub(super) fn fill_null_with_strategy(
     s.fill_null(strategy)
 }
 
+pub(super) fn mask_nulls(s: &[Column]) -> PolarsResult<Column> {
+    let mask = s[1].bool()?;
+    polars_ops::series::mask_nulls(s[0].as_materialized_series(), mask).map(Column::from)
+}
+
+#[cfg(feature = "to_dummies")]
+pub(super) fn one_hot(s: &Column, encoder: &OneHotEncoder) -> PolarsResult<Column> {
+    encoder
+        .transform(s.as_materialized_series())
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "target_encoding")]
+pub(super) fn target_encode(s: &Column, encoder: &TargetEncoder) -> PolarsResult<Column> {
+    encoder
+        .transform(s.as_materialized_series())
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "target_encoding")]
+pub(super) fn target_encode_out_of_fold(s: &[Column], smoothing: f64) -> PolarsResult<Column> {
+    polars_ops::series::target_encode_out_of_fold(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        s[2].as_materialized_series(),
+        smoothing,
+    )
+    .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "scale")]
+pub(super) fn scale(s: &Column, method: ScaleMethod) -> PolarsResult<Column> {
+    polars_ops::series::scale(s.as_materialized_series(), method).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "scale")]
+pub(super) fn scale_with(s: &Column, params: &ScaleParams) -> PolarsResult<Column> {
+    params
+        .transform(s.as_materialized_series())
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "scale")]
+pub(super) fn scale_fit(s: &Column, method: ScaleMethod) -> PolarsResult<Column> {
+    let params = ScaleParams::fit(s.as_materialized_series(), method)?;
+    ScaleParams::into_struct(params, s.name().clone()).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn is_outlier(s: &Column, method: OutlierMethod) -> PolarsResult<Column> {
+    polars_ops::series::is_outlier(s.as_materialized_series(), method).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn modified_zscore(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::modified_zscore(s.as_materialized_series()).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "outliers")]
+pub(super) fn isolation_forest_score(
+    s: &[Column],
+    options: &IsolationForestOptions,
+) -> PolarsResult<Column> {
+    let columns = s
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect::<Vec<_>>();
+    polars_ops::series::isolation_forest_score(&columns, options).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "hypothesis_test")]
+pub(super) fn hypothesis_test(s: &[Column], test: HypothesisTest) -> PolarsResult<Column> {
+    polars_ops::series::hypothesis_test(
+        s[0].as_materialized_series(),
+        s[1].as_materialized_series(),
+        test,
+    )
+    .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "assertions")]
+pub(super) fn assert(s: &[Column], message: &str) -> PolarsResult<Column> {
+    // The number of failing rows that are shown in the error.
+    const N_SAMPLES: usize = 5;
+
+    let values = &s[0];
+    let predicate = s[1].bool()?;
+    polars_ensure!(
+        predicate.len() == values.len() || predicate.len() == 1,
+        ShapeMismatch: "the predicate of assertion `{}` has length {}, expected {}",
+        message, predicate.len(), values.len()
+    );
+    // Like a check constraint, a null predicate does not fail.
+    let failed = (!predicate).fill_null_with_values(false)?;
+    if !failed.any() {
+        return Ok(values.clone());
+    }
+
+    let (n_failed, rows) = if failed.len() == 1 {
+        let rows = (0..values.len()).take(N_SAMPLES).collect::<Vec<_>>();
+        (values.len(), rows)
+    } else {
+        let rows = failed
+            .iter()
+            .enumerate()
+            .filter_map(|(row, failed)| failed.unwrap_or(false).then_some(row))
+            .take(N_SAMPLES)
+            .collect();
+        (failed.num_trues(), rows)
+    };
+    let mut samples = String::new();
+    for row in rows {
+        samples.push_str(&format!("\n  row {row}: {}", values.get(row)?));
+    }
+    polars_bail!(
+        ComputeError: "assertion `{}` failed for {} of {} values of `{}`, the first are:{}",
+        message, n_failed, values.len(), values.name(), samples
+    )
+}
+
+#[cfg(feature = "quantile_sketch")]
+pub(super) fn quantile_sketch(s: &Column, compression: f64) -> PolarsResult<Column> {
+    polars_ops::series::quantile_sketch(s.as_materialized_series(), compression)
+        .map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "quantile_sketch")]
+pub(super) fn quantile_sketch_merge(s: &Column) -> PolarsResult<Column> {
+    polars_ops::series::quantile_sketch_merge(s.as_materialized_series()).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "quantile_sketch")]
+pub(super) fn quantile_sketch_query(s: &Column, quantile: f64) -> PolarsResult<Column> {
+    polars_ops::series::quantile_sketch_query(s.as_materialized_series(), quantile)
+        .map(|ca| ca.into_column())
+}
+
 pub(super) fn gather_every(s: &Column, n: usize, offset: usize) -> PolarsResult<Column> {
     polars_ensure!(n > 0, InvalidOperation: "gather_every(n): n should be positive");
     Ok(s.gather_every(n, offset))
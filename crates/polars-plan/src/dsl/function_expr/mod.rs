@@ -158,6 +158,8 @@ pub enum FunctionExpr {
     Sign,
     FillNull,
     FillNullWithStrategy(FillNullStrategy),
+    /// Set the values of the first input at which the boolean second input is not `true` to null.
+    MaskNulls,
     #[cfg(feature = "rolling_window")]
     RollingExpr(RollingFunction),
     #[cfg(feature = "rolling_window_by")]
@@ -293,6 +295,42 @@ pub enum FunctionExpr {
         allow_duplicates: bool,
         include_breaks: bool,
     },
+    #[cfg(feature = "to_dummies")]
+    OneHot(OneHotEncoder),
+    #[cfg(feature = "target_encoding")]
+    TargetEncode(TargetEncoder),
+    #[cfg(feature = "target_encoding")]
+    TargetEncodeOutOfFold {
+        smoothing: f64,
+    },
+    #[cfg(feature = "scale")]
+    Scale(ScaleMethod),
+    #[cfg(feature = "scale")]
+    ScaleWith(ScaleParams),
+    #[cfg(feature = "scale")]
+    ScaleFit(ScaleMethod),
+    #[cfg(feature = "outliers")]
+    IsOutlier(OutlierMethod),
+    #[cfg(feature = "outliers")]
+    ModifiedZScore,
+    #[cfg(feature = "outliers")]
+    IsolationForest(IsolationForestOptions),
+    #[cfg(feature = "hypothesis_test")]
+    HypothesisTest(HypothesisTest),
+    #[cfg(feature = "assertions")]
+    Assert {
+        message: PlSmallStr,
+    },
+    #[cfg(feature = "quantile_sketch")]
+    QuantileSketch {
+        compression: f64,
+    },
+    #[cfg(feature = "quantile_sketch")]
+    QuantileSketchMerge,
+    #[cfg(feature = "quantile_sketch")]
+    QuantileSketchQuery {
+        quantile: f64,
+    },
     #[cfg(feature = "rle")]
     RLE,
     #[cfg(feature = "rle")]
@@ -545,6 +583,32 @@ impl Hash for FunctionExpr {
                 allow_duplicates.hash(state);
                 include_breaks.hash(state);
             },
+            #[cfg(feature = "to_dummies")]
+            OneHot(encoder) => encoder.hash(state),
+            #[cfg(feature = "target_encoding")]
+            TargetEncode(encoder) => encoder.hash(state),
+            #[cfg(feature = "target_encoding")]
+            TargetEncodeOutOfFold { smoothing } => smoothing.to_bits().hash(state),
+            #[cfg(feature = "scale")]
+            Scale(method) | ScaleFit(method) => method.hash(state),
+            #[cfg(feature = "scale")]
+            ScaleWith(params) => params.hash(state),
+            #[cfg(feature = "outliers")]
+            IsOutlier(method) => method.hash(state),
+            #[cfg(feature = "outliers")]
+            ModifiedZScore => {},
+            #[cfg(feature = "outliers")]
+            IsolationForest(options) => options.hash(state),
+            #[cfg(feature = "hypothesis_test")]
+            HypothesisTest(test) => test.hash(state),
+            #[cfg(feature = "assertions")]
+            Assert { message } => message.hash(state),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketch { compression } => compression.to_bits().hash(state),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchMerge => {},
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchQuery { quantile } => quantile.to_bits().hash(state),
             #[cfg(feature = "rle")]
             RLE => {},
             #[cfg(feature = "rle")]
@@ -575,6 +639,7 @@ impl Hash for FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => return_dtype.hash(state),
             FillNullWithStrategy(strategy) => strategy.hash(state),
+            MaskNulls => {},
             GatherEvery { n, offset } => (n, offset).hash(state),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => signed.hash(state),
@@ -728,6 +793,36 @@ impl Display for FunctionExpr {
             Cut { .. } => "cut",
             #[cfg(feature = "cutqcut")]
             QCut { .. } => "qcut",
+            #[cfg(feature = "to_dummies")]
+            OneHot(_) => "one_hot",
+            #[cfg(feature = "target_encoding")]
+            TargetEncode(_) => "target_encode",
+            #[cfg(feature = "target_encoding")]
+            TargetEncodeOutOfFold { .. } => "target_encode_out_of_fold",
+            #[cfg(feature = "scale")]
+            Scale(_) | ScaleWith(_) => "scale",
+            #[cfg(feature = "scale")]
+            ScaleFit(_) => "scale_params",
+            #[cfg(feature = "outliers")]
+            IsOutlier(_) => "is_outlier",
+            #[cfg(feature = "outliers")]
+            ModifiedZScore => "modified_zscore",
+            #[cfg(feature = "outliers")]
+            IsolationForest(_) => "isolation_forest_score",
+            #[cfg(feature = "hypothesis_test")]
+            HypothesisTest(test) => match test {
+                polars_ops::series::HypothesisTest::TTest { .. } => "t_test",
+                polars_ops::series::HypothesisTest::ChiSquare => "chi_square_test",
+                polars_ops::series::HypothesisTest::KolmogorovSmirnov => "ks_test",
+            },
+            #[cfg(feature = "assertions")]
+            Assert { .. } => "assert",
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketch { .. } => "quantile_sketch",
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchMerge => "quantile_sketch_merge",
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchQuery { .. } => "quantile_sketch_query",
             Reshape(_, _) => "reshape",
             #[cfg(feature = "repeat_by")]
             RepeatBy => "repeat_by",
@@ -762,6 +857,7 @@ impl Display for FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { .. } => "replace_strict",
             FillNullWithStrategy(_) => "fill_null_with_strategy",
+            MaskNulls => "mask_nulls",
             GatherEvery { .. } => "gather_every",
             #[cfg(feature = "reinterpret")]
             Reinterpret(_) => "reinterpret",
@@ -853,6 +949,41 @@ macro_rules! map {
     }};
 }
 
+impl FunctionExpr {
+    /// A copy of this function with the given `seed`, if the function is random and has no
+    /// seed of its own.
+    pub fn with_seed(&self, seed: u64) -> Option<FunctionExpr> {
+        use FunctionExpr::*;
+        match self {
+            #[cfg(feature = "random")]
+            Random { method, seed: None } => Some(Random {
+                method: *method,
+                seed: Some(seed),
+            }),
+            #[cfg(feature = "list_sample")]
+            ListExpr(ListFunction::Sample {
+                is_fraction,
+                with_replacement,
+                shuffle,
+                seed: None,
+            }) => Some(ListExpr(ListFunction::Sample {
+                is_fraction: *is_fraction,
+                with_replacement: *with_replacement,
+                shuffle: *shuffle,
+                seed: Some(seed),
+            })),
+            #[cfg(feature = "outliers")]
+            IsolationForest(options) if options.seed.is_none() => {
+                Some(IsolationForest(IsolationForestOptions {
+                    seed: Some(seed),
+                    ..*options
+                }))
+            },
+            _ => None,
+        }
+    }
+}
+
 impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
     fn from(func: FunctionExpr) -> Self {
         use FunctionExpr::*;
@@ -1097,6 +1228,36 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 allow_duplicates,
                 include_breaks
             ),
+            #[cfg(feature = "to_dummies")]
+            OneHot(encoder) => map!(dispatch::one_hot, &encoder),
+            #[cfg(feature = "target_encoding")]
+            TargetEncode(encoder) => map!(dispatch::target_encode, &encoder),
+            #[cfg(feature = "target_encoding")]
+            TargetEncodeOutOfFold { smoothing } => {
+                map_as_slice!(dispatch::target_encode_out_of_fold, smoothing)
+            },
+            #[cfg(feature = "scale")]
+            Scale(method) => map!(dispatch::scale, method),
+            #[cfg(feature = "scale")]
+            ScaleWith(params) => map!(dispatch::scale_with, &params),
+            #[cfg(feature = "scale")]
+            ScaleFit(method) => map!(dispatch::scale_fit, method),
+            #[cfg(feature = "outliers")]
+            IsOutlier(method) => map!(dispatch::is_outlier, method),
+            #[cfg(feature = "outliers")]
+            ModifiedZScore => map!(dispatch::modified_zscore),
+            #[cfg(feature = "outliers")]
+            IsolationForest(options) => map_as_slice!(dispatch::isolation_forest_score, &options),
+            #[cfg(feature = "hypothesis_test")]
+            HypothesisTest(test) => map_as_slice!(dispatch::hypothesis_test, test),
+            #[cfg(feature = "assertions")]
+            Assert { message } => map_as_slice!(dispatch::assert, &message),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketch { compression } => map!(dispatch::quantile_sketch, compression),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchMerge => map!(dispatch::quantile_sketch_merge),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchQuery { quantile } => map!(dispatch::quantile_sketch_query, quantile),
             #[cfg(feature = "rle")]
             RLE => map!(rle),
             #[cfg(feature = "rle")]
@@ -1118,6 +1279,14 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                             map_as_slice!(random::sample_n, with_replacement, shuffle, seed)
                         }
                     },
+                    FoldId { k, by_key } => map!(random::fold_id, k, by_key, seed),
+                    Split { by_key } => map_as_slice!(random::split, by_key, seed),
+                    WeightedSample {
+                        is_fraction,
+                        with_replacement,
+                    } => {
+                        map_as_slice!(random::weighted_sample, is_fraction, with_replacement, seed)
+                    },
                 }
             },
             SetSortedFlag(sorted) => map!(dispatch::set_sorted_flag, sorted),
@@ -1127,11 +1296,13 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
                 symbol,
                 kwargs,
             } => unsafe {
+                let state = plugin::PluginState::default();
                 map_as_slice!(
                     plugin::call_plugin,
                     lib.as_ref(),
                     symbol.as_ref(),
-                    kwargs.as_ref()
+                    kwargs.as_ref(),
+                    &state
                 )
             },
             BackwardFill { limit } => map!(dispatch::backward_fill, limit),
@@ -1158,6 +1329,7 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn ColumnsUdf>> {
             },
 
             FillNullWithStrategy(strategy) => map!(dispatch::fill_null_with_strategy, strategy),
+            MaskNulls => map_as_slice!(dispatch::mask_nulls),
             GatherEvery { n, offset } => map!(dispatch::gather_every, n, offset),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => map!(dispatch::reinterpret, signed),
@@ -1,9 +1,9 @@
 use std::ffi::CStr;
 use std::sync::RwLock;
 
-use arrow::ffi::{import_field_from_c, ArrowSchema};
+use arrow::ffi::{import_array_from_c, import_field_from_c, ArrowArray, ArrowSchema};
 use libloading::Library;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
 
 use super::*;
 
@@ -25,12 +25,29 @@ fn get_lib(lib: &str) -> PolarsResult<&'static PluginAndVersion> {
         let version_function: libloading::Symbol<unsafe extern "C" fn() -> u32> = unsafe {
             library
                 .get("_polars_plugin_get_version".as_bytes())
-                .unwrap()
+                .map_err(|e| {
+                    polars_err!(ComputeError: "dynamic library '{}' is not a polars plugin: {}", lib, e)
+                })?
         };
 
         let version = unsafe { version_function() };
         let major = (version >> 16) as u16;
         let minor = version as u16;
+        polars_ensure!(
+            polars_ffi::is_supported_version(major, minor),
+            ComputeError: "plugin '{}' was compiled for plugin ABI version {}.{}, but this Polars engine supports up to version {}.{}",
+            lib, major, minor, polars_ffi::MAJOR, polars_ffi::MINOR
+        );
+
+        // Plugins that want to know the capabilities of the engine export this symbol.
+        if let Ok(set_host_info) = unsafe {
+            library.get::<unsafe extern "C" fn(*const polars_ffi::HostInfo)>(
+                "_polars_plugin_set_host_info".as_bytes(),
+            )
+        } {
+            let host_info = polars_ffi::HostInfo::host();
+            unsafe { set_host_info(&host_info) };
+        }
 
         let mut lib_map = LOADED.write().unwrap();
         lib_map.insert(lib.to_string(), (library, major, minor));
@@ -47,11 +64,153 @@ unsafe fn retrieve_error_msg(lib: &Library) -> &CStr {
     CStr::from_ptr(msg_ptr)
 }
 
+struct StateHandle {
+    ptr: *mut std::os::raw::c_void,
+    drop: polars_ffi::version_0::StateDropFunction,
+}
+
+// The plugin is responsible for making its state thread safe.
+unsafe impl Send for StateHandle {}
+unsafe impl Sync for StateHandle {}
+
+impl Drop for StateHandle {
+    fn drop(&mut self) {
+        unsafe { (self.drop)(self.ptr) }
+    }
+}
+
+/// The state a plugin keeps across the batch calls of a single expression. It is created on the
+/// first call and released when the expression is dropped.
+#[derive(Default)]
+pub(super) struct PluginState(OnceCell<Option<StateHandle>>);
+
+impl PluginState {
+    unsafe fn get(
+        &self,
+        lib: &Library,
+        symbol: &str,
+        kwargs: &[u8],
+    ) -> PolarsResult<*mut std::os::raw::c_void> {
+        use polars_ffi::version_0::*;
+
+        let handle = self.0.get_or_try_init(|| {
+            let Ok(new) =
+                lib.get::<StateNewFunction>(format!("_polars_plugin_state_new_{}", symbol).as_bytes())
+            else {
+                return Ok(None);
+            };
+            let drop = lib
+                .get::<StateDropFunction>(format!("_polars_plugin_state_drop_{}", symbol).as_bytes())
+                .map_err(|e| {
+                    polars_err!(ComputeError: "plugin function '{}' has state but cannot drop it: {}", symbol, e)
+                })?;
+            let ptr = new(kwargs.as_ptr(), kwargs.len());
+            if ptr.is_null() {
+                let msg = retrieve_error_msg(lib);
+                let msg = msg.to_string_lossy();
+                check_panic(msg.as_ref())?;
+                polars_bail!(ComputeError: "the plugin failed to create its state with message: {}", msg)
+            }
+            Ok(Some(StateHandle { ptr, drop: *drop }))
+        })?;
+        Ok(handle
+            .as_ref()
+            .map_or(std::ptr::null_mut(), |handle| handle.ptr))
+    }
+}
+
+/// Call the [`BatchFunction`](polars_ffi::version_0::BatchFunction) of a plugin once per batch
+/// of aligned chunks of the inputs.
+unsafe fn call_batch_plugin(
+    s: &[Column],
+    lib: &Library,
+    symbol: &str,
+    kwargs: &[u8],
+    state: &PluginState,
+    batch_fn: polars_ffi::version_0::BatchFunction,
+) -> PolarsResult<Column> {
+    use polars_ffi::version_0::*;
+
+    let state = state.get(lib, symbol, kwargs)?;
+    let mut inputs = s
+        .iter()
+        .map(|c| c.as_materialized_series().clone())
+        .collect::<Vec<_>>();
+    // Inputs of length one are passed whole with every batch.
+    for s in inputs.iter_mut().filter(|s| s.len() == 1) {
+        *s = s.rechunk();
+    }
+    let lengths = inputs
+        .iter()
+        .find(|s| s.len() != 1)
+        .map(|s| s.chunk_lengths().collect::<Vec<_>>());
+    if let Some(lengths) = &lengths {
+        let aligned = inputs
+            .iter()
+            .filter(|s| s.len() != 1)
+            .all(|s| s.chunk_lengths().eq(lengths.iter().copied()));
+        if !aligned {
+            for s in inputs.iter_mut() {
+                *s = s.rechunk();
+            }
+        }
+    }
+    let n_batches = inputs
+        .iter()
+        .find(|s| s.len() != 1)
+        .map_or(1, |s| s.chunks().len().max(1));
+
+    let context = CallerContext::default();
+    let mut name = None;
+    let mut chunks = Vec::with_capacity(n_batches);
+    for batch_idx in 0..n_batches {
+        let (mut arrays, schemas): (Vec<_>, Vec<_>) = inputs
+            .iter()
+            .map(|s| export_batch_array(s, if s.len() == 1 { 0 } else { batch_idx }))
+            .unzip();
+        let mut out_array = ArrowArray::empty();
+        let mut out_schema = ArrowSchema::empty();
+        batch_fn(
+            arrays.as_mut_ptr(),
+            schemas.as_ptr(),
+            arrays.len(),
+            kwargs.as_ptr(),
+            kwargs.len(),
+            state,
+            &mut out_array,
+            &mut out_schema,
+            &context,
+        );
+        // The inputs that the plugin didn't move out are released here.
+        drop(arrays);
+
+        if out_schema.is_null() {
+            let msg = retrieve_error_msg(lib);
+            let msg = msg.to_string_lossy();
+            check_panic(msg.as_ref())?;
+            polars_bail!(ComputeError: "the plugin failed with message: {}", msg)
+        }
+        let field = import_field_from_c(&out_schema)?;
+        if let Some(first) = chunks.first() {
+            let first: &ArrayRef = first;
+            polars_ensure!(
+                first.dtype() == field.dtype(),
+                ComputeError: "the plugin returned batches of different types: {:?} and {:?}",
+                first.dtype(), field.dtype()
+            );
+        }
+        chunks.push(import_array_from_c(out_array, field.dtype.clone())?);
+        name.get_or_insert(field.name);
+    }
+    Series::try_from((name.unwrap(), chunks)).map(Column::from)
+}
+
 pub(super) unsafe fn call_plugin(
     s: &[Column],
     lib: &str,
     symbol: &str,
     kwargs: &[u8],
+    state: &PluginState,
 ) -> PolarsResult<Column> {
     let plugin = get_lib(lib)?;
     let lib = &plugin.0;
@@ -59,6 +218,14 @@ pub(super) unsafe fn call_plugin(
 
     if major == 0 {
         use polars_ffi::version_0::*;
+
+        // Plugins that process whole batches export a batch entry point next to the regular one.
+        if let Ok(batch_fn) =
+            lib.get::<BatchFunction>(format!("_polars_plugin_batch_{}", symbol).as_bytes())
+        {
+            return call_batch_plugin(s, lib, symbol, kwargs, state, *batch_fn);
+        }
+
         // *const SeriesExport: pointer to Box<SeriesExport>
         // * usize: length of that pointer
         // *const u8: pointer to &[u8]
@@ -156,7 +323,7 @@ pub(super) unsafe fn plugin_field(
                     .unwrap();
                 symbol(slice_ptr, n_args, return_value_ptr);
             },
-            1 => {
+            1 | 2 => {
                 // *const ArrowSchema: pointer to heap Box<ArrowSchema>
                 // usize: length of the boxed slice
                 // *mut ArrowSchema: pointer where the return value can be written
@@ -15,6 +15,17 @@ pub enum RandomMethod {
         with_replacement: bool,
         shuffle: bool,
     },
+    FoldId {
+        k: IdxSize,
+        by_key: bool,
+    },
+    Split {
+        by_key: bool,
+    },
+    WeightedSample {
+        is_fraction: bool,
+        with_replacement: bool,
+    },
 }
 
 impl Hash for RandomMethod {
@@ -72,3 +83,84 @@ pub(super) fn sample_n(
         None => Ok(Column::new_empty(src.name().clone(), src.dtype())),
     }
 }
+
+pub(super) fn weighted_sample(
+    s: &[Column],
+    is_fraction: bool,
+    with_replacement: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    let src = &s[0];
+    let n_s = &s[1];
+    let weights = &s[2];
+
+    polars_ensure!(
+        n_s.len() == 1,
+        ComputeError: "Sample size must be a single value."
+    );
+    polars_ensure!(
+        weights.len() == src.len(),
+        ShapeMismatch: "sample weights must have the same length as the sampled values"
+    );
+
+    let n = if is_fraction {
+        let frac_s = n_s.cast(&Float64)?;
+        frac_s
+            .f64()?
+            .get(0)
+            .map(|frac| (src.len() as f64 * frac) as usize)
+    } else {
+        let n_s = n_s.cast(&IDX_DTYPE)?;
+        n_s.idx()?.get(0).map(|n| n as usize)
+    };
+    let Some(n) = n else {
+        return Ok(Column::new_empty(src.name().clone(), src.dtype()));
+    };
+    let idx = polars_ops::series::weighted_sample_idx(
+        weights.as_materialized_series(),
+        n,
+        with_replacement,
+        seed,
+    )?;
+    // SAFETY: the indices are within bounds.
+    Ok(unsafe { src.take_unchecked(&idx) })
+}
+
+pub(super) fn fold_id(
+    s: &Column,
+    k: IdxSize,
+    by_key: bool,
+    seed: Option<u64>,
+) -> PolarsResult<Column> {
+    let out = if by_key {
+        polars_ops::series::fold_id_by_key(s.as_materialized_series(), k, seed)?
+    } else {
+        polars_ops::series::fold_id(s.len(), k, seed)?
+    };
+    Ok(out.with_name(s.name().clone()).into_column())
+}
+
+pub(super) fn split(s: &[Column], by_key: bool, seed: Option<u64>) -> PolarsResult<Column> {
+    let src = &s[0];
+    let frac_s = &s[1];
+
+    polars_ensure!(
+        frac_s.len() == 1,
+        ComputeError: "Split fraction must be a single value."
+    );
+
+    let frac_s = frac_s.cast(&Float64)?;
+    let Some(frac) = frac_s.f64()?.get(0) else {
+        return Ok(Column::full_null(
+            src.name().clone(),
+            src.len(),
+            &DataType::Boolean,
+        ));
+    };
+    let out = if by_key {
+        polars_ops::series::split_mask_by_key(src.as_materialized_series(), frac, seed)?
+    } else {
+        polars_ops::series::split_mask(src.len(), frac, seed)?
+    };
+    Ok(out.with_name(src.name().clone()).into_column())
+}
@@ -283,6 +283,26 @@ impl FunctionExpr {
                 ]);
                 mapper.with_dtype(struct_dt)
             },
+            #[cfg(feature = "to_dummies")]
+            OneHot(encoder) => mapper.with_dtype(encoder.output_dtype(mapper.args()[0].name())),
+            #[cfg(feature = "target_encoding")]
+            TargetEncode(_) | TargetEncodeOutOfFold { .. } => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "scale")]
+            Scale(_) | ScaleWith(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "scale")]
+            ScaleFit(_) => mapper.with_dtype(ScaleParams::dtype()),
+            #[cfg(feature = "outliers")]
+            IsOutlier(_) => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "outliers")]
+            ModifiedZScore | IsolationForest(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "hypothesis_test")]
+            HypothesisTest(_) => mapper.with_dtype(TestResult::dtype()),
+            #[cfg(feature = "assertions")]
+            Assert { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketch { .. } | QuantileSketchMerge => mapper.with_dtype(DataType::Binary),
+            #[cfg(feature = "quantile_sketch")]
+            QuantileSketchQuery { .. } => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "rle")]
             RLE => mapper.map_dtype(|dt| {
                 DataType::Struct(vec![
@@ -294,7 +314,11 @@ impl FunctionExpr {
             RLEID => mapper.with_dtype(IDX_DTYPE),
             ToPhysical => mapper.to_physical_type(),
             #[cfg(feature = "random")]
-            Random { .. } => mapper.with_same_dtype(),
+            Random { method, .. } => match method {
+                RandomMethod::FoldId { .. } => mapper.with_dtype(IDX_DTYPE),
+                RandomMethod::Split { .. } => mapper.with_dtype(DataType::Boolean),
+                _ => mapper.with_same_dtype(),
+            },
             SetSortedFlag(_) => mapper.with_same_dtype(),
             #[cfg(feature = "ffi_plugin")]
             FfiPlugin {
@@ -327,6 +351,7 @@ impl FunctionExpr {
             #[cfg(feature = "replace")]
             ReplaceStrict { return_dtype } => mapper.replace_dtype(return_dtype.clone()),
             FillNullWithStrategy(_) => mapper.with_same_dtype(),
+            MaskNulls => mapper.with_same_dtype(),
             GatherEvery { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "reinterpret")]
             Reinterpret(signed) => {
@@ -109,6 +109,10 @@ pub enum StringFunction {
     SplitN(usize),
     #[cfg(feature = "temporal")]
     Strptime(DataType, StrptimeOptions),
+    #[cfg(feature = "string_parse")]
+    Parse(DataType, StrParseOptions),
+    #[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+    InferDatetimeFormat(usize),
     Split(bool),
     #[cfg(feature = "dtype-decimal")]
     ToDecimal(usize),
@@ -130,6 +134,15 @@ pub enum StringFunction {
         ascii_case_insensitive: bool,
         overlapping: bool,
     },
+    #[cfg(feature = "text_search")]
+    Tokenize(TokenizeOptions),
+    #[cfg(feature = "text_search")]
+    Bm25Score(Bm25Options),
+    #[cfg(feature = "text_search")]
+    Vectorize {
+        vectorizer: TermVectorizer,
+        sparse: bool,
+    },
 }
 
 impl StringFunction {
@@ -162,6 +175,32 @@ impl StringFunction {
             Reverse => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "string_parse")]
+            Parse(dtype, options) => {
+                #[cfg(feature = "dtype-struct")]
+                if options.with_errors {
+                    return mapper.with_dtype(DataType::Struct(vec![
+                        Field::new(PlSmallStr::from_static("value"), dtype.clone()),
+                        Field::new(PlSmallStr::from_static("error"), DataType::String),
+                    ]));
+                }
+                #[cfg(not(feature = "dtype-struct"))]
+                polars_ensure!(
+                    !options.with_errors,
+                    ComputeError: "`with_errors` requires the 'dtype-struct' feature"
+                );
+                mapper.with_dtype(dtype.clone())
+            },
+            #[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+            InferDatetimeFormat(_) => mapper.with_dtype(DataType::Struct(vec![
+                Field::new(PlSmallStr::from_static("format"), DataType::String),
+                Field::new(PlSmallStr::from_static("n_sampled"), IDX_DTYPE),
+                Field::new(PlSmallStr::from_static("n_matched"), IDX_DTYPE),
+                Field::new(
+                    PlSmallStr::from_static("ambiguous_with"),
+                    DataType::List(Box::new(DataType::String)),
+                ),
+            ])),
             Split(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
             #[cfg(feature = "nightly")]
             Titlecase => mapper.with_same_dtype(),
@@ -197,6 +236,12 @@ impl StringFunction {
             ReplaceMany { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "find_many")]
             ExtractMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "text_search")]
+            Tokenize(_) => mapper.with_dtype(DataType::List(Box::new(DataType::String))),
+            #[cfg(feature = "text_search")]
+            Bm25Score(_) => mapper.with_dtype(DataType::Float64),
+            #[cfg(feature = "text_search")]
+            Vectorize { vectorizer, sparse } => mapper.with_dtype(vectorizer.output_dtype(*sparse)),
         }
     }
 }
@@ -265,6 +310,10 @@ impl Display for StringFunction {
             SplitN(_) => "splitn",
             #[cfg(feature = "temporal")]
             Strptime(_, _) => "strptime",
+            #[cfg(feature = "string_parse")]
+            Parse(_, _) => "parse",
+            #[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+            InferDatetimeFormat(_) => "infer_datetime_format",
             Split(inclusive) => {
                 if *inclusive {
                     "split_inclusive"
@@ -285,6 +334,12 @@ impl Display for StringFunction {
             ReplaceMany { .. } => "replace_many",
             #[cfg(feature = "find_many")]
             ExtractMany { .. } => "extract_many",
+            #[cfg(feature = "text_search")]
+            Tokenize(_) => "tokenize",
+            #[cfg(feature = "text_search")]
+            Bm25Score(_) => "bm25_score",
+            #[cfg(feature = "text_search")]
+            Vectorize { .. } => "vectorize",
         };
         write!(f, "str.{s}")
     }
@@ -329,6 +384,12 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             Strptime(dtype, options) => {
                 map_as_slice!(strings::strptime, dtype.clone(), &options)
             },
+            #[cfg(feature = "string_parse")]
+            Parse(dtype, options) => map!(strings::parse, &dtype, &options),
+            #[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+            InferDatetimeFormat(sample_size) => {
+                map!(strings::infer_datetime_format, sample_size)
+            },
             Split(inclusive) => {
                 map_as_slice!(strings::split, inclusive)
             },
@@ -400,6 +461,12 @@ impl From<StringFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => {
                 map_as_slice!(extract_many, ascii_case_insensitive, overlapping)
             },
+            #[cfg(feature = "text_search")]
+            Tokenize(options) => map!(tokenize, &options),
+            #[cfg(feature = "text_search")]
+            Bm25Score(options) => map_as_slice!(bm25_score, &options),
+            #[cfg(feature = "text_search")]
+            Vectorize { vectorizer, sparse } => map!(vectorize, &vectorizer, sparse),
         }
     }
 }
@@ -444,6 +511,39 @@ fn extract_many(
     .map(|out| out.into_column())
 }
 
+#[cfg(feature = "text_search")]
+fn tokenize(s: &Column, options: &TokenizeOptions) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    Ok(polars_ops::chunked_array::strings::tokenize(ca, options).into_column())
+}
+
+#[cfg(feature = "text_search")]
+fn bm25_score(s: &[Column], options: &Bm25Options) -> PolarsResult<Column> {
+    let ca = s[0].str()?;
+    let query = s[1].str()?;
+    polars_ensure!(
+        query.len() == 1,
+        ComputeError: "`bm25_score` expects a single query string, got {}", query.len()
+    );
+    match query.get(0) {
+        Some(query) => polars_ops::chunked_array::strings::bm25_score(ca, query, options)
+            .map(|out| out.into_column()),
+        None => Ok(Column::full_null(
+            ca.name().clone(),
+            ca.len(),
+            &DataType::Float64,
+        )),
+    }
+}
+
+#[cfg(feature = "text_search")]
+fn vectorize(s: &Column, vectorizer: &TermVectorizer, sparse: bool) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    vectorizer
+        .transform(ca, sparse)
+        .map(|out| out.into_column())
+}
+
 fn uppercase(s: &Column) -> PolarsResult<Column> {
     let ca = s.str()?;
     Ok(ca.to_uppercase().into_column())
@@ -741,6 +841,144 @@ fn to_time(s: &Column, options: &StrptimeOptions) -> PolarsResult<Column> {
     Ok(out.into_column())
 }
 
+#[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+pub(super) fn infer_datetime_format(s: &Column, sample_size: usize) -> PolarsResult<Column> {
+    use polars_time::chunkedarray::string::infer;
+
+    let ca = s.str()?;
+    let out = infer::infer_datetime_format(ca, sample_size);
+    let ambiguous_with = Series::new(PlSmallStr::EMPTY, out.ambiguous_with);
+    let fields = [
+        Series::new(PlSmallStr::from_static("format"), [out.format]),
+        Series::new(
+            PlSmallStr::from_static("n_sampled"),
+            [out.n_sampled as IdxSize],
+        ),
+        Series::new(
+            PlSmallStr::from_static("n_matched"),
+            [out.n_matched as IdxSize],
+        ),
+        Series::new(PlSmallStr::from_static("ambiguous_with"), [ambiguous_with]),
+    ];
+    StructChunked::from_series(s.name().clone(), fields.iter()).map(|ca| ca.into_column())
+}
+
+#[cfg(feature = "string_parse")]
+pub(super) fn parse(
+    s: &Column,
+    dtype: &DataType,
+    options: &StrParseOptions,
+) -> PolarsResult<Column> {
+    let ca = s.str()?;
+    let value = match dtype {
+        DataType::String => ca.clone().into_series(),
+        DataType::Boolean => parse_bool(ca, options).into_series(),
+        #[cfg(feature = "dtype-date")]
+        DataType::Date => parse_temporal(s, dtype, options)?,
+        #[cfg(feature = "dtype-datetime")]
+        DataType::Datetime(_, _) => parse_temporal(s, dtype, options)?,
+        #[cfg(feature = "dtype-time")]
+        DataType::Time => parse_temporal(s, dtype, options)?,
+        dt if dt.is_numeric() || dt.is_decimal() => parse_number(ca, dt, options)?,
+        dt => polars_bail!(InvalidOperation: "`str.parse` not supported for dtype {}", dt),
+    };
+
+    #[cfg(feature = "dtype-struct")]
+    if options.with_errors {
+        let failed = !ca.is_null() & value.is_null();
+        let mut error: StringChunked = ca
+            .into_iter()
+            .zip(&failed)
+            .map(|(opt_v, failed)| match (opt_v, failed) {
+                (Some(v), Some(true)) => Some(format!("cannot parse '{v}' as {dtype}")),
+                _ => None,
+            })
+            .collect();
+        error.rename(PlSmallStr::from_static("error"));
+        let value = value.with_name(PlSmallStr::from_static("value"));
+        let fields = [value, error.into_series()];
+        return StructChunked::from_series(s.name().clone(), fields.iter())
+            .map(|ca| ca.into_column());
+    }
+
+    if options.strict && ca.null_count() != value.null_count() {
+        handle_casting_failures(s.as_materialized_series(), &value)?;
+    }
+    Ok(value.with_name(s.name().clone()).into_column())
+}
+
+#[cfg(feature = "string_parse")]
+fn parse_bool(ca: &StringChunked, options: &StrParseOptions) -> BooleanChunked {
+    let is_in = |values: &[PlSmallStr], v: &str| values.iter().any(|t| t.eq_ignore_ascii_case(v));
+    let mut out: BooleanChunked = ca
+        .into_iter()
+        .map(|opt_v| {
+            let v = opt_v?.trim();
+            if is_in(&options.true_values, v) {
+                Some(true)
+            } else if is_in(&options.false_values, v) {
+                Some(false)
+            } else {
+                None
+            }
+        })
+        .collect();
+    out.rename(ca.name().clone());
+    out
+}
+
+#[cfg(feature = "string_parse")]
+fn parse_number(
+    ca: &StringChunked,
+    dtype: &DataType,
+    options: &StrParseOptions,
+) -> PolarsResult<Series> {
+    let decimal_separator = options.decimal_separator;
+    let thousands_separator = options.thousands_separator;
+    let normalized = ca.apply_into_string_amortized(|v, buf| {
+        for c in v.trim().chars() {
+            if Some(c) == thousands_separator {
+                continue;
+            }
+            buf.push(if c == decimal_separator { '.' } else { c });
+        }
+    });
+    normalized.cast(dtype)
+}
+
+/// Try the candidate formats in order, every value takes the first format that parses it.
+#[cfg(all(feature = "string_parse", feature = "temporal"))]
+fn parse_temporal(s: &Column, dtype: &DataType, options: &StrParseOptions) -> PolarsResult<Series> {
+    let args = [
+        s.clone(),
+        Column::new(PlSmallStr::from_static("ambiguous"), ["raise"]),
+    ];
+    let mut formats = options.formats.iter().map(Some).collect::<Vec<_>>();
+    if formats.is_empty() {
+        formats.push(None);
+    }
+
+    let mut out: Option<Column> = None;
+    for format in formats {
+        let strptime_options = StrptimeOptions {
+            format: format.cloned(),
+            strict: false,
+            ..Default::default()
+        };
+        let parsed = strptime(&args, dtype.clone(), &strptime_options)?;
+        let parsed = match out {
+            None => parsed,
+            Some(out) => out.zip_with(&out.is_not_null(), &parsed)?,
+        };
+        let done = parsed.null_count() == s.null_count();
+        out = Some(parsed);
+        if done {
+            break;
+        }
+    }
+    Ok(out.unwrap().take_materialized_series())
+}
+
 #[cfg(feature = "concat_str")]
 pub(super) fn join(s: &Column, delimiter: &str, ignore_nulls: bool) -> PolarsResult<Column> {
     let str_s = s.cast(&DataType::String)?;
@@ -66,6 +66,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn ColumnsUdf>> {
             } => {
                 map_as_slice!(temporal::datetime, &time_unit, time_zone.as_deref())
             },
+            #[cfg(feature = "event_windows")]
+            EventWindow(window) => map!(datetime::event_window, window),
         }
     }
 }
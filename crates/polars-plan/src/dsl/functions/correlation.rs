@@ -70,6 +70,30 @@ pub fn spearman_rank_corr(a: Expr, b: Expr, ddof: u8, propagate_nans: bool) -> E
     }
 }
 
+/// Compute the Kendall tau-b rank correlation between two columns.
+/// Missing data will be excluded from the computation.
+///
+/// Unlike the spearman rank correlation, it is based on the number of concordant and
+/// discordant pairs, which makes it more robust to outliers and ties.
+#[cfg(feature = "rank")]
+pub fn kendall_rank_corr(a: Expr, b: Expr) -> Expr {
+    let input = vec![a, b];
+    let function = FunctionExpr::Correlation {
+        method: CorrelationMethod::KendallTau,
+        ddof: 0,
+    };
+    Expr::Function {
+        input,
+        function,
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            cast_to_supertypes: Some(Default::default()),
+            flags: FunctionFlags::default() | FunctionFlags::RETURNS_SCALAR,
+            ..Default::default()
+        },
+    }
+}
+
 #[cfg(feature = "rolling_window")]
 pub fn rolling_corr(x: Expr, y: Expr, options: RollingCovOptions) -> Expr {
     // see: https://github.com/pandas-dev/pandas/blob/v1.5.1/pandas/core/window/rolling.py#L1780-L1804
@@ -0,0 +1,39 @@
+use super::*;
+
+fn hypothesis_test(a: Expr, b: Expr, test: HypothesisTest) -> Expr {
+    Expr::Function {
+        input: vec![a, b],
+        function: FunctionExpr::HypothesisTest(test),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags: FunctionFlags::default() | FunctionFlags::RETURNS_SCALAR,
+            ..Default::default()
+        },
+    }
+}
+
+/// Test whether the means of two independent samples differ, giving a struct with the
+/// `statistic` and the two-sided `p_value`.
+///
+/// # Arguments
+/// * equal_var
+///     If `true` the samples are assumed to have equal variances (Student's t-test), otherwise
+///     Welch's t-test is used.
+///
+/// The samples may have different lengths, so a column can be compared across two groups by
+/// filtering it on either group.
+pub fn t_test(a: Expr, b: Expr, equal_var: bool) -> Expr {
+    hypothesis_test(a, b, HypothesisTest::TTest { equal_var })
+}
+
+/// Test whether two categorical columns are independent with Pearson's chi-square test on their
+/// contingency table, giving a struct with the `statistic` and the `p_value`.
+pub fn chi_square_test(a: Expr, b: Expr) -> Expr {
+    hypothesis_test(a, b, HypothesisTest::ChiSquare)
+}
+
+/// Test whether two independent samples come from the same distribution with the two-sample
+/// Kolmogorov-Smirnov test, giving a struct with the `statistic` and the `p_value`.
+pub fn ks_test(a: Expr, b: Expr) -> Expr {
+    hypothesis_test(a, b, HypothesisTest::KolmogorovSmirnov)
+}
@@ -10,8 +10,12 @@ mod concat;
 #[cfg(feature = "cov")]
 mod correlation;
 pub(crate) mod horizontal;
+#[cfg(feature = "hypothesis_test")]
+mod hypothesis_test;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 mod index;
+#[cfg(feature = "outliers")]
+mod outliers;
 #[cfg(feature = "range")]
 mod range;
 mod repeat;
@@ -29,8 +33,12 @@ pub use concat::*;
 #[cfg(feature = "cov")]
 pub use correlation::*;
 pub use horizontal::*;
+#[cfg(feature = "hypothesis_test")]
+pub use hypothesis_test::*;
 #[cfg(any(feature = "range", feature = "arg_where"))]
 pub use index::*;
+#[cfg(feature = "outliers")]
+pub use outliers::*;
 #[cfg(feature = "dtype-struct")]
 use polars_core::utils::get_supertype;
 #[cfg(all(feature = "range", feature = "temporal"))]
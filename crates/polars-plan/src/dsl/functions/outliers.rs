@@ -0,0 +1,20 @@
+use super::*;
+
+/// Score every row formed by `exprs` with an isolation forest fitted on the rows themselves.
+///
+/// Scores close to one indicate outliers. In a window the forest is fitted per group.
+pub fn isolation_forest_score<E: AsRef<[Expr]>>(exprs: E, options: IsolationForestOptions) -> Expr {
+    let input = exprs.as_ref().to_vec();
+    let mut flags = FunctionFlags::default();
+    // Without a seed, every evaluation grows different trees.
+    flags.set(FunctionFlags::NON_DETERMINISTIC, options.seed.is_none());
+    Expr::Function {
+        input,
+        function: FunctionExpr::IsolationForest(options),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::GroupWise,
+            flags,
+            ..Default::default()
+        },
+    }
+}
@@ -37,6 +37,23 @@ pub fn quantile(name: &str, quantile: Expr, interpol: QuantileInterpolOptions) -
     col(name).quantile(quantile, interpol)
 }
 
+/// Count the values at which `predicate` is `true`.
+///
+/// This is computed in a single pass over the predicate and, unlike
+/// `predicate.filter(predicate).count()`, does not filter the groups of a group-by or window one by
+/// one.
+pub fn count_if(predicate: Expr) -> Expr {
+    predicate.cast(DataType::Boolean).sum()
+}
+
+/// Sum the values of `value` at which `predicate` is `true`.
+///
+/// The values at which `predicate` is not `true` are masked out as nulls, which the sum skips. This
+/// avoids the per-group filtering of `value.filter(predicate).sum()`.
+pub fn sum_if(predicate: Expr, value: Expr) -> Expr {
+    value.mask_nulls(predicate).sum()
+}
+
 /// Negates a boolean column.
 pub fn not(expr: Expr) -> Expr {
     expr.not()
@@ -1065,6 +1065,14 @@ impl Expr {
         self.apply_private(FunctionExpr::FillNullWithStrategy(strategy))
     }
 
+    /// Replace the values at which the boolean `mask` is not `true` by null.
+    ///
+    /// Unlike a `filter`, this keeps the length and only updates the validity, so aggregating
+    /// the result in a group-by needs no per-group filtering.
+    pub fn mask_nulls<E: Into<Expr>>(self, mask: E) -> Self {
+        self.map_many_private(FunctionExpr::MaskNulls, &[mask.into()], false, None)
+    }
+
     /// Replace the floating point `NaN` values by a value.
     pub fn fill_nan<E: Into<Expr>>(self, fill_value: E) -> Self {
         // we take the not branch so that self is truthy value of `when -> then -> otherwise`
@@ -1662,6 +1670,151 @@ impl Expr {
         })
     }
 
+    #[cfg(feature = "to_dummies")]
+    /// One-hot encode the values as a struct with a dummy field per category of a fitted
+    /// [`OneHotEncoder`].
+    pub fn one_hot(self, encoder: OneHotEncoder) -> Expr {
+        self.map_private(FunctionExpr::OneHot(encoder))
+            .with_function_options(|mut opt| {
+                opt.flags |= FunctionFlags::PASS_NAME_TO_APPLY;
+                opt
+            })
+    }
+
+    #[cfg(feature = "target_encoding")]
+    /// Replace the values by the target encoding of a fitted [`TargetEncoder`].
+    pub fn target_encode(self, encoder: TargetEncoder) -> Expr {
+        self.map_private(FunctionExpr::TargetEncode(encoder))
+    }
+
+    #[cfg(feature = "target_encoding")]
+    /// Target encode the values, computing the encoding of every row on the other `fold`s.
+    ///
+    /// This prevents leaking the target of a row into its own encoding when the encoding is
+    /// used as a training feature.
+    pub fn target_encode_out_of_fold<E: Into<Expr>>(
+        self,
+        target: E,
+        fold: E,
+        smoothing: f64,
+    ) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::TargetEncodeOutOfFold { smoothing },
+            &[target.into(), fold.into()],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "scale")]
+    /// Scale the values with the parameters of `method` fitted on the values themselves.
+    ///
+    /// In a window the parameters are fitted per group. Use [`Expr::scale_params`] to keep the
+    /// parameters and [`Expr::scale_with`] to apply them to other data.
+    pub fn scale(self, method: ScaleMethod) -> Expr {
+        self.apply_private(FunctionExpr::Scale(method))
+    }
+
+    #[cfg(feature = "scale")]
+    /// Standardize the values to zero mean and unit standard deviation.
+    pub fn scale_standard(self) -> Expr {
+        self.scale(ScaleMethod::Standard)
+    }
+
+    #[cfg(feature = "scale")]
+    /// Scale the values onto `[0, 1]` by their minimum and maximum.
+    pub fn scale_minmax(self) -> Expr {
+        self.scale(ScaleMethod::MinMax)
+    }
+
+    #[cfg(feature = "scale")]
+    /// Center the values on their median and scale them by their interquartile range.
+    pub fn scale_robust(self) -> Expr {
+        self.scale(ScaleMethod::Robust)
+    }
+
+    #[cfg(feature = "scale")]
+    /// Scale the values with previously fitted parameters.
+    pub fn scale_with(self, params: ScaleParams) -> Expr {
+        self.map_private(FunctionExpr::ScaleWith(params))
+    }
+
+    #[cfg(feature = "scale")]
+    /// Fit the parameters of `method` and return them as a struct with a `center` and a
+    /// `scale` field.
+    ///
+    /// In a group by the parameters are fitted per group.
+    pub fn scale_params(self, method: ScaleMethod) -> Expr {
+        self.apply_private(FunctionExpr::ScaleFit(method))
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options
+            })
+    }
+
+    #[cfg(feature = "outliers")]
+    /// Flag the outliers among the values according to `method`.
+    ///
+    /// In a window the fences are computed per group.
+    pub fn is_outlier(self, method: OutlierMethod) -> Expr {
+        self.apply_private(FunctionExpr::IsOutlier(method))
+    }
+
+    #[cfg(feature = "outliers")]
+    /// Compute the modified z-score of the values, based on the median and the median absolute
+    /// deviation.
+    pub fn modified_zscore(self) -> Expr {
+        self.apply_private(FunctionExpr::ModifiedZScore)
+    }
+
+    #[cfg(feature = "assertions")]
+    /// Pass the values through, but raise an error with `message` and a sample of the failing
+    /// values if `predicate` is false for any of them.
+    ///
+    /// This allows data contracts to be checked inline in a query. A null predicate does not
+    /// fail the assertion. The failing values are reported with their row in the input, or in
+    /// their group in a group-by aggregation.
+    pub fn assert<S: Into<PlSmallStr>>(self, predicate: Expr, message: S) -> Expr {
+        self.apply_many_private(
+            FunctionExpr::Assert {
+                message: message.into(),
+            },
+            &[predicate],
+            false,
+            false,
+        )
+    }
+
+    #[cfg(feature = "quantile_sketch")]
+    /// Aggregate the values into a serialized t-digest quantile sketch.
+    ///
+    /// The sketch can be stored, merged with sketches of other batches with
+    /// [`Expr::quantile_sketch_merge`] and queried with [`Expr::quantile_sketch_query`].
+    /// A higher `compression` gives more accurate quantiles at the cost of a larger sketch.
+    pub fn quantile_sketch(self, compression: f64) -> Expr {
+        self.apply_private(FunctionExpr::QuantileSketch { compression })
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options
+            })
+    }
+
+    #[cfg(feature = "quantile_sketch")]
+    /// Merge a column of serialized quantile sketches into a single sketch.
+    pub fn quantile_sketch_merge(self) -> Expr {
+        self.apply_private(FunctionExpr::QuantileSketchMerge)
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options
+            })
+    }
+
+    #[cfg(feature = "quantile_sketch")]
+    /// Estimate the `quantile` of every serialized quantile sketch.
+    pub fn quantile_sketch_query(self, quantile: f64) -> Expr {
+        self.map_private(FunctionExpr::QuantileSketchQuery { quantile })
+    }
+
     #[cfg(feature = "rle")]
     /// Get the lengths of runs of identical values.
     pub fn rle(self) -> Expr {
@@ -41,6 +41,45 @@ impl Default for StrptimeOptions {
     }
 }
 
+#[cfg(feature = "string_parse")]
+#[derive(Clone, PartialEq, Debug, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StrParseOptions {
+    /// Character that separates the integral and fractional part of a number,
+    /// e.g. `,` for `"1.234,5"`.
+    pub decimal_separator: char,
+    /// Character that groups the digits of a number and is ignored while parsing.
+    pub thousands_separator: Option<char>,
+    /// Values that are parsed as `true` (case insensitive).
+    pub true_values: Vec<PlSmallStr>,
+    /// Values that are parsed as `false` (case insensitive).
+    pub false_values: Vec<PlSmallStr>,
+    /// Candidate formats for temporal data types. They are tried in order for every value and
+    /// the first format that parses wins. If empty, the format is inferred.
+    pub formats: Vec<PlSmallStr>,
+    /// Return a struct with the parsed `value` and an `error` message for every value that
+    /// could not be parsed, instead of raising.
+    pub with_errors: bool,
+    /// If set then polars will return an error if any value could not be parsed.
+    /// Ignored if `with_errors` is set.
+    pub strict: bool,
+}
+
+#[cfg(feature = "string_parse")]
+impl Default for StrParseOptions {
+    fn default() -> Self {
+        StrParseOptions {
+            decimal_separator: '.',
+            thousands_separator: None,
+            true_values: vec![PlSmallStr::from_static("true")],
+            false_values: vec![PlSmallStr::from_static("false")],
+            formats: vec![],
+            with_errors: false,
+            strict: true,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct JoinOptions {
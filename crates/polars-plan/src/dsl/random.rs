@@ -1,11 +1,22 @@
 use super::*;
 
+/// Without a seed, every evaluation draws new random numbers.
+fn set_determinism(expr: Expr, seed: Option<u64>) -> Expr {
+    expr.with_function_options(|mut options| {
+        options
+            .flags
+            .set(FunctionFlags::NON_DETERMINISTIC, seed.is_none());
+        options
+    })
+}
+
 impl Expr {
     pub fn shuffle(self, seed: Option<u64>) -> Self {
-        self.apply_private(FunctionExpr::Random {
+        let expr = self.apply_private(FunctionExpr::Random {
             method: RandomMethod::Shuffle,
             seed,
-        })
+        });
+        set_determinism(expr, seed)
     }
 
     pub fn sample_n(
@@ -15,7 +26,7 @@ impl Expr {
         shuffle: bool,
         seed: Option<u64>,
     ) -> Self {
-        self.apply_many_private(
+        let expr = self.apply_many_private(
             FunctionExpr::Random {
                 method: RandomMethod::Sample {
                     is_fraction: false,
@@ -27,7 +38,8 @@ impl Expr {
             &[n],
             false,
             false,
-        )
+        );
+        set_determinism(expr, seed)
     }
 
     pub fn sample_frac(
@@ -37,7 +49,7 @@ impl Expr {
         shuffle: bool,
         seed: Option<u64>,
     ) -> Self {
-        self.apply_many_private(
+        let expr = self.apply_many_private(
             FunctionExpr::Random {
                 method: RandomMethod::Sample {
                     is_fraction: true,
@@ -49,6 +61,86 @@ impl Expr {
             &[frac],
             false,
             false,
-        )
+        );
+        set_determinism(expr, seed)
+    }
+
+    /// Sample `n` values with a probability proportional to `weights`.
+    ///
+    /// Values with a null or zero weight are never sampled. With replacement the values are
+    /// drawn with the alias method, which stays fast for large and skewed weights.
+    pub fn sample_n_weighted(
+        self,
+        n: Expr,
+        weights: Expr,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        let expr = self.apply_many_private(
+            FunctionExpr::Random {
+                method: RandomMethod::WeightedSample {
+                    is_fraction: false,
+                    with_replacement,
+                },
+                seed,
+            },
+            &[n, weights],
+            false,
+            false,
+        );
+        set_determinism(expr, seed)
+    }
+
+    /// Sample a fraction `frac` of the values with a probability proportional to `weights`,
+    /// see [`Expr::sample_n_weighted`].
+    pub fn sample_frac_weighted(
+        self,
+        frac: Expr,
+        weights: Expr,
+        with_replacement: bool,
+        seed: Option<u64>,
+    ) -> Self {
+        let expr = self.apply_many_private(
+            FunctionExpr::Random {
+                method: RandomMethod::WeightedSample {
+                    is_fraction: true,
+                    with_replacement,
+                },
+                seed,
+            },
+            &[frac, weights],
+            false,
+            false,
+        );
+        set_determinism(expr, seed)
+    }
+
+    /// Assign every row to one of `k` cross-validation folds of (nearly) equal size.
+    ///
+    /// With `by_key` the values are keys that are kept together in one fold. Use
+    /// [`Expr::over`] to stratify the folds over groups.
+    pub fn fold_id(self, k: IdxSize, by_key: bool, seed: Option<u64>) -> Self {
+        let expr = self.apply_private(FunctionExpr::Random {
+            method: RandomMethod::FoldId { k, by_key },
+            seed,
+        });
+        set_determinism(expr, seed)
+    }
+
+    /// Mark a random `frac` of the rows, e.g. as the training set of a train/test split.
+    ///
+    /// With `by_key` the values are keys whose rows are kept on the same side of the split.
+    /// Use [`Expr::over`] to stratify the split over groups.
+    pub fn split_mask(self, frac: Expr, by_key: bool, seed: Option<u64>) -> Self {
+        let expr = self.apply_many_private(
+            FunctionExpr::Random {
+                method: RandomMethod::Split { by_key },
+                seed,
+            },
+            &[frac],
+            false,
+            false,
+        );
+        set_determinism(expr, seed)
     }
 }
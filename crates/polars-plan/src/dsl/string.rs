@@ -100,6 +100,46 @@ impl StringNameSpace {
         )
     }
 
+    /// Split the string values into lowercase word tokens.
+    ///
+    /// Depending on the `options` the stop words of the language are dropped and the tokens are
+    /// reduced to their stem.
+    #[cfg(feature = "text_search")]
+    pub fn tokenize(self, options: TokenizeOptions) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Tokenize(options)))
+    }
+
+    /// Score the string values against `query` with Okapi BM25.
+    ///
+    /// The column (or group) is the corpus the document frequencies are computed over.
+    /// # Arguments
+    /// - `query`: an expression that evaluates to a single String value
+    /// - `options`: the tokenization and the `k1` and `b` parameters
+    #[cfg(feature = "text_search")]
+    pub fn bm25_score(self, query: Expr, options: Bm25Options) -> Expr {
+        self.0.apply_many_private(
+            FunctionExpr::StringExpr(StringFunction::Bm25Score(options)),
+            &[query],
+            false,
+            false,
+        )
+    }
+
+    /// Turn the string values into term-frequency vectors over the vocabulary of a fitted
+    /// [`TermVectorizer`].
+    ///
+    /// With `sparse` the output is a `List` of `{index, value}` structs instead of an `Array`
+    /// with a value for every term of the vocabulary.
+    #[cfg(feature = "text_search")]
+    pub fn vectorize(self, vectorizer: TermVectorizer, sparse: bool) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Vectorize {
+                vectorizer,
+                sparse,
+            }))
+    }
+
     /// Check if a string value ends with the `sub` string.
     pub fn ends_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -280,6 +320,34 @@ impl StringNameSpace {
         )
     }
 
+    /// Parse a String column into `dtype`.
+    ///
+    /// Numbers honour the separators of `options`, booleans are matched against its
+    /// true/false values and temporal values try each of its formats in order. Set
+    /// `options.with_errors` to get a struct of the parsed `value` and the parse `error`
+    /// per row instead of raising.
+    #[cfg(feature = "string_parse")]
+    pub fn parse(self, dtype: DataType, options: StrParseOptions) -> Expr {
+        self.0
+            .map_private(StringFunction::Parse(dtype, options).into())
+    }
+
+    /// Infer the date(time) format of the first `sample_size` non-null values, the same way
+    /// the CSV reader does.
+    ///
+    /// Returns a single struct with the chosen `format`, the number of sampled (`n_sampled`)
+    /// and parsed (`n_matched`) values and the formats it is `ambiguous_with`.
+    #[cfg(all(feature = "temporal", feature = "dtype-struct"))]
+    pub fn infer_datetime_format(self, sample_size: usize) -> Expr {
+        self.0
+            .apply_private(StringFunction::InferDatetimeFormat(sample_size).into())
+            .with_function_options(|mut options| {
+                options.flags |= FunctionFlags::RETURNS_SCALAR;
+                options.collect_groups = ApplyOptions::GroupWise;
+                options
+            })
+    }
+
     /// Convert a String column into a Date column.
     #[cfg(feature = "dtype-date")]
     pub fn to_date(self, options: StrptimeOptions) -> Expr {
@@ -33,6 +33,9 @@ bitflags! {
         const ROW_ESTIMATE = 1 << 13;
         /// Replace simple projections with a faster inlined projection that skips the expression engine.
         const FAST_PROJECTION = 1 << 14;
+        /// Run on the streaming engine if the estimated size of the scanned data is large compared
+        /// to the available memory.
+        const AUTO_STREAMING = 1 << 15;
     }
 }
 
@@ -302,6 +302,23 @@ fn func_args_to_fields(
         .collect()
 }
 
+/// The type of arithmetic between a list or array of numbers and a number, which is broadcast
+/// over the inner values.
+fn nested_numeric_supertype(left: &DataType, right: &DataType) -> Option<PolarsResult<DataType>> {
+    let (nested, other) = match (left, right) {
+        (nested, other) | (other, nested)
+            if nested.is_nested() && nested.leaf_dtype().is_numeric() && !other.is_nested() =>
+        {
+            (nested, other)
+        },
+        _ => return None,
+    };
+    if !(other.is_numeric() || matches!(other, DataType::Unknown(_))) {
+        return None;
+    }
+    Some(try_get_supertype(nested.leaf_dtype(), other).map(|st| nested.cast_leaf(st)))
+}
+
 fn get_arithmetic_field(
     left: Node,
     right: Node,
@@ -327,6 +344,10 @@ fn get_arithmetic_field(
     let super_type = match op {
         Operator::Minus => {
             let right_type = right_ae.to_field_impl(schema, arena, nested)?.dtype;
+            if let Some(dtype) = nested_numeric_supertype(&left_field.dtype, &right_type) {
+                left_field.coerce(dtype?);
+                return Ok(left_field);
+            }
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
                 (Struct(_), Struct(_)) => {
@@ -360,6 +381,10 @@ fn get_arithmetic_field(
         },
         Operator::Plus => {
             let right_type = right_ae.to_field_impl(schema, arena, nested)?.dtype;
+            if let Some(dtype) = nested_numeric_supertype(&left_field.dtype, &right_type) {
+                left_field.coerce(dtype?);
+                return Ok(left_field);
+            }
             match (&left_field.dtype, &right_type) {
                 (Duration(_), Datetime(_, _))
                 | (Datetime(_, _), Duration(_))
@@ -385,6 +410,10 @@ fn get_arithmetic_field(
         },
         _ => {
             let right_type = right_ae.to_field_impl(schema, arena, nested)?.dtype;
+            if let Some(dtype) = nested_numeric_supertype(&left_field.dtype, &right_type) {
+                left_field.coerce(dtype?);
+                return Ok(left_field);
+            }
 
             match (&left_field.dtype, &right_type) {
                 #[cfg(feature = "dtype-struct")]
@@ -453,8 +482,20 @@ fn get_truediv_field(
     let mut left_field = arena.get(left).to_field_impl(schema, arena, nested)?;
     use DataType::*;
     let out_type = match left_field.dtype() {
-        Float32 => Float32,
-        dt if dt.is_numeric() => Float64,
+        // The values of lists and arrays are divided as `Float64`.
+        dt @ List(_) => dt.cast_leaf(Float64),
+        #[cfg(feature = "dtype-array")]
+        dt @ Array(_, _) => dt.cast_leaf(Float64),
+        dt if dt.is_numeric() => {
+            let right_dtype = arena.get(right).to_field_impl(schema, arena, nested)?.dtype;
+            match right_dtype {
+                List(_) => right_dtype.cast_leaf(Float64),
+                #[cfg(feature = "dtype-array")]
+                Array(_, _) => right_dtype.cast_leaf(Float64),
+                _ if matches!(dt, Float32) => Float32,
+                _ => Float64,
+            }
+        },
         #[cfg(feature = "dtype-duration")]
         Duration(_) => match arena
             .get(right)
@@ -48,6 +48,14 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the scan provider can be used as a source of the streaming engine.
+    /// The streaming engine calls [`AnonymousScan::next_batch`] until it returns `None`,
+    /// so that method must be implemented if this returns `true`.
+    ///
+    /// Defaults to `false`
+    fn allows_streaming(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
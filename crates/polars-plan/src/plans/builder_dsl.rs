@@ -8,7 +8,7 @@ use polars_io::csv::read::CsvReadOptions;
 #[cfg(feature = "ipc")]
 use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{ParquetOptions, SkippedRowGroups};
 use polars_io::HiveOptions;
 #[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
 use polars_io::RowIndex;
@@ -84,6 +84,8 @@ impl DslBuilder {
         low_memory: bool,
         cloud_options: Option<CloudOptions>,
         use_statistics: bool,
+        use_field_ids: bool,
+        skip_corrupt_row_groups: Option<SkippedRowGroups>,
         hive_options: HiveOptions,
         glob: bool,
         include_file_paths: Option<PlSmallStr>,
@@ -108,9 +110,12 @@ impl DslBuilder {
                     parallel,
                     low_memory,
                     use_statistics,
+                    use_field_ids,
+                    skip_corrupt_row_groups: skip_corrupt_row_groups.is_some(),
                 },
                 cloud_options,
                 metadata: None,
+                skipped_row_groups: skip_corrupt_row_groups,
             },
             cached_ir: Default::default(),
         }
@@ -108,6 +108,55 @@ pub(super) fn convert_diagonal_concat(
     }
 }
 
+/// Align the columns of the inputs of a union with the columns of the first input.
+pub(super) fn convert_union_matching(
+    inputs: &mut [Node],
+    matching: UnionColumnMatching,
+    lp_arena: &mut Arena<IR>,
+    expr_arena: &mut Arena<AExpr>,
+) -> PolarsResult<()> {
+    if matching == UnionColumnMatching::Exact || inputs.is_empty() {
+        return Ok(());
+    }
+    let schemas = nodes_to_schemas(inputs, lp_arena);
+    let first = &schemas[0];
+
+    for (i, (node, schema)) in inputs.iter_mut().zip(schemas.iter()).enumerate().skip(1) {
+        if schema.iter_names().eq(first.iter_names()) {
+            continue;
+        }
+        let exprs = match matching {
+            UnionColumnMatching::Position => {
+                polars_ensure!(
+                    schema.len() == first.len(),
+                    ShapeMismatch: "union by position requires inputs of equal width, input {} has {} columns instead of {}",
+                    i, schema.len(), first.len()
+                );
+                schema
+                    .iter_names()
+                    .zip(first.iter_names())
+                    .map(|(name, target)| col(name.clone()).alias(target.clone()))
+                    .collect::<Vec<_>>()
+            },
+            _ => first
+                .iter()
+                .map(|(name, dtype)| {
+                    if schema.contains(name) {
+                        col(name.clone())
+                    } else {
+                        NULL.lit().cast(dtype.clone()).alias(name.clone())
+                    }
+                })
+                .collect(),
+        };
+        let exprs = to_expr_irs(exprs, expr_arena)?;
+        *node = IRBuilder::new(*node, expr_arena, lp_arena)
+            .project(exprs, Default::default())
+            .node();
+    }
+    Ok(())
+}
+
 pub(super) fn h_concat_schema(
     inputs: &[Node],
     lp_arena: &mut Arena<IR>,
@@ -289,6 +289,14 @@ pub fn to_alp_impl(lp: DslPlan, ctxt: &mut DslConversionContext) -> PolarsResult
             if args.diagonal {
                 inputs =
                     convert_utils::convert_diagonal_concat(inputs, ctxt.lp_arena, ctxt.expr_arena)?;
+            } else {
+                convert_utils::convert_union_matching(
+                    &mut inputs,
+                    args.matching,
+                    ctxt.lp_arena,
+                    ctxt.expr_arena,
+                )
+                .map_err(|e| e.context(failed_here!(vertical concat)))?;
             }
 
             if args.to_supertypes {
@@ -5,6 +5,8 @@ use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
 use polars_io::utils::compression::maybe_decompress_bytes;
 use polars_io::RowIndex;
+#[cfg(feature = "parquet")]
+use polars_parquet::parquet::statistics::Statistics;
 
 use super::*;
 
@@ -36,6 +38,11 @@ pub(super) fn parquet_file_info(
 ) -> PolarsResult<(FileInfo, Option<FileMetadataRef>)> {
     use polars_core::error::feature_gated;
 
+    #[cfg(feature = "metadata_cache")]
+    if let Some(file_info) = cached_parquet_file_info(sources, file_options, cloud_options)? {
+        return Ok((file_info, None));
+    }
+
     let (reader_schema, num_rows, metadata) = {
         if sources.is_cloud_url() {
             let first_path = &sources.as_paths().unwrap()[0];
@@ -71,15 +78,116 @@ pub(super) fn parquet_file_info(
         file_options.row_index.as_ref(),
     );
 
-    let file_info = FileInfo::new(
+    let mut file_info = FileInfo::new(
         schema,
-        Some(Either::Left(reader_schema)),
+        Some(Either::Left(reader_schema.clone())),
         (num_rows, num_rows.unwrap_or(0)),
     );
+    file_info.statistics = metadata
+        .as_deref()
+        .map(|md| Arc::new(parquet_statistics(md, &reader_schema)));
+
+    #[cfg(feature = "metadata_cache")]
+    if let (Some(path), Some(statistics)) = (sources.first_path(), &file_info.statistics) {
+        use polars_io::metadata_cache::{ParquetScanMetadata, METADATA_CACHE};
+
+        if METADATA_CACHE.is_enabled() {
+            let metadata = ParquetScanMetadata {
+                schema: reader_schema,
+                num_rows: statistics.num_rows,
+                column_counts: statistics
+                    .columns
+                    .iter()
+                    .map(|(name, c)| (name.clone(), c.null_count, c.distinct_count))
+                    .collect(),
+            };
+            METADATA_CACHE.insert_parquet(&path.to_string_lossy(), &metadata, cloud_options);
+        }
+    }
 
     Ok((file_info, metadata))
 }
 
+/// Build the [`FileInfo`] of a Parquet scan from the metadata cache, without reading the footer
+/// of the first file.
+#[cfg(all(feature = "parquet", feature = "metadata_cache"))]
+fn cached_parquet_file_info(
+    sources: &ScanSources,
+    file_options: &FileScanOptions,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<Option<FileInfo>> {
+    use polars_io::metadata_cache::METADATA_CACHE;
+
+    let Some(path) = sources.first_path().filter(|_| METADATA_CACHE.is_enabled()) else {
+        return Ok(None);
+    };
+    let Some(cached) = METADATA_CACHE.get_parquet(&path.to_string_lossy(), cloud_options) else {
+        return Ok(None);
+    };
+
+    let schema = prepare_output_schema(
+        Schema::from_arrow_schema(cached.schema.as_ref()),
+        file_options.row_index.as_ref(),
+    );
+    let mut file_info = FileInfo::new(
+        schema,
+        Some(Either::Left(cached.schema)),
+        (Some(cached.num_rows), cached.num_rows),
+    );
+    file_info.statistics = Some(Arc::new(ScanStatistics {
+        num_rows: cached.num_rows,
+        columns: cached
+            .column_counts
+            .into_iter()
+            .map(|(name, null_count, distinct_count)| {
+                let column = ColumnStatistics {
+                    null_count,
+                    distinct_count,
+                };
+                (name, column)
+            })
+            .collect(),
+    }));
+    Ok(Some(file_info))
+}
+
+/// Gather the null and distinct counts of the columns of a Parquet file from the statistics of
+/// its row groups. Only columns that are not nested are considered.
+#[cfg(feature = "parquet")]
+fn parquet_statistics(md: &FileMetadata, schema: &ArrowSchema) -> ScanStatistics {
+    let columns = schema
+        .iter_values()
+        .filter_map(|field| {
+            let mut column = ColumnStatistics {
+                null_count: Some(0),
+                distinct_count: Some(0),
+            };
+            for rg in &md.row_groups {
+                let mut chunks = rg.columns_under_root_iter(&field.name);
+                let (Some(chunk), None) = (chunks.next(), chunks.next()) else {
+                    return None;
+                };
+                let stats = chunk.statistics().and_then(|stats| stats.ok());
+                let stat = |f: fn(&_) -> Option<i64>| stats.as_ref().and_then(f)?.try_into().ok();
+                column.null_count = column
+                    .null_count
+                    .zip(stat(Statistics::null_count))
+                    .map(|(acc, v)| acc + v);
+                // Values may repeat across row groups, so the largest count is the best bound.
+                column.distinct_count = column
+                    .distinct_count
+                    .zip(stat(Statistics::distinct_count))
+                    .map(|(acc, v)| acc.max(v));
+            }
+            Some((field.name.clone(), column))
+        })
+        .collect();
+    ScanStatistics {
+        num_rows: md.num_rows,
+        columns,
+    }
+}
+
 // TODO! return metadata arced
 #[cfg(feature = "ipc")]
 pub(super) fn ipc_file_info(
@@ -176,6 +284,24 @@ pub(super) fn csv_file_info(
 
     let infer_schema_func = |i| {
         let source = sources.at(i);
+
+        #[cfg(feature = "metadata_cache")]
+        let cache_uri = match source {
+            ScanSourceRef::Path(path) if polars_io::metadata_cache::METADATA_CACHE.is_enabled() => {
+                let uri = path.to_string_lossy();
+                let cached = polars_io::metadata_cache::METADATA_CACHE.get_csv_inference(
+                    &uri,
+                    csv_options,
+                    cloud_options,
+                );
+                if let Some(cached) = cached {
+                    return Ok(cached);
+                }
+                Some(uri)
+            },
+            _ => None,
+        };
+
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
         let owned = &mut vec![];
         let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
@@ -187,7 +313,20 @@ pub(super) fn csv_file_info(
         let reader_bytes = get_reader_bytes(&mut reader).expect("could not mmap file");
 
         // this needs a way to estimated bytes/rows.
-        SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)
+        let result =
+            SchemaInferenceResult::try_from_reader_bytes_and_options(&reader_bytes, csv_options)?;
+
+        #[cfg(feature = "metadata_cache")]
+        if let Some(uri) = cache_uri {
+            polars_io::metadata_cache::METADATA_CACHE.insert_csv_inference(
+                &uri,
+                csv_options,
+                &result,
+                cloud_options,
+            );
+        }
+
+        Ok(result)
     };
 
     let merge_func = |a: PolarsResult<SchemaInferenceResult>,
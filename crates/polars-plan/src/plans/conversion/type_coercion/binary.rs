@@ -47,6 +47,14 @@ fn is_cat_str_binary(type_left: &DataType, type_right: &DataType) -> bool {
     }
 }
 
+fn is_numeric_or_dyn(dtype: &DataType) -> bool {
+    dtype.is_numeric()
+        || matches!(
+            dtype,
+            DataType::Unknown(UnknownKind::Int(_) | UnknownKind::Float)
+        )
+}
+
 fn process_list_arithmetic(
     type_left: DataType,
     type_right: DataType,
@@ -55,6 +63,38 @@ fn process_list_arithmetic(
     op: Operator,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<Option<AExpr>> {
+    // A numeric side is broadcast over the inner values, so both sides only need to agree on the
+    // leaf type.
+    let leaves = match (&type_left, &type_right) {
+        (nested, other) | (other, nested)
+            if nested.is_nested()
+                && nested.leaf_dtype().is_numeric()
+                && is_numeric_or_dyn(other) =>
+        {
+            Some((nested.leaf_dtype(), other))
+        },
+        _ => None,
+    };
+    if let Some((leaf, other)) = leaves {
+        let st = unpack!(get_supertype(leaf, other));
+        let mut cast = |node: Node, dtype: &DataType| {
+            if *dtype.leaf_dtype() == st {
+                return node;
+            }
+            expr_arena.add(AExpr::Cast {
+                expr: node,
+                dtype: dtype.cast_leaf(st.clone()),
+                options: CastOptions::NonStrict,
+            })
+        };
+        let left = cast(node_left, &type_left);
+        let right = cast(node_right, &type_right);
+        if left == node_left && right == node_right {
+            return Ok(None);
+        }
+        return Ok(Some(AExpr::BinaryExpr { left, op, right }));
+    }
+
     match (&type_left, &type_right) {
         (DataType::List(_), _) => {
             let leaf = type_left.leaf_dtype();
@@ -92,7 +132,7 @@ fn process_list_arithmetic(
                 Ok(None)
             }
         },
-        _ => unreachable!(),
+        _ => Ok(None),
     }
 }
 
@@ -270,6 +310,12 @@ pub(super) fn process_binary(
                     type_left, type_right, node_left, node_right, op, expr_arena,
                 )
             },
+            #[cfg(feature = "dtype-array")]
+            (Array(_, _), a) | (a, Array(_, _)) if is_numeric_or_dyn(a) => {
+                return process_list_arithmetic(
+                    type_left, type_right, node_left, node_right, op, expr_arena,
+                )
+            },
             (Datetime(_, _), _)
             | (_, Datetime(_, _))
             | (Date, _)
@@ -83,7 +83,7 @@ fn get_input(lp_arena: &Arena<IR>, lp_node: Node) -> UnitVec<Node> {
     inputs
 }
 
-fn get_schema(lp_arena: &Arena<IR>, lp_node: Node) -> Cow<'_, SchemaRef> {
+pub(crate) fn get_schema(lp_arena: &Arena<IR>, lp_node: Node) -> Cow<'_, SchemaRef> {
     let inputs = get_input(lp_arena, lp_node);
     if inputs.is_empty() {
         // Files don't have an input, so we must take their schema.
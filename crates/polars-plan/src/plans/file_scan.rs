@@ -7,7 +7,7 @@ use polars_io::ipc::IpcScanOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::metadata::FileMetadataRef;
 #[cfg(feature = "parquet")]
-use polars_io::parquet::read::ParquetOptions;
+use polars_io::parquet::read::{ParquetOptions, SkippedRowGroups};
 
 use super::*;
 
@@ -25,6 +25,10 @@ pub enum FileScan {
         cloud_options: Option<polars_io::cloud::CloudOptions>,
         #[cfg_attr(feature = "serde", serde(skip))]
         metadata: Option<FileMetadataRef>,
+        /// Where the row groups skipped by [`ParquetOptions::skip_corrupt_row_groups`] are
+        /// recorded. A deserialized plan records them in a collector of its own.
+        #[cfg_attr(feature = "serde", serde(skip))]
+        skipped_row_groups: Option<SkippedRowGroups>,
     },
     #[cfg(feature = "ipc")]
     Ipc {
@@ -64,14 +68,16 @@ impl PartialEq for FileScan {
                 FileScan::Parquet {
                     options: opt_l,
                     cloud_options: c_l,
+                    skipped_row_groups: s_l,
                     ..
                 },
                 FileScan::Parquet {
                     options: opt_r,
                     cloud_options: c_r,
+                    skipped_row_groups: s_r,
                     ..
                 },
-            ) => opt_l == opt_r && c_l == c_r,
+            ) => opt_l == opt_r && c_l == c_r && s_l == s_r,
             #[cfg(feature = "ipc")]
             (
                 FileScan::Ipc {
@@ -120,6 +126,7 @@ impl Hash for FileScan {
                 options,
                 cloud_options,
                 metadata: _,
+                skipped_row_groups: _,
             } => {
                 options.hash(state);
                 cloud_options.hash(state);
@@ -184,6 +191,7 @@ impl FileScan {
             Self::Parquet { .. } => true,
             #[cfg(feature = "json")]
             Self::NDJson { .. } => false,
+            Self::Anonymous { function, .. } => function.allows_streaming(),
             #[allow(unreachable_patterns)]
             _ => false,
         }
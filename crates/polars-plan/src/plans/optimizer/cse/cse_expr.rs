@@ -329,11 +329,12 @@ impl ExprIdentifierVisitor<'_> {
                     REFUSE_ALLOW_MEMBER
                 }
             },
-            #[cfg(feature = "random")]
-            AExpr::Function {
-                function: FunctionExpr::Random { .. },
-                ..
-            } => REFUSE_NO_MEMBER,
+            // Every evaluation of these can give a different result, so they can't be shared.
+            AExpr::Function { options, .. }
+                if options.flags.contains(FunctionFlags::NON_DETERMINISTIC) =>
+            {
+                REFUSE_NO_MEMBER
+            },
             #[cfg(feature = "rolling_window")]
             AExpr::Function {
                 function: FunctionExpr::RollingExpr { .. },
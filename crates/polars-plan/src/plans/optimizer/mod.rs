@@ -15,8 +15,15 @@ mod flatten_union;
 #[cfg(feature = "fused")]
 mod fused;
 mod join_utils;
+#[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+mod parquet_categorical;
+#[cfg(feature = "parquet")]
+mod parquet_min_max;
+mod partition_wise;
 mod predicate_pushdown;
 mod projection_pushdown;
+#[cfg(feature = "unstable_rewrite_rules")]
+mod rewrite_rules;
 mod simplify_expr;
 mod slice_pushdown_expr;
 mod slice_pushdown_lp;
@@ -28,6 +35,10 @@ use polars_core::config::verbose;
 use polars_io::predicates::PhysicalIoExpr;
 pub use predicate_pushdown::PredicatePushDown;
 pub use projection_pushdown::ProjectionPushDown;
+#[cfg(feature = "unstable_rewrite_rules")]
+pub use rewrite_rules::{
+    register_expr_rewrite_rule, unregister_expr_rewrite_rule, ExprRewriteRule,
+};
 pub use simplify_expr::{SimplifyBooleanRule, SimplifyExprRule};
 use slice_pushdown_lp::SlicePushDown;
 pub use stack_opt::{OptimizationRule, StackOptimizer};
@@ -80,6 +91,13 @@ pub fn optimize(
     }
     let mut lp_top = to_alp(logical_plan, expr_arena, lp_arena, &mut opt_state)?;
 
+    // The registered rewrites must run before the pushdowns that they enable.
+    #[cfg(feature = "unstable_rewrite_rules")]
+    if let Some(rule) = rewrite_rules::RegisteredRewriteRules::new() {
+        let mut rewrite_rules: [Box<dyn OptimizationRule>; 1] = [Box::new(rule)];
+        lp_top = opt.optimize_loop(&mut rewrite_rules, expr_arena, lp_arena, lp_top)?;
+    }
+
     // get toggle values
     let cluster_with_columns = opt_state.contains(OptFlags::CLUSTER_WITH_COLUMNS);
     let predicate_pushdown = opt_state.contains(OptFlags::PREDICATE_PUSHDOWN);
@@ -87,6 +105,8 @@ pub fn optimize(
     let simplify_expr = opt_state.contains(OptFlags::SIMPLIFY_EXPR);
     let slice_pushdown = opt_state.contains(OptFlags::SLICE_PUSHDOWN);
     let streaming = opt_state.contains(OptFlags::STREAMING);
+    #[allow(unused_variables)]
+    let new_streaming = opt_state.contains(OptFlags::NEW_STREAMING);
     let fast_projection = opt_state.contains(OptFlags::FAST_PROJECTION);
 
     // Don't run optimizations that don't make sense on a single node.
@@ -173,6 +193,20 @@ pub fn optimize(
         rules.push(Box::new(DelayRechunk::new()));
     }
 
+    // The new streaming engine decodes Parquet files with its own reader.
+    #[cfg(all(feature = "parquet", feature = "dtype-categorical"))]
+    if !eager && !new_streaming {
+        rules.push(Box::new(parquet_categorical::ParquetCategorical));
+    }
+    #[cfg(feature = "parquet")]
+    if !eager {
+        rules.push(Box::<parquet_min_max::ParquetMinMax>::default());
+    }
+    // The streaming engines have their own way of splitting the work.
+    if !eager && !streaming && !new_streaming {
+        rules.push(Box::new(partition_wise::PartitionWise));
+    }
+
     if slice_pushdown {
         let slice_pushdown_opt = SlicePushDown::new(streaming);
         let alp = lp_arena.take(lp_top);
@@ -0,0 +1,133 @@
+use either::Either;
+use polars_core::prelude::CompatLevel;
+
+use super::*;
+
+/// Read string columns of a Parquet file that are directly cast to `Categorical` as
+/// `Categorical`. The reader then decodes the dictionary pages into the categories and the
+/// physical keys, instead of materializing all strings and hashing them again in the cast.
+pub(super) struct ParquetCategorical;
+
+/// Whether the expression is a cast of a column to a `Categorical` with physical ordering,
+/// the dtype that the reader decodes dictionaries into.
+fn categorical_cast_of_column(node: Node, expr_arena: &Arena<AExpr>) -> Option<&PlSmallStr> {
+    let AExpr::Cast {
+        expr,
+        dtype: DataType::Categorical(None, CategoricalOrdering::Physical),
+        ..
+    } = expr_arena.get(node)
+    else {
+        return None;
+    };
+    match expr_arena.get(*expr) {
+        AExpr::Column(name) => Some(name),
+        _ => None,
+    }
+}
+
+impl OptimizationRule for ParquetCategorical {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        // The expressions that may be rewritten and all other expressions of the node.
+        let (input, candidates, others, is_hstack): (_, &[ExprIR], &[ExprIR], _) =
+            match lp_arena.get(node) {
+                IR::Select { input, expr, .. } => (*input, expr, &[], false),
+                IR::HStack { input, exprs, .. } => (*input, exprs, &[], true),
+                IR::GroupBy {
+                    input,
+                    keys,
+                    aggs,
+                    apply: None,
+                    ..
+                } => (*input, keys, aggs, false),
+                _ => return None,
+            };
+        let IR::Scan {
+            file_info,
+            predicate,
+            scan_type: FileScan::Parquet { .. },
+            ..
+        } = lp_arena.get(input)
+        else {
+            return None;
+        };
+        let Some(Either::Left(reader_schema)) = &file_info.reader_schema else {
+            return None;
+        };
+
+        let columns = candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(i, e)| {
+                let name = categorical_cast_of_column(e.node(), expr_arena)?;
+                let is_string_in_file = file_info.schema.get(name) == Some(&DataType::String)
+                    && reader_schema
+                        .get(name)
+                        .is_some_and(|field| field.dtype == ArrowDataType::Utf8View);
+                // The other expressions and the predicate of the scan must keep seeing strings.
+                let only_used_here = candidates
+                    .iter()
+                    .chain(others)
+                    .flat_map(|e| aexpr_to_leaf_names_iter(e.node(), expr_arena))
+                    .chain(
+                        predicate
+                            .iter()
+                            .flat_map(|e| aexpr_to_leaf_names_iter(e.node(), expr_arena)),
+                    )
+                    .filter(|leaf| leaf == name)
+                    .count()
+                    == 1;
+                // A `with_columns` keeps the input column if the output name differs.
+                let replaces_column = !is_hstack || e.output_name() == name;
+
+                (is_string_in_file && only_used_here && replaces_column).then(|| (i, name.clone()))
+            })
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return None;
+        }
+
+        let dtype = DataType::Categorical(None, CategoricalOrdering::Physical);
+        let arrow_dtype = dtype.to_arrow(CompatLevel::newest());
+
+        let IR::Scan {
+            file_info,
+            output_schema,
+            ..
+        } = lp_arena.get_mut(input)
+        else {
+            unreachable!()
+        };
+        let Some(Either::Left(reader_schema)) = &mut file_info.reader_schema else {
+            unreachable!()
+        };
+        let reader_schema = Arc::make_mut(reader_schema);
+        let schema = Arc::make_mut(&mut file_info.schema);
+        for (_, name) in &columns {
+            reader_schema.try_get_mut(name).unwrap().dtype = arrow_dtype.clone();
+            *schema.try_get_mut(name).unwrap() = dtype.clone();
+            if let Some(output_schema) = output_schema {
+                if let Ok(output_dtype) = Arc::make_mut(output_schema).try_get_mut(name) {
+                    *output_dtype = dtype.clone();
+                }
+            }
+        }
+
+        let mut lp = lp_arena.get(node).clone();
+        let exprs = match &mut lp {
+            IR::Select { expr, .. } => expr,
+            IR::HStack { exprs, .. } => exprs,
+            IR::GroupBy { keys, .. } => keys,
+            _ => unreachable!(),
+        };
+        for (i, name) in columns {
+            let column = expr_arena.add(AExpr::Column(name));
+            exprs[i] = ExprIR::new(column, exprs[i].output_name_inner().clone());
+        }
+        Some(lp)
+    }
+}
@@ -0,0 +1,156 @@
+use polars_io::parquet::read::ParquetReader;
+use polars_io::SerReader;
+
+use super::*;
+
+/// Answer a `select` of only minimums and maximums of the columns of a Parquet scan from the
+/// statistics of the row groups, without reading any data pages.
+///
+/// The plan is left untouched if a file lacks the statistics of a column, so that the scan
+/// computes the result instead.
+#[derive(Default)]
+pub(super) struct ParquetMinMax {
+    /// Nodes for which the statistics turned out to be incomplete.
+    rejected: PlHashSet<Node>,
+}
+
+/// The column and whether the maximum is taken, if the expression is the minimum or maximum of a
+/// column.
+fn min_max_of_column(e: &ExprIR, expr_arena: &Arena<AExpr>) -> Option<(PlSmallStr, bool)> {
+    let (input, is_max) = match expr_arena.get(e.node()) {
+        AExpr::Agg(IRAggExpr::Min { input, .. }) => (*input, false),
+        AExpr::Agg(IRAggExpr::Max { input, .. }) => (*input, true),
+        _ => return None,
+    };
+    match expr_arena.get(input) {
+        AExpr::Column(name) => Some((name.clone(), is_max)),
+        _ => None,
+    }
+}
+
+/// Whether the statistics of columns of this dtype are exact. Floating point statistics are not
+/// trusted as writers disagree on how to handle NaN, and those of strings may be truncated.
+fn has_exact_statistics(dtype: &DataType) -> bool {
+    dtype.is_integer() || dtype.is_temporal() || dtype.is_bool()
+}
+
+impl ParquetMinMax {
+    fn min_max_from_statistics(
+        sources: &ScanSources,
+        aggs: &[(PlSmallStr, bool)],
+        names: impl Iterator<Item = PlSmallStr>,
+    ) -> PolarsResult<Option<DataFrame>> {
+        let mut results = vec![None::<(Scalar, Scalar)>; aggs.len()];
+        for source in sources.iter() {
+            let mut reader = ParquetReader::new(std::io::Cursor::new(source.to_memslice()?));
+            for ((name, _), result) in aggs.iter().zip(results.iter_mut()) {
+                let Some((min, max)) = reader.min_max_from_statistics(name)? else {
+                    return Ok(None);
+                };
+                *result = Some(match result.take() {
+                    None => (min, max),
+                    Some((acc_min, acc_max)) => {
+                        let reduce = |a: Scalar, b: Scalar, is_max: bool| -> PolarsResult<_> {
+                            let mut s = a.into_series(PlSmallStr::EMPTY);
+                            s.append(&b.into_series(PlSmallStr::EMPTY))?;
+                            if is_max {
+                                s.max_reduce()
+                            } else {
+                                s.min_reduce()
+                            }
+                        };
+                        (reduce(acc_min, min, false)?, reduce(acc_max, max, true)?)
+                    },
+                });
+            }
+        }
+
+        let columns = aggs
+            .iter()
+            .zip(results)
+            .zip(names)
+            .map(|(((_, is_max), result), name)| {
+                let (min, max) = result?;
+                Some(if *is_max { max } else { min }.into_column(name))
+            })
+            .collect::<Option<Vec<_>>>();
+        columns.map(DataFrame::new).transpose()
+    }
+}
+
+impl OptimizationRule for ParquetMinMax {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        let IR::Select {
+            input,
+            expr,
+            schema,
+            ..
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        if self.rejected.contains(&node) {
+            return None;
+        }
+        let IR::Scan {
+            sources,
+            file_info,
+            hive_parts: None,
+            predicate: None,
+            scan_type: FileScan::Parquet { options, .. },
+            file_options:
+                FileScanOptions {
+                    slice: None,
+                    row_index: None,
+                    ..
+                },
+            ..
+        } = lp_arena.get(*input)
+        else {
+            return None;
+        };
+        if !options.use_statistics
+            || options.use_field_ids
+            || sources.is_empty()
+            || sources.is_cloud_url()
+        {
+            return None;
+        }
+
+        let aggs = expr
+            .iter()
+            .map(|e| {
+                let (name, is_max) = min_max_of_column(e, expr_arena)?;
+                let dtype = file_info.schema.get(&name)?;
+                let output_dtype = schema.get(e.output_name())?;
+                (has_exact_statistics(dtype) && dtype == output_dtype).then_some((name, is_max))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        if aggs.is_empty() {
+            return None;
+        }
+
+        // Errors are left to the scan to raise.
+        let names = expr.iter().map(|e| e.output_name().clone());
+        let Ok(Some(df)) = Self::min_max_from_statistics(sources, &aggs, names) else {
+            self.rejected.insert(node);
+            return None;
+        };
+        if df.schema() != **schema {
+            self.rejected.insert(node);
+            return None;
+        }
+
+        Some(IR::DataFrameScan {
+            schema: schema.clone(),
+            df: Arc::new(df),
+            output_schema: None,
+            filter: None,
+        })
+    }
+}
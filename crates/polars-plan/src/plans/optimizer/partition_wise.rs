@@ -0,0 +1,291 @@
+use polars_utils::aliases::PlIndexMap;
+
+use super::*;
+
+/// Run a `group_by` or an equi-join on the partition columns of hive partitioned scans once per
+/// partition and concatenate the results.
+///
+/// All rows of a group, or all rows that can be joined, come from files of the same partition,
+/// so no data has to be exchanged between partitions.
+pub(super) struct PartitionWise;
+
+/// The partition values of a group of sources and the indices of these sources.
+type Partitions = PlIndexMap<Vec<AnyValue<'static>>, Vec<usize>>;
+
+/// Split the sources of the scan at `node` by the values of the hive partition columns `columns`,
+/// in order of first appearance. Returns `None` if the scan cannot be split by source.
+fn partition_sources(
+    node: Node,
+    lp_arena: &Arena<IR>,
+    columns: &[PlSmallStr],
+) -> Option<Partitions> {
+    let IR::Scan {
+        sources: ScanSources::Paths(paths),
+        hive_parts: Some(hive_parts),
+        scan_type,
+        file_options:
+            FileScanOptions {
+                slice: None,
+                row_index: None,
+                ..
+            },
+        ..
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    if matches!(scan_type, FileScan::Anonymous { .. }) || hive_parts.len() != paths.len() {
+        return None;
+    }
+
+    let mut partitions = Partitions::default();
+    for (i, hive_part) in hive_parts.iter().enumerate() {
+        let values = columns
+            .iter()
+            .map(|name| {
+                let stats = hive_part.get_statistics().get_stats(name).ok()?;
+                Some(stats.get_min_state()?.get(0).ok()?.into_static())
+            })
+            .collect::<Option<Vec<_>>>()?;
+        partitions.entry(values).or_default().push(i);
+    }
+    Some(partitions)
+}
+
+/// A copy of the scan at `node` that only reads the sources at `indices`.
+fn scan_sources_subset(node: Node, lp_arena: &Arena<IR>, indices: &[usize]) -> IR {
+    let mut scan = lp_arena.get(node).clone();
+    let IR::Scan {
+        sources,
+        hive_parts,
+        scan_type,
+        ..
+    } = &mut scan
+    else {
+        unreachable!()
+    };
+    let paths = sources.as_paths().unwrap();
+    *sources = ScanSources::Paths(indices.iter().map(|&i| paths[i].clone()).collect());
+    let parts = hive_parts.as_ref().unwrap();
+    *hive_parts = Some(Arc::new(
+        indices.iter().map(|&i| parts[i].clone()).collect(),
+    ));
+
+    // The metadata that was read during conversion belongs to the first source.
+    if indices[0] != 0 {
+        match scan_type {
+            #[cfg(feature = "parquet")]
+            FileScan::Parquet { metadata, .. } => *metadata = None,
+            #[cfg(feature = "ipc")]
+            FileScan::Ipc { metadata, .. } => *metadata = None,
+            _ => {},
+        }
+    }
+    scan
+}
+
+/// For every expression in `exprs`, the name of the hive partition column of the scan at `node`
+/// that it selects as is, if any. `None` if `node` is not a hive partitioned scan.
+fn hive_key_columns(
+    exprs: &[ExprIR],
+    node: Node,
+    lp_arena: &Arena<IR>,
+    expr_arena: &Arena<AExpr>,
+) -> Option<Vec<Option<PlSmallStr>>> {
+    let IR::Scan {
+        hive_parts: Some(hive_parts),
+        ..
+    } = lp_arena.get(node)
+    else {
+        return None;
+    };
+    let hive_schema = hive_parts.first()?.schema();
+    Some(
+        exprs
+            .iter()
+            .map(|e| match expr_arena.get(e.node()) {
+                AExpr::Column(name) if hive_schema.contains(name) => Some(name.clone()),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+fn union(inputs: Vec<Node>) -> IR {
+    IR::Union {
+        inputs,
+        options: UnionOptions {
+            parallel: true,
+            ..Default::default()
+        },
+    }
+}
+
+impl PartitionWise {
+    fn group_by_per_partition(
+        &self,
+        node: Node,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<IR> {
+        let IR::GroupBy {
+            input,
+            keys,
+            apply: None,
+            maintain_order: false,
+            options,
+            ..
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        #[cfg(feature = "dynamic_group_by")]
+        if options.dynamic.is_some() || options.rolling.is_some() {
+            return None;
+        }
+        if options.slice.is_some() {
+            return None;
+        }
+        let columns = hive_key_columns(keys, *input, lp_arena, expr_arena)?
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>();
+        if columns.is_empty() {
+            return None;
+        }
+        let partitions = partition_sources(*input, lp_arena, &columns)?;
+        if partitions.len() < 2 {
+            return None;
+        }
+
+        let input = *input;
+        let inputs = partitions
+            .values()
+            .map(|indices| {
+                let scan = scan_sources_subset(input, lp_arena, indices);
+                let mut group_by = lp_arena.get(node).clone();
+                let IR::GroupBy { input, .. } = &mut group_by else {
+                    unreachable!()
+                };
+                *input = lp_arena.add(scan);
+                lp_arena.add(group_by)
+            })
+            .collect();
+        Some(union(inputs))
+    }
+
+    fn join_per_partition(
+        &self,
+        node: Node,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &Arena<AExpr>,
+    ) -> Option<IR> {
+        let IR::Join {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            options,
+            ..
+        } = lp_arena.get(node)
+        else {
+            return None;
+        };
+        let keep_unmatched_left = match options.args.how {
+            JoinType::Inner => false,
+            JoinType::Left => true,
+            _ => return None,
+        };
+        if options.args.slice.is_some() {
+            return None;
+        }
+        let (input_left, input_right) = (*input_left, *input_right);
+
+        // The pairs of join keys that are partition columns on both sides.
+        let left_keys = hive_key_columns(left_on, input_left, lp_arena, expr_arena)?;
+        let right_keys = hive_key_columns(right_on, input_right, lp_arena, expr_arena)?;
+        let (left_columns, right_columns): (Vec<_>, Vec<_>) = left_keys
+            .into_iter()
+            .zip(right_keys)
+            .filter_map(|(l, r)| Some((l?, r?)))
+            .unzip();
+        if left_columns.is_empty() {
+            return None;
+        }
+        let left_schema = lp_arena.get(input_left).schema(lp_arena).into_owned();
+        let right_schema = lp_arena.get(input_right).schema(lp_arena).into_owned();
+        let same_dtypes = left_columns
+            .iter()
+            .zip(&right_columns)
+            .all(|(l, r)| left_schema.get(l) == right_schema.get(r));
+        if !same_dtypes {
+            return None;
+        }
+
+        let left_partitions = partition_sources(input_left, lp_arena, &left_columns)?;
+        let right_partitions = partition_sources(input_right, lp_arena, &right_columns)?;
+        if left_partitions.len() < 2 {
+            return None;
+        }
+        // Concatenating the partitions keeps the order of the left rows if every partition is a
+        // contiguous run of the sources.
+        let is_contiguous = left_partitions
+            .values()
+            .all(|indices| indices.windows(2).all(|w| w[0] + 1 == w[1]));
+        if !is_contiguous {
+            return None;
+        }
+
+        let mut inputs = Vec::with_capacity(left_partitions.len());
+        for (values, left_indices) in &left_partitions {
+            let right = match right_partitions.get(values) {
+                Some(right_indices) => scan_sources_subset(input_right, lp_arena, right_indices),
+                None if keep_unmatched_left => IR::DataFrameScan {
+                    df: Arc::new(DataFrame::empty_with_schema(&right_schema)),
+                    schema: right_schema.clone(),
+                    output_schema: None,
+                    filter: None,
+                },
+                None => continue,
+            };
+            let left = scan_sources_subset(input_left, lp_arena, left_indices);
+            let mut join = lp_arena.get(node).clone();
+            let IR::Join {
+                input_left,
+                input_right,
+                ..
+            } = &mut join
+            else {
+                unreachable!()
+            };
+            *input_left = lp_arena.add(left);
+            *input_right = lp_arena.add(right);
+            inputs.push(lp_arena.add(join));
+        }
+        if inputs.is_empty() {
+            let schema = lp_arena.get(node).schema(lp_arena).into_owned();
+            return Some(IR::DataFrameScan {
+                df: Arc::new(DataFrame::empty_with_schema(&schema)),
+                schema,
+                output_schema: None,
+                filter: None,
+            });
+        }
+        Some(union(inputs))
+    }
+}
+
+impl OptimizationRule for PartitionWise {
+    fn optimize_plan(
+        &mut self,
+        lp_arena: &mut Arena<IR>,
+        expr_arena: &mut Arena<AExpr>,
+        node: Node,
+    ) -> Option<IR> {
+        match lp_arena.get(node) {
+            IR::GroupBy { .. } => self.group_by_per_partition(node, lp_arena, expr_arena),
+            IR::Join { .. } => self.join_per_partition(node, lp_arena, expr_arena),
+            _ => None,
+        }
+    }
+}
@@ -67,10 +67,24 @@ pub(super) fn process_projection(
             // ourselves
             exprs[0].node()
         } else {
+            let name = match lp_arena.get(input) {
+                // A filter must read its predicate columns anyway, so counting the rows that
+                // pass it needs no other column.
+                IR::Filter { predicate, .. } => {
+                    aexpr_to_leaf_names_iter(predicate.node(), expr_arena).next()
+                },
+                _ => None,
+            };
             // simply select the last column
             // NOTE: the first can be the inserted index column, so that might not work
-            let (first_name, _) = input_schema.try_get_at_index(input_schema.len() - 1)?;
-            let expr = expr_arena.add(AExpr::Column(first_name.clone()));
+            let name = match name {
+                Some(name) => name,
+                None => input_schema
+                    .try_get_at_index(input_schema.len() - 1)?
+                    .0
+                    .clone(),
+            };
+            let expr = expr_arena.add(AExpr::Column(name));
             if !acc_projections.is_empty() {
                 check_double_projection(
                     &exprs[0],
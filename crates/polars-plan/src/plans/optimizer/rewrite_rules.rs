@@ -0,0 +1,99 @@
+use std::sync::RwLock;
+
+use once_cell::sync::Lazy;
+use polars_core::prelude::*;
+
+use super::OptimizationRule;
+use crate::plans::conversion::type_coercion::get_schema;
+use crate::prelude::*;
+
+/// A rewrite of expressions that is registered by a downstream crate, e.g. to canonicalize the
+/// function calls of a domain library so that the optimizer can push them down.
+///
+/// This API is unstable and may change in any release.
+pub trait ExprRewriteRule: Send + Sync {
+    /// Return the replacement of the expression at `expr_node` if it matches the pattern of this
+    /// rule, or `None` to keep it.
+    ///
+    /// The replacement must have the same output name and dtype, else the optimization fails,
+    /// and must not match the rule itself, as the rules run until no rule matches.
+    /// `input_schema` is the schema of the input of the plan node that the expression belongs
+    /// to. Expressions are visited before their inputs and columns and literals are not visited.
+    fn rewrite(
+        &self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        input_schema: &Schema,
+    ) -> PolarsResult<Option<AExpr>>;
+}
+
+type NamedRules = Vec<(PlSmallStr, Arc<dyn ExprRewriteRule>)>;
+static RULES: Lazy<RwLock<NamedRules>> = Lazy::new(Default::default);
+
+/// Register `rule` under `name` to run in the optimizer of every query, in order of
+/// registration. Replaces the rule that is registered under `name`.
+///
+/// The rules run after the conversion to the IR and before the pushdown optimizations.
+pub fn register_expr_rewrite_rule(name: &str, rule: Arc<dyn ExprRewriteRule>) {
+    let mut rules = RULES.write().unwrap();
+    match rules.iter_mut().find(|(n, _)| n == name) {
+        Some((_, r)) => *r = rule,
+        None => rules.push((PlSmallStr::from_str(name), rule)),
+    }
+}
+
+/// Remove the rule that is registered under `name`, returns whether there was one.
+pub fn unregister_expr_rewrite_rule(name: &str) -> bool {
+    let mut rules = RULES.write().unwrap();
+    let len = rules.len();
+    rules.retain(|(n, _)| n != name);
+    rules.len() != len
+}
+
+/// The registered rules at the start of an optimization, so that registering a rule does not
+/// affect queries that are being optimized.
+pub(super) struct RegisteredRewriteRules {
+    rules: NamedRules,
+}
+
+impl RegisteredRewriteRules {
+    pub(super) fn new() -> Option<Self> {
+        let rules = RULES.read().unwrap();
+        if rules.is_empty() {
+            return None;
+        }
+        Some(Self {
+            rules: rules.clone(),
+        })
+    }
+}
+
+impl OptimizationRule for RegisteredRewriteRules {
+    fn optimize_expr(
+        &mut self,
+        expr_arena: &mut Arena<AExpr>,
+        expr_node: Node,
+        lp_arena: &Arena<IR>,
+        lp_node: Node,
+    ) -> PolarsResult<Option<AExpr>> {
+        let input_schema = get_schema(lp_arena, lp_node);
+        for (name, rule) in &self.rules {
+            if let Some(out) = rule.rewrite(expr_arena, expr_node, &input_schema)? {
+                let expected = expr_arena.get(expr_node).to_field(
+                    &input_schema,
+                    Context::Default,
+                    expr_arena,
+                )?;
+                let field = out.to_field(&input_schema, Context::Default, expr_arena)?;
+                polars_ensure!(
+                    field == expected,
+                    SchemaMismatch: "expression rewrite rule `{}` changed the output {:?} \
+                    into {:?}",
+                    name, expected, field
+                );
+                return Ok(Some(out));
+            }
+        }
+        Ok(None)
+    }
+}
@@ -126,10 +126,14 @@ impl Default for UnsafeBool {
 }
 
 bitflags!(
+        /// The flags are serialized by name to JSON and as an integer to the binary (CBOR)
+        /// format, which encodes integers in as few bytes as their value needs. So plans that
+        /// were serialized while the flags were a `u8` still deserialize. Plans that set
+        /// `NON_DETERMINISTIC` cannot be deserialized by versions that predate it.
         #[repr(transparent)]
         #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
         #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
-        pub struct FunctionFlags: u8 {
+        pub struct FunctionFlags: u16 {
             // Raise if use in group by
             const ALLOW_GROUP_AWARE = 1 << 0;
             // For example a `unique` or a `slice`
@@ -169,6 +173,9 @@ bitflags!(
             const OPTIONAL_RE_ENTRANT = 1 << 6;
             /// Whether this function allows no inputs.
             const ALLOW_EMPTY_INPUTS = 1 << 7;
+            /// The output may differ between evaluations on the same input, e.g. an unseeded
+            /// `shuffle`. Such functions are not shared by common subexpression elimination.
+            const NON_DETERMINISTIC = 1 << 8;
         }
 );
 
@@ -301,6 +308,13 @@ pub enum SinkType {
         path: Arc<PathBuf>,
         file_type: FileType,
     },
+    /// A hive partitioned dataset below `path`, with a file for every distinct value of the
+    /// `partition_by` columns.
+    Partitioned {
+        path: Arc<PathBuf>,
+        file_type: FileType,
+        partition_by: Vec<PlSmallStr>,
+    },
     #[cfg(feature = "cloud")]
     Cloud {
         uri: Arc<String>,
@@ -360,6 +374,21 @@ impl ProjectionOptions {
     }
 }
 
+/// How the columns of the inputs of a `concat` are matched with each other.
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum UnionColumnMatching {
+    /// The inputs must have the same columns in the same order.
+    #[default]
+    Exact,
+    /// The columns are matched by position and renamed to the columns of the first input. The
+    /// inputs must have the same number of columns.
+    Position,
+    /// The columns are matched by name with the columns of the first input. The columns that the
+    /// first input does not have are dropped and the missing columns are filled with nulls.
+    Name,
+}
+
 // Arguments given to `concat`. Differs from `UnionOptions` as the latter is IR state.
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
@@ -368,6 +397,8 @@ pub struct UnionArgs {
     pub rechunk: bool,
     pub to_supertypes: bool,
     pub diagonal: bool,
+    /// Ignored for diagonal concatenation, which matches by name and keeps all columns.
+    pub matching: UnionColumnMatching,
     // If it is a union from a scan over multiple files.
     pub from_partitioned_ds: bool,
 }
@@ -379,6 +410,7 @@ impl Default for UnionArgs {
             rechunk: false,
             to_supertypes: false,
             diagonal: false,
+            matching: UnionColumnMatching::Exact,
             from_partitioned_ds: false,
         }
     }
@@ -27,6 +27,73 @@ impl DslPlan {
 
         Ok(lp_arena.get(node).schema(&lp_arena).into_owned())
     }
+
+    /// Report how the unions in this plan reconcile the columns of their inputs.
+    ///
+    /// Returns a frame with a row for every column of a union input that is `"renamed"`,
+    /// `"dropped"`, `"null_filled"` or `"cast"`: the index of the `union` in depth-first order,
+    /// the index of the `input`, the output `column` or the dropped input column, the `action`
+    /// and its `detail`. The schemas are resolved like [`DslPlan::compute_schema`].
+    pub fn union_reconciliation(&self) -> PolarsResult<DataFrame> {
+        let mut rows = vec![];
+        let mut n_unions = 0 as IdxSize;
+        for plan in self {
+            let DslPlan::Union { inputs, args } = plan else {
+                continue;
+            };
+            let union = n_unions;
+            n_unions += 1;
+            let output = plan.compute_schema()?;
+            for (i, input) in inputs.iter().enumerate() {
+                let schema = input.compute_schema()?;
+                let mut push = |column: &PlSmallStr, action: &'static str, detail| {
+                    rows.push((union, i as IdxSize, column.clone(), action, detail))
+                };
+                if !args.diagonal && args.matching != UnionColumnMatching::Name {
+                    for ((name, dtype), (out_name, out_dtype)) in schema.iter().zip(output.iter()) {
+                        if args.matching == UnionColumnMatching::Position && name != out_name {
+                            push(out_name, "renamed", Some(format!("from `{name}`")));
+                        }
+                        if dtype != out_dtype {
+                            push(
+                                out_name,
+                                "cast",
+                                Some(format!("from {dtype} to {out_dtype}")),
+                            );
+                        }
+                    }
+                } else {
+                    for (out_name, out_dtype) in output.iter() {
+                        match schema.get(out_name) {
+                            Some(dtype) if dtype != out_dtype => push(
+                                out_name,
+                                "cast",
+                                Some(format!("from {dtype} to {out_dtype}")),
+                            ),
+                            Some(_) => {},
+                            None => push(out_name, "null_filled", None),
+                        }
+                    }
+                    for name in schema.iter_names().filter(|name| !output.contains(name)) {
+                        push(name, "dropped", None);
+                    }
+                }
+            }
+        }
+
+        let union = rows.iter().map(|r| r.0).collect::<Vec<_>>();
+        let input = rows.iter().map(|r| r.1).collect::<Vec<_>>();
+        let column = rows.iter().map(|r| r.2.as_str()).collect::<Vec<_>>();
+        let action = rows.iter().map(|r| r.3).collect::<Vec<_>>();
+        let detail = rows.iter().map(|r| r.4.as_deref()).collect::<Vec<_>>();
+        DataFrame::new(vec![
+            Column::new(PlSmallStr::from_static("union"), union),
+            Column::new(PlSmallStr::from_static("input"), input),
+            Column::new(PlSmallStr::from_static("column"), column),
+            Column::new(PlSmallStr::from_static("action"), action),
+            Column::new(PlSmallStr::from_static("detail"), detail),
+        ])
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -39,6 +106,25 @@ pub struct FileInfo {
     /// - known size
     /// - estimated size (set to unsize::max if unknown).
     pub row_estimation: (Option<usize>, usize),
+    /// Statistics of the columns, if the file format stores them.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub statistics: Option<Arc<ScanStatistics>>,
+}
+
+/// Statistics of a column of a scan.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ColumnStatistics {
+    pub null_count: Option<usize>,
+    /// A lower bound of the number of distinct values.
+    pub distinct_count: Option<usize>,
+}
+
+/// Statistics of the columns of a scan, taken from the metadata of the first file.
+#[derive(Clone, Debug, Default)]
+pub struct ScanStatistics {
+    /// The number of rows the statistics describe.
+    pub num_rows: usize,
+    pub columns: PlHashMap<PlSmallStr, ColumnStatistics>,
 }
 
 impl FileInfo {
@@ -52,6 +138,7 @@ impl FileInfo {
             schema: schema.clone(),
             reader_schema,
             row_estimation,
+            statistics: None,
         }
     }
 
@@ -87,6 +174,72 @@ fn estimate_sizes(
     }
 }
 
+/// The fraction of rows assumed to pass a predicate that statistics say nothing about.
+#[cfg(feature = "streaming")]
+const DEFAULT_SELECTIVITY: f64 = 0.9;
+
+/// Estimate the fraction of the rows of a scan with `stats` that pass `predicate`.
+#[cfg(feature = "streaming")]
+fn estimate_selectivity(predicate: Node, expr_arena: &Arena<AExpr>, stats: &ScanStatistics) -> f64 {
+    let column_stats = |node: Node| match expr_arena.get(node) {
+        AExpr::Column(name) => stats.columns.get(name),
+        _ => None,
+    };
+    let null_fraction = |column: &ColumnStatistics| -> Option<f64> {
+        let num_rows = stats.num_rows.max(1);
+        Some(column.null_count?.min(num_rows) as f64 / num_rows as f64)
+    };
+    // The fraction of the rows that equal a single literal.
+    let equal_fraction = |left: Node, right: Node| -> Option<f64> {
+        let (column, other) = match column_stats(left) {
+            Some(column) => (column, right),
+            None => (column_stats(right)?, left),
+        };
+        if !matches!(expr_arena.get(other), AExpr::Literal(_)) {
+            return None;
+        }
+        let distinct_count = column.distinct_count?.max(1);
+        Some((1.0 - null_fraction(column).unwrap_or(0.0)) / distinct_count as f64)
+    };
+
+    let selectivity = match expr_arena.get(predicate) {
+        AExpr::BinaryExpr { left, op, right } => match op {
+            Operator::And | Operator::LogicalAnd => Some(
+                estimate_selectivity(*left, expr_arena, stats)
+                    * estimate_selectivity(*right, expr_arena, stats),
+            ),
+            Operator::Or | Operator::LogicalOr => {
+                let left = estimate_selectivity(*left, expr_arena, stats);
+                let right = estimate_selectivity(*right, expr_arena, stats);
+                Some(left + right - left * right)
+            },
+            Operator::Eq => equal_fraction(*left, *right),
+            Operator::NotEq => equal_fraction(*left, *right).map(|equal| {
+                let column = column_stats(*left).or_else(|| column_stats(*right));
+                1.0 - column.and_then(null_fraction).unwrap_or(0.0) - equal
+            }),
+            _ => None,
+        },
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(function),
+            ..
+        } => {
+            let column = input.first().and_then(|e| column_stats(e.node()));
+            match function {
+                BooleanFunction::IsNull => column.and_then(null_fraction),
+                BooleanFunction::IsNotNull => column.and_then(null_fraction).map(|f| 1.0 - f),
+                BooleanFunction::Not => {
+                    Some(1.0 - estimate_selectivity(input[0].node(), expr_arena, stats))
+                },
+                _ => None,
+            }
+        },
+        _ => None,
+    };
+    selectivity.unwrap_or(DEFAULT_SELECTIVITY).clamp(0.0, 1.0)
+}
+
 #[cfg(feature = "streaming")]
 pub fn set_estimated_row_counts(
     root: Node,
@@ -104,8 +257,33 @@ pub fn set_estimated_row_counts(
         }
     }
 
+    /// The statistics of the scan below `node` if the rows of the scan reach `node` unfiltered,
+    /// e.g. through the cache that common subplan elimination inserts over a shared scan.
+    fn unfiltered_scan_statistics(node: Node, lp_arena: &Arena<IR>) -> Option<Arc<ScanStatistics>> {
+        match lp_arena.get(node) {
+            Cache { input, .. } => unfiltered_scan_statistics(*input, lp_arena),
+            Scan {
+                file_info,
+                predicate: None,
+                ..
+            } => file_info.statistics.clone(),
+            _ => None,
+        }
+    }
+
     match lp_arena.get(root) {
         Filter { predicate, input } => {
+            if let Some(stats) = unfiltered_scan_statistics(*input, lp_arena) {
+                let predicate = predicate.node();
+                let (_, estimated_size, filter_count) =
+                    set_estimated_row_counts(*input, lp_arena, expr_arena, _filter_count, scratch);
+                let selectivity = estimate_selectivity(predicate, expr_arena, &stats);
+                return (
+                    None,
+                    (estimated_size as f64 * selectivity) as usize,
+                    filter_count,
+                );
+            }
             _filter_count += expr_arena
                 .iter(predicate.node())
                 .filter(|(_, ae)| matches!(ae, AExpr::BinaryExpr { .. }))
@@ -210,9 +388,23 @@ pub fn set_estimated_row_counts(
             let len = df.height();
             (Some(len), len, _filter_count)
         },
-        Scan { file_info, .. } => {
+        Scan {
+            file_info,
+            predicate,
+            ..
+        } => {
             let (known_size, estimated_size) = file_info.row_estimation;
-            (known_size, estimated_size, _filter_count)
+            match (predicate, &file_info.statistics) {
+                (Some(predicate), Some(stats)) => {
+                    let selectivity = estimate_selectivity(predicate.node(), expr_arena, stats);
+                    (
+                        None,
+                        (estimated_size as f64 * selectivity) as usize,
+                        _filter_count,
+                    )
+                },
+                _ => (known_size, estimated_size, _filter_count),
+            }
         },
         #[cfg(feature = "python")]
         PythonScan { .. } => {
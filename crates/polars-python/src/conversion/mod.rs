@@ -1015,6 +1015,21 @@ impl<'py> FromPyObject<'py> for Wrap<RankMethod> {
     }
 }
 
+#[cfg(feature = "text_search")]
+impl<'py> FromPyObject<'py> for Wrap<TextLanguage> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "english" => TextLanguage::English,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "`language` must be one of {{'english'}}, got {v}",
+                )))
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 impl<'py> FromPyObject<'py> for Wrap<Roll> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
@@ -7,4 +7,8 @@ impl PyExpr {
     fn cat_get_categories(&self) -> Self {
         self.inner.clone().cat().get_categories().into()
     }
+
+    fn cat_compact(&self) -> Self {
+        self.inner.clone().cat().compact().into()
+    }
 }
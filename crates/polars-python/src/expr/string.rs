@@ -338,4 +338,46 @@ impl PyExpr {
             .extract_many(patterns.inner, ascii_case_insensitive, overlapping)
             .into()
     }
+
+    #[cfg(feature = "text_search")]
+    fn str_tokenize(
+        &self,
+        language: Wrap<TextLanguage>,
+        remove_stop_words: bool,
+        stem: bool,
+    ) -> Self {
+        let options = TokenizeOptions {
+            language: language.0,
+            remove_stop_words,
+            stem,
+        };
+        self.inner.clone().str().tokenize(options).into()
+    }
+
+    #[cfg(feature = "text_search")]
+    #[pyo3(signature = (query, language, remove_stop_words, stem, k1, b))]
+    fn str_bm25_score(
+        &self,
+        query: PyExpr,
+        language: Wrap<TextLanguage>,
+        remove_stop_words: bool,
+        stem: bool,
+        k1: f64,
+        b: f64,
+    ) -> Self {
+        let options = Bm25Options {
+            tokenize: TokenizeOptions {
+                language: language.0,
+                remove_stop_words,
+                stem,
+            },
+            k1,
+            b,
+        };
+        self.inner
+            .clone()
+            .str()
+            .bm25_score(query.inner, options)
+            .into()
+    }
 }
@@ -25,6 +25,7 @@ pub fn register_plugin_function(
     cast_to_supertype: bool,
     pass_name_to_apply: bool,
     changes_length: bool,
+    is_deterministic: bool,
 ) -> PyResult<PyExpr> {
     let collect_groups = if is_elementwise {
         ApplyOptions::ElementWise
@@ -42,6 +43,7 @@ pub fn register_plugin_function(
     flags.set(FunctionFlags::CHANGES_LENGTH, changes_length);
     flags.set(FunctionFlags::PASS_NAME_TO_APPLY, pass_name_to_apply);
     flags.set(FunctionFlags::RETURNS_SCALAR, returns_scalar);
+    flags.set(FunctionFlags::NON_DETERMINISTIC, !is_deterministic);
     flags.set(
         FunctionFlags::INPUT_WILDCARD_EXPANSION,
         input_wildcard_expansion,
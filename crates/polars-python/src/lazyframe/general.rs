@@ -284,6 +284,8 @@ impl PyLazyFrame {
             low_memory,
             cloud_options: None,
             use_statistics,
+            use_field_ids: false,
+            skip_corrupt_row_groups: None,
             hive_options,
             glob,
             include_file_paths: include_file_paths.map(|x| x.into()),
@@ -890,6 +890,12 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                         options.cache,
                     )
                         .to_object(py),
+                    StringFunction::Parse(..) => {
+                        return Err(PyNotImplementedError::new_err("parse"))
+                    },
+                    StringFunction::InferDatetimeFormat(_) => {
+                        return Err(PyNotImplementedError::new_err("infer_datetime_format"))
+                    },
                     StringFunction::Split(inclusive) => {
                         (PyStringFunction::Split.into_py(py), inclusive).to_object(py)
                     },
@@ -924,6 +930,18 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                     StringFunction::ExtractMany { .. } => {
                         return Err(PyNotImplementedError::new_err("extract_many"))
                     },
+                    #[cfg(feature = "text_search")]
+                    StringFunction::Tokenize(_) => {
+                        return Err(PyNotImplementedError::new_err("tokenize"))
+                    },
+                    #[cfg(feature = "text_search")]
+                    StringFunction::Bm25Score(_) => {
+                        return Err(PyNotImplementedError::new_err("bm25_score"))
+                    },
+                    #[cfg(feature = "text_search")]
+                    StringFunction::Vectorize { .. } => {
+                        return Err(PyNotImplementedError::new_err("vectorize"))
+                    },
                 },
                 FunctionExpr::StructExpr(_) => {
                     return Err(PyNotImplementedError::new_err("struct expr"))
@@ -1022,6 +1040,10 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                             .map_or_else(|| py.None(), |s| s.to_object(py)),
                     )
                         .into_py(py),
+                    #[cfg(feature = "event_windows")]
+                    TemporalFunction::EventWindow(_) => {
+                        return Err(PyNotImplementedError::new_err("event window"))
+                    },
                 },
                 FunctionExpr::Boolean(boolfun) => match boolfun {
                     BooleanFunction::Any { ignore_nulls } => {
@@ -1247,6 +1269,35 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 FunctionExpr::Cut { .. } => return Err(PyNotImplementedError::new_err("cut")),
                 #[cfg(feature = "cutqcut")]
                 FunctionExpr::QCut { .. } => return Err(PyNotImplementedError::new_err("qcut")),
+                FunctionExpr::OneHot(_) => return Err(PyNotImplementedError::new_err("one_hot")),
+                #[cfg(feature = "target_encoding")]
+                FunctionExpr::TargetEncode(_) | FunctionExpr::TargetEncodeOutOfFold { .. } => {
+                    return Err(PyNotImplementedError::new_err("target_encode"))
+                },
+                #[cfg(feature = "scale")]
+                FunctionExpr::Scale(_) | FunctionExpr::ScaleWith(_) | FunctionExpr::ScaleFit(_) => {
+                    return Err(PyNotImplementedError::new_err("scale"))
+                },
+                #[cfg(feature = "outliers")]
+                FunctionExpr::IsOutlier(_)
+                | FunctionExpr::ModifiedZScore
+                | FunctionExpr::IsolationForest(_) => {
+                    return Err(PyNotImplementedError::new_err("outliers"))
+                },
+                #[cfg(feature = "hypothesis_test")]
+                FunctionExpr::HypothesisTest(_) => {
+                    return Err(PyNotImplementedError::new_err("hypothesis test"))
+                },
+                #[cfg(feature = "assertions")]
+                FunctionExpr::Assert { .. } => {
+                    return Err(PyNotImplementedError::new_err("assert"))
+                },
+                #[cfg(feature = "quantile_sketch")]
+                FunctionExpr::QuantileSketch { .. }
+                | FunctionExpr::QuantileSketchMerge
+                | FunctionExpr::QuantileSketchQuery { .. } => {
+                    return Err(PyNotImplementedError::new_err("quantile_sketch"))
+                },
                 #[cfg(feature = "rle")]
                 FunctionExpr::RLE => ("rle",).to_object(py),
                 #[cfg(feature = "rle")]
@@ -1297,6 +1348,7 @@ pub(crate) fn into_py(py: Python<'_>, expr: &AExpr) -> PyResult<PyObject> {
                 },
                 FunctionExpr::Reinterpret(signed) => ("reinterpret", signed).to_object(py),
                 FunctionExpr::ExtendConstant => ("extend_constant",).to_object(py),
+                FunctionExpr::MaskNulls => ("mask_nulls",).to_object(py),
                 FunctionExpr::Business(_) => {
                     return Err(PyNotImplementedError::new_err("business"))
                 },
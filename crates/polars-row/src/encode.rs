@@ -1,3 +1,5 @@
+use std::mem::MaybeUninit;
+
 use arrow::array::{
     Array, BinaryArray, BinaryViewArray, BooleanArray, DictionaryArray, PrimitiveArray,
     StructArray, Utf8ViewArray,
@@ -7,13 +9,20 @@ use arrow::compute::utils::combine_validities_and;
 use arrow::datatypes::ArrowDataType;
 use arrow::legacy::prelude::{LargeBinaryArray, LargeListArray};
 use arrow::types::NativeType;
-use polars_utils::slice::GetSaferUnchecked;
+use polars_utils::slice::{GetSaferUnchecked, Slice2Uninit};
 use polars_utils::vec::PushUnchecked;
 
-use crate::fixed::FixedLengthEncoding;
+use crate::fixed::{get_null_sentinel, FixedLengthEncoding};
 use crate::row::{EncodingField, RowsEncoded};
 use crate::{with_match_arrow_primitive_type, ArrayRef};
 
+/// Precedes every element of a list in the order-preserving list encoding.
+const LIST_CONTINUATION_TOKEN: u8 = 2;
+
+/// Closes a list in the order-preserving list encoding. It sorts before
+/// [`LIST_CONTINUATION_TOKEN`], so a list sorts before every list it is a prefix of.
+const LIST_TERMINATOR: u8 = 1;
+
 pub fn convert_columns(columns: &[ArrayRef], fields: &[EncodingField]) -> RowsEncoded {
     let mut rows = RowsEncoded::new(vec![], vec![]);
     convert_columns_amortized(columns, fields, &mut rows);
@@ -228,6 +237,7 @@ fn encode_primitive<T: NativeType + FixedLengthEncoding>(
 /// `out` must have enough bytes allocated otherwise it will be out of bounds.
 unsafe fn encode_array(encoder: &Encoder, field: &EncodingField, out: &mut RowsEncoded) {
     match encoder {
+        Encoder::List { field, .. } if !field.no_order => encode_list_ordered(encoder, field, out),
         Encoder::List { .. } => {
             let iter = encoder.list_iter();
             crate::variable::encode_iter(iter, out, &EncodingField::new_unsorted())
@@ -271,6 +281,75 @@ unsafe fn encode_array(encoder: &Encoder, field: &EncodingField, out: &mut RowsE
     }
 }
 
+/// Encodes a list so that rows compare lexicographically by their elements.
+///
+/// Every element is written as a [`LIST_CONTINUATION_TOKEN`] followed by its row encoding
+/// and the list is closed by a [`LIST_TERMINATOR`]. For descending order both tokens are
+/// inverted; the elements are already encoded with the descending flag.
+///
+/// # Safety
+/// `out` must have enough bytes allocated otherwise it will be out of bounds.
+unsafe fn encode_list_ordered(encoder: &Encoder, field: &EncodingField, out: &mut RowsEncoded) {
+    let Encoder::List { original, rows, .. } = encoder else {
+        unreachable!()
+    };
+    let rows = rows.as_ref().unwrap();
+    let (continuation, terminator) = if field.descending {
+        (!LIST_CONTINUATION_TOKEN, !LIST_TERMINATOR)
+    } else {
+        (LIST_CONTINUATION_TOKEN, LIST_TERMINATOR)
+    };
+
+    out.values.set_len(0);
+    let values = out.values.spare_capacity_mut();
+
+    let windows = original.offsets().windows(2);
+    let zipped = ZipValidity::new_with_validity(windows, original.validity());
+    for (offset, opt_window) in out.offsets.iter_mut().skip(1).zip(zipped) {
+        let dst = values.get_unchecked_release_mut(*offset..);
+        let written_len = match opt_window {
+            None => {
+                *dst.get_unchecked_release_mut(0) = MaybeUninit::new(get_null_sentinel(field));
+                1
+            },
+            Some(window) => {
+                let start = *window.get_unchecked_release(0) as usize;
+                let end = *window.get_unchecked_release(1) as usize;
+
+                let mut written_len = 0;
+                for idx in start..end {
+                    let value = rows.value_unchecked(idx).as_uninit();
+                    *dst.get_unchecked_release_mut(written_len) = MaybeUninit::new(continuation);
+                    std::ptr::copy_nonoverlapping(
+                        value.as_ptr(),
+                        dst.as_mut_ptr().add(written_len + 1),
+                        value.len(),
+                    );
+                    written_len += 1 + value.len();
+                }
+                *dst.get_unchecked_release_mut(written_len) = MaybeUninit::new(terminator);
+                written_len + 1
+            },
+        };
+        *offset += written_len;
+    }
+    let offset = out.offsets.last().unwrap();
+    let dst = values.get_unchecked_release_mut(*offset..);
+    // write remainder as zeros
+    dst.fill(MaybeUninit::new(0));
+    out.values.set_len(out.values.capacity())
+}
+
+/// Encoded length of a list row in the order-preserving list encoding.
+#[inline]
+fn ordered_list_encoded_len(value: Option<&[u8]>, n_elements: usize) -> usize {
+    match value {
+        // A token per element and the terminator.
+        Some(value) => value.len() + n_elements + 1,
+        None => 1,
+    }
+}
+
 pub fn encoded_size(dtype: &ArrowDataType) -> usize {
     use ArrowDataType::*;
     match dtype {
@@ -333,6 +412,7 @@ fn allocate_rows_buf(
                 } => {
                     let field = *field;
                     let fields = inner_enc.iter().map(|_| field).collect::<Vec<_>>();
+                    let list_offsets = original.offsets().clone();
                     // Nested lists don't yet work as that requires the leaves not only allocating, but also
                     // encoding. To make that work we must add a flag `in_list` that tell the leaves to immediately
                     // encode the rows instead of only setting the length.
@@ -365,26 +445,26 @@ fn allocate_rows_buf(
                     let values_rows = values_rows.into_array();
                     *rows = Some(values_rows);
 
-                    let iter = enc.list_iter();
+                    let iter = enc.list_iter().zip(list_offsets.lengths());
+                    let encoded_len = |opt_val: Option<&[u8]>, n_elements: usize| {
+                        if field.no_order {
+                            crate::variable::encoded_len(opt_val, &EncodingField::new_unsorted())
+                        } else {
+                            ordered_list_encoded_len(opt_val, n_elements)
+                        }
+                    };
 
                     if processed_count == 0 {
-                        for opt_val in iter {
+                        for (opt_val, n_elements) in iter {
                             unsafe {
                                 lengths.push_unchecked(
-                                    row_size_fixed
-                                        + crate::variable::encoded_len(
-                                            opt_val,
-                                            &EncodingField::new_unsorted(),
-                                        ),
+                                    row_size_fixed + encoded_len(opt_val, n_elements),
                                 );
                             }
                         }
                     } else {
-                        for (opt_val, row_length) in iter.zip(lengths.iter_mut()) {
-                            *row_length += crate::variable::encoded_len(
-                                opt_val,
-                                &EncodingField::new_unsorted(),
-                            )
+                        for ((opt_val, n_elements), row_length) in iter.zip(lengths.iter_mut()) {
+                            *row_length += encoded_len(opt_val, n_elements)
                         }
                     }
                     processed_count += 1;
@@ -637,7 +717,32 @@ mod test {
         let out = out.into_array();
         assert_eq!(
             out.values().iter().map(|v| *v as usize).sum::<usize>(),
-            82411
+            86454
+        );
+    }
+
+    #[test]
+    fn test_list_encode_order() {
+        let values = Int32Array::from_slice([1, 2, 1, 0, 5, 1, 3]);
+        let dtype = LargeListArray::default_datatype(values.dtype().clone());
+        // [[1, 2], [1], [], [0, 5], [1, 3]]
+        let array = LargeListArray::new(
+            dtype,
+            Offsets::<i64>::try_from(vec![0i64, 2, 3, 3, 5, 7])
+                .unwrap()
+                .into(),
+            values.boxed(),
+            None,
         );
+
+        let sorted_rows = |descending: bool| {
+            let fields = &[EncodingField::new_sorted(descending, false)];
+            let out = convert_columns(&[array.clone().boxed()], fields).into_array();
+            let mut idx = (0..out.len()).collect::<Vec<_>>();
+            idx.sort_by_key(|i| out.value(*i));
+            idx
+        };
+        assert_eq!(sorted_rows(false), [2, 3, 1, 0, 4]);
+        assert_eq!(sorted_rows(true), [4, 0, 1, 3, 2]);
     }
 }
@@ -0,0 +1,227 @@
+//! A compact, versioned frame format for shipping [`RowsEncoded`] blocks between processes.
+//!
+//! ```text
+//! stream := MAGIC VERSION block*
+//! block  := n_fields: u32 | field: u8 * n_fields
+//!           n_rows: u64 | offset: u64 * (n_rows + 1)
+//!           values: u8 * offset[n_rows]
+//! ```
+//!
+//! All integers are little endian. Offsets always start at zero. A field is stored as a
+//! bitset of `descending` (bit 0), `nulls_last` (bit 1) and `no_order` (bit 2).
+use std::io::{Read, Write};
+
+use polars_error::{polars_ensure, polars_err, PolarsResult};
+
+use crate::{EncodingField, RowsEncoded};
+
+const MAGIC: [u8; 4] = *b"PLRW";
+const VERSION: u8 = 1;
+
+const DESCENDING: u8 = 1;
+const NULLS_LAST: u8 = 1 << 1;
+const NO_ORDER: u8 = 1 << 2;
+
+const MAX_PREALLOCATED_OFFSETS: usize = 1 << 16;
+
+fn field_to_byte(field: &EncodingField) -> u8 {
+    (field.descending as u8 * DESCENDING)
+        | (field.nulls_last as u8 * NULLS_LAST)
+        | (field.no_order as u8 * NO_ORDER)
+}
+
+fn byte_to_field(byte: u8) -> PolarsResult<EncodingField> {
+    polars_ensure!(
+        byte & !(DESCENDING | NULLS_LAST | NO_ORDER) == 0,
+        ComputeError: "invalid encoding field in row frame: {byte:#x}"
+    );
+    Ok(EncodingField {
+        descending: byte & DESCENDING != 0,
+        nulls_last: byte & NULLS_LAST != 0,
+        no_order: byte & NO_ORDER != 0,
+    })
+}
+
+/// Writes [`RowsEncoded`] blocks together with their encoding schema.
+pub struct RowsWriter<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> RowsWriter<W> {
+    /// Create a new writer and write the stream header.
+    pub fn new(mut writer: W) -> PolarsResult<Self> {
+        writer.write_all(&MAGIC)?;
+        writer.write_all(&[VERSION])?;
+        Ok(Self { writer })
+    }
+
+    /// Write a block of rows that were encoded with `fields`.
+    pub fn write(&mut self, rows: &RowsEncoded, fields: &[EncodingField]) -> PolarsResult<()> {
+        let w = &mut self.writer;
+        w.write_all(&(fields.len() as u32).to_le_bytes())?;
+        let fields = fields.iter().map(field_to_byte).collect::<Vec<_>>();
+        w.write_all(&fields)?;
+
+        let start = rows.offsets.first().copied().unwrap_or(0);
+        let end = rows.offsets.last().copied().unwrap_or(0);
+        let n_rows = rows.offsets.len().saturating_sub(1);
+        w.write_all(&(n_rows as u64).to_le_bytes())?;
+        if rows.offsets.is_empty() {
+            w.write_all(&0u64.to_le_bytes())?;
+        }
+        let mut buf = Vec::with_capacity(rows.offsets.len() * size_of::<u64>());
+        for offset in &rows.offsets {
+            buf.extend_from_slice(&((offset - start) as u64).to_le_bytes());
+        }
+        w.write_all(&buf)?;
+        w.write_all(&rows.values[start..end])?;
+        Ok(())
+    }
+
+    /// Flush and return the inner writer.
+    pub fn finish(mut self) -> PolarsResult<W> {
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+/// Reads the blocks written by a [`RowsWriter`].
+pub struct RowsReader<R: Read> {
+    reader: R,
+}
+
+impl<R: Read> RowsReader<R> {
+    /// Create a new reader and validate the stream header.
+    pub fn new(mut reader: R) -> PolarsResult<Self> {
+        let mut header = [0u8; 5];
+        reader.read_exact(&mut header)?;
+        polars_ensure!(
+            header[..4] == MAGIC,
+            ComputeError: "not a row frame: invalid magic bytes"
+        );
+        polars_ensure!(
+            header[4] == VERSION,
+            ComputeError: "unsupported row frame version {}, expected {}", header[4], VERSION
+        );
+        Ok(Self { reader })
+    }
+
+    /// Read the next block, returns `None` at the end of the stream.
+    pub fn next_block(&mut self) -> PolarsResult<Option<(RowsEncoded, Vec<EncodingField>)>> {
+        let mut n_fields = [0u8; 4];
+        // A clean end of stream can only happen at a block boundary.
+        match self.reader.read(&mut n_fields[..1])? {
+            0 => return Ok(None),
+            _ => self.reader.read_exact(&mut n_fields[1..])?,
+        }
+        let n_fields = u32::from_le_bytes(n_fields) as usize;
+        let fields = self
+            .read_bytes(n_fields, "fields")?
+            .into_iter()
+            .map(byte_to_field)
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let n_offsets = usize::try_from(self.read_u64()?)
+            .ok()
+            .and_then(|n_rows| n_rows.checked_add(1))
+            .ok_or_else(
+                || polars_err!(ComputeError: "corrupt row frame: invalid number of rows"),
+            )?;
+        // The lengths come from the input, so the buffers grow with the data that is actually
+        // read instead of being allocated up front.
+        let mut offsets = Vec::with_capacity(n_offsets.min(MAX_PREALLOCATED_OFFSETS));
+        for i in 0..n_offsets {
+            let offset = usize::try_from(self.read_u64()?).map_err(
+                |_| polars_err!(ComputeError: "corrupt row frame: offset out of bounds"),
+            )?;
+            polars_ensure!(
+                if i == 0 { offset == 0 } else { offsets[i - 1] <= offset },
+                ComputeError: "corrupt row frame: offsets are not monotonically increasing"
+            );
+            offsets.push(offset);
+        }
+        let values = self.read_bytes(offsets[n_offsets - 1], "values")?;
+        Ok(Some((RowsEncoded::new(values, offsets), fields)))
+    }
+
+    /// Read exactly `len` bytes, failing if the stream ends before.
+    fn read_bytes(&mut self, len: usize, what: &str) -> PolarsResult<Vec<u8>> {
+        let mut buf = vec![];
+        (&mut self.reader).take(len as u64).read_to_end(&mut buf)?;
+        polars_ensure!(
+            buf.len() == len,
+            ComputeError: "corrupt row frame: truncated {}", what
+        );
+        Ok(buf)
+    }
+
+    fn read_u64(&mut self) -> PolarsResult<u64> {
+        let mut buf = [0u8; 8];
+        self.reader.read_exact(&mut buf)?;
+        Ok(u64::from_le_bytes(buf))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use arrow::array::{Int32Array, Utf8ViewArray};
+
+    use super::*;
+    use crate::convert_columns;
+
+    #[test]
+    fn test_rows_frame_roundtrip() {
+        let a = Int32Array::from(&[Some(1), None, Some(3)]);
+        let b = Utf8ViewArray::from_slice([Some("a"), Some(""), None]);
+        let fields = [
+            EncodingField::new_sorted(true, false),
+            EncodingField::new_unsorted(),
+        ];
+        let rows = convert_columns(&[Box::new(a), Box::new(b)], &fields);
+
+        let mut writer = RowsWriter::new(vec![]).unwrap();
+        writer.write(&rows, &fields).unwrap();
+        writer.write(&RowsEncoded::default(), &[]).unwrap();
+        let buf = writer.finish().unwrap();
+
+        let mut reader = RowsReader::new(buf.as_slice()).unwrap();
+        let (out, out_fields) = reader.next_block().unwrap().unwrap();
+        assert_eq!(out.offsets, rows.offsets);
+        assert_eq!(out.values, rows.values);
+        assert_eq!(out_fields.len(), 2);
+        assert!(out_fields[0].descending && !out_fields[0].nulls_last);
+        assert!(out_fields[1].no_order);
+
+        let (out, out_fields) = reader.next_block().unwrap().unwrap();
+        assert_eq!(out.iter().count(), 0);
+        assert!(out_fields.is_empty());
+        assert!(reader.next_block().unwrap().is_none());
+
+        assert!(RowsReader::new(&buf[1..]).is_err());
+        let mut reader = RowsReader::new(&buf[..buf.len() - 21]).unwrap();
+        assert!(reader.next_block().is_err());
+    }
+
+    #[test]
+    fn test_rows_frame_malformed_lengths() {
+        let header = [&MAGIC[..], &[VERSION]].concat();
+        let block = |n_rows: u64, offsets: &[u64]| {
+            let mut buf = header.clone();
+            buf.extend_from_slice(&0u32.to_le_bytes());
+            buf.extend_from_slice(&n_rows.to_le_bytes());
+            for offset in offsets {
+                buf.extend_from_slice(&offset.to_le_bytes());
+            }
+            buf
+        };
+        let read = |buf: Vec<u8>| RowsReader::new(buf.as_slice()).unwrap().next_block();
+
+        // The number of rows overflows, or is larger than the input.
+        assert!(read(block(u64::MAX, &[0])).is_err());
+        assert!(read(block(u32::MAX as u64, &[0, 1])).is_err());
+        // The offsets decrease, don't start at zero or point past the input.
+        assert!(read(block(2, &[0, 2, 1])).is_err());
+        assert!(read(block(1, &[1, 2])).is_err());
+        assert!(read(block(1, &[0, u64::MAX])).is_err());
+    }
+}
@@ -244,6 +244,24 @@
 //! These byte arrays will then be encoded using the variable length byte encoding
 //! described above.
 //!
+//! When the order of the rows matters, lists are instead encoded such that they
+//! compare lexicographically. Every element is written as a `02_u8` continuation
+//! token followed by its row encoding, and the list is closed with a `01_u8`
+//! terminator. As the terminator sorts before the continuation token, a list sorts
+//! before every list it is a prefix of. For descending order both tokens are inverted.
+//!
+//! ```text
+//!                         ┌──┬──┬──┬──┬──┬──┬──┬──┬──┬──┐
+//!  [1_u8, 2_u8, 3_u8]     │02│01│01│02│01│02│02│01│03│01│
+//!                         └──┴──┴──┴──┴──┴──┴──┴──┴──┴──┘
+//!
+//!                         ┌──┬──┬──┬──┬──┬──┬──┐
+//!  [1_u8, null]           │02│01│01│02│00│00│01│
+//!                         └──┴──┴──┴──┴──┴──┴──┘
+//!```
+//!
+//! With `[]` represented by a single terminator, and `null` by the null sentinel.
+//!
 //! # Ordering
 //!
 //! ## Float Ordering
@@ -268,6 +286,8 @@ extern crate core;
 pub mod decode;
 pub mod encode;
 pub(crate) mod fixed;
+pub mod io;
+mod partition;
 mod row;
 mod utils;
 pub(crate) mod variable;
@@ -279,4 +299,5 @@ pub use encode::{
     convert_columns, convert_columns_amortized, convert_columns_amortized_no_order,
     convert_columns_no_order,
 };
+pub use partition::hash_partition;
 pub use row::{EncodingField, RowsEncoded};
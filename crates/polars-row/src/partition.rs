@@ -0,0 +1,66 @@
+use std::hash::BuildHasher;
+
+use polars_utils::hashing::hash_to_partition;
+
+use crate::RowsEncoded;
+
+/// Split encoded rows into `n_partitions` by hashing their bytes, without decoding them.
+///
+/// Rows with equal keys always land in the same partition and keep their relative order.
+/// To get the same assignment in different processes, all of them must use a
+/// `build_hasher` with identical (fixed) seeds.
+pub fn hash_partition<S: BuildHasher>(
+    rows: &RowsEncoded,
+    n_partitions: usize,
+    build_hasher: &S,
+) -> Vec<RowsEncoded> {
+    assert!(n_partitions > 0);
+    let n_rows = rows.offsets.len().saturating_sub(1);
+    let expected_rows = n_rows / n_partitions + 1;
+    let expected_bytes = rows.values.len() / n_partitions + 1;
+    let mut out = (0..n_partitions)
+        .map(|_| {
+            let mut offsets = Vec::with_capacity(expected_rows + 1);
+            offsets.push(0);
+            RowsEncoded::new(Vec::with_capacity(expected_bytes), offsets)
+        })
+        .collect::<Vec<_>>();
+    if n_rows == 0 {
+        return out;
+    }
+
+    for row in rows.iter() {
+        let partition = hash_to_partition(build_hasher.hash_one(row), n_partitions);
+        // SAFETY: partition < n_partitions.
+        let part = unsafe { out.get_unchecked_mut(partition) };
+        part.values.extend_from_slice(row);
+        part.offsets.push(part.values.len());
+    }
+    out
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::BuildHasherDefault;
+
+    use arrow::array::Int32Array;
+
+    use super::*;
+    use crate::convert_columns_no_order;
+
+    #[test]
+    fn test_hash_partition() {
+        let a = Int32Array::from_vec(vec![1, 2, 3, 1, 2, 3, 4]);
+        let rows = convert_columns_no_order(&[Box::new(a)]);
+        let hasher = BuildHasherDefault::<DefaultHasher>::default();
+        let parts = hash_partition(&rows, 3, &hasher);
+
+        assert_eq!(parts.iter().map(|p| p.iter().count()).sum::<usize>(), 7);
+        for row in rows.iter() {
+            let n = parts.iter().filter(|p| p.iter().any(|r| r == row)).count();
+            assert_eq!(n, 1);
+        }
+        assert_eq!(hash_partition(&rows, 1, &hasher)[0].values, rows.values);
+    }
+}
@@ -11,10 +11,10 @@ use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
     BinaryOperator, CreateTable, Distinct, ExcludeSelectItem, Expr as SQLExpr, FunctionArg,
-    GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset, OrderBy,
-    Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier, Statement,
-    TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    GroupByExpr, GroupByWithModifier, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType,
+    Offset, OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
@@ -64,6 +64,8 @@ pub struct SQLContext {
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    /// The keys of the grouping sets of the select being translated, for `GROUPING(..)`.
+    pub(crate) grouping_keys: Option<Vec<SQLExpr>>,
 }
 
 impl Default for SQLContext {
@@ -74,6 +76,7 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            grouping_keys: None,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -653,16 +656,61 @@ impl SQLContext {
             replace: vec![],
         };
 
-        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers)?;
+        // Grouping sets are resolved before the projections, which may refer to their keys
+        // with "GROUPING(..)".
+        let sql_grouping_sets = match &select_stmt.group_by {
+            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
+                sql_grouping_sets(group_by_exprs, modifiers)?
+            },
+            GroupByExpr::All(_) => None,
+        };
+        let outer_grouping_keys = std::mem::replace(
+            &mut self.grouping_keys,
+            sql_grouping_sets.as_ref().map(|sets| {
+                let mut keys: Vec<SQLExpr> = vec![];
+                for key in sets.iter().flatten() {
+                    if !keys.contains(key) {
+                        keys.push(key.clone());
+                    }
+                }
+                keys
+            }),
+        );
+        let projections = self.column_projections(select_stmt, &schema, &mut select_modifiers);
+        self.grouping_keys = outer_grouping_keys;
+        let projections = projections?;
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<Expr>>> = None;
         match &select_stmt.group_by {
-            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
-            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
+            // "GROUP BY GROUPING SETS (..)", "ROLLUP (..)" and "CUBE (..)" syntax
+            GroupByExpr::Expressions(..) if sql_grouping_sets.is_some() => {
+                let mut sets = vec![];
+                for sql_set in sql_grouping_sets.unwrap() {
+                    let set = sql_set
+                        .iter()
+                        .map(|e| {
+                            self.expr_or_ordinal(
+                                e,
+                                &projections,
+                                None,
+                                Some(schema.deref()),
+                                "GROUP BY",
+                            )
+                        })
+                        .collect::<PolarsResult<Vec<_>>>()?;
+                    for key in &set {
+                        if !group_by_keys.contains(key) {
+                            group_by_keys.push(key.clone());
+                        }
+                    }
+                    sets.push(set);
                 }
+                grouping_sets = Some(sets);
+            },
+            // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
+            GroupByExpr::Expressions(group_by_exprs, _) => {
                 // translate the group expressions, allowing ordinal values
                 group_by_keys = group_by_exprs
                     .iter()
@@ -708,7 +756,7 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // Final/selected cols, accounting for 'SELECT *' modifiers
             let mut retained_cols = Vec::with_capacity(projections.len());
             let have_order_by = query.order_by.is_some();
@@ -755,7 +803,8 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf =
+                self.process_group_by(lf, &group_by_keys, grouping_sets.as_deref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
@@ -1151,9 +1200,15 @@ impl SQLContext {
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<Expr>]>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        if grouping_sets.is_some() {
+            // "GROUPING(..)" projections are evaluated on the grouping id of the aggregation.
+            Arc::make_mut(&mut schema_before)
+                .with_column(PlSmallStr::from_static(GROUPING_ID_NAME), DataType::UInt32);
+        }
         let group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
@@ -1208,7 +1263,12 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = match grouping_sets {
+            Some(sets) => lf
+                .group_by_grouping_sets(sets)
+                .agg(&aggregation_projection)?,
+            None => lf.group_by(group_by_keys).agg(&aggregation_projection),
+        };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1534,3 +1594,87 @@ fn process_join_constraint(
         polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint);
     }
 }
+
+/// The grouping sets of a "GROUP BY" clause, or `None` for a plain "GROUP BY".
+///
+/// Every element of the clause contributes a list of sets, plain keys contribute a single set
+/// with just the key. The grouping sets are the cartesian product of these lists, as in
+/// "GROUP BY a, ROLLUP (b, c)".
+fn sql_grouping_sets(
+    group_by_exprs: &[SQLExpr],
+    modifiers: &[GroupByWithModifier],
+) -> PolarsResult<Option<Vec<Vec<SQLExpr>>>> {
+    let has_grouping_sets = group_by_exprs.iter().any(|e| {
+        matches!(
+            e,
+            SQLExpr::GroupingSets(_) | SQLExpr::Rollup(_) | SQLExpr::Cube(_)
+        )
+    });
+    let elements = || {
+        group_by_exprs
+            .iter()
+            .map(|e| vec![e.clone()])
+            .collect::<Vec<_>>()
+    };
+    match modifiers {
+        [] if !has_grouping_sets => return Ok(None),
+        [] => {},
+        [GroupByWithModifier::Rollup] if !has_grouping_sets => {
+            return Ok(Some(rollup_sets(&elements())))
+        },
+        [GroupByWithModifier::Cube] if !has_grouping_sets => {
+            return Ok(Some(cube_sets(&elements())?))
+        },
+        _ => {
+            polars_bail!(SQLInterface: "GROUP BY does not support TOTALS or combined CUBE and ROLLUP modifiers")
+        },
+    }
+
+    let mut sets: Vec<Vec<SQLExpr>> = vec![vec![]];
+    for e in group_by_exprs {
+        let element_sets = match e {
+            SQLExpr::GroupingSets(element_sets) => element_sets.clone(),
+            SQLExpr::Rollup(elements) => rollup_sets(elements),
+            SQLExpr::Cube(elements) => cube_sets(elements)?,
+            e => vec![vec![e.clone()]],
+        };
+        sets = sets
+            .iter()
+            .flat_map(|set| {
+                element_sets
+                    .iter()
+                    .map(move |other| set.iter().chain(other).cloned().collect())
+            })
+            .collect();
+    }
+    Ok(Some(sets))
+}
+
+/// "ROLLUP (a, b)" is "GROUPING SETS ((a, b), (a), ())".
+fn rollup_sets(elements: &[Vec<SQLExpr>]) -> Vec<Vec<SQLExpr>> {
+    (0..=elements.len())
+        .rev()
+        .map(|n| elements[..n].concat())
+        .collect()
+}
+
+/// "CUBE (a, b)" is "GROUPING SETS ((a, b), (a), (b), ())".
+fn cube_sets(elements: &[Vec<SQLExpr>]) -> PolarsResult<Vec<Vec<SQLExpr>>> {
+    let n = elements.len();
+    // Every key has a bit in the grouping id, which also bounds the number of sets.
+    polars_ensure!(
+        n <= u32::BITS as usize,
+        SQLInterface: "CUBE supports at most {} elements, got {}", u32::BITS, n
+    );
+    let sets = (0..1u64 << n)
+        .map(|id| {
+            elements
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| id & (1 << (n - 1 - i)) == 0)
+                .flat_map(|(_, element)| element.iter().cloned())
+                .collect()
+        })
+        .collect();
+    Ok(sets)
+}
@@ -2,10 +2,13 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::export::regex;
-use polars_core::prelude::{polars_bail, polars_err, DataType, PolarsResult, Schema, TimeUnit};
+use polars_core::prelude::{
+    polars_bail, polars_ensure, polars_err, DataType, PolarsResult, Schema, TimeUnit,
+};
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
+use polars_lazy::prelude::GROUPING_ID_NAME;
 use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
 use polars_plan::plans::{typed_lit, LiteralValue};
 use polars_plan::prelude::LiteralValue::Null;
@@ -486,6 +489,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function
+    /// Returns a bit mask with a bit set for every argument that is aggregated over, rather
+    /// than grouped on, in the grouping set of the row.
+    /// ```sql
+    /// SELECT a, b, GROUPING(a, b), SUM(c) FROM df GROUP BY ROLLUP (a, b);
+    /// ```
+    Grouping,
     /// SQL 'last' function
     /// Returns the last element of the grouping.
     /// ```sql
@@ -663,6 +673,7 @@ impl PolarsSQLFunctions {
             "first",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
@@ -815,6 +826,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -1240,6 +1252,7 @@ impl SQLFunctionVisitor<'_> {
             Avg => self.visit_unary(Expr::mean),
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
@@ -1643,6 +1656,34 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
+    /// Translate "GROUPING(..)" to the bits of the arguments in the grouping id.
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        let Some(keys) = self.ctx.grouping_keys.as_ref() else {
+            polars_bail!(SQLSyntax: "GROUPING requires GROUP BY with GROUPING SETS, ROLLUP or CUBE");
+        };
+        let args = extract_args(self.func)?;
+        polars_ensure!(!args.is_empty(), SQLSyntax: "GROUPING expects at least one argument");
+        let n_args = args.len();
+        // The grouping id and the result have a bit per key and per argument.
+        polars_ensure!(
+            keys.len() <= u32::BITS as usize && n_args <= u32::BITS as usize,
+            SQLInterface: "GROUPING supports at most {} keys and arguments", u32::BITS
+        );
+        let mut grouping = lit(0u32);
+        for (i, arg) in args.into_iter().enumerate() {
+            let FunctionArgExpr::Expr(sql_expr) = arg else {
+                return self.not_supported_error();
+            };
+            let Some(pos) = keys.iter().position(|key| key == sql_expr) else {
+                polars_bail!(SQLSyntax: "GROUPING argument '{}' is not a GROUP BY key", sql_expr);
+            };
+            let bit =
+                col(GROUPING_ID_NAME).floor_div(lit(1u32 << (keys.len() - 1 - pos))) % lit(2u32);
+            grouping = grouping + bit * lit(1u32 << (n_args - 1 - i));
+        }
+        Ok(grouping.alias("grouping"))
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
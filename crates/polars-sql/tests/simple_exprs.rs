@@ -614,6 +614,52 @@ fn test_group_by_2() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_group_by_grouping_sets() -> PolarsResult<()> {
+    let df = df! {
+        "a" => ["x", "x", "y"],
+        "b" => [1, 2, 1],
+        "v" => [1, 2, 3],
+    }?;
+    let mut context = SQLContext::new();
+    context.register("df", df.lazy());
+
+    let sql = r#"
+    SELECT a, b, GROUPING(a, b) AS g, SUM(v) AS total
+    FROM df
+    GROUP BY GROUPING SETS ((a, b), (b), ())
+    ORDER BY g, a, b"#;
+    let out = context.execute(sql)?.collect()?;
+    assert_eq!(out.get_column_names(), &["a", "b", "g", "total"]);
+    assert_eq!(
+        Vec::from(out.column("g")?.u32()?),
+        &[Some(0), Some(0), Some(0), Some(2), Some(2), Some(3)]
+    );
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(1), Some(2), Some(1), Some(1), Some(2), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("total")?.i32()?),
+        &[Some(1), Some(2), Some(3), Some(4), Some(2), Some(6)]
+    );
+
+    let sql = "SELECT a, b, SUM(v) AS total FROM df GROUP BY ROLLUP (a, b)";
+    assert_eq!(context.execute(sql)?.collect()?.height(), 6);
+    let sql = "SELECT a, b, SUM(v) AS total FROM df GROUP BY CUBE (a, b)";
+    assert_eq!(context.execute(sql)?.collect()?.height(), 8);
+    let sql = "SELECT GROUPING(a) FROM df GROUP BY a";
+    assert!(context.execute(sql).is_err());
+
+    // Every key and argument has a bit in the `u32` grouping id.
+    let many = vec!["a"; 33].join(", ");
+    let sql = format!("SELECT SUM(v) AS total FROM df GROUP BY CUBE ({many})");
+    assert!(context.execute(&sql).is_err());
+    let sql = format!("SELECT GROUPING({many}) AS g FROM df GROUP BY ROLLUP (a)");
+    assert!(context.execute(&sql).is_err());
+    Ok(())
+}
+
 #[test]
 fn test_case_expr() {
     let df = create_sample_df().head(Some(10));
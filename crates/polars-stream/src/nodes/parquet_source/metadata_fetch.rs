@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use futures::StreamExt;
 use polars_error::{polars_bail, PolarsResult};
+use polars_io::parquet::read::_internal::resolve_field_ids;
 use polars_io::prelude::FileMetadata;
 use polars_io::utils::byte_source::{DynByteSource, MemSliceByteSource};
 use polars_io::utils::slice::SplitSlicePosition;
@@ -116,6 +117,7 @@ impl ParquetSourceNode {
             .unwrap_left()
             .len();
         let has_projection = self.file_options.with_columns.is_some();
+        let use_field_ids = self.options.use_field_ids;
 
         let process_metadata_bytes = {
             move |handle: task_handles_ext::AbortOnDropHandle<
@@ -128,6 +130,15 @@ impl ParquetSourceNode {
                 let handle = async_executor::spawn(TaskPriority::Low, async move {
                     let (path_index, byte_source, metadata_bytes) = handle.await.unwrap()?;
 
+                    // The field ids of the first file resolve the columns of all files.
+                    let field_id_reference = match &first_metadata {
+                        _ if !use_field_ids => None,
+                        Some(md) => Some(md.clone()),
+                        None => polars_bail!(
+                            nyi = "resolving Parquet field ids without the metadata of the first file"
+                        ),
+                    };
+
                     let metadata = match first_metadata {
                         Some(md) if path_index == 0 => Arc::unwrap_or_clone(md),
                         _ => polars_parquet::parquet::read::deserialize_metadata(
@@ -137,6 +148,14 @@ impl ParquetSourceNode {
                     };
 
                     let schema = polars_parquet::arrow::read::infer_schema(&metadata)?;
+                    let (metadata, schema) = match &field_id_reference {
+                        Some(reference) => {
+                            let (metadata, schema) =
+                                resolve_field_ids(&metadata, &schema, reference);
+                            (metadata, Arc::unwrap_or_clone(schema))
+                        },
+                        None => (metadata, schema),
+                    };
 
                     if !has_projection && schema.len() > reader_schema_len {
                         polars_bail!(
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use polars_error::PolarsResult;
+use polars_error::{polars_ensure, PolarsResult};
 use polars_expr::planner::{create_physical_expr, get_expr_depth_limit, ExpressionConversionState};
 use polars_expr::reduce::into_reduction;
 use polars_expr::state::ExecutionState;
@@ -306,7 +306,12 @@ fn to_graph_rec<'a>(
                         options,
                         cloud_options,
                         metadata: first_metadata,
+                        skipped_row_groups: _,
                     } => {
+                        polars_ensure!(
+                            !options.skip_corrupt_row_groups,
+                            nyi = "skipping corrupt row groups in the new streaming engine"
+                        );
                         if std::env::var("POLARS_DISABLE_PARQUET_SOURCE").as_deref() != Ok("1") {
                             ctx.graph.add_node(
                                 nodes::parquet_source::ParquetSourceNode::new(
@@ -2,7 +2,7 @@ use arrow::bitmap::MutableBitmap;
 use arrow::legacy::kernels::rolling::no_nulls::{self, RollingAggWindowNoNulls};
 use bytemuck::allocation::zeroed_vec;
 #[cfg(feature = "timezones")]
-use chrono_tz::Tz;
+use polars_core::chunked_array::temporal::parse_time_zone;
 
 use super::*;
 
@@ -152,7 +152,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -190,7 +190,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -228,7 +228,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -266,7 +266,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -304,7 +304,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -342,7 +342,7 @@ where
 {
     let offset_iter = match tz {
         #[cfg(feature = "timezones")]
-        Some(tz) => group_by_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
+        Some(tz) => group_by_values_iter(period, time, closed_window, tu, parse_time_zone(tz).ok()),
         _ => group_by_values_iter(period, time, closed_window, tu, None),
     }?;
     if sorting_indices.is_none() {
@@ -499,3 +499,132 @@ pub(crate) fn to_date(ca: &StringChunked) -> PolarsResult<DateChunked> {
         },
     }
 }
+
+/// Outcome of [`infer_datetime_format`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InferredFormat {
+    /// The group of formats (order of day, month and year) the inference settled on.
+    pub pattern: Option<Pattern>,
+    /// The format of that group that parses most of the sampled values.
+    pub format: Option<String>,
+    /// The number of non-null values that were sampled.
+    pub n_sampled: usize,
+    /// The number of sampled values that `format` parses.
+    pub n_matched: usize,
+    /// Other formats that parse each of the matched values as well, e.g. `%m/%d/%Y` next to
+    /// `%d/%m/%Y` if no sampled day is larger than 12. If this is not empty, the format
+    /// should be set explicitly.
+    pub ambiguous_with: Vec<String>,
+}
+
+fn pattern_formats(pattern: Pattern) -> &'static [&'static str] {
+    match pattern {
+        Pattern::DateDMY => patterns::DATE_D_M_Y,
+        Pattern::DateYMD => patterns::DATE_Y_M_D,
+        Pattern::DatetimeDMY => patterns::DATETIME_D_M_Y,
+        Pattern::DatetimeYMD => patterns::DATETIME_Y_M_D,
+        Pattern::DatetimeYMDZ => patterns::DATETIME_Y_M_D_Z,
+    }
+}
+
+fn parses_with(pattern: Pattern, val: &str, fmt: &str) -> bool {
+    match pattern {
+        Pattern::DateDMY | Pattern::DateYMD => NaiveDate::parse_from_str(val, fmt).is_ok(),
+        Pattern::DatetimeDMY | Pattern::DatetimeYMD => {
+            NaiveDateTime::parse_from_str(val, fmt).is_ok()
+                || NaiveDate::parse_from_str(val, fmt).is_ok()
+        },
+        Pattern::DatetimeYMDZ => DateTime::parse_from_str(val, fmt).is_ok(),
+    }
+}
+
+/// Infer the format of a column of date(time) strings the same way the CSV reader does.
+///
+/// The first `sample_size` non-null values are used. Next to the chosen format, this reports
+/// how many sampled values it parses and which other formats would have been just as valid,
+/// so that a format can be fixed explicitly before running in production.
+pub fn infer_datetime_format(ca: &StringChunked, sample_size: usize) -> InferredFormat {
+    let sample = ca
+        .into_iter()
+        .flatten()
+        .take(sample_size)
+        .collect::<Vec<_>>();
+    let n_sampled = sample.len();
+
+    let Some(pattern) = sample.iter().find_map(|val| infer_pattern_single(val)) else {
+        return InferredFormat {
+            pattern: None,
+            format: None,
+            n_sampled,
+            n_matched: 0,
+            ambiguous_with: vec![],
+        };
+    };
+
+    let count = |fmt: &str| {
+        sample
+            .iter()
+            .filter(|val| parses_with(pattern, val, fmt))
+            .count()
+    };
+    let (format, n_matched) = pattern_formats(pattern)
+        .iter()
+        .map(|fmt| (*fmt, count(fmt)))
+        // `max_by_key` returns the last maximum, we want the first one.
+        .rev()
+        .max_by_key(|(_, n)| *n)
+        .unwrap();
+
+    // Swapping day and month gives the formats the patterns don't cover (e.g. `%m/%d/%Y`).
+    let swapped = format
+        .replace("%d", "\0")
+        .replace("%m", "%d")
+        .replace('\0', "%m");
+    let matched = sample
+        .iter()
+        .filter(|val| parses_with(pattern, val, format))
+        .collect::<Vec<_>>();
+    let ambiguous_with = pattern_formats(pattern)
+        .iter()
+        .map(|fmt| fmt.to_string())
+        .chain(std::iter::once(swapped))
+        .filter(|fmt| fmt != format)
+        .filter(|fmt| {
+            !matched.is_empty() && matched.iter().all(|val| parses_with(pattern, val, fmt))
+        })
+        .collect();
+
+    InferredFormat {
+        pattern: Some(pattern),
+        format: Some(format.to_string()),
+        n_sampled,
+        n_matched,
+        ambiguous_with,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_infer_datetime_format() {
+        let ca = StringChunked::new(
+            PlSmallStr::EMPTY,
+            [Some("01/02/2024"), None, Some("03/04/2024"), Some("foo")],
+        );
+        let out = infer_datetime_format(&ca, 10);
+        assert_eq!(out.pattern, Some(Pattern::DateDMY));
+        assert_eq!(out.format.as_deref(), Some("%d/%m/%Y"));
+        assert_eq!((out.n_sampled, out.n_matched), (3, 2));
+        assert_eq!(out.ambiguous_with, ["%m/%d/%Y"]);
+
+        let ca = StringChunked::new(PlSmallStr::EMPTY, ["13/02/2024", "03/04/2024"]);
+        let out = infer_datetime_format(&ca, 10);
+        assert_eq!(out.format.as_deref(), Some("%d/%m/%Y"));
+        assert!(out.ambiguous_with.is_empty());
+
+        let ca = StringChunked::new(PlSmallStr::EMPTY, ["foo"]);
+        assert_eq!(infer_datetime_format(&ca, 10).format, None);
+    }
+}
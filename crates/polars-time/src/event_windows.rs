@@ -0,0 +1,304 @@
+use arrow::array::LargeListArray;
+use arrow::legacy::time_zone::Tz;
+use arrow::offset::OffsetsBuffer;
+use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::*;
+
+/// The windows that [`event_window`] assigns every event to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EventWindow {
+    /// Consecutive windows of length `every`, shifted by `offset`. Every event lies in exactly
+    /// one window.
+    Tumbling { every: Duration, offset: Duration },
+    /// Windows of length `period` that start every `every`, shifted by `offset`. An event lies in
+    /// all windows that overlap it, which may be none if `period` is shorter than `every`.
+    Hopping {
+        every: Duration,
+        period: Duration,
+        offset: Duration,
+    },
+    /// Windows of events that follow each other by at most `gap`. A window ends `gap` after its
+    /// last event.
+    Session { gap: Duration },
+}
+
+impl EventWindow {
+    fn bounds_dtype(dtype: &DataType) -> DataType {
+        DataType::Struct(vec![
+            Field::new(PlSmallStr::from_static("start"), dtype.clone()),
+            Field::new(PlSmallStr::from_static("end"), dtype.clone()),
+        ])
+    }
+
+    /// The output dtype for an input of `dtype`: a struct with the `start` and `end` of the
+    /// window, or a list of them for hopping windows.
+    pub fn output_dtype(&self, dtype: &DataType) -> DataType {
+        match self {
+            Self::Hopping { .. } => DataType::List(Box::new(Self::bounds_dtype(dtype))),
+            _ => Self::bounds_dtype(dtype),
+        }
+    }
+}
+
+type EarliestBounds = fn(&Window, i64, ClosedWindow, Option<&Tz>) -> PolarsResult<Bounds>;
+type AddDuration = fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+
+fn time_unit_fns(tu: TimeUnit) -> (EarliestBounds, AddDuration) {
+    match tu {
+        TimeUnit::Nanoseconds => (Window::get_earliest_bounds_ns, Duration::add_ns),
+        TimeUnit::Microseconds => (Window::get_earliest_bounds_us, Duration::add_us),
+        TimeUnit::Milliseconds => (Window::get_earliest_bounds_ms, Duration::add_ms),
+    }
+}
+
+/// The start of the last window that starts at or before `t`.
+fn last_start_before(
+    window: &Window,
+    every: &Duration,
+    t: i64,
+    (earliest_bounds, add): (EarliestBounds, AddDuration),
+    tz: Option<&Tz>,
+) -> PolarsResult<i64> {
+    let mut start = earliest_bounds(window, t, ClosedWindow::Left, tz)?.start;
+    loop {
+        let next = add(every, start, tz)?;
+        if next > t {
+            return Ok(start);
+        }
+        start = next;
+    }
+}
+
+fn bounds_struct(
+    name: PlSmallStr,
+    starts: Vec<i64>,
+    ends: Vec<i64>,
+    dtype: &DataType,
+) -> PolarsResult<StructChunked> {
+    let start = Int64Chunked::from_vec(PlSmallStr::from_static("start"), starts)
+        .into_series()
+        .cast(dtype)?;
+    let end = Int64Chunked::from_vec(PlSmallStr::from_static("end"), ends)
+        .into_series()
+        .cast(dtype)?;
+    StructChunked::from_series(name, [start, end].iter())
+}
+
+/// Assign every event of `ca` to its `window` and return the `start` and `end` of the window.
+///
+/// Unlike a dynamic group by this keeps a row per event, so that the windows can be grouped on
+/// later. Tumbling and session windows return a struct of the bounds, hopping windows a list of
+/// them. Null events are assigned to a null window. Session windows require `ca` to be sorted
+/// and a constant `gap`, use them with `over` to find the sessions per key.
+pub fn event_window(
+    ca: &DatetimeChunked,
+    window: EventWindow,
+    tz: Option<&Tz>,
+) -> PolarsResult<Series> {
+    let tu = ca.time_unit();
+    let dtype = DataType::Datetime(tu, ca.time_zone().clone());
+    let fns = time_unit_fns(tu);
+    let validity = ca.is_not_null();
+
+    let out = match window {
+        EventWindow::Tumbling { every, offset } => {
+            polars_ensure!(
+                !every.negative() && !every.is_zero(),
+                InvalidOperation: "tumbling window `every` must be positive, got {}", every
+            );
+            let w = Window::new(every, every, offset);
+            let mut starts = Vec::with_capacity(ca.len());
+            let mut ends = Vec::with_capacity(ca.len());
+            for t in ca.iter() {
+                let start = match t {
+                    Some(t) => last_start_before(&w, &every, t, fns, tz)?,
+                    None => 0,
+                };
+                starts.push(start);
+                ends.push(fns.1(&every, start, tz)?);
+            }
+            bounds_struct(ca.name().clone(), starts, ends, &dtype)?
+                .with_outer_validity_chunked(validity)
+                .into_series()
+        },
+        EventWindow::Hopping {
+            every,
+            period,
+            offset,
+        } => {
+            polars_ensure!(
+                !every.negative() && !every.is_zero() && !period.negative() && !period.is_zero(),
+                InvalidOperation: "hopping window `every` and `period` must be positive, got {} and {}",
+                every, period
+            );
+            let w = Window::new(every, period, offset);
+            let mut back = every;
+            back.negative = true;
+            let mut starts = vec![];
+            let mut ends = vec![];
+            let mut offsets = Vec::with_capacity(ca.len() + 1);
+            offsets.push(0i64);
+            for t in ca.iter() {
+                if let Some(t) = t {
+                    let first = starts.len();
+                    let mut start = last_start_before(&w, &every, t, fns, tz)?;
+                    loop {
+                        let end = fns.1(&period, start, tz)?;
+                        if end <= t {
+                            break;
+                        }
+                        starts.push(start);
+                        ends.push(end);
+                        start = fns.1(&back, start, tz)?;
+                    }
+                    starts[first..].reverse();
+                    ends[first..].reverse();
+                }
+                offsets.push(starts.len() as i64);
+            }
+
+            let entries = bounds_struct(PlSmallStr::EMPTY, starts, ends, &dtype)?.rechunk();
+            let entries = entries.chunks()[0].clone();
+            // SAFETY: the offsets are monotonically increasing and end at the number of entries.
+            let offsets = unsafe { OffsetsBuffer::new_unchecked(offsets.into()) };
+            let arrow_dtype = LargeListArray::default_datatype(entries.dtype().clone());
+            let validity = ca.rechunk().chunks()[0].validity().cloned();
+            let arr = LargeListArray::new(arrow_dtype, offsets, entries, validity).boxed();
+            // SAFETY: the array matches the output dtype.
+            unsafe {
+                Series::from_chunks_and_dtype_unchecked(
+                    ca.name().clone(),
+                    vec![arr],
+                    &window.output_dtype(&dtype),
+                )
+            }
+        },
+        EventWindow::Session { gap } => {
+            ensure_is_constant_duration(gap, ca.time_zone().as_deref(), "gap")?;
+            let gap = match tu {
+                TimeUnit::Nanoseconds => gap.duration_ns(),
+                TimeUnit::Microseconds => gap.duration_us(),
+                TimeUnit::Milliseconds => gap.duration_ms(),
+            };
+            polars_ensure!(
+                gap >= 0,
+                InvalidOperation: "session window `gap` must not be negative"
+            );
+            let mut starts = vec![0; ca.len()];
+            let mut ends = vec![0; ca.len()];
+            // The rows of the current session, which are assigned once its last event is known.
+            let mut session = vec![];
+            let mut session_start = 0;
+            let mut prev: Option<i64> = None;
+            for (i, t) in ca.iter().enumerate() {
+                let Some(t) = t else { continue };
+                if let Some(prev) = prev {
+                    polars_ensure!(
+                        t >= prev,
+                        InvalidOperation: "session windows require the events to be sorted"
+                    );
+                    if t - prev > gap {
+                        for row in session.drain(..) {
+                            starts[row] = session_start;
+                            ends[row] = prev + gap;
+                        }
+                    }
+                }
+                if session.is_empty() {
+                    session_start = t;
+                }
+                session.push(i);
+                prev = Some(t);
+            }
+            if let Some(prev) = prev {
+                for row in session {
+                    starts[row] = session_start;
+                    ends[row] = prev + gap;
+                }
+            }
+            bounds_struct(ca.name().clone(), starts, ends, &dtype)?
+                .with_outer_validity_chunked(validity)
+                .into_series()
+        },
+    };
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn minutes(values: &[Option<i64>]) -> DatetimeChunked {
+        Int64Chunked::from_iter_options(
+            PlSmallStr::from_static("t"),
+            values.iter().map(|v| v.map(|m| m * 60_000)),
+        )
+        .into_datetime(TimeUnit::Milliseconds, None)
+    }
+
+    fn field_minutes(s: &Series, field: &str) -> Vec<Option<i64>> {
+        let ca = s.struct_().unwrap();
+        let field = ca.field_by_name(field).unwrap();
+        let field = field.datetime().unwrap();
+        field.iter().map(|v| v.map(|t| t / 60_000)).collect()
+    }
+
+    #[test]
+    fn test_tumbling_and_session_windows() -> PolarsResult<()> {
+        let ca = minutes(&[Some(1), Some(7), None, Some(12), Some(30)]);
+        let window = EventWindow::Tumbling {
+            every: Duration::parse("10m"),
+            offset: Duration::parse("5m"),
+        };
+        let out = event_window(&ca, window, None)?;
+        assert_eq!(out.null_count(), 1);
+        assert_eq!(
+            field_minutes(&out, "start"),
+            &[Some(-5), Some(5), None, Some(5), Some(25)]
+        );
+        assert_eq!(
+            field_minutes(&out, "end"),
+            &[Some(5), Some(15), None, Some(15), Some(35)]
+        );
+
+        let window = EventWindow::Session {
+            gap: Duration::parse("6m"),
+        };
+        let out = event_window(&ca, window, None)?;
+        assert_eq!(
+            field_minutes(&out, "start"),
+            &[Some(1), Some(1), None, Some(1), Some(30)]
+        );
+        assert_eq!(
+            field_minutes(&out, "end"),
+            &[Some(18), Some(18), None, Some(18), Some(36)]
+        );
+
+        let unsorted = minutes(&[Some(3), Some(1)]);
+        assert!(event_window(&unsorted, window, None).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_hopping_windows() -> PolarsResult<()> {
+        let ca = minutes(&[Some(7), None, Some(10)]);
+        let window = EventWindow::Hopping {
+            every: Duration::parse("5m"),
+            period: Duration::parse("10m"),
+            offset: Duration::parse("0m"),
+        };
+        let out = event_window(&ca, window, None)?;
+        let list = out.list()?;
+        assert_eq!(out.null_count(), 1);
+        let first = list.get_as_series(0).unwrap();
+        assert_eq!(field_minutes(&first, "start"), &[Some(0), Some(5)]);
+        let last = list.get_as_series(2).unwrap();
+        assert_eq!(field_minutes(&last, "start"), &[Some(5), Some(10)]);
+        assert_eq!(field_minutes(&last, "end"), &[Some(15), Some(20)]);
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
 use arrow::legacy::time_zone::Tz;
 use arrow::legacy::utils::CustomIterTools;
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::export::rayon::prelude::*;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -180,9 +182,14 @@ impl Wrap<&DataFrame> {
         };
         match tz {
             #[cfg(feature = "timezones")]
-            Some(tz) => {
-                self.impl_rolling(dt, group_by, options, tu, tz.parse::<Tz>().ok(), time_type)
-            },
+            Some(tz) => self.impl_rolling(
+                dt,
+                group_by,
+                options,
+                tu,
+                parse_time_zone(&tz).ok(),
+                time_type,
+            ),
             _ => self.impl_rolling(dt, group_by, options, tu, None, time_type),
         }
     }
@@ -5,6 +5,8 @@ pub mod chunkedarray;
 mod date_range;
 #[cfg(feature = "timezones")]
 mod dst_offset;
+#[cfg(feature = "event_windows")]
+mod event_windows;
 mod group_by;
 #[cfg(feature = "month_end")]
 mod month_end;
@@ -25,6 +27,8 @@ pub use base_utc_offset::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
+#[cfg(feature = "event_windows")]
+pub use event_windows::*;
 #[cfg(any(feature = "dtype-date", feature = "dtype-datetime"))]
 pub use group_by::dynamic::*;
 #[cfg(feature = "month_end")]
@@ -1,4 +1,6 @@
 use arrow::legacy::time_zone::Tz;
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::prelude::arity::broadcast_try_binary_elementwise;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
@@ -82,7 +84,7 @@ pub fn impl_offset_by(ts: &Series, offsets: &Series) -> PolarsResult<Series> {
             let out = match tz {
                 #[cfg(feature = "timezones")]
                 Some(ref tz) => {
-                    apply_offsets_to_datetime(datetime, offsets, tz.parse::<Tz>().ok().as_ref())?
+                    apply_offsets_to_datetime(datetime, offsets, parse_time_zone(tz).ok().as_ref())?
                 },
                 _ => apply_offsets_to_datetime(datetime, offsets, None)?,
             };
@@ -1,5 +1,7 @@
 use arrow::legacy::time_zone::Tz;
 use arrow::trusted_len::TrustedLen;
+#[cfg(feature = "timezones")]
+use polars_core::chunked_array::temporal::parse_time_zone;
 use polars_core::export::rayon::prelude::*;
 use polars_core::prelude::*;
 use polars_core::utils::_split_offsets;
@@ -183,7 +185,7 @@ pub fn group_by_windows(
                         boundary,
                         closed_window,
                         tu,
-                        tz.parse::<Tz>().ok().as_ref(),
+                        parse_time_zone(tz).ok().as_ref(),
                         start_by,
                     )
                     .unwrap(),
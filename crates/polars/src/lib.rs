@@ -185,9 +185,14 @@
 //! * `random` - Generate arrays with randomly sampled values
 //! * `ndarray`- Convert from [`DataFrame`] to [ndarray](https://docs.rs/ndarray/)
 //! * `temporal` - Conversions between [Chrono](https://docs.rs/chrono/) and Polars for temporal data types
-//! * `timezones` - Activate timezone support.
+//! * `time` - Conversions between the [time](https://docs.rs/time/) crate and Polars for temporal data types
+//! * `rust_decimal` - Conversions between [rust_decimal](https://docs.rs/rust_decimal/) and Polars decimals
+//! * `bigdecimal` - Conversions between [bigdecimal](https://docs.rs/bigdecimal/) and Polars decimals
+//! * `timezones` - Activate timezone support. Fixed offsets of whole hours (e.g. `+01:00`) are
+//!   accepted wherever a time zone is.
 //! * `strings` - Extra string utilities for [`StringChunked`] //!     - `string_pad` - `zfill`, `ljust`, `rjust`
 //!     - `string_to_integer` - `parse_int`
+//!     - `string_parse` - `str.parse`
 //! * `object` - Support for generic ChunkedArrays called [`ObjectChunked<T>`] (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -221,7 +226,8 @@
 //!                           Also activates rolling window group by operations.
 //!     - `sort_multiple` - Allow sorting a [`DataFrame`] on multiple columns
 //!     - `rows` - Create [`DataFrame`] from rows and extract rows from [`DataFrame`]s.
-//!                And activates `pivot` and `transpose` operations
+//!                And activates `pivot` and `transpose` operations.
+//!                With `lazy` it also adds `LazyFrame::scan_rows` to stream rows into a query.
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
 //!     - `cross_join` - Create the Cartesian product of two [`DataFrame`]s.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
@@ -255,6 +261,8 @@
 //!     - `abs` - Get absolute values of [`Series`].
 //!     - `arange` - Range operation on [`Series`].
 //!     - `product` - Compute the product of a [`Series`].
+//!     - `aggregation_state` - Partial aggregation states that can be merged, e.g. across machines.
+//!     - `binary_encryption` - Encrypt and decrypt binary values with keys from a key provider.
 //!     - `diff` - [`diff`] operation.
 //!     - `pct_change` - Compute change percentages.
 //!     - `unique_counts` - Count unique values in expressions.
@@ -33,3 +33,32 @@ fn split_at() {
     assert_eq!(rhs.value(1), "!!!");
     assert_eq!(rhs.value(2), "hello there");
 }
+
+#[test]
+fn to_utf8view_checks_char_boundaries() {
+    let binview = |data: &[u8], start: usize, end: usize| {
+        let view = View::new_from_bytes(&data[start..end], 0, start as u32);
+        let buffers = Arc::new([Buffer::from(data.to_vec())]);
+        BinaryViewArray::try_new(
+            ArrowDataType::BinaryView,
+            Buffer::from(vec![view]),
+            buffers,
+            None,
+        )
+        .unwrap()
+    };
+
+    let data = "the café is open, the crème brûlée is gone".as_bytes();
+    let arr = binview(data, 4, 29).to_utf8view().unwrap();
+    assert_eq!(arr.value(0), "café is open, the crème");
+    // Starts and ends in the middle of `é`.
+    assert!(binview(data, 8, 29).to_utf8view().is_err());
+    assert!(binview(data, 4, 26).to_utf8view().is_err());
+
+    // Bytes that are not referenced by a view may be invalid.
+    let mut data = data.to_vec();
+    data.extend_from_slice(&[0xff, 0xfe]);
+    let arr = binview(&data, 4, 29).to_utf8view().unwrap();
+    assert_eq!(arr.value(0), "café is open, the crème");
+    assert!(binview(&data, 4, data.len()).to_utf8view().is_err());
+}
@@ -618,6 +618,102 @@ fn test_join_floats() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-struct")]
+fn test_join_nested_keys() -> PolarsResult<()> {
+    let key = |name: &str, a: &[i32], b: &[f64]| {
+        StructChunked::from_series(
+            name.into(),
+            [Series::new("a".into(), a), Series::new("b".into(), b)].iter(),
+        )
+        .map(|ca| ca.into_series())
+    };
+    let df_a = DataFrame::new(vec![
+        key("key", &[1, 1, 2], &[0.0, 1.0, 0.0])?.into(),
+        Column::new("left".into(), &["x", "y", "z"]),
+    ])?;
+    let df_b = DataFrame::new(vec![
+        key("key", &[1, 2, 3], &[1.0, -0.0, 0.0])?.into(),
+        Column::new("right".into(), &[10, 20, 30]),
+    ])?;
+
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Inner.into())?;
+    let mut left = out
+        .column("left")?
+        .str()?
+        .into_no_null_iter()
+        .collect::<Vec<_>>();
+    left.sort();
+    assert_eq!(left, &["y", "z"]);
+
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Left.into())?;
+    assert_eq!(
+        Vec::from(out.column("right")?.i32()?),
+        &[None, Some(10), Some(20)]
+    );
+
+    let list_key = |values: &[&[i32]]| {
+        let values = values
+            .iter()
+            .map(|v| Series::new(PlSmallStr::EMPTY, *v))
+            .collect::<Vec<_>>();
+        Column::new("key".into(), values)
+    };
+    let df_a = DataFrame::new(vec![
+        list_key(&[&[1, 2], &[1], &[]]),
+        Column::new("left".into(), &["x", "y", "z"]),
+    ])?;
+    let df_b = DataFrame::new(vec![
+        list_key(&[&[], &[1, 2], &[2, 1]]),
+        Column::new("right".into(), &[10, 20, 30]),
+    ])?;
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Left.into())?;
+    assert_eq!(
+        Vec::from(out.column("right")?.i32()?),
+        &[Some(20), None, Some(10)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(all(feature = "dtype-struct", feature = "dtype-categorical"))]
+fn test_join_nested_categorical_keys() -> PolarsResult<()> {
+    // Without a string cache both sides get their own codes for the same strings.
+    let key = |values: &[&str]| {
+        let cat = Series::new("a".into(), values)
+            .cast(&DataType::Categorical(None, Default::default()))?;
+        StructChunked::from_series("key".into(), [cat].iter()).map(|ca| ca.into_series())
+    };
+    let df_a = DataFrame::new(vec![
+        key(&["x", "y"])?.into(),
+        Column::new("left".into(), &[1, 2]),
+    ])?;
+    let df_b = DataFrame::new(vec![
+        key(&["y", "x"])?.into(),
+        Column::new("right".into(), &[10, 20]),
+    ])?;
+    let out = df_a.join(&df_b, ["key"], ["key"], JoinType::Left.into())?;
+    assert_eq!(
+        Vec::from(out.column("right")?.i32()?),
+        &[Some(20), Some(10)]
+    );
+
+    let nested = Series::new(
+        "key".into(),
+        [Series::new(
+            PlSmallStr::EMPTY,
+            [Series::new(PlSmallStr::EMPTY, &[1])],
+        )],
+    );
+    let df = DataFrame::new(vec![nested.into()])?;
+    assert!(df
+        .join(&df, ["key"], ["key"], JoinType::Inner.into())
+        .is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 #[cfg(feature = "lazy")]
@@ -860,6 +860,28 @@ fn test_utf8() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_utf8_validated_per_chunk() -> PolarsResult<()> {
+    let csv = "a,b\nπ,\"café, \"\"crème\"\"\"\n🐬,x\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    let expected = df![
+        "a" => ["π", "🐬"],
+        "b" => ["café, \"crème\"", "x"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    // An invalid field in an otherwise valid file.
+    let mut csv = csv.as_bytes().to_vec();
+    csv.extend_from_slice(&[b'y', b',', 0xff, b'\n']);
+    assert!(CsvReader::new(Cursor::new(csv.clone())).finish().is_err());
+    let df = CsvReadOptions::default()
+        .with_parse_options(CsvParseOptions::default().with_encoding(CsvEncoding::LossyUtf8))
+        .into_reader_with_file_handle(Cursor::new(csv))
+        .finish()?;
+    assert_eq!(df.column("b")?.str()?.get(2), Some("\u{FFFD}"));
+    Ok(())
+}
+
 #[test]
 fn test_header_inference() -> PolarsResult<()> {
     let csv = r#"not_a_header,really,even_if,it_looks_like_one
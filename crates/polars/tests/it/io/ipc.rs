@@ -151,3 +151,35 @@ fn write_and_read_ipc_empty_series() {
     let df_read = IpcReader::new(buf).finish().unwrap();
     assert!(df.equals(&df_read));
 }
+
+#[test]
+fn test_read_ipc_with_predicate() -> PolarsResult<()> {
+    let batch = |i: i32| {
+        df![
+            "a" => (i * 10..(i + 1) * 10).collect::<Vec<_>>(),
+            "b" => (i * 10..(i + 1) * 10).map(|v| format!("{v}")).collect::<Vec<_>>(),
+        ]
+    };
+    let mut df = batch(0)?;
+    for i in 1..10 {
+        df.vstack_mut(&batch(i)?)?;
+    }
+    let expected = df.filter(&df.column("a")?.as_materialized_series().lt(25)?)?;
+
+    for batch_statistics in [false, true] {
+        let mut buf = Cursor::new(Vec::new());
+        IpcWriter::new(&mut buf)
+            .with_batch_statistics(batch_statistics)
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let predicate = super::LessThan::new(25);
+        let out = IpcReader::new(buf)
+            .with_predicate(Some(predicate.clone()))
+            .finish()?;
+        assert!(out.equals(&expected));
+        // The statistics show that only the first three record batches can pass the predicate.
+        assert_eq!(predicate.evaluated(), if batch_statistics { 3 } else { 10 });
+    }
+    Ok(())
+}
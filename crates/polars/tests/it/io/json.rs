@@ -185,3 +185,29 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_with_predicate() -> PolarsResult<()> {
+    let json = (0..100)
+        .map(|i| format!(r#"{{"a": {i}, "b": "{i}", "c": [{i}]}}"#))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let read = |predicate: Option<Arc<super::LessThan>>| {
+        let mut row_index = polars::io::RowIndex {
+            name: "idx".into(),
+            offset: 0,
+        };
+        JsonLineReader::new(Cursor::new(json.as_str()))
+            .with_n_threads(Some(4))
+            .with_projection(Some(["a".into(), "b".into()].into()))
+            .with_row_index(Some(&mut row_index))
+            .with_predicate(predicate.map(|p| p as _))
+            .finish()
+    };
+
+    let df = read(None)?;
+    let expected = df.filter(&df.column("a")?.as_materialized_series().lt(25)?)?;
+    let out = read(Some(super::LessThan::new(25)))?;
+    assert!(out.equals(&expected));
+    Ok(())
+}
@@ -21,3 +21,58 @@ pub(crate) fn create_df() -> DataFrame {
     let s1 = Column::new("temp".into(), [22.1, 19.9, 7., 2., 3.].as_ref());
     DataFrame::new(vec![s0, s1]).unwrap()
 }
+
+/// The predicate `col("a") < value` on an `Int32` column, counting how often it is evaluated.
+#[cfg(any(feature = "ipc", feature = "json"))]
+pub(crate) struct LessThan {
+    value: i32,
+    evaluated: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(any(feature = "ipc", feature = "json"))]
+impl LessThan {
+    pub(crate) fn new(value: i32) -> Arc<Self> {
+        Arc::new(Self {
+            value,
+            evaluated: Default::default(),
+        })
+    }
+
+    pub(crate) fn evaluated(&self) -> usize {
+        self.evaluated.load(std::sync::atomic::Ordering::Relaxed)
+    }
+}
+
+#[cfg(any(feature = "ipc", feature = "json"))]
+impl polars::io::predicates::PhysicalIoExpr for LessThan {
+    fn evaluate_io(&self, df: &DataFrame) -> PolarsResult<Series> {
+        self.evaluated
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        Ok(df
+            .column("a")?
+            .as_materialized_series()
+            .lt(self.value)?
+            .into_series())
+    }
+
+    fn live_variables(&self) -> Option<Vec<PlSmallStr>> {
+        Some(vec!["a".into()])
+    }
+
+    fn as_stats_evaluator(&self) -> Option<&dyn polars::io::predicates::StatsEvaluator> {
+        Some(self)
+    }
+}
+
+#[cfg(any(feature = "ipc", feature = "json"))]
+impl polars::io::predicates::StatsEvaluator for LessThan {
+    fn should_read(&self, stats: &polars::io::predicates::BatchStats) -> PolarsResult<bool> {
+        let min = stats.get_stats("a")?.to_min();
+        Ok(min.map_or(true, |min| {
+            min.i32()
+                .unwrap()
+                .get(0)
+                .map_or(true, |min| min < self.value)
+        }))
+    }
+}
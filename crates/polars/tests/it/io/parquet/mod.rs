@@ -206,3 +206,169 @@ fn test_vstack_empty_3220() -> PolarsResult<()> {
     assert!(stacked.equals(&read_df));
     Ok(())
 }
+
+#[test]
+fn test_read_skip_corrupt_row_groups() -> PolarsResult<()> {
+    let mut df = df! {
+        "a" => (0..21).collect::<Vec<i32>>(),
+    }?;
+    let mut buf = Cursor::new(Vec::new());
+    ParquetWriter::new(&mut buf)
+        .with_row_group_size(Some(7))
+        .finish(&mut df)?;
+    let mut bytes = buf.into_inner();
+    let metadata = ParquetReader::new(Cursor::new(bytes.clone()))
+        .get_metadata()?
+        .clone();
+    let range = metadata.row_groups[1].full_byte_range();
+    bytes[range.start as usize..range.end as usize].fill(0xFF);
+
+    let read = |salvage: Option<RowGroupSalvage>| {
+        ParquetReader::new(Cursor::new(bytes.clone()))
+            .skip_corrupt_row_groups(salvage)
+            .with_row_index(Some(polars::io::RowIndex {
+                name: "idx".into(),
+                offset: 0,
+            }))
+            .finish()
+    };
+
+    assert!(read(None).is_err());
+    let skipped = SkippedRowGroups::default();
+    let out = read(Some(skipped.for_source("corrupt.parquet".into())))?;
+    let expected = df.with_row_index("idx".into(), None)?;
+    let expected = expected.slice(0, 7).vstack(&expected.slice(14, 7))?;
+    assert!(out.equals(&expected));
+
+    let skipped = skipped.take();
+    assert_eq!(skipped.len(), 1);
+    assert_eq!(&*skipped[0].source, "corrupt.parquet");
+    assert_eq!(skipped[0].row_group, 1);
+    assert_eq!(skipped[0].rows, 7..14);
+
+    // Every scan records the row groups it skipped in its own collector.
+    #[cfg(feature = "lazy")]
+    {
+        let path = std::env::temp_dir().join(format!(
+            "polars_test_skip_corrupt_row_groups_{}.parquet",
+            std::process::id()
+        ));
+        std::fs::write(&path, &bytes)?;
+        let scan = |streaming| {
+            let skipped = SkippedRowGroups::default();
+            let args = ScanArgsParquet {
+                skip_corrupt_row_groups: Some(skipped.clone()),
+                ..Default::default()
+            };
+            let out = LazyFrame::scan_parquet(&path, args)?
+                .with_streaming(streaming)
+                .collect()?;
+            PolarsResult::Ok((out, skipped.take()))
+        };
+        let results = [scan(false), scan(true)];
+        std::fs::remove_file(&path)?;
+        for result in results {
+            let (out, skipped) = result?;
+            assert!(out.equals(&df.slice(0, 7).vstack(&df.slice(14, 7))?));
+            assert_eq!(skipped.len(), 1);
+            assert_eq!(skipped[0].row_group, 1);
+        }
+    }
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy")]
+fn test_scan_parquet_with_field_ids() -> PolarsResult<()> {
+    use ::arrow::array::{Array, Int64Array};
+    use ::arrow::datatypes::{ArrowSchema, Field, Metadata};
+    use ::arrow::record_batch::RecordBatchT;
+    use polars_parquet::arrow::write::{FileWriter, WriteOptions};
+    use polars_parquet::arrow::FIELD_ID_META_KEY;
+    use polars_parquet::write::{
+        CompressionOptions, Encoding, RowGroupIterator, StatisticsOptions, Version,
+    };
+
+    let write = |path: &PathBuf, columns: &[(&str, Option<&str>, Vec<i64>)]| {
+        let schema = columns
+            .iter()
+            .map(|(name, id, _)| {
+                let mut metadata = Metadata::new();
+                if let Some(id) = id {
+                    metadata.insert(FIELD_ID_META_KEY.into(), (*id).into());
+                }
+                Field::new((*name).into(), ArrowDataType::Int64, true).with_metadata(metadata)
+            })
+            .collect::<ArrowSchema>();
+        let arrays = columns
+            .iter()
+            .map(|(_, _, values)| Int64Array::from_vec(values.clone()).boxed())
+            .collect::<Vec<Box<dyn Array>>>();
+        let options = WriteOptions {
+            statistics: StatisticsOptions::full(),
+            compression: CompressionOptions::Uncompressed,
+            version: Version::V2,
+            data_page_size: None,
+        };
+        let row_groups = RowGroupIterator::try_new(
+            std::iter::once(RecordBatchT::try_new(arrays)),
+            &schema,
+            options,
+            vec![vec![Encoding::Plain]; columns.len()],
+        )?;
+        let mut writer = FileWriter::try_new(std::fs::File::create(path)?, schema, options)?;
+        for group in row_groups {
+            writer.write(group?)?;
+        }
+        writer.end(None)?;
+        PolarsResult::Ok(())
+    };
+
+    // Tests run concurrently, give every run its own directory.
+    let dir = std::env::temp_dir().join(format!("polars_test_field_ids_{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let paths = [
+        dir.join("polars_test_field_ids_new.parquet"),
+        dir.join("polars_test_field_ids_old.parquet"),
+    ];
+    // In the older file `id` was named `key`, and `name` was named `label`.
+    write(
+        &paths[0],
+        &[
+            ("id", Some("1"), vec![1, 2]),
+            ("name", Some("2"), vec![10, 20]),
+            ("extra", None, vec![100, 200]),
+        ],
+    )?;
+    write(
+        &paths[1],
+        &[
+            ("label", Some("2"), vec![30]),
+            ("removed", Some("3"), vec![0]),
+            ("key", Some("1"), vec![3]),
+            ("extra", None, vec![300]),
+        ],
+    )?;
+
+    let scan = |use_field_ids, streaming| {
+        let args = ScanArgsParquet {
+            use_field_ids,
+            ..Default::default()
+        };
+        LazyFrame::scan_parquet_files(paths.to_vec().into(), args)?
+            .with_streaming(streaming)
+            .collect()
+    };
+    let expected = df!(
+        "id" => [1i64, 2, 3],
+        "name" => [10i64, 20, 30],
+        "extra" => [100i64, 200, 300],
+    )?;
+    let results = [scan(true, false), scan(true, true), scan(false, false)];
+    std::fs::remove_dir_all(&dir)?;
+    let [default, streaming, without_field_ids] = results;
+    assert!(default?.equals(&expected));
+    assert!(streaming?.equals(&expected));
+    assert!(without_field_ids.is_err());
+    Ok(())
+}
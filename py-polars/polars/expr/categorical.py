@@ -38,3 +38,32 @@ class ExprCatNameSpace:
         └──────┘
         """
         return wrap_expr(self._pyexpr.cat_get_categories())
+
+    def compact(self) -> Expr:
+        """
+        Drop the categories that don't occur in the column from its mapping.
+
+        This shrinks the mapping after a filter removed most categories, and makes
+        subsequent group-bys on the column faster. Local categoricals get a new
+        physical representation, so they can no longer be combined with the columns
+        they were derived from without a string cache.
+
+        Examples
+        --------
+        >>> df = pl.Series(
+        ...     "cats", ["foo", "bar", "foo", "foo", "ham"], dtype=pl.Categorical
+        ... ).to_frame()
+        >>> df.filter(pl.col("cats") != "bar").select(
+        ...     pl.col("cats").cat.compact().cat.get_categories()
+        ... )
+        shape: (2, 1)
+        ┌──────┐
+        │ cats │
+        │ ---  │
+        │ str  │
+        ╞══════╡
+        │ foo  │
+        │ ham  │
+        └──────┘
+        """
+        return wrap_expr(self._pyexpr.cat_compact())
@@ -2681,6 +2681,113 @@ class ExprStringNameSpace:
             self._pyexpr.str_extract_many(patterns, ascii_case_insensitive, overlapping)
         )
 
+    @unstable()
+    def tokenize(
+        self,
+        language: str = "english",
+        *,
+        remove_stop_words: bool = True,
+        stem: bool = True,
+    ) -> Expr:
+        """
+        Split the string values into lowercase word tokens.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        language : {'english'}
+            Language that determines the stop words and the stemmer.
+        remove_stop_words
+            Drop the stop words of the language.
+        stem
+            Reduce the tokens to their stem with the Porter stemmer.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame({"text": ["The foxes are running", None]})
+        >>> df.select(pl.col("text").str.tokenize())
+        shape: (2, 1)
+        ┌────────────────┐
+        │ text           │
+        │ ---            │
+        │ list[str]      │
+        ╞════════════════╡
+        │ ["fox", "run"] │
+        │ null           │
+        └────────────────┘
+        """
+        return wrap_expr(self._pyexpr.str_tokenize(language, remove_stop_words, stem))
+
+    @unstable()
+    def bm25_score(
+        self,
+        query: str | Expr,
+        *,
+        language: str = "english",
+        remove_stop_words: bool = True,
+        stem: bool = True,
+        k1: float = 1.2,
+        b: float = 0.75,
+    ) -> Expr:
+        """
+        Score the string values against a query with Okapi BM25.
+
+        The column is the corpus: document frequencies and the average document
+        length are computed over its non-null values (or over the group in a
+        group-by context). The query is tokenized the same way as the documents.
+
+        .. warning::
+            This functionality is considered **unstable**. It may be changed
+            at any point without it being considered a breaking change.
+
+        Parameters
+        ----------
+        query
+            The search query.
+        language : {'english'}
+            Language that determines the stop words and the stemmer.
+        remove_stop_words
+            Drop the stop words of the language.
+        stem
+            Reduce the tokens to their stem with the Porter stemmer.
+        k1
+            Saturation of the term frequency, must be non-negative.
+        b
+            Strength of the document length normalization, between 0 and 1.
+
+        Examples
+        --------
+        >>> df = pl.DataFrame(
+        ...     {
+        ...         "text": [
+        ...             "the quick brown fox",
+        ...             "foxes are running in the forest",
+        ...             "a lazy dog",
+        ...         ]
+        ...     }
+        ... )
+        >>> df.with_columns(
+        ...     score=pl.col("text").str.bm25_score("running fox").round(3)
+        ... )
+        shape: (3, 2)
+        ┌─────────────────────────────────┬───────┐
+        │ text                            ┆ score │
+        │ ---                             ┆ ---   │
+        │ str                             ┆ f64   │
+        ╞═════════════════════════════════╪═══════╡
+        │ the quick brown fox             ┆ 0.447 │
+        │ foxes are running in the forest ┆ 1.38  │
+        │ a lazy dog                      ┆ 0.0   │
+        └─────────────────────────────────┴───────┘
+        """
+        query = parse_into_expression(query, str_as_lit=True)
+        return wrap_expr(
+            self._pyexpr.str_bm25_score(query, language, remove_stop_words, stem, k1, b)
+        )
+
     def join(self, delimiter: str = "", *, ignore_nulls: bool = True) -> Expr:
         """
         Vertically concatenate the string values in the column to a single string value.
@@ -30,6 +30,7 @@ def register_plugin_function(
     cast_to_supertype: bool = False,
     input_wildcard_expansion: bool = False,
     pass_name_to_apply: bool = False,
+    is_deterministic: bool = True,
 ) -> Expr:
     """
     Register a plugin function.
@@ -67,6 +68,10 @@ def register_plugin_function(
     pass_name_to_apply
         If set to `True`, the `Series` passed to the function in a group-by operation
         will ensure the name is set. This is an extra heap allocation per group.
+    is_deterministic
+        Indicate that the function gives the same output every time it is called on
+        the same input. If set to `False`, repeated calls are not merged into a
+        single one by common subexpression elimination.
 
     Returns
     -------
@@ -96,6 +101,7 @@ def register_plugin_function(
             cast_to_supertype=cast_to_supertype,
             pass_name_to_apply=pass_name_to_apply,
             changes_length=changes_length,
+            is_deterministic=is_deterministic,
         )
     )
 
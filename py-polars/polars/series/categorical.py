@@ -37,6 +37,27 @@ class CatNameSpace:
         ]
         """
 
+    def compact(self) -> Series:
+        """
+        Drop the categories that don't occur in the series from its mapping.
+
+        This shrinks the mapping after a filter removed most categories, and makes
+        subsequent group-bys on the series faster. Local categoricals get a new
+        physical representation, so they can no longer be combined with the series
+        they were derived from without a string cache.
+
+        Examples
+        --------
+        >>> s = pl.Series(["foo", "bar", "foo", "foo", "ham"], dtype=pl.Categorical)
+        >>> s.filter(s != "bar").cat.compact().cat.get_categories()
+        shape: (2,)
+        Series: '' [str]
+        [
+            "foo"
+            "ham"
+        ]
+        """
+
     def is_local(self) -> bool:
         """
         Return whether or not the column is a local categorical.